@@ -0,0 +1,123 @@
+CREATE TABLE webhook_reliability_events_queue (
+    `merchant_id` String,
+    `payment_id` String,
+    `attempt_id` String,
+    `connector` LowCardinality(String),
+    `payment_method` LowCardinality(String),
+    `payment_method_type` LowCardinality(Nullable(String)),
+    `arrival_status` LowCardinality(String),
+    `expected_window_secs` Int64,
+    `arrival_latency_secs` Nullable(Int64),
+    `created_at_timestamp` DateTime64(3)
+) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
+kafka_topic_list = 'hyperswitch-webhook-reliability-events',
+kafka_group_name = 'hyper',
+kafka_format = 'JSONEachRow',
+kafka_handle_error_mode = 'stream';
+
+CREATE TABLE webhook_reliability_events (
+    `merchant_id` LowCardinality(String),
+    `payment_id` String,
+    `attempt_id` String,
+    `connector` LowCardinality(String),
+    `payment_method` LowCardinality(String),
+    `payment_method_type` LowCardinality(Nullable(String)),
+    `arrival_status` LowCardinality(String),
+    `expected_window_secs` Int64,
+    `arrival_latency_secs` Nullable(Int64),
+    `created_at` DateTime64(3),
+    `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
+    INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
+    INDEX arrivalStatusIndex arrival_status TYPE bloom_filter GRANULARITY 1
+) ENGINE = MergeTree PARTITION BY toStartOfDay(created_at)
+ORDER BY
+    (created_at, merchant_id, connector) TTL inserted_at + toIntervalMonth(18) SETTINGS index_granularity = 8192;
+
+CREATE MATERIALIZED VIEW webhook_reliability_events_mv TO webhook_reliability_events (
+    `merchant_id` String,
+    `payment_id` String,
+    `attempt_id` String,
+    `connector` LowCardinality(String),
+    `payment_method` LowCardinality(String),
+    `payment_method_type` LowCardinality(Nullable(String)),
+    `arrival_status` LowCardinality(String),
+    `expected_window_secs` Int64,
+    `arrival_latency_secs` Nullable(Int64),
+    `created_at` DateTime64(3),
+    `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4)
+) AS
+SELECT
+    merchant_id,
+    payment_id,
+    attempt_id,
+    connector,
+    payment_method,
+    payment_method_type,
+    arrival_status,
+    expected_window_secs,
+    arrival_latency_secs,
+    created_at_timestamp AS created_at,
+    now() AS inserted_at
+FROM
+    webhook_reliability_events_queue
+WHERE
+    length(_error) = 0;
+
+-- Hourly webhook reliability per connector and payment method, to alert on degrading connector
+-- webhooks. Payments resolved by a sync before their webhook arrived are recorded as resolved
+-- without webhook rather than missed. A webhook which is received late is recorded both as missed
+-- (or resolved without webhook) and as received late, so the missing rate is
+-- `(missed_count + resolved_without_webhook_count) / (received_count + missed_count +
+-- resolved_without_webhook_count)` and the average arrival latency is
+-- `arrival_latency_secs_sum / (received_count + received_late_count)`.
+CREATE TABLE webhook_reliability_hourly (
+    `bucket` DateTime,
+    `connector` LowCardinality(String),
+    `payment_method` LowCardinality(String),
+    `received_count` UInt64,
+    `received_late_count` UInt64,
+    `missed_count` UInt64,
+    `resolved_without_webhook_count` UInt64,
+    `arrival_latency_secs_sum` Int64
+) ENGINE = SummingMergeTree
+ORDER BY
+    (bucket, connector, payment_method) TTL bucket + toIntervalMonth(18) SETTINGS index_granularity = 8192;
+
+CREATE MATERIALIZED VIEW webhook_reliability_hourly_mv TO webhook_reliability_hourly AS
+SELECT
+    toStartOfHour(created_at_timestamp) AS bucket,
+    connector,
+    payment_method,
+    countIf(arrival_status = 'received') AS received_count,
+    countIf(arrival_status = 'received_late') AS received_late_count,
+    countIf(arrival_status = 'missed') AS missed_count,
+    countIf(arrival_status = 'resolved_without_webhook') AS resolved_without_webhook_count,
+    sum(ifNull(arrival_latency_secs, 0)) AS arrival_latency_secs_sum
+FROM
+    webhook_reliability_events_queue
+WHERE
+    length(_error) = 0
+GROUP BY
+    bucket,
+    connector,
+    payment_method;
+
+CREATE MATERIALIZED VIEW webhook_reliability_parse_errors (
+    `topic` String,
+    `partition` Int64,
+    `offset` Int64,
+    `raw` String,
+    `error` String
+) ENGINE = MergeTree
+ORDER BY
+    (topic, partition, offset) SETTINGS index_granularity = 8192 AS
+SELECT
+    _topic AS topic,
+    _partition AS partition,
+    _offset AS offset,
+    _raw_message AS raw,
+    _error AS error
+FROM
+    webhook_reliability_events_queue
+WHERE
+    length(_error) > 0;
\ No newline at end of file
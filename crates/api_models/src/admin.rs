@@ -913,6 +913,29 @@ pub struct BusinessProfileCreate {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Policy to be applied on manually captured payments whose authorization is about to expire
+    pub authorization_expiry_config: Option<AuthorizationExpiryConfig>,
+
+    /// Time in seconds after which payments created under this business profile that are not yet confirmed are moved to `expired`. Payments do not expire if this is not set
+    #[schema(example = 86400)]
+    pub intent_expiry: Option<u32>,
+
+    /// Maximum percentage of the capturable amount by which a capture can exceed the capturable amount, for connectors and card networks which support over-capture. Over-capture is disabled if this is not set
+    #[schema(example = 20)]
+    pub max_over_capture_percentage: Option<u8>,
+
+    /// Policy deciding which refunds require the approval of a dashboard user before being sent to the connector. Refunds are sent to the connector right away if this is not set
+    pub refund_approval_policy: Option<RefundApprovalPolicy>,
+
+    /// Policy deciding whether payments matched to a pre-dispute alert are refunded automatically. Alerts are only recorded for manual review if this is not set
+    pub dispute_alert_policy: Option<DisputeAlertPolicy>,
+
+    /// Additional endpoints to which webhooks are delivered, each receiving only the events it is subscribed to. The URL in `webhook_details` continues to receive all events
+    pub webhook_endpoints: Option<Vec<WebhookEndpoint>>,
+
+    /// Policy deciding whether refunds which the connector cannot process are fulfilled through a payout to the customer's saved payout method. Such refunds fail if this is not set
+    pub refund_payout_fallback_policy: Option<RefundPayoutFallbackPolicy>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -982,6 +1005,29 @@ pub struct BusinessProfileResponse {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Policy to be applied on manually captured payments whose authorization is about to expire
+    pub authorization_expiry_config: Option<AuthorizationExpiryConfig>,
+
+    /// Time in seconds after which payments created under this business profile that are not yet confirmed are moved to `expired`. Payments do not expire if this is not set
+    #[schema(example = 86400)]
+    pub intent_expiry: Option<i64>,
+
+    /// Maximum percentage of the capturable amount by which a capture can exceed the capturable amount, for connectors and card networks which support over-capture. Over-capture is disabled if this is not set
+    #[schema(example = 20)]
+    pub max_over_capture_percentage: Option<u8>,
+
+    /// Policy deciding which refunds require the approval of a dashboard user before being sent to the connector. Refunds are sent to the connector right away if this is not set
+    pub refund_approval_policy: Option<RefundApprovalPolicy>,
+
+    /// Policy deciding whether payments matched to a pre-dispute alert are refunded automatically. Alerts are only recorded for manual review if this is not set
+    pub dispute_alert_policy: Option<DisputeAlertPolicy>,
+
+    /// Additional endpoints to which webhooks are delivered, each receiving only the events it is subscribed to. The URL in `webhook_details` continues to receive all events. The signing secrets of the endpoints are not included, and can be retrieved through the webhook secret endpoint of the business profile
+    pub webhook_endpoints: Option<Vec<WebhookEndpointResponse>>,
+
+    /// Policy deciding whether refunds which the connector cannot process are fulfilled through a payout to the customer's saved payout method. Such refunds fail if this is not set
+    pub refund_payout_fallback_policy: Option<RefundPayoutFallbackPolicy>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1046,6 +1092,226 @@ pub struct BusinessProfileUpdate {
 
     /// Merchant's config to support extended card info feature
     pub extended_card_info_config: Option<ExtendedCardInfoConfig>,
+
+    /// Policy to be applied on manually captured payments whose authorization is about to expire
+    pub authorization_expiry_config: Option<AuthorizationExpiryConfig>,
+
+    /// Time in seconds after which payments created under this business profile that are not yet confirmed are moved to `expired`. Payments do not expire if this is not set
+    #[schema(example = 86400)]
+    pub intent_expiry: Option<u32>,
+
+    /// Maximum percentage of the capturable amount by which a capture can exceed the capturable amount, for connectors and card networks which support over-capture. Over-capture is disabled if this is not set
+    #[schema(example = 20)]
+    pub max_over_capture_percentage: Option<u8>,
+
+    /// Policy deciding which refunds require the approval of a dashboard user before being sent to the connector. Refunds are sent to the connector right away if this is not set
+    pub refund_approval_policy: Option<RefundApprovalPolicy>,
+
+    /// Policy deciding whether payments matched to a pre-dispute alert are refunded automatically. Alerts are only recorded for manual review if this is not set
+    pub dispute_alert_policy: Option<DisputeAlertPolicy>,
+
+    /// Additional endpoints to which webhooks are delivered, each receiving only the events it is subscribed to. The URL in `webhook_details` continues to receive all events
+    pub webhook_endpoints: Option<Vec<WebhookEndpoint>>,
+
+    /// Policy deciding whether refunds which the connector cannot process are fulfilled through a payout to the customer's saved payout method. Such refunds fail if this is not set
+    pub refund_payout_fallback_policy: Option<RefundPayoutFallbackPolicy>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuthorizationExpiryConfig {
+    /// Number of days for which an authorization stays valid. Overrides the connector and card network defaults
+    #[schema(example = 7)]
+    pub validity_days: Option<u16>,
+
+    /// Action to be taken on the payment before the authorization expires
+    #[schema(value_type = AuthorizationExpiryAction, default = "no_action")]
+    #[serde(default)]
+    pub expiry_action: api_enums::AuthorizationExpiryAction,
+
+    /// Number of hours before the expiry at which the `payment_authorization_expiring` webhook is sent
+    #[schema(example = 24)]
+    pub warning_offset_hours: Option<u16>,
+
+    /// Number of hours before the expiry at which the `expiry_action` is performed
+    #[schema(example = 2)]
+    pub action_offset_hours: Option<u16>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RefundApprovalPolicy {
+    /// Refunds of an amount greater than or equal to this amount require approval. The amount is in the lowest denomination of the currency of the payment
+    #[schema(example = 100000)]
+    pub amount_threshold: Option<i64>,
+
+    /// Refunds created with any of these reasons require approval, irrespective of their amount. The reasons are compared case-insensitively
+    #[schema(example = json!(["goodwill", "chargeback_avoidance"]))]
+    pub reasons: Option<Vec<String>>,
+
+    /// Number of hours after which a refund that is still pending approval is rejected automatically. Defaults to 72 hours
+    #[schema(example = 72)]
+    pub approval_expiry_hours: Option<u16>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAlertPolicy {
+    /// Whether payments matched to a pre-dispute alert are refunded automatically
+    #[schema(example = true)]
+    pub auto_refund: bool,
+
+    /// Alerts for an amount greater than this amount are left for manual review instead of being refunded automatically. The amount is in the lowest denomination of the currency of the payment
+    #[schema(example = 50000)]
+    pub max_refund_amount: Option<i64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RefundPayoutFallbackPolicy {
+    /// Whether refunds are fulfilled through a payout when the connector does not support refunding the payment
+    #[schema(example = true)]
+    pub enabled: bool,
+
+    /// Error codes returned by the connector when refunding a payment, for which the refund is also fulfilled through a payout. Refunds failed by the connector with any other error code are not fulfilled through a payout
+    #[schema(example = json!(["charge_expired_for_refund"]))]
+    pub connector_error_codes: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpoint {
+    /// The identifier of the endpoint, unique within the business profile. Delivery attempts to this endpoint are recorded against this identifier
+    #[schema(max_length = 32, example = "finance")]
+    pub endpoint_id: String,
+
+    /// The URL to which webhooks are delivered. This must be set if `message_queue` is not set
+    #[schema(value_type = Option<String>, example = "https://finance.ekart.com/webhooks")]
+    pub url: Option<Secret<String>>,
+
+    /// The message queue to which webhooks are published instead of being delivered to a URL. Either `url` or `message_queue` must be set
+    pub message_queue: Option<WebhookMessageQueue>,
+
+    /// The classes of events delivered to this endpoint. Events of all classes are delivered if this is not set
+    #[schema(value_type = Option<Vec<EventClass>>, example = json!(["refunds"]))]
+    pub enabled_event_classes: Option<Vec<api_enums::EventClass>>,
+
+    /// The types of events delivered to this endpoint. Events of all types are delivered if this is not set
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["refund_succeeded", "refund_failed"]))]
+    pub enabled_event_types: Option<Vec<api_enums::EventType>>,
+
+    /// The format of the webhook payload. The compatible connector of the merchant account decides the format if this is not set
+    #[schema(value_type = Option<WebhookApiVersion>, example = "v1")]
+    pub api_version: Option<api_enums::WebhookApiVersion>,
+
+    /// The key used to sign webhooks delivered to this endpoint. The webhook signing secret of the business profile is used if this is not set
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// Whether webhooks are delivered to this endpoint
+    #[schema(default = true, example = true)]
+    #[serde(default = "webhook_endpoint_enabled_default")]
+    pub enabled: bool,
+}
+
+fn webhook_endpoint_enabled_default() -> bool {
+    true
+}
+
+impl WebhookEndpoint {
+    /// Whether an event of the specified class and type should be delivered to this endpoint
+    pub fn is_subscribed_to(
+        &self,
+        event_class: api_enums::EventClass,
+        event_type: api_enums::EventType,
+    ) -> bool {
+        self.enabled
+            && self
+                .enabled_event_classes
+                .as_ref()
+                .map_or(true, |event_classes| event_classes.contains(&event_class))
+            && self
+                .enabled_event_types
+                .as_ref()
+                .map_or(true, |event_types| event_types.contains(&event_type))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier of the endpoint, unique within the business profile. Delivery attempts to this endpoint are recorded against this identifier
+    #[schema(max_length = 32, example = "finance")]
+    pub endpoint_id: String,
+
+    /// The URL to which webhooks are delivered
+    #[schema(value_type = Option<String>, example = "https://finance.ekart.com/webhooks")]
+    pub url: Option<Secret<String>>,
+
+    /// The message queue to which webhooks are published instead of being delivered to a URL
+    pub message_queue: Option<WebhookMessageQueue>,
+
+    /// The classes of events delivered to this endpoint. Events of all classes are delivered if this is not set
+    #[schema(value_type = Option<Vec<EventClass>>, example = json!(["refunds"]))]
+    pub enabled_event_classes: Option<Vec<api_enums::EventClass>>,
+
+    /// The types of events delivered to this endpoint. Events of all types are delivered if this is not set
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["refund_succeeded", "refund_failed"]))]
+    pub enabled_event_types: Option<Vec<api_enums::EventType>>,
+
+    /// The format of the webhook payload. The compatible connector of the merchant account decides the format if this is not set
+    #[schema(value_type = Option<WebhookApiVersion>, example = "v1")]
+    pub api_version: Option<api_enums::WebhookApiVersion>,
+
+    /// Whether webhooks delivered to this endpoint are signed with a key of its own instead of the webhook signing secret of the business profile. The key can be retrieved through the webhook secret endpoint of the business profile
+    #[schema(example = false)]
+    pub has_secret: bool,
+
+    /// Whether webhooks are delivered to this endpoint
+    #[schema(example = true)]
+    pub enabled: bool,
+}
+
+impl From<WebhookEndpoint> for WebhookEndpointResponse {
+    fn from(webhook_endpoint: WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: webhook_endpoint.endpoint_id,
+            url: webhook_endpoint.url,
+            message_queue: webhook_endpoint.message_queue,
+            enabled_event_classes: webhook_endpoint.enabled_event_classes,
+            enabled_event_types: webhook_endpoint.enabled_event_types,
+            api_version: webhook_endpoint.api_version,
+            has_secret: webhook_endpoint.secret.is_some(),
+            enabled: webhook_endpoint.enabled,
+        }
+    }
+}
+
+/// A message queue to which webhooks are published. Webhooks are published with the same payload and signature headers as webhooks delivered to a URL, to a topic or stream whose name is prefixed with `merchant_webhooks.{merchant_id}.`
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum WebhookMessageQueue {
+    /// Publish webhooks to a Kafka topic. The message key is the identifier of the object (Payment Intent ID, Refund ID, etc.) the event is associated with, and the signature headers are sent as Kafka message headers. A webhook is considered delivered once the broker acknowledges the message. Consumers should be granted read access only to the topics with the `merchant_webhooks.{merchant_id}.` prefix
+    Kafka {
+        /// The name of the topic, excluding the prefix
+        #[schema(example = "payments")]
+        topic: String,
+    },
+    /// Append webhooks to a Redis stream. Each stream entry contains the `headers` field, with the signature headers as a JSON object, and the `body` field. The stream retains approximately the latest 100000 entries, so consumers should read it using a consumer group (`XREADGROUP`) and acknowledge entries as they are processed. Consumers should be granted access only to the keys with the `merchant_webhooks.{merchant_id}.` prefix
+    RedisStream {
+        /// The name of the stream, excluding the prefix
+        #[schema(example = "payments")]
+        stream: String,
+    },
+}
+
+impl WebhookMessageQueue {
+    /// The name of the topic or stream, excluding the prefix
+    pub fn get_name(&self) -> &str {
+        match self {
+            Self::Kafka { topic } => topic,
+            Self::RedisStream { stream } => stream,
+        }
+    }
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
@@ -1107,6 +1373,62 @@ pub struct ConnectorAgnosticMitChoice {
 
 impl common_utils::events::ApiEventMetric for ConnectorAgnosticMitChoice {}
 
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSecretRotateRequest {
+    /// Number of hours for which webhooks continue to be signed with the previous secret in addition to the new secret. Defaults to 24 hours. Webhooks are signed with the new secret alone if this is 0
+    #[schema(example = 24, maximum = 168)]
+    pub previous_secret_expiry_hours: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookSecretResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The identifier for the Business Profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    /// The version of the secret currently used for signing webhooks
+    #[schema(example = 2)]
+    pub version: i32,
+
+    /// The secret currently used for signing webhooks
+    #[schema(value_type = String)]
+    pub secret: Secret<String>,
+
+    /// The versions of the previous secrets with which webhooks are still signed, the most recent first
+    pub previous_versions: Vec<PreviousWebhookSecretVersion>,
+
+    /// The keys used to sign webhooks delivered to the webhook endpoints of the business profile which have a key of their own
+    pub endpoint_secrets: Vec<WebhookEndpointSecret>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointSecret {
+    /// The identifier of the webhook endpoint
+    #[schema(max_length = 32, example = "finance")]
+    pub endpoint_id: String,
+
+    /// The key used to sign webhooks delivered to the webhook endpoint
+    #[schema(value_type = String)]
+    pub secret: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PreviousWebhookSecretVersion {
+    /// The version of the previous secret
+    #[schema(example = 1)]
+    pub version: i32,
+
+    /// Time until which webhooks are signed with the previous secret in addition to the current secret
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ExtendedCardInfoConfig {
     /// Merchant public key
@@ -0,0 +1,194 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use utoipa::ToSchema;
+
+use crate::{enums as api_enums, refunds};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BulkOperationCreateRequest {
+    /// The identifier of the file uploaded with the purpose `bulk_operations`, containing one operation per row.
+    /// The file can either be a CSV file with a header row, or a JSON Lines file, and its rows are described by `BulkOperationRow`
+    #[schema(example = "file_1Xz0FWHNFP0oQtsbwnO3")]
+    pub file_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct BulkOperationJobId {
+    /// The identifier of the bulk operation job
+    pub job_id: String,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BulkOperationType {
+    /// Create and confirm a merchant initiated payment using a saved payment method or a mandate
+    PaymentCreate,
+    /// Capture an authorized payment
+    PaymentCapture,
+    /// Void an authorized payment
+    PaymentVoid,
+    /// Refund a succeeded payment
+    Refund,
+}
+
+/// A single row of a bulk operations file. In CSV files, the header row names these fields
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct BulkOperationRow {
+    /// The operation to be performed for this row
+    pub operation: BulkOperationType,
+
+    /// The identifier of the payment. Required for captures, voids and refunds, and used as the identifier of the created payment otherwise. Defaults to `{job_id}_{row_number}` when creating a payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<String>,
+
+    /// The amount in the lowest denomination of the currency. Required when creating a payment, and defaults to the full amount for captures and refunds
+    #[schema(example = 6540)]
+    pub amount: Option<i64>,
+
+    /// The currency of the payment to be created
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The customer whose saved payment method or mandate is charged when creating a payment
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The saved payment method to be charged when creating a payment
+    #[schema(example = "pm_01926c58bc6e77c09e809964e72af8c8")]
+    pub payment_method_id: Option<String>,
+
+    /// The mandate to be charged when creating a payment
+    #[schema(example = "man_P3Ix0K0v7wFtHBTwhfoF")]
+    pub mandate_id: Option<String>,
+
+    /// A description of the payment to be created
+    pub description: Option<String>,
+
+    /// The identifier of the refund to be created. Defaults to `{job_id}_{row_number}`
+    pub refund_id: Option<String>,
+
+    /// The reason for the refund or the void
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkOperationStatus {
+    /// The job has been created and is waiting to be picked up
+    Pending,
+    /// The rows of the file are being processed
+    Processing,
+    /// All the rows have been processed, and the results file is available
+    Completed,
+    /// The job was stopped before all the rows could be processed. The results of the rows processed before it was stopped are available in the results file
+    Failed,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct BulkOperationResponse {
+    /// The identifier of the bulk operation job
+    #[schema(example = "bulk_op_mbabizu24mvu3mela5njyhpit4")]
+    pub job_id: String,
+
+    /// The identifier of the file being processed
+    pub file_id: String,
+
+    /// The status of the job
+    pub status: BulkOperationStatus,
+
+    /// The number of operations in the file
+    pub total_rows: usize,
+
+    /// The number of operations processed so far
+    pub processed_rows: usize,
+
+    /// The number of processed operations which succeeded
+    pub succeeded_rows: usize,
+
+    /// The number of processed operations which failed
+    pub failed_rows: usize,
+
+    /// The identifier of the JSON Lines file containing a `BulkOperationRowResult` for every processed row, which can be downloaded using the files API once the job is completed or failed
+    pub result_file_id: Option<String>,
+
+    /// The reason why the job failed
+    pub error_message: Option<String>,
+
+    /// Time when the job was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// Time when the job was last updated
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkOperationRowStatus {
+    Succeeded,
+    Failed,
+}
+
+/// The result of a single row of a bulk operations file
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct BulkOperationRowResult {
+    /// The position of the row in the file, starting from 1 and excluding the CSV header row
+    pub row_number: usize,
+
+    /// The operation performed for this row
+    pub operation: BulkOperationType,
+
+    /// Whether the operation succeeded
+    pub status: BulkOperationRowStatus,
+
+    /// The identifier of the payment
+    pub payment_id: Option<String>,
+
+    /// The identifier of the refund
+    pub refund_id: Option<String>,
+
+    /// The status of the payment after the operation
+    #[schema(value_type = Option<IntentStatus>)]
+    pub payment_status: Option<api_enums::IntentStatus>,
+
+    /// The status of the refund, if a refund was created
+    pub refund_status: Option<refunds::RefundStatus>,
+
+    /// The error code, if the operation failed
+    pub error_code: Option<String>,
+
+    /// The error message, if the operation failed
+    pub error_message: Option<String>,
+}
+
+impl ApiEventMetric for BulkOperationCreateRequest {}
+
+impl ApiEventMetric for BulkOperationJobId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BulkOperation {
+            job_id: self.job_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for BulkOperationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BulkOperation {
+            job_id: self.job_id.clone(),
+        })
+    }
+}
@@ -1,8 +1,15 @@
+use common_utils::events;
+use euclid::frontend::{
+    ast::Program,
+    dir::{DirKeyKind, EuclidDirFilter},
+};
 use masking::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::enums::{DisputeStage, DisputeStatus};
+use super::enums::{
+    Currency, DisputeAlertOutcome, DisputeAlertProvider, DisputeStage, DisputeStatus,
+};
 use crate::files;
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -47,6 +54,8 @@ pub struct DisputeResponse {
     pub profile_id: Option<String>,
     /// The `merchant_connector_id` of the connector / processor through which the dispute was processed
     pub merchant_connector_id: Option<String>,
+    /// The action taken on the dispute by the dispute policy of the business profile, if any
+    pub policy_decision: Option<DisputePolicyDecision>,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -204,3 +213,155 @@ pub struct DeleteEvidenceRequest {
     /// Evidence Type to be deleted
     pub evidence_type: EvidenceType,
 }
+
+/// The action to be taken on a dispute as soon as it is opened
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputePolicyAction {
+    /// Accept the dispute with the connector
+    AutoAccept,
+    /// Assemble the evidence from the details of the disputed payment and submit it
+    AutoSubmitEvidence,
+    /// Leave the dispute to be reviewed and acted upon by the merchant
+    ManualReview,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DisputePolicyOutput {
+    pub action: Option<DisputePolicyAction>,
+}
+
+/// Apart from the payment method, card network, amount and currency of the dispute, policies can
+/// match on the `connector` and `reason_code` of the dispute as metadata keys
+impl EuclidDirFilter for DisputePolicyOutput {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentMethod,
+        DirKeyKind::CardNetwork,
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::MetaData,
+    ];
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisputePolicyRecord {
+    pub name: String,
+    pub profile_id: String,
+    pub program: Program<DisputePolicyOutput>,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl events::ApiEventMetric for DisputePolicyRecord {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::DisputePolicy)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisputePolicyRequest {
+    pub name: Option<String>,
+    pub program: Option<Program<DisputePolicyOutput>>,
+}
+
+impl events::ApiEventMetric for DisputePolicyRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::DisputePolicy)
+    }
+}
+
+pub type DisputePolicyResponse = DisputePolicyRecord;
+
+/// Audit record of the dispute policy which acted on a dispute
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, Eq, PartialEq)]
+pub struct DisputePolicyDecision {
+    /// Name of the dispute policy of the business profile
+    pub policy_name: String,
+    /// Name of the rule of the policy which matched the dispute, absent if the default selection
+    /// of the policy was applied
+    pub rule_name: Option<String>,
+    /// The action taken on the dispute
+    pub action: DisputePolicyAction,
+    /// Whether the action was performed successfully
+    pub succeeded: bool,
+    /// Error encountered while performing the action, if any
+    pub error_message: Option<String>,
+    /// Time at which the policy acted on the dispute
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub decided_at: PrimitiveDateTime,
+}
+
+/// A pre-dispute alert raised by a card network alert service, in the generic format accepted by
+/// the alert ingestion API
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAlertRequest {
+    /// The alert service through which the alert was received
+    #[schema(value_type = DisputeAlertProvider, example = "ethoca")]
+    pub provider: DisputeAlertProvider,
+    /// Identifier of the alert at the provider. An alert which was already received is not
+    /// processed again
+    #[schema(example = "ETH-1234567890")]
+    pub provider_alert_id: String,
+    /// Acquirer reference number of the disputed transaction, matched against the acquirer
+    /// reference number returned by the connector for the payment
+    #[schema(example = "74537604221431003881865")]
+    pub arn: Option<String>,
+    /// Last four digits of the card used for the disputed transaction
+    #[schema(example = "4242")]
+    pub card_last4: Option<String>,
+    /// Amount of the disputed transaction in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// Currency of the disputed transaction
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+    /// Date of the disputed transaction
+    #[schema(value_type = PrimitiveDateTime, example = "2024-05-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub transaction_date: PrimitiveDateTime,
+    /// Reason for the dispute given by the cardholder
+    #[schema(example = "Merchandise not received")]
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputeAlertResponse {
+    /// The identifier for the alert
+    pub alert_id: String,
+    /// The alert service through which the alert was received
+    #[schema(value_type = DisputeAlertProvider)]
+    pub provider: DisputeAlertProvider,
+    /// Identifier of the alert at the provider
+    pub provider_alert_id: String,
+    /// Amount of the disputed transaction in the lowest denomination of the currency
+    pub amount: i64,
+    /// Currency of the disputed transaction
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+    /// Date of the disputed transaction
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub transaction_date: PrimitiveDateTime,
+    /// The payment the alert was matched to, if any
+    pub payment_id: Option<String>,
+    /// The payment attempt the alert was matched to, if any
+    pub attempt_id: Option<String>,
+    /// The refund created in response to the alert, if any
+    pub refund_id: Option<String>,
+    /// What was done in response to the alert. Absent while the alert is being processed
+    #[schema(value_type = Option<DisputeAlertOutcome>)]
+    pub outcome: Option<DisputeAlertOutcome>,
+    /// Describes why the alert could not be matched or refunded, if applicable
+    pub error_message: Option<String>,
+    /// Time at which the alert was received
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DisputeAlertId {
+    /// The identifier for the alert
+    pub alert_id: String,
+}
@@ -445,11 +445,83 @@ pub enum RetryAction {
     Requeue,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    PartialEq,
+    Eq,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthorizationExpiryAction {
+    /// Only notify the merchant through the `payment_authorization_expiring` webhook
+    #[default]
+    NoAction,
+    /// Capture the authorized amount before the authorization expires
+    AutoCapture,
+    /// Void the authorization before it expires
+    AutoVoid,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    PartialEq,
+    Eq,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookApiVersion {
+    /// The Hyperswitch webhook payload
+    V1,
+    /// A payload compatible with Stripe webhooks
+    StripeCompatible,
+}
+
 #[derive(Clone, Copy)]
 pub enum LockerChoice {
     HyperswitchCardVault,
 }
 
+/// The vault in which the card data of a merchant is stored
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VaultProvider {
+    /// The Hyperswitch card vault, reached over JWE encrypted HTTP requests
+    HyperswitchCardVault,
+    /// The `locker_mock_up` table, meant for local development and testing only
+    MockLocker,
+    /// The application database, with the data encrypted using the merchant key
+    LocalEncrypted,
+    /// The third party vault configured for the application, reached over HTTP
+    External,
+}
+
 #[derive(
     Clone,
     Copy,
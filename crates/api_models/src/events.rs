@@ -57,6 +57,8 @@ impl_misc_api_event_type!(
     BusinessProfileResponse,
     BusinessProfileUpdate,
     BusinessProfileCreate,
+    WebhookSecretRotateRequest,
+    WebhookSecretResponse,
     RevokeApiKeyResponse,
     ToggleKVResponse,
     ToggleKVRequest,
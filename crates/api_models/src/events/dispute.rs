@@ -1,7 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve, SubmitEvidenceRequest,
+    DeleteEvidenceRequest, DisputeAlertId, DisputeAlertRequest, DisputeAlertResponse,
+    DisputeResponse, DisputeResponsePaymentsRetrieve, SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -32,3 +33,22 @@ impl ApiEventMetric for DeleteEvidenceRequest {
         })
     }
 }
+impl ApiEventMetric for DisputeAlertRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+impl ApiEventMetric for DisputeAlertResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DisputeAlert {
+            alert_id: self.alert_id.clone(),
+        })
+    }
+}
+impl ApiEventMetric for DisputeAlertId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DisputeAlert {
+            alert_id: self.alert_id.clone(),
+        })
+    }
+}
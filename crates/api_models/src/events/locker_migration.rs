@@ -1,9 +1,36 @@
 use common_utils::events::ApiEventMetric;
 
-use crate::locker_migration::MigrateCardResponse;
+use crate::locker_migration::{
+    MigrateCardResponse, VaultMigrationRequest, VaultMigrationRequestInternal,
+    VaultMigrationResponse, VaultProviderResponse,
+};
 
 impl ApiEventMetric for MigrateCardResponse {
     fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
         Some(common_utils::events::ApiEventsType::RustLocker)
     }
 }
+
+impl ApiEventMetric for VaultMigrationRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::RustLocker)
+    }
+}
+
+impl ApiEventMetric for VaultMigrationRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::RustLocker)
+    }
+}
+
+impl ApiEventMetric for VaultProviderResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::RustLocker)
+    }
+}
+
+impl ApiEventMetric for VaultMigrationResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::RustLocker)
+    }
+}
@@ -3,6 +3,7 @@ pub mod admin;
 pub mod analytics;
 pub mod api_keys;
 pub mod blocklist;
+pub mod bulk_operations;
 pub mod cards_info;
 pub mod conditional_configs;
 pub mod connector_onboarding;
@@ -35,5 +36,6 @@ pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_dead_letters;
 pub mod webhook_events;
 pub mod webhooks;
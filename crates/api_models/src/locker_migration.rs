@@ -1,3 +1,5 @@
+use crate::enums;
+
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MigrateCardResponse {
@@ -6,3 +8,72 @@ pub struct MigrateCardResponse {
     pub customers_moved: usize,
     pub cards_moved: usize,
 }
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct VaultMigrationRequest {
+    /// The vault to move the stored cards of the merchant to
+    pub vault_provider: enums::VaultProvider,
+    /// Whether to delete the cards from the previous vault once they have been moved
+    #[serde(default)]
+    pub delete_from_source: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct VaultMigrationRequestInternal {
+    pub merchant_id: String,
+    pub request: VaultMigrationRequest,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct VaultProviderResponse {
+    pub merchant_id: String,
+    pub vault_provider: enums::VaultProvider,
+    /// The latest migration of the stored cards of the merchant to another vault
+    pub migration: Option<VaultMigrationResponse>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultMigrationStatus {
+    /// The stored data is being copied to the new vault, while the merchant keeps using the
+    /// previous vault
+    Copying,
+    /// The merchant has been switched to the new vault, and the data saved in the previous vault
+    /// just before the switch is being moved, or the moved data is being deleted from the previous
+    /// vault
+    Switched,
+    /// All the stored data has been moved and the merchant uses the new vault
+    Completed,
+    /// The migration was stopped. The merchant keeps using the previous vault unless it was
+    /// stopped after the switch.
+    Failed,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct VaultMigrationResponse {
+    pub migration_id: String,
+    pub merchant_id: String,
+    pub status: VaultMigrationStatus,
+    /// The vault the cards are moved from
+    pub source_vault_provider: enums::VaultProvider,
+    /// The vault the cards are moved to, used for all card operations of the merchant once the
+    /// stored data has been copied
+    pub vault_provider: enums::VaultProvider,
+    /// Whether the moved data is deleted from the previous vault after the switch
+    pub delete_from_source: bool,
+    pub cards_moved: usize,
+    /// Stored payment methods other than cards, such as bank accounts saved for payouts
+    pub payment_methods_moved: usize,
+    /// IDs of the payment methods which could not be moved to the new vault. The merchant is not
+    /// switched to the new vault when any payment method could not be copied.
+    pub payment_methods_failed: Vec<String>,
+    /// The reason why the migration failed
+    pub error_message: Option<String>,
+    /// Time when the merchant was switched to the new vault
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub switched_at: Option<time::PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: time::PrimitiveDateTime,
+}
@@ -265,6 +265,8 @@ pub struct PaymentsRequest {
 
     /// A timestamp (ISO 8601 code) that determines when the payment should be captured.
     /// Providing this field will automatically set `capture` to true
+    ///
+    /// The scheduled capture of an authorized payment can be moved by updating the payment with a new `capture_on`, and cancelled by updating the payment with `capture_method` set to `manual` and without `capture_on`. No other field can be updated in the same request.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub capture_on: Option<PrimitiveDateTime>,
@@ -418,6 +420,11 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<RetryAction>)]
     pub retry_action: Option<api_enums::RetryAction>,
 
+    /// The amount to be charged on the payment method provided in this confirm call, when the payment is paid using multiple payment methods (split tender). Only gift cards can pay a part of the payment, the remaining amount can then be paid by confirming the payment again with another payment method. If not provided, the remaining amount of the payment is charged.
+    #[schema(example = 2000)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    pub tender_amount: Option<i64>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
@@ -465,6 +472,14 @@ pub struct PaymentsRequest {
     #[schema(example = true)]
     pub request_external_three_ds_authentication: Option<bool>,
 
+    /// Whether the payment can be authorized for an amount lower than the requested amount, when the customer's payment method does not have sufficient funds. A partially authorized payment can be captured for the authorized amount, the remaining amount can then be paid by confirming the payment again with another payment method.
+    #[schema(example = true)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub allow_partial_authorization: Option<bool>,
+
+    /// Level 2 and Level 3 data of the order, sent to the processor to qualify commercial card payments for lower interchange rates
+    pub l2_l3_data: Option<L2L3Data>,
+
     /// Details required for recurring payment
     pub recurring_details: Option<RecurringDetails>,
 }
@@ -687,6 +702,18 @@ impl PaymentsRequest {
             })
             .transpose()
     }
+
+    pub fn get_l2_l3_data_as_value(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Option<serde_json::Value>,
+        common_utils::errors::ParsingError,
+    > {
+        self.l2_l3_data
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+    }
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
@@ -1717,6 +1744,42 @@ pub struct AdditionalCardInfo {
     pub authentication_data: Option<serde_json::Value>,
 }
 
+/// The outcome of an address or card security code check done by the issuer of the card
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CardVerificationCheckResult {
+    /// The provided value matches the one on file with the issuer
+    Match,
+    /// The provided value does not match the one on file with the issuer
+    NoMatch,
+    /// The value was not checked, as it was not provided or the check was not requested
+    NotChecked,
+    /// The check is not supported by the issuer or could not be performed
+    Unavailable,
+}
+
+/// The address verification (AVS) and card security code (CVV) results of a card payment,
+/// normalized across connectors
+#[derive(Default, Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CardVerificationResults {
+    /// The result of the check of the street address against the billing address of the card
+    pub avs_street_match: Option<CardVerificationCheckResult>,
+
+    /// The result of the check of the postal code against the billing address of the card
+    pub avs_postal_code_match: Option<CardVerificationCheckResult>,
+
+    /// The result of the check of the card security code
+    pub cvv_match: Option<CardVerificationCheckResult>,
+
+    /// The address verification result code as returned by the connector
+    #[schema(example = "Y")]
+    pub avs_raw_code: Option<String>,
+
+    /// The card security code result code as returned by the connector
+    #[schema(example = "M")]
+    pub cvv_raw_code: Option<String>,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AdditionalPaymentData {
@@ -3121,6 +3184,10 @@ pub struct PaymentsResponse {
     #[schema(minimum = 100, example = 6540)]
     pub amount_received: Option<i64>,
 
+    /// The amount authorized on the payment, when the payment was partially authorized for an amount lower than the requested amount
+    #[schema(example = 5000)]
+    pub amount_authorized: Option<i64>,
+
     /// The connector used for the payment
     #[schema(example = "stripe")]
     pub connector: Option<String>,
@@ -3204,6 +3271,12 @@ pub struct PaymentsResponse {
     #[serde(serialize_with = "serialize_payment_method_data_response")]
     pub payment_method_data: Option<PaymentMethodDataResponseWithBilling>,
 
+    /// The address verification and card security code results of a card payment
+    pub card_verification_results: Option<CardVerificationResults>,
+
+    /// The result of the 3DS authentication of the payment, done by the connector or externally
+    pub authentication_details: Option<PaymentAuthenticationDetails>,
+
     /// Provide a reference to a stored payment method
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
@@ -3361,6 +3434,11 @@ pub struct PaymentsResponse {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub expires_on: Option<PrimitiveDateTime>,
 
+    /// Date Time at which the authorization of a manually captured payment expires
+    #[schema(example = "2022-09-17T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+
     /// Payment Fingerprint
     pub fingerprint: Option<String>,
 
@@ -3401,6 +3479,46 @@ pub struct ExternalAuthenticationDetailsResponse {
     pub error_message: Option<String>,
 }
 
+/// The party that performed the 3DS authentication of a payment
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthenticationSource {
+    /// The 3DS authentication was performed by the connector processing the payment
+    Connector,
+    /// The 3DS authentication was performed by an external 3DS authentication provider
+    External,
+}
+
+/// The result of the 3DS authentication of a payment, normalized across connectors and external
+/// 3DS authentication providers
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentAuthenticationDetails {
+    /// The party that performed the 3DS authentication
+    pub authentication_source: AuthenticationSource,
+
+    /// The 3DS transaction status of the authentication
+    #[schema(value_type = Option<TransactionStatus>)]
+    pub trans_status: Option<common_enums::TransactionStatus>,
+
+    /// Electronic Commerce Indicator (ECI) of the authentication
+    #[schema(example = "05")]
+    pub electronic_commerce_indicator: Option<String>,
+
+    /// The 3DS version used for the authentication
+    #[schema(example = "2.2.0")]
+    pub version: Option<String>,
+
+    /// Whether the liability for fraud chargebacks shifted to the issuer of the card
+    pub liability_shift: Option<bool>,
+
+    /// The transaction id of the authentication at the directory server
+    pub ds_transaction_id: Option<String>,
+
+    /// Authentication Type - Challenge / Frictionless
+    #[schema(value_type = Option<DecoupledAuthenticationType>)]
+    pub authentication_flow: Option<enums::DecoupledAuthenticationType>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentListConstraints {
@@ -3785,6 +3903,74 @@ pub struct OrderDetailsWithAmount {
     pub product_type: Option<ProductType>,
 }
 
+#[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3Data {
+    /// Code provided by the customer to identify the order, such as a purchase order number
+    #[schema(max_length = 25, example = "PO-1234567")]
+    pub customer_code: Option<String>,
+    /// Total tax amount included in the payment amount
+    #[schema(example = 650)]
+    pub tax_amount: Option<i64>,
+    /// Whether the order is exempt from tax
+    #[schema(example = false)]
+    pub tax_exempt: Option<bool>,
+    /// Total duty amount included in the payment amount
+    #[schema(example = 0)]
+    pub duty_amount: Option<i64>,
+    /// Total shipping amount included in the payment amount
+    #[schema(example = 500)]
+    pub shipping_amount: Option<i64>,
+    /// Total discount applied on the order
+    #[schema(example = 0)]
+    pub discount_amount: Option<i64>,
+    /// Postal code of the location from which the order is shipped
+    #[schema(value_type = Option<String>, example = "94122")]
+    pub ship_from_postal_code: Option<Secret<String>>,
+    /// Line items of the order, sent as Level 3 data
+    pub line_items: Option<Vec<L2L3LineItem>>,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3LineItem {
+    /// Name of the product
+    #[schema(max_length = 255, example = "Printer paper")]
+    pub product_name: String,
+    /// Merchant defined code or SKU of the product
+    #[schema(max_length = 12, example = "SKU-0042")]
+    pub product_code: Option<String>,
+    /// Commodity code of the product, as defined by the card network
+    #[schema(max_length = 12, example = "44121600")]
+    pub commodity_code: Option<String>,
+    /// Quantity of the product
+    #[schema(example = 2)]
+    pub quantity: u16,
+    /// Unit of measure of the quantity, such as `EA` for each
+    #[schema(max_length = 12, example = "EA")]
+    pub unit_of_measure: Option<String>,
+    /// Price of a single unit of the product
+    #[schema(example = 1500)]
+    pub unit_price: i64,
+    /// Discount applied on the line item
+    #[schema(example = 0)]
+    pub discount_amount: Option<i64>,
+    /// Tax applied on the line item
+    #[schema(example = 250)]
+    pub tax_amount: Option<i64>,
+}
+
+impl L2L3LineItem {
+    /// Total amount of the line item, after applying the discount and tax.
+    /// Returns `None` if the computation overflows
+    pub fn get_total_amount(&self) -> Option<i64> {
+        self.unit_price
+            .checked_mul(i64::from(self.quantity))?
+            .checked_sub(self.discount_amount.unwrap_or(0))?
+            .checked_add(self.tax_amount.unwrap_or(0))
+    }
+}
+
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProductType {
@@ -4349,6 +4535,109 @@ pub struct PaymentsExternalAuthenticationResponse {
     pub acs_signed_content: Option<String>,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CardVerificationRequest {
+    /// The identifier for the customer object. Required to save the verified card for future use
+    #[schema(
+        max_length = 64,
+        min_length = 1,
+        example = "cus_y3oqhf46pyzuxjbcn2giaqnb44"
+    )]
+    pub customer_id: Option<String>,
+
+    /// The card to be verified
+    pub payment_method_data: PaymentMethodDataRequest,
+
+    /// The currency in which the card is verified
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The billing details of the customer, used for the address verification of the card
+    pub billing: Option<Address>,
+
+    /// This allows to manually select a connector with which the card can be verified
+    #[schema(value_type = Option<Vec<Connector>>, max_length = 255, example = json!(["stripe", "adyen"]))]
+    pub connector: Option<Vec<api_enums::Connector>>,
+
+    /// Save the card for merchant initiated transactions, if it is verified successfully. Requires `customer_id` and `customer_acceptance`
+    #[schema(default = false, example = true)]
+    pub save_payment_method: Option<bool>,
+
+    /// The acceptance of the customer to save the card for future use
+    #[schema(value_type = Option<CustomerAcceptance>)]
+    pub customer_acceptance: Option<CustomerAcceptance>,
+
+    /// Additional details required by 3DS 2.0
+    #[schema(value_type = Option<BrowserInformation>)]
+    pub browser_info: Option<serde_json::Value>,
+
+    /// The business profile to use for the verification. If not passed, the default business profile of the merchant is used
+    pub profile_id: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CardVerificationStatus {
+    /// The card was verified by the connector
+    Verified,
+    /// The card was declined by the connector
+    Failed,
+    /// The verification requires further action from the customer, or is yet to be confirmed by the connector
+    Pending,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct CardVerificationResponse {
+    /// The identifier of the zero-amount payment through which the card was verified
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// The identifier for the merchant
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The outcome of the verification
+    #[schema(value_type = CardVerificationStatus, example = "verified")]
+    pub status: CardVerificationStatus,
+
+    /// The connector through which the card was verified
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The transaction id of the verification at the connector
+    pub connector_transaction_id: Option<String>,
+
+    /// The network transaction id of the verification, stored with the saved card for merchant initiated transactions
+    pub network_transaction_id: Option<String>,
+
+    /// The identifier of the saved card, if `save_payment_method` was requested
+    #[schema(example = "pm_01926c58bc6e77c09e809964e72af8c8")]
+    pub payment_method_id: Option<String>,
+
+    /// The details of the verified card
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method_data: Option<PaymentMethodDataResponseWithBilling>,
+
+    /// The address verification and card security code results of the verification
+    pub card_verification_results: Option<CardVerificationResults>,
+
+    /// The error code returned by the connector, if the verification failed
+    pub error_code: Option<String>,
+
+    /// The error message returned by the connector, if the verification failed
+    pub error_message: Option<String>,
+
+    /// The time at which the card was verified
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub created: Option<PrimitiveDateTime>,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct PaymentsApproveRequest {
     /// The identifier for the payment
@@ -4736,6 +5025,181 @@ pub struct ExtendedCardInfoResponse {
     pub payload: String,
 }
 
+/// The kind of an event in the timeline of a payment
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentTimelineEventType {
+    /// A payment attempt was made, either as the first attempt or as a retry
+    PaymentAttempt,
+    /// The payment was authenticated through an external 3DS authentication provider
+    ExternalAuthentication,
+    /// A partial capture of the payment was made
+    Capture,
+    /// A refund of the payment was created
+    Refund,
+    /// A dispute of the payment was raised
+    Dispute,
+    /// A webhook about the payment was received from the connector
+    IncomingWebhook,
+    /// A webhook about the payment was sent to the merchant
+    OutgoingWebhook,
+    /// An API call was made to the connector
+    ConnectorApiCall,
+}
+
+/// An event in the timeline of a payment
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentTimelineEvent {
+    /// The kind of the event
+    pub event_type: PaymentTimelineEventType,
+
+    /// Time at which the event occurred
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The identifier of the object the event is about, e.g. the refund id of a refund
+    pub reference_id: Option<String>,
+
+    /// The payment attempt the event is related to
+    pub attempt_id: Option<String>,
+
+    /// The connector involved in the event
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The status of the object the event is about, or the HTTP status code of an API call
+    pub status: Option<String>,
+
+    /// The amount of the object the event is about
+    #[schema(example = 6540)]
+    pub amount: Option<i64>,
+
+    /// The currency of the amount
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<enums::Currency>,
+
+    /// The error code of the event, if it failed
+    pub error_code: Option<String>,
+
+    /// The error message of the event, if it failed
+    pub error_message: Option<String>,
+
+    /// Details specific to the kind of the event, e.g. the routing decision of a payment attempt
+    /// or the masked request and response of a connector API call
+    pub details: Option<PaymentTimelineEventDetails>,
+}
+
+/// How the connector of a payment attempt was decided
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentTimelineRoutingApproach {
+    /// The connector of the mandate used for the recurring payment
+    Mandate,
+    /// The straight through routing algorithm passed in the payment request
+    StraightThrough,
+    /// The connectors decided for each payment method type when the session tokens were fetched
+    PreRouting,
+    /// The routing algorithm of the business profile, or its default fallback connectors
+    BusinessProfile,
+}
+
+/// The routing decision of a payment attempt
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentTimelineRoutingDetails {
+    /// How the connector of the payment attempt was decided
+    pub approach: PaymentTimelineRoutingApproach,
+
+    /// The straight through routing algorithm passed in the payment request
+    #[schema(value_type = Option<Object>)]
+    pub straight_through_algorithm: Option<serde_json::Value>,
+
+    /// The connectors decided for each payment method type when the session tokens were fetched
+    #[schema(value_type = Option<Object>)]
+    pub pre_routing_results: Option<serde_json::Value>,
+
+    /// The routing algorithm of the business profile. This is known only if the routing
+    /// configuration of the business profile was not changed since the payment attempt was made.
+    pub routing_algorithm_id: Option<String>,
+}
+
+/// Details specific to the kind of an event in the timeline of a payment
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaymentTimelineEventDetails {
+    PaymentAttempt {
+        #[schema(value_type = Option<PaymentMethod>)]
+        payment_method: Option<api_enums::PaymentMethod>,
+        #[schema(value_type = Option<PaymentMethodType>)]
+        payment_method_type: Option<api_enums::PaymentMethodType>,
+        #[schema(value_type = Option<AuthenticationType>)]
+        authentication_type: Option<api_enums::AuthenticationType>,
+        merchant_connector_id: Option<String>,
+        /// The routing decision of the payment attempt
+        routing: Option<PaymentTimelineRoutingDetails>,
+        unified_code: Option<String>,
+        unified_message: Option<String>,
+        /// The GSM decision for the error of a failed payment attempt
+        gsm_decision: Option<String>,
+        gsm_step_up_possible: Option<bool>,
+        authentication_details: Option<PaymentAuthenticationDetails>,
+    },
+    ExternalAuthentication {
+        #[schema(value_type = Option<DecoupledAuthenticationType>)]
+        authentication_flow: Option<api_enums::DecoupledAuthenticationType>,
+        #[schema(value_type = Option<TransactionStatus>)]
+        trans_status: Option<common_enums::TransactionStatus>,
+        electronic_commerce_indicator: Option<String>,
+        message_version: Option<String>,
+    },
+    Capture {
+        capture_sequence: i16,
+        connector_capture_id: Option<String>,
+    },
+    Refund {
+        refund_type: String,
+        refund_reason: Option<String>,
+        connector_refund_id: Option<String>,
+    },
+    Dispute {
+        #[schema(value_type = DisputeStage)]
+        dispute_stage: api_enums::DisputeStage,
+        connector_status: String,
+        connector_reason: Option<String>,
+        connector_reason_code: Option<String>,
+    },
+    OutgoingWebhook {
+        #[schema(value_type = EventType)]
+        event_type: api_enums::EventType,
+        primary_object_id: String,
+        #[schema(value_type = String)]
+        primary_object_type: api_enums::EventObjectType,
+        is_webhook_notified: bool,
+    },
+    ConnectorApiCall {
+        flow: String,
+        method: Option<String>,
+        /// The latency of the API call
+        #[schema(value_type = Option<u64>)]
+        latency: Option<u128>,
+        /// The masked request of the API call
+        request: String,
+        /// The masked response of the API call
+        response: Option<String>,
+    },
+}
+
+/// The chronological timeline of a payment, from its attempts to its refunds and disputes
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentTimelineResponse {
+    /// The identifier of the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// The events of the payment, ordered by the time at which they occurred
+    pub events: Vec<PaymentTimelineEvent>,
+}
+
 #[cfg(test)]
 mod payments_request_api_contract {
     #![allow(clippy::unwrap_used)]
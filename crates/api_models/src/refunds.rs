@@ -132,6 +132,57 @@ pub struct RefundResponse {
     pub profile_id: Option<String>,
     /// The merchant_connector_id of the processor through which this payment went through
     pub merchant_connector_id: Option<String>,
+    /// The approval details of the refund, if the refund required approval according to the refund approval policy of the business profile
+    pub approval_details: Option<RefundApprovalDetails>,
+    /// The identifier of the payout through which the refund is fulfilled, when the connector could not process the refund
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub fulfilment_payout_id: Option<String>,
+    /// The refunds created for the other tenders of a split tender payment, when the refund amount was allocated across more than one tender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_tender_refunds: Option<Vec<RefundResponse>>,
+}
+
+/// The decision taken on a refund which required approval
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundApprovalDecision {
+    /// The refund was approved and sent to the connector
+    Approved,
+    /// The refund was rejected by a dashboard user
+    Rejected,
+    /// The refund was rejected automatically, since it was not approved before it expired
+    Expired,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundApprovalDetails {
+    /// The time after which the refund is rejected automatically, if it has not been approved by then
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+    /// The identifier of the dashboard user who created the refund, who cannot approve it
+    pub requested_by: Option<String>,
+    /// The identifier of the dashboard user who gave the first of the two approvals required for a refund created with an API key
+    pub first_approved_by: Option<String>,
+    /// The decision taken on the refund, if any
+    pub decision: Option<RefundApprovalDecision>,
+    /// The identifier of the dashboard user who approved or rejected the refund
+    pub decided_by: Option<String>,
+    /// The time at which the refund was approved or rejected
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub decided_at: Option<PrimitiveDateTime>,
+    /// The reason provided for approving or rejecting the refund
+    pub reason: Option<String>,
+}
+
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundApprovalRequest {
+    #[serde(skip)]
+    pub refund_id: String,
+    /// The reason for approving or rejecting the refund
+    #[schema(max_length = 255, example = "Verified with the customer")]
+    pub reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -202,6 +253,7 @@ pub enum RefundStatus {
     #[default]
     Pending,
     Review,
+    PendingApproval,
 }
 
 impl From<enums::RefundStatus> for RefundStatus {
@@ -211,6 +263,7 @@ impl From<enums::RefundStatus> for RefundStatus {
             enums::RefundStatus::ManualReview => Self::Review,
             enums::RefundStatus::Pending => Self::Pending,
             enums::RefundStatus::Success => Self::Succeeded,
+            enums::RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
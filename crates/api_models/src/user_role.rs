@@ -9,10 +9,12 @@ pub enum Permission {
     PaymentWrite,
     RefundRead,
     RefundWrite,
+    RefundApprove,
     ApiKeyRead,
     ApiKeyWrite,
     MerchantAccountRead,
     MerchantAccountWrite,
+    WebhookSecretManage,
     MerchantConnectorAccountRead,
     MerchantConnectorAccountWrite,
     RoutingRead,
@@ -0,0 +1,152 @@
+use common_enums::IncomingWebhookDeadLetterStatus;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The constraints to apply when filtering incoming webhooks which could not be processed.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeadLetterListConstraints {
+    /// Filter webhooks with the specified status.
+    pub status: Option<IncomingWebhookDeadLetterStatus>,
+
+    /// Filter webhooks received after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter webhooks received before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of webhooks.
+    pub limit: Option<u16>,
+
+    /// Include webhooks after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// The response body for each item when listing incoming webhooks which could not be processed.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeadLetterListItemResponse {
+    /// The identifier for the failed incoming webhook.
+    #[schema(max_length = 64, example = "whdl_018fb4f3ba3c7e7fa3dbc07b12b6c2f5")]
+    pub dead_letter_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The connector name or merchant connector account ID the webhook was received for, as
+    /// specified in the webhook URL.
+    #[schema(max_length = 64, example = "stripe")]
+    pub connector: String,
+
+    /// Indicates whether the webhook is yet to be processed successfully.
+    pub status: IncomingWebhookDeadLetterStatus,
+
+    /// The error code of the last failure in processing the webhook.
+    #[schema(example = "WE_04")]
+    pub error_code: String,
+
+    /// The error message of the last failure in processing the webhook.
+    #[schema(example = "Webhook resource not found")]
+    pub error_message: String,
+
+    /// The number of times the connector delivered the webhook and processing it failed.
+    #[schema(example = 1)]
+    pub failure_count: i32,
+
+    /// The number of times the webhook was replayed.
+    #[schema(example = 0)]
+    pub replay_count: i32,
+
+    /// Time at which the webhook was first received.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the webhook was last replayed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_replayed_at: Option<PrimitiveDateTime>,
+}
+
+/// The response body for retrieving or replaying an incoming webhook which could not be processed.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeadLetterRetrieveResponse {
+    #[serde(flatten)]
+    pub dead_letter_information: DeadLetterListItemResponse,
+
+    /// Additional details about the last failure in processing the webhook.
+    #[schema(example = "Incoming webhook flow for payments failed")]
+    pub error_reason: Option<String>,
+
+    /// The request information (method, path, headers and body) of the webhook, as last received.
+    /// The values in the body are masked and only non-sensitive headers are included.
+    pub request: IncomingWebhookRequestContent,
+}
+
+impl common_utils::events::ApiEventMetric for DeadLetterRetrieveResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(
+            common_utils::events::ApiEventsType::IncomingWebhookDeadLetter {
+                merchant_id: self.dead_letter_information.merchant_id.clone(),
+                dead_letter_id: Some(self.dead_letter_information.dead_letter_id.clone()),
+            },
+        )
+    }
+}
+
+/// The request information (method, path, headers and body) of an incoming webhook.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookRequestContent {
+    /// The HTTP method of the webhook.
+    #[schema(example = "POST")]
+    pub method: String,
+
+    /// The path of the webhook URL.
+    #[schema(example = "/webhooks/merchant_1/stripe")]
+    pub path: String,
+
+    /// The non-sensitive request headers of the webhook.
+    #[schema(example = json!([["content-type", "application/json"], ["content-length", "1024"]]))]
+    pub headers: Vec<(String, String)>,
+
+    /// The request body of the webhook, with all values masked. Bodies which are not JSON are
+    /// masked as a whole.
+    #[schema(value_type = Object, example = json!({"type": "*** alloc::string::String ***"}))]
+    pub body: serde_json::Value,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterListRequestInternal {
+    pub merchant_id: String,
+    pub constraints: DeadLetterListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for DeadLetterListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(
+            common_utils::events::ApiEventsType::IncomingWebhookDeadLetter {
+                merchant_id: self.merchant_id.clone(),
+                dead_letter_id: None,
+            },
+        )
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterRequestInternal {
+    pub merchant_id: String,
+    pub dead_letter_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for DeadLetterRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(
+            common_utils::events::ApiEventsType::IncomingWebhookDeadLetter {
+                merchant_id: self.merchant_id.clone(),
+                dead_letter_id: Some(self.dead_letter_id.clone()),
+            },
+        )
+    }
+}
@@ -72,6 +72,12 @@ pub struct EventListItemResponse {
     #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
     pub initial_attempt_id: String,
 
+    /// The identifier of the business profile webhook endpoint the event was delivered to. This
+    /// is not set for events delivered to the webhook URL in the webhook details of the business
+    /// profile.
+    #[schema(max_length = 32, example = "finance")]
+    pub webhook_endpoint_id: Option<String>,
+
     /// Time at which the event was created.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
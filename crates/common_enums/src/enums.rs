@@ -1068,6 +1068,8 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    #[cfg(feature = "payouts")]
+    Payouts,
 }
 
 #[derive(
@@ -1094,6 +1096,9 @@ pub enum EventType {
     PaymentCancelled,
     PaymentAuthorized,
     PaymentCaptured,
+    /// Authorization of a manually captured payment is about to expire
+    PaymentAuthorizationExpiring,
+    PaymentExpired,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -1104,8 +1109,18 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    /// The deadline to challenge an open dispute is approaching
+    DisputeDeadlineApproaching,
     MandateActive,
     MandateRevoked,
+    #[cfg(feature = "payouts")]
+    PayoutSuccess,
+    #[cfg(feature = "payouts")]
+    PayoutFailed,
+    #[cfg(feature = "payouts")]
+    PayoutProcessing,
+    #[cfg(feature = "payouts")]
+    PayoutCancelled,
 }
 
 #[derive(
@@ -1129,6 +1144,31 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// The status of an incoming webhook which could not be processed
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum IncomingWebhookDeadLetterStatus {
+    /// The webhook has not been processed successfully yet, and can be replayed
+    Pending,
+    /// The webhook was processed successfully, either when it was replayed or when the connector
+    /// delivered it again
+    Resolved,
+}
+
 // TODO: This decision about using KV mode or not,
 // should be taken at a top level rather than pushing it down to individual functions via an enum.
 #[derive(
@@ -1183,6 +1223,8 @@ pub enum IntentStatus {
     RequiresCapture,
     PartiallyCaptured,
     PartiallyCapturedAndCapturable,
+    Expired,
+    PartiallyAuthorized,
 }
 
 #[derive(
@@ -1525,6 +1567,7 @@ pub enum RefundStatus {
     Pending,
     Success,
     TransactionFailure,
+    PendingApproval,
 }
 
 /// The status of the mandate, which indicates whether it can be used to initiate a payment.
@@ -1636,6 +1679,63 @@ pub enum DisputeStatus {
     DisputeLost,
 }
 
+/// The network alert service through which a pre-dispute alert was received
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeAlertProvider {
+    Ethoca,
+    Verifi,
+    /// Stand-in provider for alerts posted directly by the merchant, used for testing
+    Local,
+}
+
+/// What was done in response to a pre-dispute alert
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeAlertOutcome {
+    /// The disputed payment was refunded in response to the alert
+    Refunded,
+    /// The disputed payment had already been refunded
+    AlreadyRefunded,
+    /// A dispute has already been raised against the payment, so a refund would not prevent it
+    AlreadyDisputed,
+    /// The alert could not be matched to a single payment
+    NotMatched,
+    /// The alert was matched, but the dispute alert policy of the business profile does not allow
+    /// refunding it automatically
+    ManualReview,
+    /// The refund created in response to the alert failed
+    RefundFailed,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -2504,6 +2604,7 @@ pub enum PermissionGroup {
     MerchantDetailsView,
     MerchantDetailsManage,
     OrganizationManage,
+    RefundApprovalsManage,
 }
 
 /// Name of banks supported by Hyperswitch
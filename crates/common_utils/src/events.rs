@@ -53,12 +53,23 @@ pub enum ApiEventsType {
     Dispute {
         dispute_id: String,
     },
+    DisputeAlert {
+        alert_id: String,
+    },
+    DisputePolicy,
     Events {
         merchant_id_or_profile_id: String,
     },
+    IncomingWebhookDeadLetter {
+        merchant_id: String,
+        dead_letter_id: Option<String>,
+    },
     Poll {
         poll_id: String,
     },
+    BulkOperation {
+        job_id: String,
+    },
 }
 
 impl ApiEventMetric for serde_json::Value {}
@@ -43,6 +43,7 @@ pub struct Authentication {
     pub profile_id: String,
     pub payment_id: Option<String>,
     pub merchant_connector_id: String,
+    pub ds_trans_id: Option<String>,
 }
 
 impl Authentication {
@@ -88,6 +89,7 @@ pub struct AuthenticationNew {
     pub profile_id: String,
     pub payment_id: Option<String>,
     pub merchant_connector_id: String,
+    pub ds_trans_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -114,6 +116,7 @@ pub enum AuthenticationUpdate {
         acs_trans_id: Option<String>,
         acs_signed_content: Option<String>,
         authentication_status: common_enums::AuthenticationStatus,
+        ds_trans_id: Option<String>,
     },
     PostAuthenticationUpdate {
         trans_status: common_enums::TransactionStatus,
@@ -162,6 +165,7 @@ pub struct AuthenticationUpdateInternal {
     pub acs_trans_id: Option<String>,
     pub three_dsserver_trans_id: Option<String>,
     pub acs_signed_content: Option<String>,
+    pub ds_trans_id: Option<String>,
 }
 
 impl Default for AuthenticationUpdateInternal {
@@ -193,6 +197,7 @@ impl Default for AuthenticationUpdateInternal {
             acs_trans_id: Default::default(),
             three_dsserver_trans_id: Default::default(),
             acs_signed_content: Default::default(),
+            ds_trans_id: Default::default(),
         }
     }
 }
@@ -226,6 +231,7 @@ impl AuthenticationUpdateInternal {
             acs_trans_id,
             three_dsserver_trans_id,
             acs_signed_content,
+            ds_trans_id,
         } = self;
         Authentication {
             connector_authentication_id: connector_authentication_id
@@ -258,6 +264,7 @@ impl AuthenticationUpdateInternal {
             acs_trans_id: acs_trans_id.or(source.acs_trans_id),
             three_ds_server_trans_id: three_dsserver_trans_id.or(source.three_ds_server_trans_id),
             acs_signed_content: acs_signed_content.or(source.acs_signed_content),
+            ds_trans_id: ds_trans_id.or(source.ds_trans_id),
             ..source
         }
     }
@@ -333,6 +340,7 @@ impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
                 acs_trans_id,
                 acs_signed_content,
                 authentication_status,
+                ds_trans_id,
             } => Self {
                 cavv: authentication_value,
                 trans_status: Some(trans_status),
@@ -343,6 +351,7 @@ impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
                 acs_trans_id,
                 acs_signed_content,
                 authentication_status: Some(authentication_status),
+                ds_trans_id,
                 ..Default::default()
             },
             AuthenticationUpdate::PostAuthenticationUpdate {
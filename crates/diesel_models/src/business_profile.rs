@@ -1,5 +1,6 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use masking::Secret;
 
 use crate::schema::business_profile;
 
@@ -38,6 +39,14 @@ pub struct BusinessProfile {
     pub is_extended_card_info_enabled: Option<bool>,
     pub extended_card_info_config: Option<pii::SecretSerdeValue>,
     pub is_connector_agnostic_mit_enabled: Option<bool>,
+    pub authorization_expiry_config: Option<serde_json::Value>,
+    pub intent_expiry: Option<i64>,
+    pub max_over_capture_percentage: Option<i16>,
+    pub refund_approval_policy: Option<serde_json::Value>,
+    pub dispute_alert_policy: Option<serde_json::Value>,
+    pub webhook_endpoints: Option<serde_json::Value>,
+    pub webhook_secret_versions: Option<serde_json::Value>,
+    pub refund_payout_fallback_policy: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -67,6 +76,14 @@ pub struct BusinessProfileNew {
     pub is_extended_card_info_enabled: Option<bool>,
     pub extended_card_info_config: Option<pii::SecretSerdeValue>,
     pub is_connector_agnostic_mit_enabled: Option<bool>,
+    pub authorization_expiry_config: Option<serde_json::Value>,
+    pub intent_expiry: Option<i64>,
+    pub max_over_capture_percentage: Option<i16>,
+    pub refund_approval_policy: Option<serde_json::Value>,
+    pub dispute_alert_policy: Option<serde_json::Value>,
+    pub webhook_endpoints: Option<serde_json::Value>,
+    pub webhook_secret_versions: Option<serde_json::Value>,
+    pub refund_payout_fallback_policy: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -93,6 +110,14 @@ pub struct BusinessProfileUpdateInternal {
     pub is_extended_card_info_enabled: Option<bool>,
     pub extended_card_info_config: Option<pii::SecretSerdeValue>,
     pub is_connector_agnostic_mit_enabled: Option<bool>,
+    pub authorization_expiry_config: Option<serde_json::Value>,
+    pub intent_expiry: Option<i64>,
+    pub max_over_capture_percentage: Option<i16>,
+    pub refund_approval_policy: Option<serde_json::Value>,
+    pub dispute_alert_policy: Option<serde_json::Value>,
+    pub webhook_endpoints: Option<serde_json::Value>,
+    pub webhook_secret_versions: Option<serde_json::Value>,
+    pub refund_payout_fallback_policy: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -116,6 +141,14 @@ pub enum BusinessProfileUpdate {
         session_expiry: Option<i64>,
         authentication_connector_details: Option<serde_json::Value>,
         extended_card_info_config: Option<pii::SecretSerdeValue>,
+        authorization_expiry_config: Option<serde_json::Value>,
+        intent_expiry: Option<i64>,
+        max_over_capture_percentage: Option<i16>,
+        refund_approval_policy: Option<serde_json::Value>,
+        dispute_alert_policy: Option<serde_json::Value>,
+        webhook_endpoints: Option<serde_json::Value>,
+        webhook_secret_versions: Option<serde_json::Value>,
+        refund_payout_fallback_policy: Option<serde_json::Value>,
     },
     ExtendedCardInfoUpdate {
         is_extended_card_info_enabled: Option<bool>,
@@ -123,6 +156,9 @@ pub enum BusinessProfileUpdate {
     ConnectorAgnosticMitUpdate {
         is_connector_agnostic_mit_enabled: Option<bool>,
     },
+    WebhookSecretUpdate {
+        webhook_secret_versions: serde_json::Value,
+    },
 }
 
 impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
@@ -147,6 +183,14 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 session_expiry,
                 authentication_connector_details,
                 extended_card_info_config,
+                authorization_expiry_config,
+                intent_expiry,
+                max_over_capture_percentage,
+                refund_approval_policy,
+                dispute_alert_policy,
+                webhook_endpoints,
+                webhook_secret_versions,
+                refund_payout_fallback_policy,
             } => Self {
                 profile_name,
                 modified_at,
@@ -166,6 +210,14 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 session_expiry,
                 authentication_connector_details,
                 extended_card_info_config,
+                authorization_expiry_config,
+                intent_expiry,
+                max_over_capture_percentage,
+                refund_approval_policy,
+                dispute_alert_policy,
+                webhook_endpoints,
+                webhook_secret_versions,
+                refund_payout_fallback_policy,
                 ..Default::default()
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
@@ -180,6 +232,12 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 is_connector_agnostic_mit_enabled,
                 ..Default::default()
             },
+            BusinessProfileUpdate::WebhookSecretUpdate {
+                webhook_secret_versions,
+            } => Self {
+                webhook_secret_versions: Some(webhook_secret_versions),
+                ..Default::default()
+            },
         }
     }
 }
@@ -210,6 +268,14 @@ impl From<BusinessProfileNew> for BusinessProfile {
             is_connector_agnostic_mit_enabled: new.is_connector_agnostic_mit_enabled,
             is_extended_card_info_enabled: new.is_extended_card_info_enabled,
             extended_card_info_config: new.extended_card_info_config,
+            authorization_expiry_config: new.authorization_expiry_config,
+            intent_expiry: new.intent_expiry,
+            max_over_capture_percentage: new.max_over_capture_percentage,
+            refund_approval_policy: new.refund_approval_policy,
+            dispute_alert_policy: new.dispute_alert_policy,
+            webhook_endpoints: new.webhook_endpoints,
+            webhook_secret_versions: new.webhook_secret_versions,
+            refund_payout_fallback_policy: new.refund_payout_fallback_policy,
         }
     }
 }
@@ -237,6 +303,14 @@ impl BusinessProfileUpdate {
             is_extended_card_info_enabled,
             extended_card_info_config,
             is_connector_agnostic_mit_enabled,
+            authorization_expiry_config,
+            intent_expiry,
+            max_over_capture_percentage,
+            refund_approval_policy,
+            dispute_alert_policy,
+            webhook_endpoints,
+            webhook_secret_versions,
+            refund_payout_fallback_policy,
         } = self.into();
         BusinessProfile {
             profile_name: profile_name.unwrap_or(source.profile_name),
@@ -261,7 +335,50 @@ impl BusinessProfileUpdate {
             is_extended_card_info_enabled,
             is_connector_agnostic_mit_enabled,
             extended_card_info_config,
+            authorization_expiry_config,
+            intent_expiry,
+            max_over_capture_percentage,
+            refund_approval_policy,
+            dispute_alert_policy,
+            webhook_endpoints,
+            webhook_secret_versions: webhook_secret_versions.or(source.webhook_secret_versions),
+            refund_payout_fallback_policy,
             ..source
         }
     }
 }
+
+/// Versions of the secret used for signing outgoing webhooks of a business profile. Until the
+/// secret is first rotated, webhooks are signed with the `payment_response_hash_key` of the
+/// business profile as version 1 of the secret.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebhookSecretVersions {
+    pub current_version: i32,
+    /// The secret generated by the last rotation. It is kept apart from
+    /// `payment_response_hash_key`, which also hashes the redirect responses of the business
+    /// profile.
+    #[serde(default)]
+    pub current_secret: Option<Secret<String>>,
+    /// The secrets used before the last rotations, webhooks continue to be signed with them until
+    /// they expire
+    #[serde(default)]
+    pub previous_secrets: Vec<PreviousWebhookSecret>,
+}
+
+impl Default for WebhookSecretVersions {
+    fn default() -> Self {
+        Self {
+            current_version: 1,
+            current_secret: None,
+            previous_secrets: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PreviousWebhookSecret {
+    pub version: i32,
+    pub secret: Secret<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
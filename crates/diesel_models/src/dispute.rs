@@ -61,6 +61,7 @@ pub struct Dispute {
     pub profile_id: Option<String>,
     pub merchant_connector_id: Option<String>,
     pub dispute_amount: i64,
+    pub policy_decision: Option<serde_json::Value>,
 }
 
 #[derive(Debug)]
@@ -81,6 +82,9 @@ pub enum DisputeUpdate {
     EvidenceUpdate {
         evidence: Secret<serde_json::Value>,
     },
+    PolicyDecisionUpdate {
+        policy_decision: serde_json::Value,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -95,6 +99,7 @@ pub struct DisputeUpdateInternal {
     connector_updated_at: Option<PrimitiveDateTime>,
     modified_at: Option<PrimitiveDateTime>,
     evidence: Option<Secret<serde_json::Value>>,
+    policy_decision: Option<serde_json::Value>,
 }
 
 impl From<DisputeUpdate> for DisputeUpdateInternal {
@@ -132,6 +137,11 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 evidence: Some(evidence),
                 ..Default::default()
             },
+            DisputeUpdate::PolicyDecisionUpdate { policy_decision } => Self {
+                policy_decision: Some(policy_decision),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
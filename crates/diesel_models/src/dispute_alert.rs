@@ -0,0 +1,96 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::dispute_alert};
+
+#[derive(Clone, Debug, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = dispute_alert)]
+pub struct DisputeAlertNew {
+    pub alert_id: String,
+    pub merchant_id: String,
+    pub provider: storage_enums::DisputeAlertProvider,
+    pub provider_alert_id: String,
+    pub arn: Option<String>,
+    pub card_last4: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub transaction_date: PrimitiveDateTime,
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = dispute_alert)]
+pub struct DisputeAlert {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub alert_id: String,
+    pub merchant_id: String,
+    pub provider: storage_enums::DisputeAlertProvider,
+    pub provider_alert_id: String,
+    pub arn: Option<String>,
+    pub card_last4: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    #[serde(with = "custom_serde::iso8601")]
+    pub transaction_date: PrimitiveDateTime,
+    pub reason: Option<String>,
+    pub payment_id: Option<String>,
+    pub attempt_id: Option<String>,
+    pub profile_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub outcome: Option<storage_enums::DisputeAlertOutcome>,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum DisputeAlertUpdate {
+    OutcomeUpdate {
+        payment_id: Option<String>,
+        attempt_id: Option<String>,
+        profile_id: Option<String>,
+        refund_id: Option<String>,
+        outcome: storage_enums::DisputeAlertOutcome,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = dispute_alert)]
+pub struct DisputeAlertUpdateInternal {
+    payment_id: Option<String>,
+    attempt_id: Option<String>,
+    profile_id: Option<String>,
+    refund_id: Option<String>,
+    outcome: Option<storage_enums::DisputeAlertOutcome>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<DisputeAlertUpdate> for DisputeAlertUpdateInternal {
+    fn from(dispute_alert_update: DisputeAlertUpdate) -> Self {
+        match dispute_alert_update {
+            DisputeAlertUpdate::OutcomeUpdate {
+                payment_id,
+                attempt_id,
+                profile_id,
+                refund_id,
+                outcome,
+                error_message,
+            } => Self {
+                payment_id,
+                attempt_id,
+                profile_id,
+                refund_id,
+                outcome: Some(outcome),
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
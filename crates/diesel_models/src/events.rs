@@ -23,6 +23,7 @@ pub struct EventNew {
     pub request: Option<Encryption>,
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -53,4 +54,5 @@ pub struct Event {
     pub request: Option<Encryption>,
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
+    pub webhook_endpoint_id: Option<String>,
 }
@@ -0,0 +1,65 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, enums as storage_enums, schema::incoming_webhook_dead_letter};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_dead_letter)]
+pub struct IncomingWebhookDeadLetterNew {
+    pub dead_letter_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub body_hash: String,
+    pub request: Encryption,
+    pub status: storage_enums::IncomingWebhookDeadLetterStatus,
+    pub error_code: String,
+    pub error_message: String,
+    pub error_reason: Option<String>,
+    pub failure_count: i32,
+    pub replay_count: i32,
+    pub last_replayed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_dead_letter)]
+pub struct IncomingWebhookDeadLetterUpdateInternal {
+    pub request: Option<Encryption>,
+    pub status: Option<storage_enums::IncomingWebhookDeadLetterStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub error_reason: Option<String>,
+    pub failure_count: Option<i32>,
+    pub replay_count: Option<i32>,
+    pub last_replayed_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = incoming_webhook_dead_letter, primary_key(dead_letter_id))]
+pub struct IncomingWebhookDeadLetter {
+    pub dead_letter_id: String,
+    pub merchant_id: String,
+    /// The connector name or merchant connector account ID the webhook was received for, as
+    /// specified in the webhook URL
+    pub connector: String,
+    /// Hex encoded SHA-256 hash of the webhook body, used to identify webhooks delivered again by
+    /// the connector
+    pub body_hash: String,
+    pub request: Encryption,
+    pub status: storage_enums::IncomingWebhookDeadLetterStatus,
+    pub error_code: String,
+    pub error_message: String,
+    pub error_reason: Option<String>,
+    pub failure_count: i32,
+    pub replay_count: i32,
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub last_replayed_at: Option<PrimitiveDateTime>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
@@ -12,6 +12,7 @@ pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod customers;
 pub mod dispute;
+pub mod dispute_alert;
 pub mod encryption;
 pub mod enums;
 pub mod ephemeral_key;
@@ -21,6 +22,7 @@ pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
 pub mod gsm;
+pub mod incoming_webhook_dead_letter;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -45,6 +47,7 @@ pub mod routing_algorithm;
 pub mod schema;
 pub mod user;
 pub mod user_role;
+pub mod vault_entry;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
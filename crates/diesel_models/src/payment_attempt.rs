@@ -70,6 +70,14 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub card_verification_results: Option<serde_json::Value>,
+    pub authentication_details: Option<serde_json::Value>,
+    pub is_split_tender: Option<bool>,
+    pub acquirer_reference_number: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorized_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttempt {
@@ -150,6 +158,14 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub card_verification_results: Option<serde_json::Value>,
+    pub authentication_details: Option<serde_json::Value>,
+    pub is_split_tender: Option<bool>,
+    pub acquirer_reference_number: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorized_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttemptNew {
@@ -185,6 +201,7 @@ pub enum PaymentAttemptUpdate {
         business_sub_label: Option<String>,
         amount_to_capture: Option<i64>,
         capture_method: Option<storage_enums::CaptureMethod>,
+        capture_on: Option<PrimitiveDateTime>,
         surcharge_amount: Option<i64>,
         tax_amount: Option<i64>,
         fingerprint_id: Option<String>,
@@ -232,6 +249,7 @@ pub enum PaymentAttemptUpdate {
         authentication_connector: Option<String>,
         authentication_id: Option<String>,
         payment_method_billing_address_id: Option<String>,
+        is_split_tender: Option<bool>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -268,12 +286,18 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
         amount_capturable: Option<i64>,
+        amount: Option<i64>,
         updated_by: String,
         authentication_data: Option<serde_json::Value>,
         encoded_data: Option<String>,
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
+        card_verification_results: Option<serde_json::Value>,
+        authentication_details: Option<serde_json::Value>,
+        is_split_tender: Option<bool>,
+        acquirer_reference_number: Option<String>,
+        authorized_at: Option<PrimitiveDateTime>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -302,6 +326,8 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<Option<String>>,
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
+        card_verification_results: Option<serde_json::Value>,
+        authentication_details: Option<serde_json::Value>,
     },
     CaptureUpdate {
         amount_to_capture: Option<i64>,
@@ -340,6 +366,15 @@ pub enum PaymentAttemptUpdate {
         authentication_id: Option<String>,
         updated_by: String,
     },
+    AuthorizationExpiryUpdate {
+        authorization_expires_at: Option<PrimitiveDateTime>,
+        updated_by: String,
+    },
+    CaptureScheduleUpdate {
+        capture_method: Option<storage_enums::CaptureMethod>,
+        capture_on: Option<PrimitiveDateTime>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -387,6 +422,13 @@ pub struct PaymentAttemptUpdateInternal {
     authentication_id: Option<String>,
     fingerprint_id: Option<String>,
     payment_method_billing_address_id: Option<String>,
+    authorization_expires_at: Option<PrimitiveDateTime>,
+    capture_on: Option<Option<PrimitiveDateTime>>,
+    card_verification_results: Option<serde_json::Value>,
+    authentication_details: Option<serde_json::Value>,
+    is_split_tender: Option<bool>,
+    acquirer_reference_number: Option<String>,
+    authorized_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -452,6 +494,13 @@ impl PaymentAttemptUpdate {
             authentication_id,
             payment_method_billing_address_id,
             fingerprint_id,
+            authorization_expires_at,
+            capture_on,
+            card_verification_results,
+            authentication_details,
+            is_split_tender,
+            acquirer_reference_number,
+            authorized_at,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -500,6 +549,15 @@ impl PaymentAttemptUpdate {
             payment_method_billing_address_id: payment_method_billing_address_id
                 .or(source.payment_method_billing_address_id),
             fingerprint_id: fingerprint_id.or(source.fingerprint_id),
+            authorization_expires_at: authorization_expires_at.or(source.authorization_expires_at),
+            capture_on: capture_on.unwrap_or(source.capture_on),
+            card_verification_results: card_verification_results
+                .or(source.card_verification_results),
+            authentication_details: authentication_details.or(source.authentication_details),
+            is_split_tender: is_split_tender.or(source.is_split_tender),
+            acquirer_reference_number: acquirer_reference_number
+                .or(source.acquirer_reference_number),
+            authorized_at: authorized_at.or(source.authorized_at),
             ..source
         }
     }
@@ -522,6 +580,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 business_sub_label,
                 amount_to_capture,
                 capture_method,
+                capture_on,
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
@@ -541,6 +600,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 business_sub_label,
                 amount_to_capture,
                 capture_method,
+                capture_on: capture_on.map(Some),
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
@@ -584,6 +644,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_billing_address_id,
                 fingerprint_id,
                 payment_method_id,
+                is_split_tender,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -613,6 +674,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 fingerprint_id,
                 payment_method_id,
                 capture_method,
+                is_split_tender,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -673,12 +735,18 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
+                is_split_tender,
+                acquirer_reference_number,
+                authorized_at,
             } => Self {
                 status: Some(status),
                 connector: connector.map(Some),
@@ -694,12 +762,18 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
+                is_split_tender,
+                acquirer_reference_number,
+                authorized_at,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ErrorUpdate {
@@ -714,6 +788,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
             } => Self {
                 connector: connector.map(Some),
                 status: Some(status),
@@ -727,6 +803,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
                 ..Default::default()
             },
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -852,6 +930,25 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentAttemptUpdate::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by,
+            } => Self {
+                authorization_expires_at,
+                updated_by,
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::CaptureScheduleUpdate {
+                capture_method,
+                capture_on,
+                updated_by,
+            } => Self {
+                capture_method,
+                capture_on: Some(capture_on),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
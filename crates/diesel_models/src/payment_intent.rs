@@ -58,6 +58,8 @@ pub struct PaymentIntent {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub allow_partial_authorization: Option<bool>,
+    pub l2_l3_data: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -111,6 +113,8 @@ pub struct PaymentIntentNew {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub allow_partial_authorization: Option<bool>,
+    pub l2_l3_data: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -167,6 +171,7 @@ pub enum PaymentIntentUpdate {
         session_expiry: Option<PrimitiveDateTime>,
         fingerprint_id: Option<String>,
         request_external_three_ds_authentication: Option<bool>,
+        l2_l3_data: Option<serde_json::Value>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -236,6 +241,7 @@ pub struct PaymentIntentUpdateInternal {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub l2_l3_data: Option<serde_json::Value>,
 }
 
 impl PaymentIntentUpdate {
@@ -271,6 +277,7 @@ impl PaymentIntentUpdate {
             session_expiry,
             fingerprint_id,
             request_external_three_ds_authentication,
+            l2_l3_data,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -308,6 +315,7 @@ impl PaymentIntentUpdate {
             session_expiry: session_expiry.or(source.session_expiry),
             request_external_three_ds_authentication: request_external_three_ds_authentication
                 .or(source.request_external_three_ds_authentication),
+            l2_l3_data: l2_l3_data.or(source.l2_l3_data),
             ..source
         }
     }
@@ -337,6 +345,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 session_expiry,
                 fingerprint_id,
                 request_external_three_ds_authentication,
+                l2_l3_data,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -359,6 +368,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 session_expiry,
                 fingerprint_id,
                 request_external_three_ds_authentication,
+                l2_l3_data,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate {
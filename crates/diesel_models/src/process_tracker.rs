@@ -210,6 +210,14 @@ pub enum ProcessTrackerRunner {
     ApiKeyExpiryWorkflow,
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
+    AuthorizationExpiryWorkflow,
+    ScheduledCaptureWorkflow,
+    IntentExpiryWorkflow,
+    BulkOperationWorkflow,
+    DisputeDeadlineReminderWorkflow,
+    DisputeSyncWorkflow,
+    WebhookWatchdogWorkflow,
+    VaultMigrationWorkflow,
 }
 
 #[cfg(test)]
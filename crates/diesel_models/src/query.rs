@@ -13,11 +13,13 @@ pub mod blocklist_fingerprint;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
+pub mod dispute_alert;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
 pub mod generics;
 pub mod gsm;
+pub mod incoming_webhook_dead_letter;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -37,3 +39,4 @@ pub mod role;
 pub mod routing_algorithm;
 pub mod user;
 pub mod user_role;
+pub mod vault_entry;
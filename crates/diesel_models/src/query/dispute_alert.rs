@@ -0,0 +1,74 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    dispute_alert::{
+        DisputeAlert, DisputeAlertNew, DisputeAlertUpdate, DisputeAlertUpdateInternal,
+    },
+    enums, errors,
+    schema::dispute_alert::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl DisputeAlertNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DisputeAlert> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DisputeAlert {
+    pub async fn find_by_merchant_id_alert_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::alert_id.eq(alert_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_provider_provider_alert_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        provider: enums::DisputeAlertProvider,
+        provider_alert_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::provider.eq(provider))
+                .and(dsl::provider_alert_id.eq(provider_alert_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        dispute_alert: DisputeAlertUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::alert_id.eq(self.alert_id.to_owned()),
+            DisputeAlertUpdateInternal::from(dispute_alert),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums,
+    incoming_webhook_dead_letter::{
+        IncomingWebhookDeadLetter, IncomingWebhookDeadLetterNew,
+        IncomingWebhookDeadLetterUpdateInternal,
+    },
+    schema::incoming_webhook_dead_letter::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IncomingWebhookDeadLetterNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IncomingWebhookDeadLetter> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IncomingWebhookDeadLetter {
+    pub async fn find_by_merchant_id_dead_letter_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        dead_letter_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::dead_letter_id.eq(dead_letter_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_connector_body_hash(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector: &str,
+        body_hash: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::body_hash.eq(body_hash.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        status: Option<enums::IncomingWebhookDeadLetterStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering incoming webhook dead letters by constraints")
+    }
+
+    pub async fn update_by_merchant_id_dead_letter_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        dead_letter_id: &str,
+        dead_letter: IncomingWebhookDeadLetterUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::dead_letter_id.eq(dead_letter_id.to_owned())),
+            dead_letter,
+        )
+        .await
+    }
+}
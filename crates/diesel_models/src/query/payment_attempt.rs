@@ -145,6 +145,20 @@ impl PaymentAttempt {
         .await
     }
 
+    pub async fn find_by_merchant_id_acquirer_reference_number(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        acquirer_reference_number: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::acquirer_reference_number.eq(acquirer_reference_number.to_owned())),
+        )
+        .await
+    }
+
     pub async fn find_by_merchant_id_attempt_id(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -212,6 +226,33 @@ impl PaymentAttempt {
         .await
     }
 
+    pub async fn find_by_merchant_id_amount_currency_created_at_range(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        amount: i64,
+        currency: enums::Currency,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::amount.eq(amount))
+                .and(dsl::currency.eq(currency))
+                .and(dsl::created_at.between(start_time, end_time)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     pub async fn get_filters_for_payments(
         conn: &PgPooledConn,
         pi: &[PaymentIntent],
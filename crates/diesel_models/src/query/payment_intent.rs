@@ -2,7 +2,7 @@ use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    errors,
+    enums, errors,
     payment_intent::{
         PaymentIntent, PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal,
     },
@@ -55,6 +55,28 @@ impl PaymentIntent {
         .await
     }
 
+    pub async fn filter_by_merchant_id_profile_id_status_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        profile_id: &str,
+        statuses: &[enums::IntentStatus],
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::profile_id.eq(profile_id.to_owned()))
+                .and(dsl::status.eq_any(statuses.to_vec()))
+                .and(dsl::created_at.le(created_before)),
+            Some(limit),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     pub async fn find_optional_by_payment_id_merchant_id(
         conn: &PgPooledConn,
         payment_id: &str,
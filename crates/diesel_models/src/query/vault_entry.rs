@@ -0,0 +1,64 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::vault_entry::dsl,
+    vault_entry::{VaultEntry, VaultEntryNew},
+    PgPooledConn, StorageResult,
+};
+
+impl VaultEntryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultEntry> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultEntry {
+    pub async fn find_by_merchant_id_customer_id_vault_reference(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        vault_reference: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::vault_reference.eq(vault_reference.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_optional_by_merchant_id_customer_id_fingerprint(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        fingerprint: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::fingerprint.eq(fingerprint.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_customer_id_vault_reference(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        vault_reference: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::vault_reference.eq(vault_reference.to_owned())),
+        )
+        .await
+    }
+}
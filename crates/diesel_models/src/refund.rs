@@ -39,6 +39,8 @@ pub struct Refund {
     pub profile_id: Option<String>,
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
+    pub approval_details: Option<serde_json::Value>,
+    pub fulfilment_payout_id: Option<String>,
 }
 
 #[derive(
@@ -81,6 +83,8 @@ pub struct RefundNew {
     pub profile_id: Option<String>,
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
+    pub approval_details: Option<serde_json::Value>,
+    pub fulfilment_payout_id: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -110,6 +114,20 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    ApprovalUpdate {
+        refund_status: storage_enums::RefundStatus,
+        approval_details: serde_json::Value,
+        refund_error_message: Option<String>,
+        refund_error_code: Option<String>,
+        updated_by: String,
+    },
+    PayoutFulfilmentUpdate {
+        fulfilment_payout_id: String,
+        refund_status: storage_enums::RefundStatus,
+        refund_error_message: Option<String>,
+        refund_error_code: Option<String>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -124,6 +142,8 @@ pub struct RefundUpdateInternal {
     refund_reason: Option<String>,
     refund_error_code: Option<String>,
     updated_by: String,
+    approval_details: Option<serde_json::Value>,
+    fulfilment_payout_id: Option<String>,
 }
 
 impl RefundUpdateInternal {
@@ -138,6 +158,8 @@ impl RefundUpdateInternal {
             refund_reason: self.refund_reason,
             refund_error_code: self.refund_error_code,
             updated_by: self.updated_by,
+            approval_details: self.approval_details.or(source.approval_details),
+            fulfilment_payout_id: self.fulfilment_payout_id.or(source.fulfilment_payout_id),
             ..source
         }
     }
@@ -196,6 +218,35 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            RefundUpdate::ApprovalUpdate {
+                refund_status,
+                approval_details,
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+            } => Self {
+                refund_status: Some(refund_status),
+                approval_details: Some(approval_details),
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+                ..Default::default()
+            },
+            RefundUpdate::PayoutFulfilmentUpdate {
+                fulfilment_payout_id,
+                refund_status,
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+            } => Self {
+                fulfilment_payout_id: Some(fulfilment_payout_id),
+                refund_status: Some(refund_status),
+                sent_to_gateway: Some(true),
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -212,6 +263,8 @@ impl RefundUpdate {
             refund_reason,
             refund_error_code,
             updated_by,
+            approval_details,
+            fulfilment_payout_id,
         } = self.into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
@@ -223,6 +276,8 @@ impl RefundUpdate {
             metadata: metadata.or(source.metadata),
             refund_reason: refund_reason.or(source.refund_reason),
             updated_by,
+            approval_details: approval_details.or(source.approval_details),
+            fulfilment_payout_id: fulfilment_payout_id.or(source.fulfilment_payout_id),
             ..source
         }
     }
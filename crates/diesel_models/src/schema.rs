@@ -116,6 +116,8 @@ diesel::table! {
         payment_id -> Nullable<Varchar>,
         #[max_length = 128]
         merchant_connector_id -> Varchar,
+        #[max_length = 64]
+        ds_trans_id -> Nullable<Varchar>,
     }
 }
 
@@ -195,6 +197,14 @@ diesel::table! {
         is_extended_card_info_enabled -> Nullable<Bool>,
         extended_card_info_config -> Nullable<Jsonb>,
         is_connector_agnostic_mit_enabled -> Nullable<Bool>,
+        authorization_expiry_config -> Nullable<Jsonb>,
+        intent_expiry -> Nullable<Int8>,
+        max_over_capture_percentage -> Nullable<Int2>,
+        refund_approval_policy -> Nullable<Jsonb>,
+        dispute_alert_policy -> Nullable<Jsonb>,
+        webhook_endpoints -> Nullable<Jsonb>,
+        webhook_secret_versions -> Nullable<Jsonb>,
+        refund_payout_fallback_policy -> Nullable<Jsonb>,
     }
 }
 
@@ -361,6 +371,46 @@ diesel::table! {
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
         dispute_amount -> Int8,
+        policy_decision -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    dispute_alert (id) {
+        id -> Int4,
+        #[max_length = 64]
+        alert_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        provider -> Varchar,
+        #[max_length = 255]
+        provider_alert_id -> Varchar,
+        #[max_length = 64]
+        arn -> Nullable<Varchar>,
+        #[max_length = 4]
+        card_last4 -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        transaction_date -> Timestamp,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        attempt_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        outcome -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -383,13 +433,15 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 128]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
         request -> Nullable<Bytea>,
         response -> Nullable<Bytea>,
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
+        #[max_length = 32]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -486,6 +538,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incoming_webhook_dead_letter (dead_letter_id) {
+        #[max_length = 64]
+        dead_letter_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        body_hash -> Varchar,
+        request -> Bytea,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        error_code -> Varchar,
+        error_message -> Text,
+        error_reason -> Nullable<Text>,
+        failure_count -> Int4,
+        replay_count -> Int4,
+        last_replayed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -777,6 +857,13 @@ diesel::table! {
         fingerprint_id -> Nullable<Varchar>,
         #[max_length = 64]
         payment_method_billing_address_id -> Nullable<Varchar>,
+        authorization_expires_at -> Nullable<Timestamp>,
+        card_verification_results -> Nullable<Jsonb>,
+        authentication_details -> Nullable<Jsonb>,
+        is_split_tender -> Nullable<Bool>,
+        #[max_length = 64]
+        acquirer_reference_number -> Nullable<Varchar>,
+        authorized_at -> Nullable<Timestamp>,
     }
 }
 
@@ -845,6 +932,8 @@ diesel::table! {
         #[max_length = 64]
         fingerprint_id -> Nullable<Varchar>,
         request_external_three_ds_authentication -> Nullable<Bool>,
+        allow_partial_authorization -> Nullable<Bool>,
+        l2_l3_data -> Nullable<Jsonb>,
     }
 }
 
@@ -1079,6 +1168,9 @@ diesel::table! {
         updated_by -> Varchar,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        approval_details -> Nullable<Jsonb>,
+        #[max_length = 64]
+        fulfilment_payout_id -> Nullable<Varchar>,
     }
 }
 
@@ -1195,6 +1287,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    vault_entry (merchant_id, vault_reference) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        vault_reference -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 128]
+        fingerprint -> Nullable<Varchar>,
+        enc_data -> Bytea,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -1209,10 +1320,12 @@ diesel::allow_tables_to_appear_in_same_query!(
     customers,
     dashboard_metadata,
     dispute,
+    dispute_alert,
     events,
     file_metadata,
     fraud_check,
     gateway_status_map,
+    incoming_webhook_dead_letter,
     incremental_authorization,
     locker_mock_up,
     mandate,
@@ -1233,4 +1346,5 @@ diesel::allow_tables_to_appear_in_same_query!(
     routing_algorithm,
     user_roles,
     users,
+    vault_entry,
 );
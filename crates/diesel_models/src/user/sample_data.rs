@@ -73,6 +73,14 @@ pub struct PaymentAttemptBatchNew {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub card_verification_results: Option<serde_json::Value>,
+    pub authentication_details: Option<serde_json::Value>,
+    pub is_split_tender: Option<bool>,
+    pub acquirer_reference_number: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorized_at: Option<PrimitiveDateTime>,
 }
 
 #[allow(dead_code)]
@@ -133,6 +141,12 @@ impl PaymentAttemptBatchNew {
             mandate_data: self.mandate_data,
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            authorization_expires_at: self.authorization_expires_at,
+            card_verification_results: self.card_verification_results,
+            authentication_details: self.authentication_details,
+            is_split_tender: self.is_split_tender,
+            acquirer_reference_number: self.acquirer_reference_number,
+            authorized_at: self.authorized_at,
         }
     }
 }
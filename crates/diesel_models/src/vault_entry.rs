@@ -0,0 +1,32 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, schema::vault_entry};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = vault_entry)]
+pub struct VaultEntryNew {
+    pub merchant_id: String,
+    pub vault_reference: String,
+    pub customer_id: String,
+    pub fingerprint: Option<String>,
+    pub enc_data: Encryption,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = vault_entry, primary_key(merchant_id, vault_reference))]
+pub struct VaultEntry {
+    pub merchant_id: String,
+    /// Reference of the stored data, returned to the caller as the card reference
+    pub vault_reference: String,
+    pub customer_id: String,
+    /// Hex encoded HMAC of the card number keyed with the merchant key, used to detect cards
+    /// stored again for the same customer. Not present for non-card payment method data.
+    pub fingerprint: Option<String>,
+    /// Payment method data encrypted with the merchant key
+    pub enc_data: Encryption,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
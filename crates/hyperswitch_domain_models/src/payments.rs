@@ -60,4 +60,6 @@ pub struct PaymentIntent {
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub session_expiry: Option<PrimitiveDateTime>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub allow_partial_authorization: Option<bool>,
+    pub l2_l3_data: Option<serde_json::Value>,
 }
@@ -54,6 +54,13 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentAttempt, errors::StorageError>;
 
+    async fn find_payment_attempt_by_merchant_id_acquirer_reference_number(
+        &self,
+        merchant_id: &str,
+        acquirer_reference_number: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<PaymentAttempt, errors::StorageError>;
+
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
         &self,
         payment_id: &str,
@@ -83,6 +90,16 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
 
+    async fn find_attempts_by_merchant_id_amount_currency_time_range(
+        &self,
+        merchant_id: &str,
+        amount: i64,
+        currency: storage_enums::Currency,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
+
     async fn get_filters_for_payments(
         &self,
         pi: &[PaymentIntent],
@@ -166,6 +183,14 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub card_verification_results: Option<serde_json::Value>,
+    pub authentication_details: Option<serde_json::Value>,
+    pub is_split_tender: Option<bool>,
+    pub acquirer_reference_number: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorized_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttempt {
@@ -249,6 +274,14 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub card_verification_results: Option<serde_json::Value>,
+    pub authentication_details: Option<serde_json::Value>,
+    pub is_split_tender: Option<bool>,
+    pub acquirer_reference_number: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorized_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttemptNew {
@@ -279,6 +312,7 @@ pub enum PaymentAttemptUpdate {
         business_sub_label: Option<String>,
         amount_to_capture: Option<i64>,
         capture_method: Option<storage_enums::CaptureMethod>,
+        capture_on: Option<PrimitiveDateTime>,
         surcharge_amount: Option<i64>,
         tax_amount: Option<i64>,
         fingerprint_id: Option<String>,
@@ -326,6 +360,7 @@ pub enum PaymentAttemptUpdate {
         payment_method_billing_address_id: Option<String>,
         fingerprint_id: Option<String>,
         payment_method_id: Option<String>,
+        is_split_tender: Option<bool>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -362,12 +397,18 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
         amount_capturable: Option<i64>,
+        amount: Option<i64>,
         updated_by: String,
         authentication_data: Option<serde_json::Value>,
         encoded_data: Option<String>,
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
+        card_verification_results: Option<serde_json::Value>,
+        authentication_details: Option<serde_json::Value>,
+        is_split_tender: Option<bool>,
+        acquirer_reference_number: Option<String>,
+        authorized_at: Option<PrimitiveDateTime>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -396,6 +437,8 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<Option<String>>,
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
+        card_verification_results: Option<serde_json::Value>,
+        authentication_details: Option<serde_json::Value>,
     },
     CaptureUpdate {
         amount_to_capture: Option<i64>,
@@ -434,6 +477,15 @@ pub enum PaymentAttemptUpdate {
         authentication_id: Option<String>,
         updated_by: String,
     },
+    AuthorizationExpiryUpdate {
+        authorization_expires_at: Option<PrimitiveDateTime>,
+        updated_by: String,
+    },
+    CaptureScheduleUpdate {
+        capture_method: Option<storage_enums::CaptureMethod>,
+        capture_on: Option<PrimitiveDateTime>,
+        updated_by: String,
+    },
 }
 
 impl ForeignIDRef for PaymentAttempt {
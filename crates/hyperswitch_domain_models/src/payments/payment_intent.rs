@@ -30,6 +30,18 @@ pub trait PaymentIntentInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, errors::StorageError>;
 
+    /// Payment intents of the business profile in any of the given statuses, which were created
+    /// before the given time, the oldest first
+    async fn filter_payment_intents_by_profile_id_status_created_before(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        statuses: &[storage_enums::IntentStatus],
+        created_before: PrimitiveDateTime,
+        limit: i64,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentIntent>, errors::StorageError>;
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
@@ -113,6 +125,8 @@ pub struct PaymentIntentNew {
     pub fingerprint_id: Option<String>,
     pub session_expiry: Option<PrimitiveDateTime>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub allow_partial_authorization: Option<bool>,
+    pub l2_l3_data: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -169,6 +183,7 @@ pub enum PaymentIntentUpdate {
         fingerprint_id: Option<String>,
         session_expiry: Option<PrimitiveDateTime>,
         request_external_three_ds_authentication: Option<bool>,
+        l2_l3_data: Option<serde_json::Value>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -237,6 +252,7 @@ pub struct PaymentIntentUpdateInternal {
     pub fingerprint_id: Option<String>,
     pub session_expiry: Option<PrimitiveDateTime>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub l2_l3_data: Option<serde_json::Value>,
 }
 
 impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
@@ -263,6 +279,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 fingerprint_id,
                 session_expiry,
                 request_external_three_ds_authentication,
+                l2_l3_data,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -285,6 +302,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 fingerprint_id,
                 session_expiry,
                 request_external_three_ds_authentication,
+                l2_l3_data,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate {
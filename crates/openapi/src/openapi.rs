@@ -66,6 +66,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Bulk Operations", description = "Create payments, captures, voids and refunds in bulk from a file"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -81,6 +82,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
         routes::payments::payments_external_authentication,
+        routes::payments::payments_card_verification,
+        routes::payments::payments_timeline,
 
         // Routes for refunds
         routes::refunds::refunds_create,
@@ -136,10 +139,14 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::business_profile::business_profiles_retrieve,
         routes::business_profile::business_profiles_update,
         routes::business_profile::business_profiles_delete,
+        routes::business_profile::webhook_secret_retrieve,
+        routes::business_profile::webhook_secret_rotate,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
         routes::disputes::retrieve_disputes_list,
+        routes::disputes::receive_dispute_alert,
+        routes::disputes::retrieve_dispute_alert,
 
         // Routes for routing
         routes::routing::routing_create_config,
@@ -178,9 +185,16 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::list_incoming_webhook_dead_letters,
+        routes::webhook_events::retrieve_incoming_webhook_dead_letter,
+        routes::webhook_events::replay_incoming_webhook_dead_letter,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
+
+        // Routes for bulk operations
+        routes::bulk_operations::bulk_operations_create,
+        routes::bulk_operations::bulk_operations_retrieve,
     ),
     components(schemas(
         api_models::refunds::RefundRequest,
@@ -188,6 +202,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
+        api_models::refunds::RefundApprovalDetails,
+        api_models::refunds::RefundApprovalDecision,
         api_models::admin::MerchantAccountCreate,
         api_models::admin::MerchantAccountUpdate,
         api_models::admin::MerchantAccountDeleteResponse,
@@ -195,6 +211,17 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
+        api_models::admin::AuthorizationExpiryConfig,
+        api_models::admin::RefundApprovalPolicy,
+        api_models::admin::DisputeAlertPolicy,
+        api_models::admin::RefundPayoutFallbackPolicy,
+        api_models::admin::WebhookEndpoint,
+        api_models::admin::WebhookEndpointResponse,
+        api_models::admin::WebhookMessageQueue,
+        api_models::admin::WebhookSecretRotateRequest,
+        api_models::admin::WebhookSecretResponse,
+        api_models::admin::PreviousWebhookSecretVersion,
+        api_models::admin::WebhookEndpointSecret,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerDeleteResponse,
         api_models::payment_methods::PaymentMethodCreate,
@@ -213,6 +240,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::poll::PollResponse,
         api_models::poll::PollStatus,
+        api_models::bulk_operations::BulkOperationCreateRequest,
+        api_models::bulk_operations::BulkOperationType,
+        api_models::bulk_operations::BulkOperationRow,
+        api_models::bulk_operations::BulkOperationStatus,
+        api_models::bulk_operations::BulkOperationResponse,
+        api_models::bulk_operations::BulkOperationRowStatus,
+        api_models::bulk_operations::BulkOperationRowResult,
         api_models::customers::CustomerResponse,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
@@ -236,11 +270,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::CardNetwork,
         api_models::enums::DisputeStage,
         api_models::enums::DisputeStatus,
+        api_models::enums::DisputeAlertProvider,
+        api_models::enums::DisputeAlertOutcome,
         api_models::enums::CountryAlpha2,
         api_models::enums::FieldType,
         api_models::enums::FrmAction,
         api_models::enums::FrmPreferredFlowTypes,
         api_models::enums::RetryAction,
+        api_models::enums::AuthorizationExpiryAction,
+        api_models::enums::WebhookApiVersion,
         api_models::enums::AttemptStatus,
         api_models::enums::CaptureStatus,
         api_models::enums::ReconStatus,
@@ -263,6 +301,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
         api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeAlertRequest,
+        api_models::disputes::DisputeAlertResponse,
+        api_models::disputes::DisputePolicyAction,
+        api_models::disputes::DisputePolicyDecision,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
@@ -310,6 +352,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::NoonData,
         api_models::payments::OrderDetails,
         api_models::payments::OrderDetailsWithAmount,
+        api_models::payments::L2L3Data,
+        api_models::payments::L2L3LineItem,
         api_models::payments::NextActionType,
         api_models::payments::WalletData,
         api_models::payments::NextActionData,
@@ -401,6 +445,19 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::SdkInformation,
         api_models::payments::DeviceChannel,
         api_models::payments::ThreeDsCompletionIndicator,
+        api_models::payments::CardVerificationRequest,
+        api_models::payments::CardVerificationResponse,
+        api_models::payments::CardVerificationStatus,
+        api_models::payments::CardVerificationResults,
+        api_models::payments::CardVerificationCheckResult,
+        api_models::payments::AuthenticationSource,
+        api_models::payments::PaymentAuthenticationDetails,
+        api_models::payments::PaymentTimelineEventType,
+        api_models::payments::PaymentTimelineEvent,
+        api_models::payments::PaymentTimelineEventDetails,
+        api_models::payments::PaymentTimelineRoutingApproach,
+        api_models::payments::PaymentTimelineRoutingDetails,
+        api_models::payments::PaymentTimelineResponse,
         api_models::enums::TransactionStatus,
         api_models::payments::BrowserInformation,
         api_models::payments::PaymentCreatePaymentLinkConfig,
@@ -511,6 +568,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::webhook_dead_letters::DeadLetterListItemResponse,
+        api_models::webhook_dead_letters::DeadLetterRetrieveResponse,
+        api_models::webhook_dead_letters::IncomingWebhookRequestContent,
+        api_models::enums::IncomingWebhookDeadLetterStatus,
     )),
     modifiers(&SecurityAddon)
 )]
@@ -2,6 +2,7 @@
 
 pub mod api_keys;
 pub mod blocklist;
+pub mod bulk_operations;
 pub mod business_profile;
 pub mod customers;
 pub mod disputes;
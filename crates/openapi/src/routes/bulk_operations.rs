@@ -0,0 +1,36 @@
+/// Bulk Operations - Create
+///
+/// Starts processing the payment creates, captures, voids and refunds described by the rows of a file uploaded with the purpose `bulk_operations`. The rows are processed in the background, and the status of the job can be polled using the bulk operations retrieve API
+#[utoipa::path(
+    post,
+    path = "/bulk_operations",
+    request_body = BulkOperationCreateRequest,
+    responses(
+        (status = 200, description = "Bulk operation job created", body = BulkOperationResponse),
+        (status = 400, description = "Invalid bulk operations file"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Bulk Operations",
+    operation_id = "Create a Bulk Operation",
+    security(("api_key" = []))
+)]
+pub async fn bulk_operations_create() {}
+
+/// Bulk Operations - Retrieve
+///
+/// Retrieves the status and the progress of a bulk operation job, along with the identifier of its results file
+#[utoipa::path(
+    get,
+    path = "/bulk_operations/{job_id}",
+    params(
+        ("job_id" = String, Path, description = "The identifier for the bulk operation job")
+    ),
+    responses(
+        (status = 200, description = "Bulk operation job retrieved", body = BulkOperationResponse),
+        (status = 404, description = "Bulk operation job not found")
+    ),
+    tag = "Bulk Operations",
+    operation_id = "Retrieve a Bulk Operation",
+    security(("api_key" = []))
+)]
+pub async fn bulk_operations_retrieve() {}
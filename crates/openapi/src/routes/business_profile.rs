@@ -122,3 +122,54 @@ pub async fn business_profiles_delete() {}
     security(("api_key" = []))
 )]
 pub async fn business_profiles_retrieve() {}
+
+/// Business Profile - Retrieve Webhook Secret
+///
+/// Retrieve the secret currently used for signing webhooks of the *business profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_secret",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook Secret Retrieved", body = WebhookSecretResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Retrieve the Webhook Secret of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_secret_retrieve() {}
+
+/// Business Profile - Rotate Webhook Secret
+///
+/// Generate a new secret for signing webhooks of the *business profile*. Webhooks continue to be signed with the previous secret as well, until it expires.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_secret/rotate",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body(
+        content = WebhookSecretRotateRequest,
+        examples(
+            (
+                "Rotate the webhook secret, keeping the previous secret valid for 48 hours" = (
+                    value = json!({
+                        "previous_secret_expiry_hours": 48
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Webhook Secret Rotated", body = WebhookSecretResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Rotate the Webhook Secret of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_secret_rotate() {}
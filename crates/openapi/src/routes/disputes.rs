@@ -42,3 +42,37 @@ pub async fn retrieve_dispute() {}
     security(("api_key" = []))
 )]
 pub async fn retrieve_disputes_list() {}
+
+/// Disputes - Receive Dispute Alert
+/// Receives a pre-dispute alert from a card network alert service. The alert is matched to the disputed payment, which is refunded if the dispute alert policy of the business profile allows it
+#[utoipa::path(
+    post,
+    path = "/disputes/alerts",
+    request_body = DisputeAlertRequest,
+    responses(
+        (status = 200, description = "The dispute alert was received and processed", body = DisputeAlertResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Disputes",
+    operation_id = "Receive a Dispute Alert",
+    security(("api_key" = []))
+)]
+pub async fn receive_dispute_alert() {}
+
+/// Disputes - Retrieve Dispute Alert
+/// Retrieves a pre-dispute alert along with the outcome of processing it
+#[utoipa::path(
+    get,
+    path = "/disputes/alerts/{alert_id}",
+    params(
+        ("alert_id" = String, Path, description = "The identifier for the dispute alert")
+    ),
+    responses(
+        (status = 200, description = "The dispute alert was retrieved successfully", body = DisputeAlertResponse),
+        (status = 404, description = "Dispute alert does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve a Dispute Alert",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_dispute_alert() {}
@@ -486,3 +486,39 @@ pub fn payments_incremental_authorization() {}
   security(("publishable_key" = []))
 )]
 pub fn payments_external_authentication() {}
+
+/// Payments - Card Verification
+///
+/// Verifies a card through a zero-amount authorization, without creating a payment for the customer. Connectors that do not support zero-amount authorizations verify the card through an authorization of a minimal amount, which is voided right after
+#[utoipa::path(
+  post,
+  path = "/payments/card_verification",
+  request_body=CardVerificationRequest,
+  responses(
+      (status = 200, description = "Card verified", body = CardVerificationResponse),
+      (status = 400, description = "Missing mandatory fields")
+  ),
+  tag = "Payments",
+  operation_id = "Verify a Card",
+  security(("api_key" = []))
+)]
+pub fn payments_card_verification() {}
+
+/// Payments - Timeline
+///
+/// Retrieves the chronological timeline of a payment, with its attempts, captures, refunds, disputes, the webhooks received and sent, and the API calls made to the connector
+#[utoipa::path(
+  get,
+  path = "/payments/{payment_id}/timeline",
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Payment timeline retrieved", body = PaymentTimelineResponse),
+      (status = 404, description = "No payment found")
+  ),
+  tag = "Payments",
+  operation_id = "Retrieve the Timeline of a Payment",
+  security(("api_key" = []))
+)]
+pub fn payments_timeline() {}
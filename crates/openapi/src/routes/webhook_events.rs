@@ -92,3 +92,74 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Incoming Webhooks - Dead Letter List
+///
+/// List incoming webhooks received for the specified Merchant Account which could not be processed.
+#[utoipa::path(
+    get,
+    path = "/incoming_webhooks/{merchant_id}/dead_letters",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account"),
+        ("status" = Option<IncomingWebhookDeadLetterStatus>, Query, description = "Only include webhooks with the specified status"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only include webhooks received after the specified time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only include webhooks received before the specified time"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of webhooks to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of webhooks to skip when retrieving the list of webhooks"),
+    ),
+    responses(
+        (status = 200, description = "List of failed incoming webhooks retrieved successfully", body = Vec<DeadLetterListItemResponse>),
+    ),
+    tag = "Event",
+    operation_id = "List failed incoming webhooks for a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn list_incoming_webhook_dead_letters() {}
+
+/// Incoming Webhooks - Dead Letter Retrieve
+///
+/// Retrieve the details of an incoming webhook which could not be processed, including the
+/// request as received from the connector, with the values in its body masked.
+#[utoipa::path(
+    get,
+    path = "/incoming_webhooks/{merchant_id}/dead_letters/{dead_letter_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account"),
+        ("dead_letter_id" = String, Path, description = "The unique identifier for the failed incoming webhook"),
+    ),
+    responses(
+        (status = 200, description = "Failed incoming webhook retrieved successfully", body = DeadLetterRetrieveResponse),
+        (status = 404, description = "Failed incoming webhook not found"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve a failed incoming webhook",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_incoming_webhook_dead_letter() {}
+
+/// Incoming Webhooks - Dead Letter Replay
+///
+/// Replay an incoming webhook which could not be processed, through the same flow used for
+/// webhooks received from the connector.
+#[utoipa::path(
+    post,
+    path = "/incoming_webhooks/{merchant_id}/dead_letters/{dead_letter_id}/replay",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account"),
+        ("dead_letter_id" = String, Path, description = "The unique identifier for the failed incoming webhook"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "The webhook was replayed. \
+                           Check the `status` field in the response payload to identify whether the webhook was processed successfully.",
+            body = DeadLetterRetrieveResponse
+        ),
+        (status = 400, description = "The webhook has already been processed successfully"),
+        (status = 404, description = "Failed incoming webhook not found"),
+    ),
+    tag = "Event",
+    operation_id = "Replay a failed incoming webhook",
+    security(("admin_api_key" = []))
+)]
+pub fn replay_incoming_webhook_dead_letter() {}
@@ -478,6 +478,28 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::StreamAppendFailed)
     }
 
+    /// Append an entry to the stream, trimming the stream as specified by the cap in the same
+    /// command
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_append_entry_with_cap<F, C>(
+        &self,
+        stream: &str,
+        entry_id: &RedisEntryId,
+        fields: F,
+        xcap: C,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        F: TryInto<MultipleOrderedPairs> + Debug + Send + Sync,
+        F::Error: Into<fred::error::RedisError> + Send + Sync,
+        C: TryInto<XCap> + Debug + Send + Sync,
+        C::Error: Into<fred::error::RedisError> + Send + Sync,
+    {
+        self.pool
+            .xadd(stream, false, xcap, entry_id, fields)
+            .await
+            .change_context(errors::RedisError::StreamAppendFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_delete_entries<Ids>(
         &self,
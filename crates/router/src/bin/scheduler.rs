@@ -280,6 +280,30 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
                         )
                     }
                 }
+                storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow => Ok(Box::new(
+                    workflows::authorization_expiry::AuthorizationExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Ok(Box::new(
+                    workflows::scheduled_capture::ScheduledCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::IntentExpiryWorkflow => {
+                    Ok(Box::new(workflows::intent_expiry::IntentExpiryWorkflow))
+                }
+                storage::ProcessTrackerRunner::BulkOperationWorkflow => {
+                    Ok(Box::new(workflows::bulk_operation::BulkOperationWorkflow))
+                }
+                storage::ProcessTrackerRunner::DisputeDeadlineReminderWorkflow => Ok(Box::new(
+                    workflows::dispute_deadline_reminder::DisputeDeadlineReminderWorkflow,
+                )),
+                storage::ProcessTrackerRunner::DisputeSyncWorkflow => {
+                    Ok(Box::new(workflows::dispute_sync::DisputeSyncWorkflow))
+                }
+                storage::ProcessTrackerRunner::WebhookWatchdogWorkflow => Ok(Box::new(
+                    workflows::webhook_watchdog::WebhookWatchdogWorkflow,
+                )),
+                storage::ProcessTrackerRunner::VaultMigrationWorkflow => {
+                    Ok(Box::new(workflows::vault_migration::VaultMigrationWorkflow))
+                }
             }
         };
 
@@ -419,8 +419,9 @@ impl From<api_enums::IntentStatus> for StripePaymentStatus {
             api_enums::IntentStatus::RequiresPaymentMethod => Self::RequiresPaymentMethod,
             api_enums::IntentStatus::RequiresConfirmation => Self::RequiresConfirmation,
             api_enums::IntentStatus::RequiresCapture
-            | api_enums::IntentStatus::PartiallyCapturedAndCapturable => Self::RequiresCapture,
-            api_enums::IntentStatus::Cancelled => Self::Canceled,
+            | api_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | api_enums::IntentStatus::PartiallyAuthorized => Self::RequiresCapture,
+            api_enums::IntentStatus::Cancelled | api_enums::IntentStatus::Expired => Self::Canceled,
         }
     }
 }
@@ -73,7 +73,9 @@ impl From<refunds::RefundStatus> for StripeRefundStatus {
             refunds::RefundStatus::Succeeded => Self::Succeeded,
             refunds::RefundStatus::Failed => Self::Failed,
             refunds::RefundStatus::Pending => Self::Pending,
-            refunds::RefundStatus::Review => Self::RequiresAction,
+            refunds::RefundStatus::Review | refunds::RefundStatus::PendingApproval => {
+                Self::RequiresAction
+            }
         }
     }
 }
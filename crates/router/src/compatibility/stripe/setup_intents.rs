@@ -65,7 +65,7 @@ pub async fn setup_intents_create(
                 req_state,
                 auth.merchant_account,
                 auth.key_store,
-                payments::PaymentCreate,
+                payments::PaymentCreate::default(),
                 req,
                 api::AuthFlow::Merchant,
                 payments::CallConnectorAction::Trigger,
@@ -1,3 +1,5 @@
+#[cfg(feature = "payouts")]
+use api_models::enums::PayoutStatus;
 use api_models::{
     enums::{DisputeStatus, MandateStatus},
     webhooks::{self as api},
@@ -13,7 +15,9 @@ use super::{
 use crate::{
     core::{
         errors,
-        webhooks::types::{OutgoingWebhookPayloadWithSignature, OutgoingWebhookType},
+        webhooks::types::{
+            OutgoingWebhookFormat, OutgoingWebhookPayloadWithSignature, OutgoingWebhookType,
+        },
     },
     headers,
     services::request::Maskable,
@@ -31,6 +35,8 @@ pub struct StripeOutgoingWebhook {
 }
 
 impl OutgoingWebhookType for StripeOutgoingWebhook {
+    const OUTGOING_WEBHOOK_FORMAT: OutgoingWebhookFormat = OutgoingWebhookFormat::StripeCompatible;
+
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
@@ -81,6 +87,8 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    #[cfg(feature = "payouts")]
+    Payout(StripePayoutResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -101,6 +109,29 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[cfg(feature = "payouts")]
+#[derive(Serialize, Debug)]
+pub struct StripePayoutResponse {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub payout_type: api_models::enums::PayoutType,
+    pub status: StripePayoutStatus,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePayoutStatus {
+    Paid,
+    Pending,
+    InTransit,
+    Canceled,
+    Failed,
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeMandateStatus {
@@ -146,6 +177,37 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl From<api_models::payouts::PayoutCreateResponse> for StripePayoutResponse {
+    fn from(res: api_models::payouts::PayoutCreateResponse) -> Self {
+        Self {
+            id: res.payout_id,
+            amount: res.amount,
+            currency: res.currency.to_string().to_lowercase(),
+            payout_type: res.payout_type,
+            status: StripePayoutStatus::from(res.status),
+            failure_code: res.error_code,
+            failure_message: res.error_message,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl From<PayoutStatus> for StripePayoutStatus {
+    fn from(status: PayoutStatus) -> Self {
+        match status {
+            PayoutStatus::Success => Self::Paid,
+            PayoutStatus::Failed | PayoutStatus::Ineligible => Self::Failed,
+            PayoutStatus::Cancelled => Self::Canceled,
+            PayoutStatus::Pending => Self::InTransit,
+            PayoutStatus::RequiresCreation
+            | PayoutStatus::RequiresPayoutMethodData
+            | PayoutStatus::RequiresFulfillment
+            | PayoutStatus::RequiresVendorAccountCreation => Self::Pending,
+        }
+    }
+}
+
 impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
@@ -179,6 +241,10 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
 
         // the below are not really stripe compatible because stripe doesn't provide this
         api_models::enums::EventType::ActionRequired => "action.required",
+        api_models::enums::EventType::PaymentAuthorizationExpiring => {
+            "payment_intent.authorization_expiring"
+        }
+        api_models::enums::EventType::PaymentExpired => "payment_intent.expired",
         api_models::enums::EventType::RefundSucceeded => "refund.succeeded",
         api_models::enums::EventType::RefundFailed => "refund.failed",
         api_models::enums::EventType::DisputeOpened => "dispute.failed",
@@ -188,8 +254,17 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeDeadlineApproaching => "dispute.deadline_approaching",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        #[cfg(feature = "payouts")]
+        api_models::enums::EventType::PayoutSuccess => "payout.paid",
+        #[cfg(feature = "payouts")]
+        api_models::enums::EventType::PayoutFailed => "payout.failed",
+        #[cfg(feature = "payouts")]
+        api_models::enums::EventType::PayoutProcessing => "payout.updated",
+        #[cfg(feature = "payouts")]
+        api_models::enums::EventType::PayoutCancelled => "payout.canceled",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -237,6 +312,8 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            #[cfg(feature = "payouts")]
+            api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
         }
     }
 }
@@ -101,6 +101,40 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::AuthorizationExpiry {
+    fn default() -> Self {
+        Self {
+            default_validity_days: 7,
+            warning_offset_hours: 24,
+            action_offset_hours: 2,
+            network_validity_days: HashMap::new(),
+            connector_validity_days: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::DisputeReminders {
+    fn default() -> Self {
+        Self {
+            reminder_offset_hours: vec![72, 24, 6],
+        }
+    }
+}
+
+impl Default for super::settings::DisputeSync {
+    fn default() -> Self {
+        Self {
+            connector_list: HashSet::from([
+                enums::Connector::Checkout,
+                enums::Connector::Paypal,
+                enums::Connector::Stripe,
+            ]),
+            sync_interval_minutes: 60,
+            initial_lookback_hours: 24 * 30,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -99,6 +99,25 @@ impl SecretsHandler for settings::ForexApi {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ExternalVault {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let external_vault = value.get_inner();
+
+        let api_key = secret_management_client
+            .get_secret(external_vault.api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|external_vault| Self {
+            api_key,
+            ..external_vault
+        }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::ApiKeys {
     async fn convert_to_raw_secret(
@@ -258,6 +277,14 @@ pub(crate) async fn fetch_raw_secrets(
             .await
             .expect("Failed to decrypt forex api configs");
 
+    #[allow(clippy::expect_used)]
+    let external_vault = settings::ExternalVault::convert_to_raw_secret(
+        conf.external_vault,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt external vault configs");
+
     #[allow(clippy::expect_used)]
     let jwekey = settings::Jwekey::convert_to_raw_secret(conf.jwekey, secret_management_client)
         .await
@@ -317,9 +344,14 @@ pub(crate) async fn fetch_raw_secrets(
         replica_database,
         secrets,
         locker: conf.locker,
+        external_vault,
         connectors: conf.connectors,
         forex_api,
         refund: conf.refund,
+        authorization_expiry: conf.authorization_expiry,
+        dispute_reminders: conf.dispute_reminders,
+        dispute_sync: conf.dispute_sync,
+        webhook_watchdog: conf.webhook_watchdog,
         eph_key: conf.eph_key,
         scheduler: conf.scheduler,
         jwekey,
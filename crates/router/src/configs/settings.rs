@@ -68,9 +68,14 @@ pub struct Settings<S: SecretState> {
     pub log: Log,
     pub secrets: SecretStateContainer<Secrets, S>,
     pub locker: Locker,
+    pub external_vault: SecretStateContainer<ExternalVault, S>,
     pub connectors: Connectors,
     pub forex_api: SecretStateContainer<ForexApi, S>,
     pub refund: Refund,
+    pub authorization_expiry: AuthorizationExpiry,
+    pub dispute_reminders: DisputeReminders,
+    pub dispute_sync: DisputeSync,
+    pub webhook_watchdog: WebhookWatchdog,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -407,6 +412,21 @@ pub struct Locker {
     pub locker_enabled: bool,
 }
 
+/// Vault run by a third party, which merchants can move their stored cards to
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ExternalVault {
+    /// Base URL of the vault API. The vault can not be used by merchants when it is empty.
+    pub base_url: String,
+    pub api_key: Secret<String>,
+}
+
+impl ExternalVault {
+    pub fn is_enabled(&self) -> bool {
+        !self.base_url.is_empty()
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Refund {
@@ -414,6 +434,51 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuthorizationExpiry {
+    /// Validity of an authorization in days, used when neither the card network nor the connector
+    /// has a configured validity
+    pub default_validity_days: u16,
+    /// Hours before the expiry at which the merchant is notified about the expiring authorization
+    pub warning_offset_hours: u16,
+    /// Hours before the expiry at which the configured expiry action is performed
+    pub action_offset_hours: u16,
+    /// Validity of an authorization in days per card network, keyed by lowercase network name
+    pub network_validity_days: HashMap<String, u16>,
+    /// Validity of an authorization in days per connector, takes precedence over the network validity
+    pub connector_validity_days: HashMap<enums::Connector, u16>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DisputeReminders {
+    /// Hours before the deadline to challenge a dispute at which the merchant is reminded about
+    /// it, in decreasing order
+    pub reminder_offset_hours: Vec<u16>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DisputeSync {
+    /// Connectors whose disputes are periodically pulled from their dispute list APIs
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+    /// Interval in minutes between two runs of the dispute sync task of a connector account
+    pub sync_interval_minutes: u32,
+    /// Number of hours in the past from which disputes are pulled on the first run of the task
+    pub initial_lookback_hours: u32,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct WebhookWatchdog {
+    /// Minutes after the connector call within which the webhook of a payment awaiting one is
+    /// expected, per connector and payment method. Payments through connectors and payment methods
+    /// which are not configured here are not watched.
+    pub expected_window_minutes: HashMap<enums::Connector, HashMap<enums::PaymentMethod, u32>>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -741,6 +806,10 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.authorization_expiry.validate()?;
+        self.dispute_reminders.validate()?;
+        self.dispute_sync.validate()?;
+        self.webhook_watchdog.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -180,3 +180,72 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+impl super::settings::AuthorizationExpiry {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.default_validity_days == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "authorization expiry default_validity_days must not be 0".into(),
+            ))
+        })?;
+
+        when(
+            self.action_offset_hours >= self.warning_offset_hours,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "authorization expiry action offset must be less than the warning offset"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::DisputeReminders {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.reminder_offset_hours
+                .windows(2)
+                .any(|offsets| offsets[0] <= offsets[1]),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "dispute reminder offsets must be in decreasing order".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::DisputeSync {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.sync_interval_minutes == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "dispute sync interval must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::WebhookWatchdog {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.expected_window_minutes
+                .values()
+                .flat_map(|windows| windows.values())
+                .any(|window| *window == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "expected webhook window must be greater than zero".into(),
+                ))
+            },
+        )
+    }
+}
@@ -15,6 +15,7 @@ use self::transformers as adyen;
 use crate::{
     capture_method_not_supported,
     configs::settings,
+    connector::utils as connector_utils,
     consts,
     core::errors::{self, CustomResult},
     events::connector_api_logs::ConnectorEvent,
@@ -241,6 +242,21 @@ impl ConnectorValidation for Adyen {
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         true
     }
+    fn validate_over_capture(
+        &self,
+        card_network: Option<enums::CardNetwork>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        connector_utils::validate_over_capture_card_network(
+            card_network,
+            &[
+                enums::CardNetwork::Visa,
+                enums::CardNetwork::Mastercard,
+                enums::CardNetwork::AmericanExpress,
+                enums::CardNetwork::Discover,
+            ],
+            self.id(),
+        )
+    }
 }
 
 impl api::Payment for Adyen {}
@@ -1772,6 +1788,15 @@ impl api::IncomingWebhook for Adyen {
                 ),
             ));
         }
+        // payout modifications carry the payout's PSP reference in original_reference
+        #[cfg(feature = "payouts")]
+        if adyen::is_payout_event(&notif.event_code) {
+            return Ok(api_models::webhooks::ObjectReferenceId::PayoutId(
+                api_models::webhooks::PayoutIdType::ConnectorPayoutId(
+                    notif.original_reference.unwrap_or(notif.psp_reference),
+                ),
+            ));
+        }
         Err(report!(errors::ConnectorError::WebhookReferenceIdNotFound))
     }
 
@@ -1830,3 +1855,97 @@ impl api::IncomingWebhook for Adyen {
         })
     }
 }
+
+#[cfg(all(test, feature = "payouts"))]
+mod payout_webhook_tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::webhooks::{IncomingWebhookEvent, ObjectReferenceId, PayoutIdType};
+
+    use super::*;
+    use crate::types::api::IncomingWebhook;
+
+    fn get_request_details<'a>(
+        headers: &'a actix_web::http::header::HeaderMap,
+        body: &'a [u8],
+    ) -> api::IncomingWebhookRequestDetails<'a> {
+        api::IncomingWebhookRequestDetails {
+            method: actix_web::http::Method::POST,
+            uri: actix_web::http::Uri::default(),
+            headers,
+            body,
+            query_params: String::new(),
+        }
+    }
+
+    fn get_payout_webhook_body(event_code: &str, success: &str) -> Vec<u8> {
+        serde_json::json!({
+            "live": "false",
+            "notificationItems": [{
+                "NotificationRequestItem": {
+                    "additionalData": { "hmacSignature": "signature" },
+                    "amount": { "value": 1000, "currency": "EUR" },
+                    "originalReference": "payout_psp_reference",
+                    "pspReference": "modification_psp_reference",
+                    "eventCode": event_code,
+                    "merchantAccountCode": "MerchantAccount",
+                    "merchantReference": "payout_attempt_1",
+                    "success": success
+                }
+            }]
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_payout_webhook_event_type() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let cases = [
+            (
+                "PAYOUT_THIRDPARTY",
+                "true",
+                IncomingWebhookEvent::PayoutSuccess,
+            ),
+            (
+                "PAYOUT_THIRDPARTY",
+                "false",
+                IncomingWebhookEvent::PayoutFailure,
+            ),
+            (
+                "PAYOUT_DECLINE",
+                "true",
+                IncomingWebhookEvent::PayoutCancelled,
+            ),
+            ("PAYOUT_EXPIRE", "true", IncomingWebhookEvent::PayoutExpired),
+            (
+                "PAIDOUT_REVERSED",
+                "true",
+                IncomingWebhookEvent::PayoutReversed,
+            ),
+        ];
+
+        for (event_code, success, expected_event) in cases {
+            let body = get_payout_webhook_body(event_code, success);
+            let request = get_request_details(&headers, &body);
+
+            assert_eq!(
+                Adyen.get_webhook_event_type(&request).unwrap(),
+                expected_event,
+                "event code {event_code} with success {success}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_payout_webhook_object_reference_id() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let body = get_payout_webhook_body("PAIDOUT_REVERSED", "true");
+        let request = get_request_details(&headers, &body);
+
+        assert!(matches!(
+            Adyen.get_webhook_object_reference_id(&request).unwrap(),
+            ObjectReferenceId::PayoutId(PayoutIdType::ConnectorPayoutId(id))
+                if id == "payout_psp_reference"
+        ));
+    }
+}
@@ -119,6 +119,128 @@ pub struct AdditionalData {
     #[cfg(feature = "payouts")]
     payout_eligible: Option<PayoutEligibility>,
     funds_availability: Option<String>,
+    allow_partial_auth: Option<String>,
+    /// Result of the address verification, in the format `<code> <description>`
+    #[serde(skip_serializing)]
+    avs_result: Option<String>,
+    /// Result of the card security code check, in the format `<code> <description>`
+    #[serde(skip_serializing)]
+    cvc_result: Option<String>,
+    #[serde(skip_serializing)]
+    liability_shift: Option<String>,
+    /// Transaction status of the 3DS authentication, e.g. `Y`
+    #[serde(skip_serializing)]
+    three_d_authenticated_response: Option<String>,
+    #[serde(rename = "threeDSVersion", skip_serializing)]
+    three_ds_version: Option<String>,
+    #[serde(skip_serializing)]
+    eci: Option<String>,
+    #[serde(rename = "dsTransID", skip_serializing)]
+    ds_trans_id: Option<String>,
+}
+
+impl AdditionalData {
+    fn get_additional_payment_method_data(
+        &self,
+    ) -> Option<types::AdditionalPaymentMethodConnectorResponse> {
+        let card_verification_results = self.get_card_verification_results();
+        let authentication_details = self.get_authentication_details();
+        if card_verification_results.is_none() && authentication_details.is_none() {
+            return None;
+        }
+        Some(types::AdditionalPaymentMethodConnectorResponse::Card {
+            authentication_data: None,
+            payment_checks: None,
+            card_verification_results,
+            authentication_details,
+        })
+    }
+
+    fn get_authentication_details(&self) -> Option<payments::PaymentAuthenticationDetails> {
+        if self.three_d_authenticated_response.is_none() && self.eci.is_none() {
+            return None;
+        }
+        let trans_status = self
+            .three_d_authenticated_response
+            .as_deref()
+            .and_then(utils::get_three_ds_transaction_status);
+        // Adyen reports the liability shift itself, which takes precedence over the one derived
+        let liability_shift = self
+            .liability_shift
+            .as_deref()
+            .and_then(|liability_shift| liability_shift.parse::<bool>().ok())
+            .or_else(|| utils::get_liability_shift(self.eci.as_deref(), trans_status.as_ref()));
+        Some(payments::PaymentAuthenticationDetails {
+            authentication_source: payments::AuthenticationSource::Connector,
+            trans_status,
+            electronic_commerce_indicator: self.eci.clone(),
+            version: self.three_ds_version.clone(),
+            liability_shift,
+            ds_transaction_id: self.ds_trans_id.clone(),
+            authentication_flow: None,
+        })
+    }
+
+    fn get_card_verification_results(&self) -> Option<payments::CardVerificationResults> {
+        use payments::CardVerificationCheckResult as CheckResult;
+
+        let avs_raw_code = self.avs_result.as_deref().and_then(get_adyen_result_code);
+        let cvv_raw_code = self.cvc_result.as_deref().and_then(get_adyen_result_code);
+        if avs_raw_code.is_none() && cvv_raw_code.is_none() {
+            return None;
+        }
+
+        let (avs_street_match, avs_postal_code_match) = match avs_raw_code.as_deref() {
+            Some("1") => (Some(CheckResult::Match), Some(CheckResult::NoMatch)),
+            Some("2") => (Some(CheckResult::NoMatch), Some(CheckResult::NoMatch)),
+            Some("3") | Some("4") => (
+                Some(CheckResult::Unavailable),
+                Some(CheckResult::Unavailable),
+            ),
+            Some("5") | Some("8") | Some("18") => {
+                (Some(CheckResult::NotChecked), Some(CheckResult::NotChecked))
+            }
+            Some("6") => (Some(CheckResult::NoMatch), Some(CheckResult::Match)),
+            Some("7") | Some("20") => (Some(CheckResult::Match), Some(CheckResult::Match)),
+            Some("9") => (Some(CheckResult::Match), Some(CheckResult::Unavailable)),
+            Some("10") => (Some(CheckResult::NoMatch), Some(CheckResult::Unavailable)),
+            Some("11") => (
+                Some(CheckResult::Unavailable),
+                Some(CheckResult::NotChecked),
+            ),
+            Some("12") => (Some(CheckResult::Match), Some(CheckResult::NotChecked)),
+            Some("13") => (Some(CheckResult::NoMatch), Some(CheckResult::NotChecked)),
+            Some("14") => (Some(CheckResult::Unavailable), Some(CheckResult::Match)),
+            Some("15") => (Some(CheckResult::NotChecked), Some(CheckResult::Match)),
+            Some("16") => (Some(CheckResult::Unavailable), Some(CheckResult::NoMatch)),
+            Some("17") => (Some(CheckResult::NotChecked), Some(CheckResult::NoMatch)),
+            _ => (None, None),
+        };
+        let cvv_match = match cvv_raw_code.as_deref() {
+            Some("1") => Some(CheckResult::Match),
+            Some("2") => Some(CheckResult::NoMatch),
+            Some("3") | Some("4") | Some("6") => Some(CheckResult::NotChecked),
+            Some("5") => Some(CheckResult::Unavailable),
+            _ => None,
+        };
+
+        Some(payments::CardVerificationResults {
+            avs_street_match,
+            avs_postal_code_match,
+            cvv_match,
+            avs_raw_code,
+            cvv_raw_code,
+        })
+    }
+}
+
+/// The numeric code at the start of the AVS and CVC results of Adyen, e.g. `1` of `1 Matches`
+fn get_adyen_result_code(result: &str) -> Option<String> {
+    result
+        .split_whitespace()
+        .next()
+        .filter(|code| code.chars().all(|c| c.is_ascii_digit()))
+        .map(ToString::to_string)
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -1693,10 +1815,15 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
     } else {
         None
     };
+    let allow_partial_auth = item
+        .request
+        .allow_partial_authorization
+        .then(|| "true".to_string());
     Some(AdditionalData {
         authorisation_type,
         manual_capture,
         execute_three_d,
+        allow_partial_auth,
         network_tx_reference: None,
         recurring_detail_reference: None,
         recurring_shopper_reference: None,
@@ -3860,6 +3987,20 @@ impl<F, Req>
         ),
     ) -> Result<Self, Self::Error> {
         let is_manual_capture = utils::is_manual_capture(capture_method);
+        let connector_response = match &item.response {
+            AdyenPaymentResponse::Response(response) => types::ConnectorResponseData::new(
+                response
+                    .additional_data
+                    .as_ref()
+                    .and_then(AdditionalData::get_additional_payment_method_data),
+                response.amount.as_ref().map(|amount| amount.value),
+            ),
+            AdyenPaymentResponse::PresentToShopper(_)
+            | AdyenPaymentResponse::QrCodeResponse(_)
+            | AdyenPaymentResponse::RedirectionResponse(_)
+            | AdyenPaymentResponse::RedirectionErrorResponse(_)
+            | AdyenPaymentResponse::WebhookResponse(_) => None,
+        };
         let (status, error, payment_response_data) = match item.response {
             AdyenPaymentResponse::Response(response) => {
                 get_adyen_response(*response, is_manual_capture, item.http_code, pmt)?
@@ -3887,6 +4028,7 @@ impl<F, Req>
         Ok(Self {
             status,
             response: error.map_or_else(|| Ok(payment_response_data), Err),
+            connector_response,
             ..item.data
         })
     }
@@ -4111,6 +4253,14 @@ pub enum WebhookEventCode {
     SecondChargeback,
     PrearbitrationWon,
     PrearbitrationLost,
+    #[cfg(feature = "payouts")]
+    PayoutThirdparty,
+    #[cfg(feature = "payouts")]
+    PayoutDecline,
+    #[cfg(feature = "payouts")]
+    PayoutExpire,
+    #[cfg(feature = "payouts")]
+    PaidoutReversed,
     #[serde(other)]
     Unknown,
 }
@@ -4150,6 +4300,17 @@ pub fn is_chargeback_event(event_code: &WebhookEventCode) -> bool {
     )
 }
 
+#[cfg(feature = "payouts")]
+pub fn is_payout_event(event_code: &WebhookEventCode) -> bool {
+    matches!(
+        event_code,
+        WebhookEventCode::PayoutThirdparty
+            | WebhookEventCode::PayoutDecline
+            | WebhookEventCode::PayoutExpire
+            | WebhookEventCode::PaidoutReversed
+    )
+}
+
 fn is_success_scenario(is_success: String) -> bool {
     is_success.as_str() == "true"
 }
@@ -4209,6 +4370,20 @@ impl ForeignFrom<(WebhookEventCode, String, Option<DisputeStatus>)>
                 }
             }
             WebhookEventCode::CaptureFailed => Self::PaymentIntentCaptureFailure,
+            #[cfg(feature = "payouts")]
+            WebhookEventCode::PayoutThirdparty => {
+                if is_success_scenario(is_success) {
+                    Self::PayoutSuccess
+                } else {
+                    Self::PayoutFailure
+                }
+            }
+            #[cfg(feature = "payouts")]
+            WebhookEventCode::PayoutDecline => Self::PayoutCancelled,
+            #[cfg(feature = "payouts")]
+            WebhookEventCode::PayoutExpire => Self::PayoutExpired,
+            #[cfg(feature = "payouts")]
+            WebhookEventCode::PaidoutReversed => Self::PayoutReversed,
             WebhookEventCode::Unknown => Self::EventNotSupported,
         }
     }
@@ -4294,6 +4469,11 @@ impl From<AdyenNotificationRequestItemWH> for AdyenWebhookResponse {
                 | WebhookEventCode::PrearbitrationWon
                 | WebhookEventCode::PrearbitrationLost
                 | WebhookEventCode::Unknown => AdyenWebhookStatus::UnexpectedEvent,
+                #[cfg(feature = "payouts")]
+                WebhookEventCode::PayoutThirdparty
+                | WebhookEventCode::PayoutDecline
+                | WebhookEventCode::PayoutExpire
+                | WebhookEventCode::PaidoutReversed => AdyenWebhookStatus::UnexpectedEvent,
             },
             amount: Some(Amount {
                 value: notif.amount.value,
@@ -232,6 +232,18 @@ struct TransactionRequest {
     currency_code: String,
     payment: PaymentDetails,
     order: Order,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<LineItems>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_exempt: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    po_number: Option<String>,
     bill_to: Option<BillTo>,
     processing_options: Option<ProcessingOptions>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -263,6 +275,110 @@ pub struct Order {
     description: String,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItems {
+    line_item: Vec<LineItem>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    item_id: String,
+    name: String,
+    quantity: u16,
+    unit_price: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<f64>,
+    total_amount: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendedAmount {
+    amount: f64,
+}
+
+// Level 2 and Level 3 data of the order, in the order expected by the connector
+struct LevelTwoLevelThreeDetails {
+    line_items: Option<LineItems>,
+    tax: Option<ExtendedAmount>,
+    duty: Option<ExtendedAmount>,
+    shipping: Option<ExtendedAmount>,
+    tax_exempt: Option<bool>,
+    po_number: Option<String>,
+}
+
+impl TryFrom<(&api_models::payments::L2L3Data, enums::Currency)> for LevelTwoLevelThreeDetails {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (l2_l3_data, currency): (&api_models::payments::L2L3Data, enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        let to_base_unit = |amount: Option<i64>| {
+            amount
+                .map(|amount| utils::to_currency_base_unit_asf64(amount, currency))
+                .transpose()
+        };
+        let to_extended_amount = |amount: Option<i64>| {
+            to_base_unit(amount).map(|amount| amount.map(|amount| ExtendedAmount { amount }))
+        };
+        let line_items = l2_l3_data
+            .line_items
+            .as_ref()
+            .map(|line_items| {
+                line_items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, line_item)| {
+                        Ok(LineItem {
+                            item_id: line_item
+                                .product_code
+                                .clone()
+                                .unwrap_or_else(|| (index + 1).to_string()),
+                            name: line_item.product_name.clone(),
+                            quantity: line_item.quantity,
+                            unit_price: utils::to_currency_base_unit_asf64(
+                                line_item.unit_price,
+                                currency,
+                            )?,
+                            unit_of_measure: line_item.unit_of_measure.clone(),
+                            tax_amount: to_base_unit(line_item.tax_amount)?,
+                            total_amount: utils::to_currency_base_unit_asf64(
+                                line_item.get_total_amount().ok_or(
+                                    errors::ConnectorError::InvalidDataFormat {
+                                        field_name: "l2_l3_data.line_items",
+                                    },
+                                )?,
+                                currency,
+                            )?,
+                            commodity_code: line_item.commodity_code.clone(),
+                            product_code: line_item.product_code.clone(),
+                            discount_amount: to_base_unit(line_item.discount_amount)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Self::Error>>()
+            })
+            .transpose()?
+            .map(|line_item| LineItems { line_item });
+        Ok(Self {
+            line_items,
+            tax: to_extended_amount(l2_l3_data.tax_amount)?,
+            duty: to_extended_amount(l2_l3_data.duty_amount)?,
+            shipping: to_extended_amount(l2_l3_data.shipping_amount)?,
+            tax_exempt: l2_l3_data.tax_exempt,
+            po_number: l2_l3_data.customer_code.clone(),
+        })
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubsequentAuthInformation {
@@ -374,6 +490,27 @@ impl TryFrom<&AuthorizedotnetRouterData<&types::PaymentsAuthorizeRouterData>>
                 zip: address.zip.clone(),
                 country: address.country,
             });
+        let level_two_level_three_details = item
+            .router_data
+            .request
+            .l2_l3_data
+            .as_ref()
+            .map(|l2_l3_data| {
+                LevelTwoLevelThreeDetails::try_from((l2_l3_data, item.router_data.request.currency))
+            })
+            .transpose()?;
+        let (line_items, tax, duty, shipping, tax_exempt, po_number) =
+            match level_two_level_three_details {
+                Some(details) => (
+                    details.line_items,
+                    details.tax,
+                    details.duty,
+                    details.shipping,
+                    details.tax_exempt,
+                    details.po_number,
+                ),
+                None => (None, None, None, None, None, None),
+            };
         let transaction_request = TransactionRequest {
             transaction_type: TransactionType::try_from(item.router_data.request.capture_method)?,
             amount: item.amount,
@@ -382,6 +519,12 @@ impl TryFrom<&AuthorizedotnetRouterData<&types::PaymentsAuthorizeRouterData>>
             order: Order {
                 description: item.router_data.connector_request_reference_id.clone(),
             },
+            line_items,
+            tax,
+            duty,
+            shipping,
+            tax_exempt,
+            po_number,
             bill_to,
             processing_options,
             subsequent_auth_information,
@@ -550,6 +693,8 @@ pub struct AuthorizedotnetTransactionResponse {
     pub(super) account_number: Option<Secret<String>>,
     pub(super) errors: Option<Vec<ErrorMessage>>,
     secure_acceptance: Option<SecureAcceptance>,
+    avs_result_code: Option<String>,
+    cvv_result_code: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -674,8 +819,15 @@ impl<F, T>
                     .and_then(|x| x.secure_acceptance_url.to_owned());
                 let redirection_data =
                     url.map(|url| services::RedirectForm::from((url, services::Method::Get)));
+                let connector_response = utils::get_card_verification_results(
+                    transaction_response.avs_result_code.clone(),
+                    transaction_response.cvv_result_code.clone(),
+                    utils::AvsResultCodes::AuthorizeDotNet,
+                )
+                .map(types::ConnectorResponseData::with_card_verification_results);
                 Ok(Self {
                     status,
+                    connector_response,
                     response: match error {
                         Some(err) => Err(err),
                         None => Ok(types::PaymentsResponseData::TransactionResponse {
@@ -94,6 +94,7 @@ pub struct ProcessingInformation {
     capture: Option<bool>,
     capture_options: Option<CaptureOptions>,
     payment_solution: Option<String>,
+    purchase_level: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -247,6 +248,12 @@ pub struct FluidData {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_details: Option<ShippingDetails>,
 }
 
 #[derive(Debug, Serialize)]
@@ -254,6 +261,128 @@ pub struct OrderInformationWithBill {
 pub struct Amount {
     total_amount: String,
     currency: api_models::enums::Currency,
+    #[serde(flatten)]
+    level_two_amount_details: Option<LevelTwoAmountDetails>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelTwoAmountDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freight_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    unit_price: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    total_amount: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    taxable: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShippingDetails {
+    ship_from_postal_code: Secret<String>,
+}
+
+struct LevelTwoLevelThreeDetails {
+    amount_details: Option<LevelTwoAmountDetails>,
+    line_items: Option<Vec<LineItem>>,
+    invoice_details: Option<InvoiceDetails>,
+    shipping_details: Option<ShippingDetails>,
+}
+
+impl TryFrom<(&payments::L2L3Data, api_models::enums::Currency)> for LevelTwoLevelThreeDetails {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (l2_l3_data, currency): (&payments::L2L3Data, api_models::enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        let to_base_unit = |amount: Option<i64>| {
+            amount
+                .map(|amount| utils::to_currency_base_unit(amount, currency))
+                .transpose()
+        };
+        let line_items = l2_l3_data
+            .line_items
+            .as_ref()
+            .map(|line_items| {
+                line_items
+                    .iter()
+                    .map(|line_item| {
+                        Ok(LineItem {
+                            product_name: line_item.product_name.clone(),
+                            product_sku: line_item.product_code.clone(),
+                            commodity_code: line_item.commodity_code.clone(),
+                            quantity: line_item.quantity,
+                            unit_of_measure: line_item.unit_of_measure.clone(),
+                            unit_price: utils::to_currency_base_unit(
+                                line_item.unit_price,
+                                currency,
+                            )?,
+                            discount_amount: to_base_unit(line_item.discount_amount)?,
+                            tax_amount: to_base_unit(line_item.tax_amount)?,
+                            total_amount: utils::to_currency_base_unit(
+                                line_item.get_total_amount().ok_or(
+                                    errors::ConnectorError::InvalidDataFormat {
+                                        field_name: "l2_l3_data.line_items",
+                                    },
+                                )?,
+                                currency,
+                            )?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Self::Error>>()
+            })
+            .transpose()?;
+        let invoice_details = (l2_l3_data.customer_code.is_some()
+            || l2_l3_data.tax_exempt.is_some())
+        .then(|| InvoiceDetails {
+            purchase_order_number: l2_l3_data.customer_code.clone(),
+            taxable: l2_l3_data.tax_exempt.map(|tax_exempt| !tax_exempt),
+        });
+        Ok(Self {
+            amount_details: Some(LevelTwoAmountDetails {
+                tax_amount: to_base_unit(l2_l3_data.tax_amount)?,
+                duty_amount: to_base_unit(l2_l3_data.duty_amount)?,
+                freight_amount: to_base_unit(l2_l3_data.shipping_amount)?,
+                discount_amount: to_base_unit(l2_l3_data.discount_amount)?,
+            }),
+            line_items,
+            invoice_details,
+            shipping_details: l2_l3_data.ship_from_postal_code.clone().map(
+                |ship_from_postal_code| ShippingDetails {
+                    ship_from_postal_code,
+                },
+            ),
+        })
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -497,24 +626,48 @@ pub enum TransactionType {
 }
 
 impl
-    From<(
+    TryFrom<(
         &BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>,
         Option<BillTo>,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>,
             Option<BillTo>,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let level_two_level_three_details = item
+            .router_data
+            .request
+            .l2_l3_data
+            .as_ref()
+            .map(|l2_l3_data| {
+                LevelTwoLevelThreeDetails::try_from((l2_l3_data, item.router_data.request.currency))
+            })
+            .transpose()?;
+        let (level_two_amount_details, line_items, invoice_details, shipping_details) =
+            match level_two_level_three_details {
+                Some(details) => (
+                    details.amount_details,
+                    details.line_items,
+                    details.invoice_details,
+                    details.shipping_details,
+                ),
+                None => (None, None, None, None),
+            };
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                level_two_amount_details,
             },
             bill_to,
-        }
+            line_items,
+            invoice_details,
+            shipping_details,
+        })
     }
 }
 
@@ -534,8 +687,12 @@ impl
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                level_two_amount_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            shipping_details: None,
         }
     }
 }
@@ -609,6 +766,7 @@ impl
                 Some(enums::CaptureMethod::Automatic) | None
             )),
             payment_solution: solution.map(String::from),
+            purchase_level: get_purchase_level(item.router_data.request.l2_l3_data.as_ref()),
             action_list,
             action_token_types,
             authorization_options,
@@ -618,6 +776,13 @@ impl
     }
 }
 
+// Line items are sent only for Level 3 purchases
+fn get_purchase_level(l2_l3_data: Option<&payments::L2L3Data>) -> Option<String> {
+    l2_l3_data
+        .and_then(|l2_l3_data| l2_l3_data.line_items.as_ref())
+        .map(|_| "3".to_string())
+}
+
 impl
     From<(
         &BankOfAmericaRouterData<&types::PaymentsCompleteAuthorizeRouterData>,
@@ -664,6 +829,7 @@ impl
         Self {
             capture,
             payment_solution: solution.map(String::from),
+            purchase_level: None,
             action_list,
             action_token_types,
             authorization_options,
@@ -909,7 +1075,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = PaymentInformation::try_from(&ccard)?;
         let processing_information = ProcessingInformation::try_from((item, None, None))?;
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -946,7 +1112,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -1000,7 +1166,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = PaymentInformation::from(&google_pay_data);
         let processing_information =
             ProcessingInformation::try_from((item, Some(PaymentSolution::GooglePay), None))?;
@@ -1053,7 +1219,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>>
                                     let bill_to =
                                         build_bill_to(item.router_data.get_billing()?, email)?;
                                     let order_information: OrderInformationWithBill =
-                                        OrderInformationWithBill::from((item, Some(bill_to)));
+                                        OrderInformationWithBill::try_from((item, Some(bill_to)))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1240,7 +1406,7 @@ impl
                 .ok()
                 .and_then(|billing_details| build_bill_to(billing_details, email_id).ok())
         });
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(MandatePaymentInformation { payment_instrument });
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -1396,6 +1562,38 @@ pub struct ConsumerAuthenticationInformation {
     eci: Option<String>,
     acs_transaction_id: Option<String>,
     cavv: Option<String>,
+    pares_status: Option<String>,
+    specification_version: Option<String>,
+    directory_server_transaction_id: Option<String>,
+}
+
+impl ConsumerAuthenticationInformation {
+    /// The result of the 3DS authentication, if the payment was authenticated by the connector
+    fn get_authentication_details(
+        &self,
+    ) -> Option<api_models::payments::PaymentAuthenticationDetails> {
+        let electronic_commerce_indicator = self.eci_raw.clone().or_else(|| self.eci.clone());
+        if electronic_commerce_indicator.is_none() && self.pares_status.is_none() {
+            return None;
+        }
+        let trans_status = self
+            .pares_status
+            .as_deref()
+            .and_then(utils::get_three_ds_transaction_status);
+        let liability_shift = utils::get_liability_shift(
+            electronic_commerce_indicator.as_deref(),
+            trans_status.as_ref(),
+        );
+        Some(api_models::payments::PaymentAuthenticationDetails {
+            authentication_source: api_models::payments::AuthenticationSource::Connector,
+            trans_status,
+            liability_shift,
+            electronic_commerce_indicator,
+            version: self.specification_version.clone(),
+            ds_transaction_id: self.directory_server_transaction_id.clone(),
+            authentication_flow: None,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1814,6 +2012,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsPreProcessingRouterData>>
                     field_name: "currency",
                 },
             )?,
+            level_two_amount_details: None,
         };
 
         match redirect_response.params {
@@ -1832,6 +2031,9 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsPreProcessingRouterData>>
                 let order_information = OrderInformationWithBill {
                     amount_details,
                     bill_to: Some(bill_to),
+                    line_items: None,
+                    invoice_details: None,
+                    shipping_details: None,
                 };
                 Ok(Self::AuthEnrollment(BankOfAmericaAuthEnrollmentRequest {
                     payment_information,
@@ -2241,9 +2443,24 @@ impl
         "system_trace_audit_number": processor_information.system_trace_audit_number,
         }));
 
+        let card_verification_results = utils::get_card_verification_results(
+            processor_information
+                .avs
+                .as_ref()
+                .map(|avs| avs.code.clone()),
+            processor_information
+                .card_verification
+                .as_ref()
+                .and_then(|card_verification| card_verification.result_code.clone()),
+            utils::AvsResultCodes::Cybersource,
+        );
+
         Self::Card {
             authentication_data,
             payment_checks,
+            card_verification_results,
+            authentication_details: consumer_authentication_information
+                .get_authentication_details(),
         }
     }
 }
@@ -2499,6 +2716,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsCaptureRouterData>>
                 amount_details: Amount {
                     total_amount: value.amount.to_owned(),
                     currency: value.router_data.request.currency,
+                    level_two_amount_details: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2549,6 +2767,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsCancelRouterData>>
                             field_name: "Currency",
                         },
                     )?,
+                    level_two_amount_details: None,
                 },
                 reason: value
                     .router_data
@@ -2583,6 +2802,7 @@ impl<F> TryFrom<&BankOfAmericaRouterData<&types::RefundsRouterData<F>>>
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_two_amount_details: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2906,6 +3126,7 @@ impl TryFrom<(Option<PaymentSolution>, Option<String>)> for ProcessingInformatio
             authorization_options,
             commerce_indicator,
             payment_solution: solution.map(String::from),
+            purchase_level: None,
         })
     }
 }
@@ -2921,8 +3142,12 @@ impl TryFrom<&types::SetupMandateRouterData> for OrderInformationWithBill {
             amount_details: Amount {
                 total_amount: "0".to_string(),
                 currency: item.request.currency,
+                level_two_amount_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            shipping_details: None,
         })
     }
 }
@@ -87,6 +87,141 @@ impl TryFrom<&Option<pii::SecretSerdeValue>> for BraintreeMeta {
 pub struct TransactionBody {
     amount: String,
     merchant_account_id: Secret<String>,
+    #[serde(flatten)]
+    level_two_level_three_data: Option<TransactionLevelTwoLevelThreeData>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLevelTwoLevelThreeData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax: Option<TransactionTaxInput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping: Option<TransactionShippingInput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<TransactionLineItemInput>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTaxInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_exempt: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionShippingInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ships_from_postal_code: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransactionLineItemKind {
+    Debit,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLineItemInput {
+    name: String,
+    kind: TransactionLineItemKind,
+    quantity: String,
+    unit_amount: String,
+    total_amount: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+}
+
+impl TryFrom<(&api_models::payments::L2L3Data, enums::Currency)>
+    for TransactionLevelTwoLevelThreeData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (l2_l3_data, currency): (&api_models::payments::L2L3Data, enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        let to_base_unit = |amount: Option<i64>| {
+            amount
+                .map(|amount| utils::to_currency_base_unit(amount, currency))
+                .transpose()
+        };
+        let line_items = l2_l3_data
+            .line_items
+            .as_ref()
+            .map(|line_items| {
+                line_items
+                    .iter()
+                    .map(|line_item| {
+                        Ok(TransactionLineItemInput {
+                            name: line_item.product_name.clone(),
+                            kind: TransactionLineItemKind::Debit,
+                            quantity: line_item.quantity.to_string(),
+                            unit_amount: utils::to_currency_base_unit(
+                                line_item.unit_price,
+                                currency,
+                            )?,
+                            total_amount: utils::to_currency_base_unit(
+                                line_item.get_total_amount().ok_or(
+                                    errors::ConnectorError::InvalidDataFormat {
+                                        field_name: "l2_l3_data.line_items",
+                                    },
+                                )?,
+                                currency,
+                            )?,
+                            product_code: line_item.product_code.clone(),
+                            commodity_code: line_item.commodity_code.clone(),
+                            unit_of_measure: line_item.unit_of_measure.clone(),
+                            tax_amount: to_base_unit(line_item.tax_amount)?,
+                            discount_amount: to_base_unit(line_item.discount_amount)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Self::Error>>()
+            })
+            .transpose()?;
+        let tax = (l2_l3_data.tax_amount.is_some() || l2_l3_data.tax_exempt.is_some())
+            .then(|| {
+                to_base_unit(l2_l3_data.tax_amount).map(|tax_amount| TransactionTaxInput {
+                    tax_amount,
+                    tax_exempt: l2_l3_data.tax_exempt,
+                })
+            })
+            .transpose()?;
+        let shipping = (l2_l3_data.shipping_amount.is_some()
+            || l2_l3_data.ship_from_postal_code.is_some())
+        .then(|| {
+            to_base_unit(l2_l3_data.shipping_amount).map(|shipping_amount| {
+                TransactionShippingInput {
+                    shipping_amount,
+                    ships_from_postal_code: l2_l3_data.ship_from_postal_code.clone(),
+                }
+            })
+        })
+        .transpose()?;
+        Ok(Self {
+            purchase_order_number: l2_l3_data.customer_code.clone(),
+            discount_amount: to_base_unit(l2_l3_data.discount_amount)?,
+            tax,
+            shipping,
+            line_items,
+        })
+    }
 }
 
 impl TryFrom<&BraintreeRouterData<&types::PaymentsAuthorizeRouterData>>
@@ -1332,6 +1467,18 @@ impl
                     transaction: TransactionBody {
                         amount: item.amount.to_owned(),
                         merchant_account_id: metadata.merchant_account_id,
+                        level_two_level_three_data: item
+                            .router_data
+                            .request
+                            .l2_l3_data
+                            .as_ref()
+                            .map(|l2_l3_data| {
+                                TransactionLevelTwoLevelThreeData::try_from((
+                                    l2_l3_data,
+                                    item.router_data.request.currency,
+                                ))
+                            })
+                            .transpose()?,
                     },
                 },
             },
@@ -1386,6 +1533,7 @@ impl TryFrom<&BraintreeRouterData<&types::PaymentsCompleteAuthorizeRouterData>>
                     transaction: TransactionBody {
                         amount: item.amount.to_owned(),
                         merchant_account_id: metadata.merchant_account_id,
+                        level_two_level_three_data: None,
                     },
                 },
             },
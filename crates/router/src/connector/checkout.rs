@@ -171,6 +171,7 @@ impl api::PaymentToken for Checkout {}
 impl api::Dispute for Checkout {}
 impl api::RetrieveFile for Checkout {}
 impl api::DefendDispute for Checkout {}
+impl api::FetchDisputes for Checkout {}
 
 impl
     ConnectorIntegration<
@@ -947,6 +948,11 @@ impl api::FileUpload for Checkout {
                     })?
                 }
             }
+            api::FilePurpose::BulkOperations => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "Bulk operation files are not uploaded to connectors".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
@@ -1200,6 +1206,78 @@ impl
     }
 }
 
+impl ConnectorIntegration<api::Fetch, types::FetchDisputesRequestData, types::FetchDisputesResponse>
+    for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::FetchDisputesRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::FetchDisputesRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let query_params =
+            serde_urlencoded::to_string(checkout::CheckoutDisputeListRequest::try_from(req)?)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(format!(
+            "{}disputes?{}",
+            self.base_url(connectors),
+            query_params
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FetchDisputesRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::FetchDisputesType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::FetchDisputesType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FetchDisputesRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::FetchDisputesRouterData, errors::ConnectorError> {
+        let response: checkout::CheckoutDisputeListResponse = res
+            .response
+            .parse_struct("CheckoutDisputeListResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Checkout {
     fn get_webhook_source_verification_algorithm(
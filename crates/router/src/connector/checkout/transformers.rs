@@ -603,6 +603,24 @@ pub struct PaymentsResponse {
     response_summary: Option<String>,
     approved: Option<bool>,
     processed_on: Option<String>,
+    source: Option<PaymentsResponseSource>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct PaymentsResponseSource {
+    avs_check: Option<String>,
+    cvv_check: Option<String>,
+}
+
+impl PaymentsResponseSource {
+    fn get_connector_response(&self) -> Option<types::ConnectorResponseData> {
+        utils::get_card_verification_results(
+            self.avs_check.clone(),
+            self.cvv_check.clone(),
+            utils::AvsResultCodes::CardNetwork,
+        )
+        .map(types::ConnectorResponseData::with_card_verification_results)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -652,6 +670,11 @@ impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
             item.response.status,
             item.data.request.capture_method,
         ));
+        let connector_response = item
+            .response
+            .source
+            .as_ref()
+            .and_then(PaymentsResponseSource::get_connector_response);
         let error_response = if status == enums::AttemptStatus::Failure {
             Some(types::ErrorResponse {
                 status_code: item.http_code,
@@ -685,6 +708,7 @@ impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
         Ok(Self {
             status,
             response: error_response.map_or_else(|| Ok(payments_response_data), Err),
+            connector_response,
             ..item.data
         })
     }
@@ -704,6 +728,11 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<PaymentsResponse>>
             to_connector_meta(item.data.request.connector_meta.clone())?;
         let status =
             enums::AttemptStatus::foreign_from((item.response.status, checkout_meta.psync_flow));
+        let connector_response = item
+            .response
+            .source
+            .as_ref()
+            .and_then(PaymentsResponseSource::get_connector_response);
         let error_response = if status == enums::AttemptStatus::Failure {
             Some(types::ErrorResponse {
                 status_code: item.http_code,
@@ -737,6 +766,7 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<PaymentsResponse>>
         Ok(Self {
             status,
             response: error_response.map_or_else(|| Ok(payments_response_data), Err),
+            connector_response,
             ..item.data
         })
     }
@@ -1385,6 +1415,7 @@ impl TryFrom<&api::IncomingWebhookRequestDetails<'_>> for PaymentsResponse {
             currency: Some(data.currency),
             processed_on: data.processed_on,
             approved: data.approved,
+            source: None,
         };
 
         Ok(psync_struct)
@@ -1435,6 +1466,179 @@ impl TryFrom<&types::SubmitEvidenceRouterData> for Evidence {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct CheckoutDisputeListRequest {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub from: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub to: PrimitiveDateTime,
+    pub limit: u32,
+    pub skip: u32,
+}
+
+impl TryFrom<&types::FetchDisputesRouterData> for CheckoutDisputeListRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FetchDisputesRouterData) -> Result<Self, Self::Error> {
+        // The cursor is the number of disputes already fetched
+        let skip = item
+            .request
+            .cursor
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Invalid cursor for fetching disputes")?
+            .unwrap_or_default();
+        Ok(Self {
+            from: item.request.start_time,
+            to: item.request.end_time,
+            limit: DISPUTE_LIST_PAGE_SIZE,
+            skip,
+        })
+    }
+}
+
+const DISPUTE_LIST_PAGE_SIZE: u32 = 50;
+
+#[derive(Debug, Clone, Deserialize, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckoutDisputeStatus {
+    EvidenceRequired,
+    EvidenceUnderReview,
+    Resolved,
+    ArbitrationUnderReview,
+    Won,
+    Lost,
+    Expired,
+    Accepted,
+    Canceled,
+    ArbitrationWon,
+    ArbitrationLost,
+    #[serde(other)]
+    Unknown,
+}
+
+impl CheckoutDisputeStatus {
+    fn get_dispute_stage_and_status(
+        &self,
+    ) -> Option<(
+        api_models::enums::DisputeStage,
+        api_models::enums::DisputeStatus,
+    )> {
+        use api_models::enums::{DisputeStage, DisputeStatus};
+
+        match self {
+            Self::EvidenceRequired => Some((DisputeStage::Dispute, DisputeStatus::DisputeOpened)),
+            Self::EvidenceUnderReview => {
+                Some((DisputeStage::Dispute, DisputeStatus::DisputeChallenged))
+            }
+            Self::Resolved | Self::Canceled => {
+                Some((DisputeStage::Dispute, DisputeStatus::DisputeCancelled))
+            }
+            Self::Won => Some((DisputeStage::Dispute, DisputeStatus::DisputeWon)),
+            Self::Lost => Some((DisputeStage::Dispute, DisputeStatus::DisputeLost)),
+            Self::Expired => Some((DisputeStage::Dispute, DisputeStatus::DisputeExpired)),
+            Self::Accepted => Some((DisputeStage::Dispute, DisputeStatus::DisputeAccepted)),
+            Self::ArbitrationUnderReview => Some((
+                DisputeStage::PreArbitration,
+                DisputeStatus::DisputeChallenged,
+            )),
+            Self::ArbitrationWon => Some((DisputeStage::PreArbitration, DisputeStatus::DisputeWon)),
+            Self::ArbitrationLost => {
+                Some((DisputeStage::PreArbitration, DisputeStatus::DisputeLost))
+            }
+            Self::Unknown => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CheckoutDispute {
+    pub id: String,
+    pub category: Option<String>,
+    pub status: CheckoutDisputeStatus,
+    pub amount: i64,
+    pub currency: String,
+    pub reason_code: Option<String>,
+    pub payment_id: String,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub evidence_required_by: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub received_on: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_update: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CheckoutDisputeListResponse {
+    pub limit: u32,
+    pub skip: u32,
+    pub total_count: u32,
+    #[serde(default)]
+    pub data: Vec<CheckoutDispute>,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            CheckoutDisputeListResponse,
+            types::FetchDisputesRequestData,
+            types::FetchDisputesResponse,
+        >,
+    > for types::RouterData<F, types::FetchDisputesRequestData, types::FetchDisputesResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            CheckoutDisputeListResponse,
+            types::FetchDisputesRequestData,
+            types::FetchDisputesResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let fetched_count = item.response.skip + item.response.limit;
+        let next_cursor =
+            (fetched_count < item.response.total_count).then(|| fetched_count.to_string());
+        let disputes = item
+            .response
+            .data
+            .into_iter()
+            .filter_map(|dispute| {
+                let (dispute_stage, dispute_status) =
+                    dispute.status.get_dispute_stage_and_status()?;
+                Some(api::disputes::FetchedDispute {
+                    payment_reference: api_models::payments::PaymentIdType::ConnectorTransactionId(
+                        dispute.payment_id,
+                    ),
+                    dispute_status,
+                    details: api::disputes::DisputePayload {
+                        amount: dispute.amount.to_string(),
+                        currency: dispute.currency,
+                        dispute_stage,
+                        connector_status: dispute.status.to_string(),
+                        connector_dispute_id: dispute.id,
+                        connector_reason: dispute.category,
+                        connector_reason_code: dispute.reason_code,
+                        challenge_required_by: dispute.evidence_required_by,
+                        created_at: dispute.received_on,
+                        updated_at: dispute.last_update,
+                    },
+                })
+            })
+            .collect();
+        Ok(Self {
+            response: Ok(types::FetchDisputesResponse {
+                disputes,
+                next_cursor,
+                connector_dispute_ids_to_retrieve: Vec::new(),
+            }),
+            ..item.data
+        })
+    }
+}
+
 impl From<String> for utils::ErrorCodeAndMessage {
     fn from(error: String) -> Self {
         Self {
@@ -223,6 +223,17 @@ impl ConnectorValidation for Cybersource {
             ),
         }
     }
+
+    fn validate_over_capture(
+        &self,
+        card_network: Option<enums::CardNetwork>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        connector_utils::validate_over_capture_card_network(
+            card_network,
+            &[enums::CardNetwork::Visa, enums::CardNetwork::Mastercard],
+            self.id(),
+        )
+    }
 }
 
 impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Cybersource
@@ -71,8 +71,12 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
             amount_details: Amount {
                 total_amount: "0".to_string(),
                 currency: item.request.currency,
+                level_two_amount_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            shipping_details: None,
         };
         let (action_list, action_token_types, authorization_options) = (
             Some(vec![CybersourceActionsList::TokenCreate]),
@@ -84,6 +88,7 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
                     stored_credential_used: None,
                 }),
                 merchant_intitiated_transaction: None,
+                partial_auth_indicator: None,
             }),
         );
 
@@ -217,6 +222,7 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
             authorization_options,
             commerce_indicator: String::from("internet"),
             payment_solution: solution.map(String::from),
+            purchase_level: None,
         };
         Ok(Self {
             processing_information,
@@ -250,6 +256,7 @@ pub struct ProcessingInformation {
     capture: Option<bool>,
     capture_options: Option<CaptureOptions>,
     payment_solution: Option<String>,
+    purchase_level: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -286,6 +293,7 @@ pub enum CybersourceActionsTokenType {
 pub struct CybersourceAuthorizationOptions {
     initiator: Option<CybersourcePaymentInitiator>,
     merchant_intitiated_transaction: Option<MerchantInitiatedTransaction>,
+    partial_auth_indicator: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -406,6 +414,12 @@ pub struct Card {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_details: Option<ShippingDetails>,
 }
 
 #[derive(Debug, Serialize)]
@@ -425,6 +439,128 @@ pub struct OrderInformation {
 pub struct Amount {
     total_amount: String,
     currency: api_models::enums::Currency,
+    #[serde(flatten)]
+    level_two_amount_details: Option<LevelTwoAmountDetails>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelTwoAmountDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freight_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    unit_price: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    total_amount: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    taxable: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShippingDetails {
+    ship_from_postal_code: Secret<String>,
+}
+
+struct LevelTwoLevelThreeDetails {
+    amount_details: Option<LevelTwoAmountDetails>,
+    line_items: Option<Vec<LineItem>>,
+    invoice_details: Option<InvoiceDetails>,
+    shipping_details: Option<ShippingDetails>,
+}
+
+impl TryFrom<(&payments::L2L3Data, api_models::enums::Currency)> for LevelTwoLevelThreeDetails {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (l2_l3_data, currency): (&payments::L2L3Data, api_models::enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        let to_base_unit = |amount: Option<i64>| {
+            amount
+                .map(|amount| utils::to_currency_base_unit(amount, currency))
+                .transpose()
+        };
+        let line_items = l2_l3_data
+            .line_items
+            .as_ref()
+            .map(|line_items| {
+                line_items
+                    .iter()
+                    .map(|line_item| {
+                        Ok(LineItem {
+                            product_name: line_item.product_name.clone(),
+                            product_sku: line_item.product_code.clone(),
+                            commodity_code: line_item.commodity_code.clone(),
+                            quantity: line_item.quantity,
+                            unit_of_measure: line_item.unit_of_measure.clone(),
+                            unit_price: utils::to_currency_base_unit(
+                                line_item.unit_price,
+                                currency,
+                            )?,
+                            discount_amount: to_base_unit(line_item.discount_amount)?,
+                            tax_amount: to_base_unit(line_item.tax_amount)?,
+                            total_amount: utils::to_currency_base_unit(
+                                line_item.get_total_amount().ok_or(
+                                    errors::ConnectorError::InvalidDataFormat {
+                                        field_name: "l2_l3_data.line_items",
+                                    },
+                                )?,
+                                currency,
+                            )?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Self::Error>>()
+            })
+            .transpose()?;
+        let invoice_details = (l2_l3_data.customer_code.is_some()
+            || l2_l3_data.tax_exempt.is_some())
+        .then(|| InvoiceDetails {
+            purchase_order_number: l2_l3_data.customer_code.clone(),
+            taxable: l2_l3_data.tax_exempt.map(|tax_exempt| !tax_exempt),
+        });
+        Ok(Self {
+            amount_details: Some(LevelTwoAmountDetails {
+                tax_amount: to_base_unit(l2_l3_data.tax_amount)?,
+                duty_amount: to_base_unit(l2_l3_data.duty_amount)?,
+                freight_amount: to_base_unit(l2_l3_data.shipping_amount)?,
+                discount_amount: to_base_unit(l2_l3_data.discount_amount)?,
+            }),
+            line_items,
+            invoice_details,
+            shipping_details: l2_l3_data.ship_from_postal_code.clone().map(
+                |ship_from_postal_code| ShippingDetails {
+                    ship_from_postal_code,
+                },
+            ),
+        })
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -548,6 +684,7 @@ impl
                         stored_credential_used: None,
                     }),
                     merchant_intitiated_transaction: None,
+                    partial_auth_indicator: None,
                 }),
             )
         } else if item.router_data.request.mandate_id.is_some() {
@@ -581,6 +718,7 @@ impl
                                 )?),
                                 previous_transaction_id: None,
                             }),
+                            partial_auth_indicator: None,
                         }),
                     )
                 }
@@ -647,6 +785,7 @@ impl
                                 original_authorized_amount,
                                 previous_transaction_id: Some(Secret::new(network_transaction_id)),
                             }),
+                            partial_auth_indicator: None,
                         }),
                     )
                 }
@@ -655,6 +794,18 @@ impl
         } else {
             (None, None, None)
         };
+        let authorization_options = if item.router_data.request.allow_partial_authorization {
+            Some(CybersourceAuthorizationOptions {
+                partial_auth_indicator: Some(true),
+                ..authorization_options.unwrap_or(CybersourceAuthorizationOptions {
+                    initiator: None,
+                    merchant_intitiated_transaction: None,
+                    partial_auth_indicator: None,
+                })
+            })
+        } else {
+            authorization_options
+        };
         Ok(Self {
             capture: Some(matches!(
                 item.router_data.request.capture_method,
@@ -666,10 +817,18 @@ impl
             authorization_options,
             capture_options: None,
             commerce_indicator,
+            purchase_level: get_purchase_level(item.router_data.request.l2_l3_data.as_ref()),
         })
     }
 }
 
+// Line items are sent only for Level 3 purchases
+fn get_purchase_level(l2_l3_data: Option<&payments::L2L3Data>) -> Option<String> {
+    l2_l3_data
+        .and_then(|l2_l3_data| l2_l3_data.line_items.as_ref())
+        .map(|_| "3".to_string())
+}
+
 impl
     From<(
         &CybersourceRouterData<&types::PaymentsCompleteAuthorizeRouterData>,
@@ -703,6 +862,7 @@ impl
                         stored_credential_used: None,
                     }),
                     merchant_intitiated_transaction: None,
+                    partial_auth_indicator: None,
                 }),
             )
         } else {
@@ -714,6 +874,7 @@ impl
                 Some(enums::CaptureMethod::Automatic) | None
             )),
             payment_solution: solution.map(String::from),
+            purchase_level: None,
             action_list,
             action_token_types,
             authorization_options,
@@ -727,24 +888,48 @@ impl
 }
 
 impl
-    From<(
+    TryFrom<(
         &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
         BillTo,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
             BillTo,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let level_two_level_three_details = item
+            .router_data
+            .request
+            .l2_l3_data
+            .as_ref()
+            .map(|l2_l3_data| {
+                LevelTwoLevelThreeDetails::try_from((l2_l3_data, item.router_data.request.currency))
+            })
+            .transpose()?;
+        let (level_two_amount_details, line_items, invoice_details, shipping_details) =
+            match level_two_level_three_details {
+                Some(details) => (
+                    details.amount_details,
+                    details.line_items,
+                    details.invoice_details,
+                    details.shipping_details,
+                ),
+                None => (None, None, None, None),
+            };
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                level_two_amount_details,
             },
             bill_to: Some(bill_to),
-        }
+            line_items,
+            invoice_details,
+            shipping_details,
+        })
     }
 }
 
@@ -764,8 +949,12 @@ impl
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                level_two_amount_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            shipping_details: None,
         }
     }
 }
@@ -827,7 +1016,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
 
         let card_issuer = ccard.get_card_issuer();
         let card_type = match card_issuer {
@@ -957,7 +1146,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -1021,7 +1210,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
 
         let payment_information = PaymentInformation::GooglePay(GooglePayPaymentInformation {
             fluid_data: FluidData {
@@ -1082,7 +1271,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                                     let bill_to =
                                         build_bill_to(item.router_data.get_billing()?, email)?;
                                     let order_information =
-                                        OrderInformationWithBill::from((item, bill_to));
+                                        OrderInformationWithBill::try_from((item, bill_to))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1224,7 +1413,7 @@ impl
         };
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(MandatePaymentInformation { payment_instrument });
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -1341,13 +1530,18 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCaptureRouterData>>
                 capture: None,
                 commerce_indicator: String::from("internet"),
                 payment_solution: None,
+                purchase_level: None,
             },
             order_information: OrderInformationWithBill {
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_two_amount_details: None,
                 },
                 bill_to: None,
+                line_items: None,
+                invoice_details: None,
+                shipping_details: None,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(item.router_data.connector_request_reference_id.clone()),
@@ -1379,11 +1573,13 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsIncrementalAuthorizationRout
                         previous_transaction_id: None,
                         original_authorized_amount: None,
                     }),
+                    partial_auth_indicator: None,
                 }),
                 commerce_indicator: String::from("internet"),
                 capture: None,
                 capture_options: None,
                 payment_solution: None,
+                purchase_level: None,
             },
             order_information: OrderInformationIncrementalAuthorization {
                 amount_details: AdditionalAmount {
@@ -1433,6 +1629,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCancelRouterData>> for Cyber
                             field_name: "Currency",
                         },
                     )?,
+                    level_two_amount_details: None,
                 },
                 reason: value
                     .router_data
@@ -1495,7 +1692,7 @@ pub enum CybersourcePaymentStatus {
     PendingReview,
     Accepted,
     Cancelled,
-    //PartialAuthorized, not being consumed yet.
+    PartialAuthorized,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1510,7 +1707,8 @@ impl ForeignFrom<(CybersourcePaymentStatus, bool)> for enums::AttemptStatus {
     fn foreign_from((status, capture): (CybersourcePaymentStatus, bool)) -> Self {
         match status {
             CybersourcePaymentStatus::Authorized
-            | CybersourcePaymentStatus::AuthorizedPendingReview => {
+            | CybersourcePaymentStatus::AuthorizedPendingReview
+            | CybersourcePaymentStatus::PartialAuthorized => {
                 if capture {
                     // Because Cybersource will return Payment Status as Authorized even in AutoCapture Payment
                     Self::Charged
@@ -1572,6 +1770,67 @@ pub struct CybersourceClientReferenceResponse {
     risk_information: Option<ClientRiskInformation>,
     token_information: Option<CybersourceTokenInformation>,
     error_information: Option<CybersourceErrorInformation>,
+    order_information: Option<CybersourceOrderInformationResponse>,
+    consumer_authentication_information: Option<ClientConsumerAuthenticationInformation>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientConsumerAuthenticationInformation {
+    eci: Option<String>,
+    eci_raw: Option<String>,
+    pares_status: Option<String>,
+    specification_version: Option<String>,
+    directory_server_transaction_id: Option<String>,
+}
+
+impl From<&ClientConsumerAuthenticationInformation>
+    for api_models::payments::PaymentAuthenticationDetails
+{
+    fn from(item: &ClientConsumerAuthenticationInformation) -> Self {
+        let electronic_commerce_indicator = item.eci_raw.clone().or_else(|| item.eci.clone());
+        let trans_status = item
+            .pares_status
+            .as_deref()
+            .and_then(utils::get_three_ds_transaction_status);
+        let liability_shift = utils::get_liability_shift(
+            electronic_commerce_indicator.as_deref(),
+            trans_status.as_ref(),
+        );
+        Self {
+            authentication_source: api_models::payments::AuthenticationSource::Connector,
+            trans_status,
+            liability_shift,
+            electronic_commerce_indicator,
+            version: item.specification_version.clone(),
+            ds_transaction_id: item.directory_server_transaction_id.clone(),
+            authentication_flow: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceOrderInformationResponse {
+    amount_details: Option<CybersourceAmountDetailsResponse>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceAmountDetailsResponse {
+    authorized_amount: Option<String>,
+    currency: Option<enums::Currency>,
+}
+
+impl CybersourceClientReferenceResponse {
+    fn get_authorized_amount(&self) -> Option<i64> {
+        let amount_details = self.order_information.as_ref()?.amount_details.as_ref()?;
+        let authorized_amount = amount_details.authorized_amount.clone()?;
+        utils::to_currency_lower_unit(authorized_amount, amount_details.currency?)
+            .ok()?
+            .parse::<i64>()
+            .ok()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1808,11 +2067,18 @@ impl<F>
                     item.data.request.is_auto_capture()?,
                 ));
                 let response = get_payment_response((&info_response, status, item.http_code));
-                let connector_response = info_response
-                    .processor_information
-                    .as_ref()
-                    .map(types::AdditionalPaymentMethodConnectorResponse::from)
-                    .map(types::ConnectorResponseData::with_additional_payment_method_data);
+                let connector_response = types::ConnectorResponseData::new(
+                    info_response
+                        .processor_information
+                        .as_ref()
+                        .map(|processor_information| {
+                            types::AdditionalPaymentMethodConnectorResponse::from((
+                                processor_information,
+                                info_response.consumer_authentication_information.as_ref(),
+                            ))
+                        }),
+                    info_response.get_authorized_amount(),
+                );
 
                 Ok(Self {
                     status,
@@ -2010,6 +2276,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
                     field_name: "currency",
                 },
             )?,
+            level_two_amount_details: None,
         };
 
         match redirect_response.params {
@@ -2028,6 +2295,9 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
                 let order_information = OrderInformationWithBill {
                     amount_details,
                     bill_to: Some(bill_to),
+                    line_items: None,
+                    invoice_details: None,
+                    shipping_details: None,
                 };
                 Ok(Self::AuthEnrollment(CybersourceAuthEnrollmentRequest {
                     payment_information,
@@ -2300,11 +2570,18 @@ impl<F>
                     item.data.request.is_auto_capture()?,
                 ));
                 let response = get_payment_response((&info_response, status, item.http_code));
-                let connector_response = info_response
-                    .processor_information
-                    .as_ref()
-                    .map(types::AdditionalPaymentMethodConnectorResponse::from)
-                    .map(types::ConnectorResponseData::with_additional_payment_method_data);
+                let connector_response = types::ConnectorResponseData::new(
+                    info_response
+                        .processor_information
+                        .as_ref()
+                        .map(|processor_information| {
+                            types::AdditionalPaymentMethodConnectorResponse::from((
+                                processor_information,
+                                info_response.consumer_authentication_information.as_ref(),
+                            ))
+                        }),
+                    info_response.get_authorized_amount(),
+                );
 
                 Ok(Self {
                     status,
@@ -2322,15 +2599,39 @@ impl<F>
     }
 }
 
-impl From<&ClientProcessorInformation> for types::AdditionalPaymentMethodConnectorResponse {
-    fn from(processor_information: &ClientProcessorInformation) -> Self {
+impl
+    From<(
+        &ClientProcessorInformation,
+        Option<&ClientConsumerAuthenticationInformation>,
+    )> for types::AdditionalPaymentMethodConnectorResponse
+{
+    fn from(
+        (processor_information, consumer_authentication_information): (
+            &ClientProcessorInformation,
+            Option<&ClientConsumerAuthenticationInformation>,
+        ),
+    ) -> Self {
         let payment_checks = Some(
             serde_json::json!({"avs_response": processor_information.avs, "card_verification": processor_information.card_verification}),
         );
+        let card_verification_results = utils::get_card_verification_results(
+            processor_information
+                .avs
+                .as_ref()
+                .map(|avs| avs.code.clone()),
+            processor_information
+                .card_verification
+                .as_ref()
+                .and_then(|card_verification| card_verification.result_code.clone()),
+            utils::AvsResultCodes::Cybersource,
+        );
 
         Self::Card {
             authentication_data: None,
             payment_checks,
+            card_verification_results,
+            authentication_details: consumer_authentication_information
+                .map(api_models::payments::PaymentAuthenticationDetails::from),
         }
     }
 }
@@ -2451,7 +2752,12 @@ impl<F, T>
                 let connector_response = info_response
                     .processor_information
                     .as_ref()
-                    .map(types::AdditionalPaymentMethodConnectorResponse::from)
+                    .map(|processor_information| {
+                        types::AdditionalPaymentMethodConnectorResponse::from((
+                            processor_information,
+                            info_response.consumer_authentication_information.as_ref(),
+                        ))
+                    })
                     .map(types::ConnectorResponseData::with_additional_payment_method_data);
 
                 Ok(Self {
@@ -2679,6 +2985,7 @@ impl<F> TryFrom<&CybersourceRouterData<&types::RefundsRouterData<F>>> for Cybers
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_two_amount_details: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2848,6 +3155,7 @@ impl TryFrom<&CybersourceRouterData<&types::PayoutsRouterData<api::PoFulfill>>>
                     amount_details: Amount {
                         total_amount: item.amount.to_owned(),
                         currency: item.router_data.request.destination_currency,
+                        level_two_amount_details: None,
                     },
                 };
 
@@ -138,6 +138,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let response = match item.response {
             NetceteraAuthenticationResponse::Success(response) => {
+                let ds_trans_id = response.authentication_response.ds_trans_id.clone();
                 let authn_flow_type = match response.acs_challenge_mandated {
                     Some(ACSChallengeMandatedIndicator::Y) => {
                         types::authentication::AuthNFlowType::Challenge(Box::new(
@@ -164,6 +165,7 @@ impl
                         authn_flow_type,
                         authentication_value: response.authentication_value,
                         trans_status: response.trans_status,
+                        ds_trans_id,
                     },
                 )
             }
@@ -639,6 +641,8 @@ pub struct AuthenticationResponse {
     #[serde(rename = "acsTransID")]
     pub acs_trans_id: Option<String>,
     pub acs_signed_content: Option<String>,
+    #[serde(rename = "dsTransID")]
+    pub ds_trans_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -1487,6 +1487,103 @@ impl
     }
 }
 
+impl api::FetchDisputes for Paypal {}
+
+impl ConnectorIntegration<api::Fetch, types::FetchDisputesRequestData, types::FetchDisputesResponse>
+    for Paypal
+{
+    fn get_headers(
+        &self,
+        req: &types::FetchDisputesRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::FetchDisputesRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        match &req.request.connector_dispute_id {
+            Some(connector_dispute_id) => Ok(format!(
+                "{}v1/customer/disputes/{}",
+                self.base_url(connectors),
+                connector_dispute_id
+            )),
+            None => {
+                let query_params =
+                    serde_urlencoded::to_string(paypal::PaypalDisputeListRequest::from(req))
+                        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+                Ok(format!(
+                    "{}v1/customer/disputes?{}",
+                    self.base_url(connectors),
+                    query_params
+                ))
+            }
+        }
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FetchDisputesRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::FetchDisputesType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::FetchDisputesType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FetchDisputesRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<types::FetchDisputesRouterData, errors::ConnectorError> {
+        let router_data = if data.request.connector_dispute_id.is_some() {
+            let response: paypal::PaypalDisputeResponse = res
+                .response
+                .parse_struct("PaypalDisputeResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            event_builder.map(|i| i.set_response_body(&response));
+            router_env::logger::info!(connector_response=?response);
+            types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            })
+        } else {
+            let response: paypal::PaypalDisputeListResponse = res
+                .response
+                .parse_struct("PaypalDisputeListResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+            event_builder.map(|i| i.set_response_body(&response));
+            router_env::logger::info!(connector_response=?response);
+            types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            })
+        };
+        router_data.change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Paypal {
     fn get_webhook_object_reference_id(
@@ -1533,6 +1630,12 @@ impl api::IncomingWebhook for Paypal {
                     ),
                 ))
             }
+            #[cfg(feature = "payouts")]
+            paypal::PaypalResource::PaypalPayoutWebhooks(resource) => {
+                Ok(api_models::webhooks::ObjectReferenceId::PayoutId(
+                    api_models::webhooks::PayoutIdType::ConnectorPayoutId(resource.payout_batch_id),
+                ))
+            }
         }
     }
 
@@ -1565,6 +1668,16 @@ impl api::IncomingWebhook for Paypal {
             | PaypalWebhookEventType::CheckoutOrderCompleted
             | PaypalWebhookEventType::CheckoutOrderProcessed
             | PaypalWebhookEventType::Unknown => None,
+            #[cfg(feature = "payouts")]
+            PaypalWebhookEventType::PayoutsItemSucceeded
+            | PaypalWebhookEventType::PayoutsItemFailed
+            | PaypalWebhookEventType::PayoutsItemBlocked
+            | PaypalWebhookEventType::PayoutsItemDenied
+            | PaypalWebhookEventType::PayoutsItemCanceled
+            | PaypalWebhookEventType::PayoutsItemHeld
+            | PaypalWebhookEventType::PayoutsItemUnclaimed
+            | PaypalWebhookEventType::PayoutsItemRefunded
+            | PaypalWebhookEventType::PayoutsItemReturned => None,
         };
 
         Ok(api::IncomingWebhookEvent::foreign_from((
@@ -1593,6 +1706,8 @@ impl api::IncomingWebhook for Paypal {
                 paypal::RefundSyncResponse::try_from((*resource, details.event_type))?,
             ),
             paypal::PaypalResource::PaypalDisputeWebhooks(_) => Box::new(details),
+            #[cfg(feature = "payouts")]
+            paypal::PaypalResource::PaypalPayoutWebhooks(_) => Box::new(details),
         })
     }
 
@@ -1751,3 +1866,91 @@ impl ConnectorErrorTypeMapping for Paypal {
         }
     }
 }
+
+#[cfg(all(test, feature = "payouts"))]
+mod payout_webhook_tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::webhooks::{IncomingWebhookEvent, ObjectReferenceId, PayoutIdType};
+
+    use super::*;
+    use crate::types::api::IncomingWebhook;
+
+    fn get_request_details<'a>(
+        headers: &'a actix_web::http::header::HeaderMap,
+        body: &'a [u8],
+    ) -> api::IncomingWebhookRequestDetails<'a> {
+        api::IncomingWebhookRequestDetails {
+            method: actix_web::http::Method::POST,
+            uri: actix_web::http::Uri::default(),
+            headers,
+            body,
+            query_params: String::new(),
+        }
+    }
+
+    fn get_payout_webhook_body(event_type: &str) -> Vec<u8> {
+        serde_json::json!({
+            "id": "WH-1",
+            "event_type": event_type,
+            "resource": {
+                "payout_item_id": "payout_item_1",
+                "payout_batch_id": "payout_batch_1",
+                "transaction_id": "transaction_1",
+                "transaction_status": "SUCCESS"
+            }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_payout_webhook_event_type() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let cases = [
+            (
+                "PAYMENT.PAYOUTS-ITEM.SUCCEEDED",
+                IncomingWebhookEvent::PayoutSuccess,
+            ),
+            (
+                "PAYMENT.PAYOUTS-ITEM.DENIED",
+                IncomingWebhookEvent::PayoutFailure,
+            ),
+            (
+                "PAYMENT.PAYOUTS-ITEM.CANCELED",
+                IncomingWebhookEvent::PayoutCancelled,
+            ),
+            (
+                "PAYMENT.PAYOUTS-ITEM.UNCLAIMED",
+                IncomingWebhookEvent::PayoutProcessing,
+            ),
+            (
+                "PAYMENT.PAYOUTS-ITEM.RETURNED",
+                IncomingWebhookEvent::PayoutReversed,
+            ),
+        ];
+
+        for (event_type, expected_event) in cases {
+            let body = get_payout_webhook_body(event_type);
+            let request = get_request_details(&headers, &body);
+
+            assert_eq!(
+                Paypal.get_webhook_event_type(&request).unwrap(),
+                expected_event,
+                "event type {event_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_payout_webhook_object_reference_id() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let body = get_payout_webhook_body("PAYMENT.PAYOUTS-ITEM.SUCCEEDED");
+        let request = get_request_details(&headers, &body);
+
+        assert!(matches!(
+            Paypal.get_webhook_object_reference_id(&request).unwrap(),
+            ObjectReferenceId::PayoutId(PayoutIdType::ConnectorPayoutId(id))
+                if id == "payout_batch_1"
+        ));
+    }
+}
@@ -2007,6 +2007,33 @@ pub enum PaypalWebhookEventType {
     CustomerDisputedUpdated,
     #[serde(rename = "RISK.DISPUTE.CREATED")]
     RiskDisputeCreated,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "PAYMENT.PAYOUTS-ITEM.SUCCEEDED")]
+    PayoutsItemSucceeded,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "PAYMENT.PAYOUTS-ITEM.FAILED")]
+    PayoutsItemFailed,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "PAYMENT.PAYOUTS-ITEM.BLOCKED")]
+    PayoutsItemBlocked,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "PAYMENT.PAYOUTS-ITEM.DENIED")]
+    PayoutsItemDenied,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "PAYMENT.PAYOUTS-ITEM.CANCELED")]
+    PayoutsItemCanceled,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "PAYMENT.PAYOUTS-ITEM.HELD")]
+    PayoutsItemHeld,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "PAYMENT.PAYOUTS-ITEM.UNCLAIMED")]
+    PayoutsItemUnclaimed,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "PAYMENT.PAYOUTS-ITEM.REFUNDED")]
+    PayoutsItemRefunded,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "PAYMENT.PAYOUTS-ITEM.RETURNED")]
+    PayoutsItemReturned,
     #[serde(other)]
     Unknown,
 }
@@ -2018,6 +2045,17 @@ pub enum PaypalResource {
     PaypalRedirectsWebhooks(Box<PaypalRedirectsWebhooks>),
     PaypalRefundWebhooks(Box<PaypalRefundWebhooks>),
     PaypalDisputeWebhooks(Box<PaypalDisputeWebhooks>),
+    #[cfg(feature = "payouts")]
+    PaypalPayoutWebhooks(Box<PaypalPayoutWebhooks>),
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct PaypalPayoutWebhooks {
+    pub payout_item_id: String,
+    pub payout_batch_id: String,
+    pub transaction_id: Option<String>,
+    pub transaction_status: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Serialize)]
@@ -2147,6 +2185,20 @@ impl ForeignFrom<(PaypalWebhookEventType, Option<OutcomeCode>)> for api::Incomin
             | PaypalWebhookEventType::CheckoutOrderApproved
             | PaypalWebhookEventType::CustomerDisputedUpdated
             | PaypalWebhookEventType::Unknown => Self::EventNotSupported,
+            #[cfg(feature = "payouts")]
+            PaypalWebhookEventType::PayoutsItemSucceeded => Self::PayoutSuccess,
+            #[cfg(feature = "payouts")]
+            PaypalWebhookEventType::PayoutsItemFailed
+            | PaypalWebhookEventType::PayoutsItemBlocked
+            | PaypalWebhookEventType::PayoutsItemDenied => Self::PayoutFailure,
+            #[cfg(feature = "payouts")]
+            PaypalWebhookEventType::PayoutsItemCanceled => Self::PayoutCancelled,
+            #[cfg(feature = "payouts")]
+            PaypalWebhookEventType::PayoutsItemHeld
+            | PaypalWebhookEventType::PayoutsItemUnclaimed => Self::PayoutProcessing,
+            #[cfg(feature = "payouts")]
+            PaypalWebhookEventType::PayoutsItemRefunded
+            | PaypalWebhookEventType::PayoutsItemReturned => Self::PayoutReversed,
         }
     }
 }
@@ -2176,6 +2228,222 @@ impl From<DisputeLifeCycleStage> for enums::DisputeStage {
     }
 }
 
+/// Max number of disputes in a page of the dispute list, as allowed by PayPal
+const DISPUTE_LIST_PAGE_SIZE: u32 = 50;
+
+#[derive(Debug, Serialize)]
+pub struct PaypalDisputeListRequest {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub update_time_after: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub update_time_before: PrimitiveDateTime,
+    pub page_size: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+}
+
+impl From<&types::FetchDisputesRouterData> for PaypalDisputeListRequest {
+    fn from(item: &types::FetchDisputesRouterData) -> Self {
+        Self {
+            update_time_after: item.request.start_time,
+            update_time_before: item.request.end_time,
+            page_size: DISPUTE_LIST_PAGE_SIZE,
+            next_page_token: item.request.cursor.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaypalDisputeListResponse {
+    #[serde(default)]
+    pub items: Vec<PaypalDisputeSummary>,
+    #[serde(default)]
+    pub links: Vec<PaypalLink>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaypalDisputeSummary {
+    pub dispute_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaypalLink {
+    pub href: String,
+    pub rel: String,
+}
+
+impl PaypalDisputeListResponse {
+    /// The token of the next page is only returned as part of the link to the next page
+    fn get_next_page_token(&self) -> Option<String> {
+        let next_page_link = self.links.iter().find(|link| link.rel == "next")?;
+        Url::parse(&next_page_link.href)
+            .ok()?
+            .query_pairs()
+            .find(|(key, _)| key == "next_page_token")
+            .map(|(_, value)| value.into_owned())
+    }
+}
+
+/// The dispute list of PayPal only contains summaries of the disputes, without the disputed
+/// transactions. Hence the listed disputes are fetched individually.
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            PaypalDisputeListResponse,
+            types::FetchDisputesRequestData,
+            types::FetchDisputesResponse,
+        >,
+    > for types::RouterData<F, types::FetchDisputesRequestData, types::FetchDisputesResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PaypalDisputeListResponse,
+            types::FetchDisputesRequestData,
+            types::FetchDisputesResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let next_cursor = item.response.get_next_page_token();
+        let connector_dispute_ids_to_retrieve = item
+            .response
+            .items
+            .into_iter()
+            .map(|dispute| dispute.dispute_id)
+            .collect();
+        Ok(Self {
+            response: Ok(types::FetchDisputesResponse {
+                disputes: Vec::new(),
+                next_cursor,
+                connector_dispute_ids_to_retrieve,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaypalDisputeResponse {
+    pub dispute_id: String,
+    #[serde(default)]
+    pub disputed_transactions: Vec<PaypalDisputedTransaction>,
+    pub dispute_amount: OrderAmount,
+    pub dispute_outcome: Option<DisputeOutcome>,
+    pub dispute_life_cycle_stage: DisputeLifeCycleStage,
+    pub status: DisputeStatus,
+    pub reason: Option<String>,
+    pub external_reason_code: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub seller_response_due_date: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub create_time: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub update_time: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaypalDisputedTransaction {
+    pub invoice_number: Option<String>,
+}
+
+impl PaypalDisputeResponse {
+    fn get_dispute_status(&self) -> Option<enums::DisputeStatus> {
+        match self.status {
+            DisputeStatus::Open | DisputeStatus::WaitingForSellerResponse => {
+                Some(enums::DisputeStatus::DisputeOpened)
+            }
+            DisputeStatus::WaitingForBuyerResponse | DisputeStatus::UnderReview => {
+                Some(enums::DisputeStatus::DisputeChallenged)
+            }
+            DisputeStatus::Resolved => match self.dispute_outcome.as_ref()?.outcome_code {
+                OutcomeCode::ResolvedBuyerFavour => Some(enums::DisputeStatus::DisputeLost),
+                OutcomeCode::ResolvedSellerFavour => Some(enums::DisputeStatus::DisputeWon),
+                OutcomeCode::ACCEPTED => Some(enums::DisputeStatus::DisputeAccepted),
+                OutcomeCode::CanceledByBuyer | OutcomeCode::DENIED | OutcomeCode::NONE => {
+                    Some(enums::DisputeStatus::DisputeCancelled)
+                }
+                OutcomeCode::ResolvedWithPayout => None,
+            },
+            DisputeStatus::Other => None,
+        }
+    }
+
+    /// Disputes whose status is not known or whose disputed transaction does not carry the
+    /// reference of the payment are skipped
+    fn try_into_fetched_dispute(
+        self,
+    ) -> CustomResult<Option<api::disputes::FetchedDispute>, errors::ConnectorError> {
+        let Some(dispute_status) = self.get_dispute_status() else {
+            return Ok(None);
+        };
+        let Some(payment_reference) = self
+            .disputed_transactions
+            .into_iter()
+            .find_map(|transaction| transaction.invoice_number)
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(api::disputes::FetchedDispute {
+            payment_reference: api_models::payments::PaymentIdType::PaymentAttemptId(
+                payment_reference,
+            ),
+            dispute_status,
+            details: api::disputes::DisputePayload {
+                amount: utils::to_currency_lower_unit(
+                    self.dispute_amount.value,
+                    self.dispute_amount.currency_code,
+                )?,
+                currency: self.dispute_amount.currency_code.to_string(),
+                dispute_stage: enums::DisputeStage::from(self.dispute_life_cycle_stage.clone()),
+                connector_status: self.status.to_string(),
+                connector_dispute_id: self.dispute_id,
+                connector_reason: self.reason,
+                connector_reason_code: self.external_reason_code,
+                challenge_required_by: self.seller_response_due_date,
+                created_at: self.create_time,
+                updated_at: self.update_time,
+            },
+        }))
+    }
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            PaypalDisputeResponse,
+            types::FetchDisputesRequestData,
+            types::FetchDisputesResponse,
+        >,
+    > for types::RouterData<F, types::FetchDisputesRequestData, types::FetchDisputesResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PaypalDisputeResponse,
+            types::FetchDisputesRequestData,
+            types::FetchDisputesResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let disputes = item
+            .response
+            .try_into_fetched_dispute()?
+            .into_iter()
+            .collect();
+        Ok(Self {
+            response: Ok(types::FetchDisputesResponse {
+                disputes,
+                next_cursor: None,
+                connector_dispute_ids_to_retrieve: Vec::new(),
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct PaypalSourceVerificationRequest {
     pub transmission_id: String,
@@ -2301,6 +2569,18 @@ impl TryFrom<PaypalWebhookEventType> for PaypalPaymentStatus {
             | PaypalWebhookEventType::Unknown => {
                 Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
             }
+            #[cfg(feature = "payouts")]
+            PaypalWebhookEventType::PayoutsItemSucceeded
+            | PaypalWebhookEventType::PayoutsItemFailed
+            | PaypalWebhookEventType::PayoutsItemBlocked
+            | PaypalWebhookEventType::PayoutsItemDenied
+            | PaypalWebhookEventType::PayoutsItemCanceled
+            | PaypalWebhookEventType::PayoutsItemHeld
+            | PaypalWebhookEventType::PayoutsItemUnclaimed
+            | PaypalWebhookEventType::PayoutsItemRefunded
+            | PaypalWebhookEventType::PayoutsItemReturned => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
+            }
         }
     }
 }
@@ -2325,6 +2605,18 @@ impl TryFrom<PaypalWebhookEventType> for RefundStatus {
             | PaypalWebhookEventType::Unknown => {
                 Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
             }
+            #[cfg(feature = "payouts")]
+            PaypalWebhookEventType::PayoutsItemSucceeded
+            | PaypalWebhookEventType::PayoutsItemFailed
+            | PaypalWebhookEventType::PayoutsItemBlocked
+            | PaypalWebhookEventType::PayoutsItemDenied
+            | PaypalWebhookEventType::PayoutsItemCanceled
+            | PaypalWebhookEventType::PayoutsItemHeld
+            | PaypalWebhookEventType::PayoutsItemUnclaimed
+            | PaypalWebhookEventType::PayoutsItemRefunded
+            | PaypalWebhookEventType::PayoutsItemReturned => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
+            }
         }
     }
 }
@@ -2349,6 +2641,18 @@ impl TryFrom<PaypalWebhookEventType> for PaypalOrderStatus {
             | PaypalWebhookEventType::Unknown => {
                 Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
             }
+            #[cfg(feature = "payouts")]
+            PaypalWebhookEventType::PayoutsItemSucceeded
+            | PaypalWebhookEventType::PayoutsItemFailed
+            | PaypalWebhookEventType::PayoutsItemBlocked
+            | PaypalWebhookEventType::PayoutsItemDenied
+            | PaypalWebhookEventType::PayoutsItemCanceled
+            | PaypalWebhookEventType::PayoutsItemHeld
+            | PaypalWebhookEventType::PayoutsItemUnclaimed
+            | PaypalWebhookEventType::PayoutsItemRefunded
+            | PaypalWebhookEventType::PayoutsItemReturned => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
+            }
         }
     }
 }
@@ -2412,3 +2716,79 @@ impl From<ErrorDetails> for utils::ErrorCodeAndMessage {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_dispute_list_next_page_token() {
+        let response: PaypalDisputeListResponse = serde_json::from_value(serde_json::json!({
+            "items": [{ "dispute_id": "PP-D-1" }, { "dispute_id": "PP-D-2" }],
+            "links": [
+                {
+                    "href": "https://api-m.sandbox.paypal.com/v1/customer/disputes?page_size=50",
+                    "rel": "self"
+                },
+                {
+                    "href": "https://api-m.sandbox.paypal.com/v1/customer/disputes?page_size=50&next_page_token=abc%3D",
+                    "rel": "next"
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(response.get_next_page_token().as_deref(), Some("abc="));
+
+        let response: PaypalDisputeListResponse =
+            serde_json::from_value(serde_json::json!({ "items": [] })).unwrap();
+        assert_eq!(response.get_next_page_token(), None);
+    }
+
+    #[test]
+    fn test_dispute_response_into_fetched_dispute() {
+        let response: PaypalDisputeResponse = serde_json::from_value(serde_json::json!({
+            "dispute_id": "PP-D-1",
+            "disputed_transactions": [{ "invoice_number": "pay_123_1" }],
+            "dispute_amount": { "currency_code": "USD", "value": "10.50" },
+            "dispute_life_cycle_stage": "CHARGEBACK",
+            "status": "WAITING_FOR_SELLER_RESPONSE",
+            "reason": "UNAUTHORISED",
+            "seller_response_due_date": "2024-06-10T10:00:00.000Z",
+            "create_time": "2024-06-01T10:00:00.000Z"
+        }))
+        .unwrap();
+        let fetched_dispute = response.try_into_fetched_dispute().unwrap().unwrap();
+
+        assert_eq!(
+            fetched_dispute.dispute_status,
+            enums::DisputeStatus::DisputeOpened
+        );
+        assert_eq!(fetched_dispute.details.amount, "1050");
+        assert_eq!(fetched_dispute.details.connector_dispute_id, "PP-D-1");
+        assert!(matches!(
+            fetched_dispute.payment_reference,
+            api_models::payments::PaymentIdType::PaymentAttemptId(ref attempt_id)
+                if attempt_id == "pay_123_1"
+        ));
+        assert!(fetched_dispute.details.challenge_required_by.is_some());
+    }
+
+    #[test]
+    fn test_resolved_dispute_status() {
+        let response: PaypalDisputeResponse = serde_json::from_value(serde_json::json!({
+            "dispute_id": "PP-D-1",
+            "dispute_amount": { "currency_code": "USD", "value": "10.50" },
+            "dispute_outcome": { "outcome_code": "RESOLVED_SELLER_FAVOUR" },
+            "dispute_life_cycle_stage": "CHARGEBACK",
+            "status": "RESOLVED"
+        }))
+        .unwrap();
+        assert_eq!(
+            response.get_dispute_status(),
+            Some(enums::DisputeStatus::DisputeWon)
+        );
+        // Disputes without the disputed transaction cannot be matched to a payment
+        assert!(response.try_into_fetched_dispute().unwrap().is_none());
+    }
+}
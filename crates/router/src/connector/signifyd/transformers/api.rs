@@ -296,6 +296,15 @@ pub struct Transactions {
     amount: i64,
     currency: storage_enums::Currency,
     gateway: Option<String>,
+    verifications: Option<Verifications>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Verifications {
+    avs_response_code: Option<String>,
+    cvv_response_code: Option<String>,
 }
 
 #[derive(Debug, Serialize, Eq, PartialEq)]
@@ -328,6 +337,12 @@ impl TryFrom<&frm_types::FrmTransactionRouterData> for SignifydPaymentsTransacti
             payment_method: item.payment_method,
             currency,
             gateway: item.request.connector.clone(),
+            verifications: item.request.card_verification_results.as_ref().map(
+                |card_verification_results| Verifications {
+                    avs_response_code: card_verification_results.avs_raw_code.clone(),
+                    cvv_response_code: card_verification_results.cvv_raw_code.clone(),
+                },
+            ),
         };
         Ok(Self {
             order_id: item.attempt_id.clone(),
@@ -1579,6 +1579,11 @@ impl api::FileUpload for Stripe {
                     })?
                 }
             }
+            api::FilePurpose::BulkOperations => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "Bulk operation files are not uploaded to connectors".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
@@ -1952,6 +1957,107 @@ impl
     }
 }
 
+impl api::FetchDisputes for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Fetch,
+        types::FetchDisputesRequestData,
+        types::FetchDisputesResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::FetchDisputesRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::FetchDisputesRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let query_params = serde_urlencoded::to_string(stripe::StripeDisputeListRequest::from(req))
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(format!(
+            "{}{}?{}",
+            self.base_url(connectors),
+            "v1/disputes",
+            query_params
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FetchDisputesRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::FetchDisputesType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::FetchDisputesType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::FetchDisputesRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::FetchDisputesRouterData, errors::ConnectorError> {
+        let response: stripe::StripeDisputeListResponse = res
+            .response
+            .parse_struct("StripeDisputeListResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_error_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: response.error.message,
+            attempt_status: None,
+            connector_transaction_id: None,
+        })
+    }
+}
+
 fn get_signature_elements_from_header(
     headers: &actix_web::http::header::HeaderMap,
 ) -> CustomResult<HashMap<String, Vec<u8>>, errors::ConnectorError> {
@@ -2130,6 +2236,28 @@ impl api::IncomingWebhook for Stripe {
                     ),
                 }
             }
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventObjectType::Transfer => {
+                api_models::webhooks::ObjectReferenceId::PayoutId(
+                    api_models::webhooks::PayoutIdType::ConnectorPayoutId(
+                        details.event_data.event_object.id,
+                    ),
+                )
+            }
+            // payouts on the connected account are linked to the transfer through metadata
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventObjectType::Payout => {
+                api_models::webhooks::ObjectReferenceId::PayoutId(
+                    api_models::webhooks::PayoutIdType::ConnectorPayoutId(
+                        details
+                            .event_data
+                            .event_object
+                            .metadata
+                            .and_then(|meta_data| meta_data.transfer_id)
+                            .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)?,
+                    ),
+                )
+            }
         })
     }
 
@@ -2202,6 +2330,16 @@ impl api::IncomingWebhook for Stripe {
             stripe::WebhookEventType::ChargeDisputeFundsReinstated => {
                 api::IncomingWebhookEvent::DisputeWon
             }
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventType::PayoutCreated => api::IncomingWebhookEvent::PayoutCreated,
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventType::PayoutPaid => api::IncomingWebhookEvent::PayoutSuccess,
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventType::PayoutFailed => api::IncomingWebhookEvent::PayoutFailure,
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventType::PayoutCanceled => api::IncomingWebhookEvent::PayoutCancelled,
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventType::TransferReversed => api::IncomingWebhookEvent::PayoutReversed,
             stripe::WebhookEventType::Unknown
             | stripe::WebhookEventType::ChargeCaptured
             | stripe::WebhookEventType::ChargeExpired
@@ -2715,3 +2853,119 @@ impl
         self.build_error_response(res, event_builder)
     }
 }
+
+#[cfg(all(test, feature = "payouts"))]
+mod payout_webhook_tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::webhooks::{IncomingWebhookEvent, ObjectReferenceId, PayoutIdType};
+
+    use super::*;
+    use crate::types::api::IncomingWebhook;
+
+    fn get_request_details<'a>(
+        headers: &'a actix_web::http::header::HeaderMap,
+        body: &'a [u8],
+    ) -> api::IncomingWebhookRequestDetails<'a> {
+        api::IncomingWebhookRequestDetails {
+            method: actix_web::http::Method::POST,
+            uri: actix_web::http::Uri::default(),
+            headers,
+            body,
+            query_params: String::new(),
+        }
+    }
+
+    fn get_transfer_webhook_body(event_type: &str) -> Vec<u8> {
+        serde_json::json!({
+            "id": "evt_1",
+            "type": event_type,
+            "data": {
+                "object": {
+                    "id": "tr_1",
+                    "object": "transfer",
+                    "amount": 1000,
+                    "currency": "usd",
+                    "created": 1717491912,
+                    "metadata": {}
+                }
+            }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    fn get_payout_webhook_body(event_type: &str) -> Vec<u8> {
+        serde_json::json!({
+            "id": "evt_2",
+            "type": event_type,
+            "data": {
+                "object": {
+                    "id": "po_1",
+                    "object": "payout",
+                    "amount": 1000,
+                    "currency": "usd",
+                    "created": 1717491912,
+                    "status": "paid",
+                    "metadata": { "transfer_id": "tr_1" }
+                }
+            }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_payout_webhook_event_type() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let cases = [
+            (
+                get_payout_webhook_body("payout.created"),
+                IncomingWebhookEvent::PayoutCreated,
+            ),
+            (
+                get_payout_webhook_body("payout.paid"),
+                IncomingWebhookEvent::PayoutSuccess,
+            ),
+            (
+                get_payout_webhook_body("payout.failed"),
+                IncomingWebhookEvent::PayoutFailure,
+            ),
+            (
+                get_payout_webhook_body("payout.canceled"),
+                IncomingWebhookEvent::PayoutCancelled,
+            ),
+            (
+                get_transfer_webhook_body("transfer.reversed"),
+                IncomingWebhookEvent::PayoutReversed,
+            ),
+        ];
+
+        for (body, expected_event) in cases {
+            let request = get_request_details(&headers, &body);
+
+            assert_eq!(
+                Stripe.get_webhook_event_type(&request).unwrap(),
+                expected_event
+            );
+        }
+    }
+
+    #[test]
+    fn test_payout_webhook_object_reference_id() {
+        let headers = actix_web::http::header::HeaderMap::new();
+
+        // Transfers and the payouts on the connected account backed by them both refer to the
+        // transfer, which is the connector payout ID
+        for body in [
+            get_transfer_webhook_body("transfer.reversed"),
+            get_payout_webhook_body("payout.paid"),
+        ] {
+            let request = get_request_details(&headers, &body);
+
+            assert!(matches!(
+                Stripe.get_webhook_object_reference_id(&request).unwrap(),
+                ObjectReferenceId::PayoutId(PayoutIdType::ConnectorPayoutId(id)) if id == "tr_1"
+            ));
+        }
+    }
+}
@@ -173,6 +173,10 @@ pub struct StripeMetadata {
     // it is set as string instead of bool because stripe pass it as string even if we set it as bool
     #[serde(rename(serialize = "metadata[is_refund_id_as_reference]"))]
     pub is_refund_id_as_reference: Option<String>,
+    // transfer backing a connect payout, only read from payout webhooks
+    #[cfg(feature = "payouts")]
+    #[serde(default, skip_serializing)]
+    pub transfer_id: Option<String>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -2279,6 +2283,87 @@ pub struct StripeAdditionalCardDetails {
     network_transaction_id: Option<String>,
 }
 
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StripeCardChecks {
+    address_line1_check: Option<StripeCardCheckResult>,
+    address_postal_code_check: Option<StripeCardCheckResult>,
+    cvc_check: Option<StripeCardCheckResult>,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCardCheckResult {
+    Pass,
+    Fail,
+    Unavailable,
+    Unchecked,
+}
+
+impl From<StripeCardCheckResult> for api_models::payments::CardVerificationCheckResult {
+    fn from(item: StripeCardCheckResult) -> Self {
+        match item {
+            StripeCardCheckResult::Pass => Self::Match,
+            StripeCardCheckResult::Fail => Self::NoMatch,
+            StripeCardCheckResult::Unavailable => Self::Unavailable,
+            StripeCardCheckResult::Unchecked => Self::NotChecked,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StripeThreeDSecureDetails {
+    authentication_flow: Option<String>,
+    electronic_commerce_indicator: Option<String>,
+    result: Option<String>,
+    transaction_id: Option<String>,
+    version: Option<String>,
+}
+
+impl From<StripeThreeDSecureDetails> for api_models::payments::PaymentAuthenticationDetails {
+    fn from(item: StripeThreeDSecureDetails) -> Self {
+        let trans_status = match item.result.as_deref() {
+            Some("authenticated") => Some(common_enums::TransactionStatus::Success),
+            Some("attempt_acknowledged") => Some(common_enums::TransactionStatus::NotVerified),
+            Some("failed") => Some(common_enums::TransactionStatus::Failure),
+            Some("not_supported") | Some("processing_error") => {
+                Some(common_enums::TransactionStatus::VerificationNotPerformed)
+            }
+            _ => None,
+        };
+        let authentication_flow = match item.authentication_flow.as_deref() {
+            Some("challenge") => Some(common_enums::DecoupledAuthenticationType::Challenge),
+            Some("frictionless") => Some(common_enums::DecoupledAuthenticationType::Frictionless),
+            _ => None,
+        };
+        let liability_shift = connector_util::get_liability_shift(
+            item.electronic_commerce_indicator.as_deref(),
+            trans_status.as_ref(),
+        );
+        Self {
+            authentication_source: api_models::payments::AuthenticationSource::Connector,
+            trans_status,
+            liability_shift,
+            electronic_commerce_indicator: item.electronic_commerce_indicator,
+            version: item.version,
+            ds_transaction_id: item.transaction_id,
+            authentication_flow,
+        }
+    }
+}
+
+impl From<StripeCardChecks> for api_models::payments::CardVerificationResults {
+    fn from(item: StripeCardChecks) -> Self {
+        // Stripe does not return the result codes of the card networks, only the outcome of the checks
+        Self {
+            avs_street_match: item.address_line1_check.map(Into::into),
+            avs_postal_code_match: item.address_postal_code_check.map(Into::into),
+            cvv_match: item.cvc_check.map(Into::into),
+            avs_raw_code: None,
+            cvv_raw_code: None,
+        }
+    }
+}
+
 #[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum StripePaymentMethodDetailsResponse {
@@ -2324,6 +2409,9 @@ pub enum StripePaymentMethodDetailsResponse {
 pub struct AdditionalPaymentMethodDetails {
     pub payment_checks: Option<Value>,
     pub authentication_details: Option<Value>,
+    pub card_verification_results: Option<api_models::payments::CardVerificationResults>,
+    pub normalized_authentication_details:
+        Option<api_models::payments::PaymentAuthenticationDetails>,
 }
 
 impl From<AdditionalPaymentMethodDetails> for types::AdditionalPaymentMethodConnectorResponse {
@@ -2331,6 +2419,8 @@ impl From<AdditionalPaymentMethodDetails> for types::AdditionalPaymentMethodConn
         Self::Card {
             authentication_data: item.authentication_details,
             payment_checks: item.payment_checks,
+            card_verification_results: item.card_verification_results,
+            authentication_details: item.normalized_authentication_details,
         }
     }
 }
@@ -2341,6 +2431,18 @@ impl StripePaymentMethodDetailsResponse {
             Self::Card { card } => Some(AdditionalPaymentMethodDetails {
                 payment_checks: card.checks.clone(),
                 authentication_details: card.three_d_secure.clone(),
+                card_verification_results: card
+                    .checks
+                    .clone()
+                    .and_then(|checks| serde_json::from_value::<StripeCardChecks>(checks).ok())
+                    .map(api_models::payments::CardVerificationResults::from),
+                normalized_authentication_details: card
+                    .three_d_secure
+                    .clone()
+                    .and_then(|three_d_secure| {
+                        serde_json::from_value::<StripeThreeDSecureDetails>(three_d_secure).ok()
+                    })
+                    .map(api_models::payments::PaymentAuthenticationDetails::from),
             }),
             Self::Ideal { .. }
             | Self::Sofort { .. }
@@ -2960,6 +3062,8 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
             meta_data: StripeMetadata {
                 order_id: Some(item.request.refund_id.clone()),
                 is_refund_id_as_reference: Some("true".to_string()),
+                #[cfg(feature = "payouts")]
+                transfer_id: None,
             },
         })
     }
@@ -3563,6 +3667,10 @@ pub enum WebhookEventObjectType {
     Charge,
     Source,
     Refund,
+    #[cfg(feature = "payouts")]
+    Payout,
+    #[cfg(feature = "payouts")]
+    Transfer,
 }
 
 #[derive(Debug, Deserialize)]
@@ -3613,6 +3721,21 @@ pub enum WebhookEventType {
     SourceTransactionCreated,
     #[serde(rename = "payment_intent.partially_funded")]
     PaymentIntentPartiallyFunded,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "payout.created")]
+    PayoutCreated,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "payout.paid")]
+    PayoutPaid,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "payout.failed")]
+    PayoutFailed,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "payout.canceled")]
+    PayoutCanceled,
+    #[cfg(feature = "payouts")]
+    #[serde(rename = "transfer.reversed")]
+    TransferReversed,
     #[serde(other)]
     Unknown,
 }
@@ -3944,6 +4067,161 @@ pub struct DisputeObj {
     pub status: String,
 }
 
+const DISPUTE_LIST_PAGE_SIZE: u32 = 100;
+
+#[derive(Debug, Serialize)]
+pub struct StripeDisputeListRequest {
+    #[serde(
+        rename = "created[gte]",
+        with = "common_utils::custom_serde::timestamp"
+    )]
+    pub created_gte: PrimitiveDateTime,
+    #[serde(
+        rename = "created[lte]",
+        with = "common_utils::custom_serde::timestamp"
+    )]
+    pub created_lte: PrimitiveDateTime,
+    pub limit: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<String>,
+}
+
+impl From<&types::FetchDisputesRouterData> for StripeDisputeListRequest {
+    fn from(item: &types::FetchDisputesRouterData) -> Self {
+        Self {
+            created_gte: item.request.start_time,
+            created_lte: item.request.end_time,
+            limit: DISPUTE_LIST_PAGE_SIZE,
+            starting_after: item.request.cursor.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StripeDisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    Won,
+    Lost,
+    #[serde(other)]
+    Unknown,
+}
+
+impl StripeDisputeStatus {
+    fn get_dispute_status(&self) -> Option<api_enums::DisputeStatus> {
+        match self {
+            Self::WarningNeedsResponse | Self::NeedsResponse => {
+                Some(api_enums::DisputeStatus::DisputeOpened)
+            }
+            Self::WarningUnderReview | Self::UnderReview => {
+                Some(api_enums::DisputeStatus::DisputeChallenged)
+            }
+            Self::WarningClosed => Some(api_enums::DisputeStatus::DisputeCancelled),
+            Self::Won => Some(api_enums::DisputeStatus::DisputeWon),
+            Self::Lost => Some(api_enums::DisputeStatus::DisputeLost),
+            Self::Unknown => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StripeDisputeEvidenceDetails {
+    #[serde(default, with = "common_utils::custom_serde::timestamp::option")]
+    pub due_by: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StripeDispute {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub payment_intent: Option<String>,
+    pub reason: Option<String>,
+    pub status: StripeDisputeStatus,
+    pub evidence_details: Option<StripeDisputeEvidenceDetails>,
+    #[serde(with = "common_utils::custom_serde::timestamp")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StripeDisputeListResponse {
+    pub data: Vec<StripeDispute>,
+    pub has_more: bool,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            StripeDisputeListResponse,
+            types::FetchDisputesRequestData,
+            types::FetchDisputesResponse,
+        >,
+    > for types::RouterData<F, types::FetchDisputesRequestData, types::FetchDisputesResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            StripeDisputeListResponse,
+            types::FetchDisputesRequestData,
+            types::FetchDisputesResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // Disputes are listed in the reverse order of creation, and the next page starts after
+        // the last dispute of this page
+        let next_cursor = item
+            .response
+            .has_more
+            .then(|| item.response.data.last().map(|dispute| dispute.id.clone()))
+            .flatten();
+        let disputes = item
+            .response
+            .data
+            .into_iter()
+            .filter_map(|dispute| {
+                // Disputes on charges which were not created through payment intents do not
+                // belong to any payment
+                let payment_intent = dispute.payment_intent?;
+                let dispute_status = dispute.status.get_dispute_status()?;
+                Some(api::disputes::FetchedDispute {
+                    payment_reference: api_models::payments::PaymentIdType::ConnectorTransactionId(
+                        payment_intent,
+                    ),
+                    dispute_status,
+                    details: api::disputes::DisputePayload {
+                        amount: dispute.amount.to_string(),
+                        currency: dispute.currency,
+                        dispute_stage: api_enums::DisputeStage::Dispute,
+                        connector_status: dispute.status.to_string(),
+                        connector_dispute_id: dispute.id,
+                        connector_reason: dispute.reason,
+                        connector_reason_code: None,
+                        challenge_required_by: dispute
+                            .evidence_details
+                            .and_then(|evidence_details| evidence_details.due_by),
+                        created_at: Some(dispute.created),
+                        updated_at: None,
+                    },
+                })
+            })
+            .collect();
+        Ok(Self {
+            response: Ok(types::FetchDisputesResponse {
+                disputes,
+                next_cursor,
+                connector_dispute_ids_to_retrieve: Vec::new(),
+            }),
+            ..item.data
+        })
+    }
+}
+
 fn get_transaction_metadata(
     merchant_metadata: Option<Secret<Value>>,
     order_id: String,
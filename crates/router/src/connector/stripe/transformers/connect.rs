@@ -57,6 +57,8 @@ pub struct TransferReversals {
 pub struct StripeConnectPayoutFulfillRequest {
     amount: i64,
     currency: enums::Currency,
+    #[serde(rename = "metadata[transfer_id]")]
+    transfer_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -251,6 +253,7 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for StripeConnectPayoutFulfillRequ
         Ok(Self {
             amount: request.amount,
             currency: request.destination_currency,
+            transfer_id: request.connector_payout_id,
         })
     }
 }
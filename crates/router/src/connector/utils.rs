@@ -1716,6 +1716,26 @@ pub fn construct_not_supported_error_report(
     .into()
 }
 
+pub fn validate_over_capture_card_network(
+    card_network: Option<enums::CardNetwork>,
+    supported_card_networks: &[enums::CardNetwork],
+    connector_name: &'static str,
+) -> CustomResult<(), errors::ConnectorError> {
+    match card_network {
+        Some(card_network) if supported_card_networks.contains(&card_network) => Ok(()),
+        Some(card_network) => Err(errors::ConnectorError::NotSupported {
+            message: format!("Over-capture for {card_network} cards"),
+            connector: connector_name,
+        }
+        .into()),
+        None => Err(errors::ConnectorError::NotSupported {
+            message: "Over-capture for payments without a card network".to_string(),
+            connector: connector_name,
+        }
+        .into()),
+    }
+}
+
 pub fn to_currency_base_unit_with_zero_decimal_check(
     amount: i64,
     currency: enums::Currency,
@@ -2171,7 +2191,8 @@ pub fn is_refund_failure(status: enums::RefundStatus) -> bool {
         }
         common_enums::RefundStatus::ManualReview
         | common_enums::RefundStatus::Pending
-        | common_enums::RefundStatus::Success => false,
+        | common_enums::RefundStatus::Success
+        | common_enums::RefundStatus::PendingApproval => false,
     }
 }
 
@@ -2221,6 +2242,149 @@ pub fn get_card_details(
     }
 }
 
+/// The set of address verification (AVS) result codes returned by a connector. Most connectors
+/// return the codes of the card networks as is, whereas some connectors redefine or extend them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvsResultCodes {
+    CardNetwork,
+    /// Authorize.Net uses "P" for AVS not being applicable to the transaction, and "B" for the
+    /// address not being provided
+    AuthorizeDotNet,
+    /// Cybersource and Bank of America additionally return numeric codes
+    Cybersource,
+}
+
+/// Maps the address verification (AVS) result code returned by the connector to the results of the
+/// street address and postal code checks
+fn get_avs_check_results(
+    avs_code: &str,
+    avs_result_codes: AvsResultCodes,
+) -> (
+    Option<payments::CardVerificationCheckResult>,
+    Option<payments::CardVerificationCheckResult>,
+) {
+    use payments::CardVerificationCheckResult as CheckResult;
+
+    let avs_code = avs_code.trim().to_uppercase();
+    match (avs_result_codes, avs_code.as_str()) {
+        (AvsResultCodes::AuthorizeDotNet, "P" | "B") => {
+            (Some(CheckResult::NotChecked), Some(CheckResult::NotChecked))
+        }
+        (AvsResultCodes::Cybersource, "3") => (Some(CheckResult::Match), Some(CheckResult::Match)),
+        (AvsResultCodes::Cybersource, "4") => {
+            (Some(CheckResult::NoMatch), Some(CheckResult::NoMatch))
+        }
+        (AvsResultCodes::Cybersource, "1" | "2") => (
+            Some(CheckResult::Unavailable),
+            Some(CheckResult::Unavailable),
+        ),
+        (_, avs_code) => get_card_network_avs_check_results(avs_code),
+    }
+}
+
+/// Maps the address verification (AVS) result code of the card networks to the results of the
+/// street address and postal code checks
+fn get_card_network_avs_check_results(
+    avs_code: &str,
+) -> (
+    Option<payments::CardVerificationCheckResult>,
+    Option<payments::CardVerificationCheckResult>,
+) {
+    use payments::CardVerificationCheckResult as CheckResult;
+
+    match avs_code {
+        "Y" | "X" | "D" | "F" | "M" => (Some(CheckResult::Match), Some(CheckResult::Match)),
+        "A" => (Some(CheckResult::Match), Some(CheckResult::NoMatch)),
+        "B" => (Some(CheckResult::Match), Some(CheckResult::NotChecked)),
+        "Z" | "W" => (Some(CheckResult::NoMatch), Some(CheckResult::Match)),
+        "P" => (Some(CheckResult::NotChecked), Some(CheckResult::Match)),
+        "N" => (Some(CheckResult::NoMatch), Some(CheckResult::NoMatch)),
+        "C" | "I" | "G" | "U" | "R" | "S" | "E" => (
+            Some(CheckResult::Unavailable),
+            Some(CheckResult::Unavailable),
+        ),
+        _ => (None, None),
+    }
+}
+
+/// Maps the card security code (CVV) result code of the card networks to the result of the check
+fn get_cvv_check_result(cvv_code: &str) -> Option<payments::CardVerificationCheckResult> {
+    match cvv_code.trim().to_uppercase().as_str() {
+        "M" | "Y" => Some(payments::CardVerificationCheckResult::Match),
+        "N" => Some(payments::CardVerificationCheckResult::NoMatch),
+        "P" | "S" => Some(payments::CardVerificationCheckResult::NotChecked),
+        "U" | "X" | "1" | "2" | "3" => Some(payments::CardVerificationCheckResult::Unavailable),
+        _ => None,
+    }
+}
+
+/// Normalizes the AVS and CVV result codes returned by the connector into the card verification
+/// results of the payment
+pub fn get_card_verification_results(
+    avs_code: Option<String>,
+    cvv_code: Option<String>,
+    avs_result_codes: AvsResultCodes,
+) -> Option<payments::CardVerificationResults> {
+    let avs_code = avs_code.filter(|avs_code| !avs_code.trim().is_empty());
+    let cvv_code = cvv_code.filter(|cvv_code| !cvv_code.trim().is_empty());
+    if avs_code.is_none() && cvv_code.is_none() {
+        return None;
+    }
+
+    let (avs_street_match, avs_postal_code_match) = avs_code
+        .as_deref()
+        .map(|avs_code| get_avs_check_results(avs_code, avs_result_codes))
+        .unwrap_or((None, None));
+    Some(payments::CardVerificationResults {
+        avs_street_match,
+        avs_postal_code_match,
+        cvv_match: cvv_code.as_deref().and_then(get_cvv_check_result),
+        avs_raw_code: avs_code,
+        cvv_raw_code: cvv_code,
+    })
+}
+
+/// Maps the 3DS transaction status code (`transStatus` / `PAResStatus`) to the transaction status
+pub fn get_three_ds_transaction_status(
+    trans_status: &str,
+) -> Option<common_enums::TransactionStatus> {
+    match trans_status.trim().to_uppercase().as_str() {
+        "Y" => Some(common_enums::TransactionStatus::Success),
+        "N" => Some(common_enums::TransactionStatus::Failure),
+        "U" => Some(common_enums::TransactionStatus::VerificationNotPerformed),
+        "A" => Some(common_enums::TransactionStatus::NotVerified),
+        "R" => Some(common_enums::TransactionStatus::Rejected),
+        "C" => Some(common_enums::TransactionStatus::ChallengeRequired),
+        "D" => Some(common_enums::TransactionStatus::ChallengeRequiredDecoupledAuthentication),
+        "I" => Some(common_enums::TransactionStatus::InformationOnly),
+        _ => None,
+    }
+}
+
+/// Whether the liability for fraud chargebacks shifted to the issuer, as indicated by the ECI of
+/// the authentication or, in its absence, by the 3DS transaction status. Fully authenticated and
+/// attempted authentications shift the liability for all card networks (ECI 05 / 06 for Visa,
+/// Amex and Discover, ECI 02 / 01 for Mastercard).
+pub fn get_liability_shift(
+    electronic_commerce_indicator: Option<&str>,
+    trans_status: Option<&common_enums::TransactionStatus>,
+) -> Option<bool> {
+    match electronic_commerce_indicator.map(|eci| eci.trim().trim_start_matches('0')) {
+        Some("5" | "6" | "2" | "1") => Some(true),
+        Some("7" | "") => Some(false),
+        _ => match trans_status? {
+            common_enums::TransactionStatus::Success
+            | common_enums::TransactionStatus::NotVerified => Some(true),
+            common_enums::TransactionStatus::Failure
+            | common_enums::TransactionStatus::VerificationNotPerformed
+            | common_enums::TransactionStatus::Rejected => Some(false),
+            common_enums::TransactionStatus::ChallengeRequired
+            | common_enums::TransactionStatus::ChallengeRequiredDecoupledAuthentication
+            | common_enums::TransactionStatus::InformationOnly => None,
+        },
+    }
+}
+
 #[cfg(test)]
 mod error_code_error_message_tests {
     #![allow(clippy::unwrap_used)]
@@ -2300,3 +2464,105 @@ mod error_code_error_message_tests {
         assert_eq!(error_code_error_message_none, None);
     }
 }
+
+#[cfg(test)]
+mod card_verification_results_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_card_verification_results() {
+        let card_verification_results = get_card_verification_results(
+            Some("A".to_string()),
+            Some("N".to_string()),
+            AvsResultCodes::CardNetwork,
+        )
+        .unwrap();
+        assert_eq!(
+            card_verification_results,
+            payments::CardVerificationResults {
+                avs_street_match: Some(payments::CardVerificationCheckResult::Match),
+                avs_postal_code_match: Some(payments::CardVerificationCheckResult::NoMatch),
+                cvv_match: Some(payments::CardVerificationCheckResult::NoMatch),
+                avs_raw_code: Some("A".to_string()),
+                cvv_raw_code: Some("N".to_string()),
+            }
+        );
+
+        let card_verification_results =
+            get_card_verification_results(Some("9".to_string()), None, AvsResultCodes::CardNetwork)
+                .unwrap();
+        assert_eq!(card_verification_results.avs_street_match, None);
+        assert_eq!(card_verification_results.cvv_match, None);
+        assert_eq!(
+            card_verification_results.avs_raw_code,
+            Some("9".to_string())
+        );
+
+        assert_eq!(
+            get_card_verification_results(
+                Some(String::new()),
+                Some(" ".to_string()),
+                AvsResultCodes::CardNetwork
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_avs_check_results_for_connector() {
+        use payments::CardVerificationCheckResult as CheckResult;
+
+        // "P" is a postal code match for the card networks, but AVS not applicable for
+        // Authorize.Net
+        assert_eq!(
+            get_avs_check_results("P", AvsResultCodes::CardNetwork),
+            (Some(CheckResult::NotChecked), Some(CheckResult::Match))
+        );
+        assert_eq!(
+            get_avs_check_results("p", AvsResultCodes::AuthorizeDotNet),
+            (Some(CheckResult::NotChecked), Some(CheckResult::NotChecked))
+        );
+        assert_eq!(
+            get_avs_check_results("Y", AvsResultCodes::AuthorizeDotNet),
+            (Some(CheckResult::Match), Some(CheckResult::Match))
+        );
+
+        assert_eq!(
+            get_avs_check_results("4", AvsResultCodes::Cybersource),
+            (Some(CheckResult::NoMatch), Some(CheckResult::NoMatch))
+        );
+        assert_eq!(
+            get_avs_check_results("4", AvsResultCodes::CardNetwork),
+            (None, None)
+        );
+    }
+}
+
+#[cfg(test)]
+mod liability_shift_tests {
+    use super::*;
+
+    #[test]
+    fn test_get_liability_shift() {
+        assert_eq!(get_liability_shift(Some("05"), None), Some(true));
+        assert_eq!(get_liability_shift(Some("02"), None), Some(true));
+        assert_eq!(
+            get_liability_shift(Some("07"), Some(&common_enums::TransactionStatus::Success)),
+            Some(false)
+        );
+        assert_eq!(get_liability_shift(Some("00"), None), Some(false));
+        assert_eq!(
+            get_liability_shift(None, Some(&common_enums::TransactionStatus::NotVerified)),
+            Some(true)
+        );
+        assert_eq!(
+            get_liability_shift(
+                Some("N/A"),
+                Some(&common_enums::TransactionStatus::Rejected)
+            ),
+            Some(false)
+        );
+        assert_eq!(get_liability_shift(None, None), None);
+    }
+}
@@ -1,12 +1,18 @@
 pub mod transformers;
 use std::fmt::Debug;
 
+#[cfg(feature = "payouts")]
+use base64::Engine;
 #[cfg(feature = "payouts")]
 use common_utils::request::RequestContent;
-use error_stack::{report, ResultExt};
+#[cfg(not(feature = "payouts"))]
+use error_stack::report;
+use error_stack::ResultExt;
 #[cfg(feature = "payouts")]
 use masking::PeekInterface;
 #[cfg(feature = "payouts")]
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Verifier};
+#[cfg(feature = "payouts")]
 use router_env::{instrument, tracing};
 
 use self::transformers as wise;
@@ -27,6 +33,8 @@ use crate::{
     utils::BytesExt,
 };
 #[cfg(feature = "payouts")]
+use crate::{connector::utils as connector_utils, consts, types::domain, utils::ByteSliceExt};
+#[cfg(feature = "payouts")]
 use crate::{core::payments, routes};
 
 #[derive(Debug, Clone)]
@@ -744,6 +752,72 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Wise {
+    #[cfg(feature = "payouts")]
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature =
+            connector_utils::get_header_key_value("X-Signature-SHA256", request.headers)?;
+        consts::BASE64_ENGINE
+            .decode(signature)
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    // Wise signs webhooks with its own RSA key, so the webhook secret configured for the
+    // connector is expected to be Wise's PEM encoded public key.
+    #[cfg(feature = "payouts")]
+    async fn verify_webhook_source(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        merchant_account: &domain::MerchantAccount,
+        merchant_connector_account: domain::MerchantConnectorAccount,
+        connector_label: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        let connector_webhook_secrets = self
+            .get_webhook_source_verification_merchant_secret(
+                merchant_account,
+                connector_label,
+                merchant_connector_account,
+            )
+            .await
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+
+        let signature = self
+            .get_webhook_source_verification_signature(request, &connector_webhook_secrets)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+
+        let public_key = PKey::public_key_from_pem(&connector_webhook_secrets.secret)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+            .attach_printable("Failed to parse Wise webhook public key")?;
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &public_key)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        verifier
+            .update(request.body)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        verifier
+            .verify(&signature)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+    }
+
+    #[cfg(feature = "payouts")]
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        let webhook: wise::WisePayoutsWebhookBody = request
+            .body
+            .parse_struct("WisePayoutsWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(api_models::webhooks::ObjectReferenceId::PayoutId(
+            api_models::webhooks::PayoutIdType::ConnectorPayoutId(
+                webhook.data.resource.id.to_string(),
+            ),
+        ))
+    }
+
+    #[cfg(not(feature = "payouts"))]
     fn get_webhook_object_reference_id(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
@@ -751,6 +825,19 @@ impl api::IncomingWebhook for Wise {
         Err(report!(errors::ConnectorError::WebhooksNotImplemented))
     }
 
+    #[cfg(feature = "payouts")]
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let webhook: wise::WisePayoutsWebhookBody = request
+            .body
+            .parse_struct("WisePayoutsWebhookBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        Ok(api::IncomingWebhookEvent::from(webhook.data.current_state))
+    }
+
+    #[cfg(not(feature = "payouts"))]
     fn get_webhook_event_type(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
@@ -758,6 +845,19 @@ impl api::IncomingWebhook for Wise {
         Err(report!(errors::ConnectorError::WebhooksNotImplemented))
     }
 
+    #[cfg(feature = "payouts")]
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
+        let webhook: wise::WisePayoutsWebhookBody = request
+            .body
+            .parse_struct("WisePayoutsWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        Ok(Box::new(webhook))
+    }
+
+    #[cfg(not(feature = "payouts"))]
     fn get_webhook_resource_object(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
@@ -765,3 +865,82 @@ impl api::IncomingWebhook for Wise {
         Err(report!(errors::ConnectorError::WebhooksNotImplemented))
     }
 }
+
+#[cfg(all(test, feature = "payouts"))]
+mod payout_webhook_tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::webhooks::{IncomingWebhookEvent, ObjectReferenceId, PayoutIdType};
+
+    use super::*;
+    use crate::types::api::IncomingWebhook;
+
+    fn get_request_details<'a>(
+        headers: &'a actix_web::http::header::HeaderMap,
+        body: &'a [u8],
+    ) -> api::IncomingWebhookRequestDetails<'a> {
+        api::IncomingWebhookRequestDetails {
+            method: actix_web::http::Method::POST,
+            uri: actix_web::http::Uri::default(),
+            headers,
+            body,
+            query_params: String::new(),
+        }
+    }
+
+    fn get_payout_webhook_body(current_state: &str) -> Vec<u8> {
+        serde_json::json!({
+            "data": {
+                "resource": { "id": 50500593, "profile_id": 217896, "type": "transfer" },
+                "current_state": current_state,
+                "previous_state": "processing",
+                "occurred_at": "2024-06-04T09:05:12Z"
+            },
+            "subscription_id": "01234567-89ab-cdef-0123-456789abcdef",
+            "event_type": "transfers#state-change",
+            "schema_version": "2.0.0",
+            "sent_at": "2024-06-04T09:05:12Z"
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_payout_webhook_event_type() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let cases = [
+            (
+                "incoming_payment_waiting",
+                IncomingWebhookEvent::PayoutCreated,
+            ),
+            ("processing", IncomingWebhookEvent::PayoutProcessing),
+            ("outgoing_payment_sent", IncomingWebhookEvent::PayoutSuccess),
+            ("cancelled", IncomingWebhookEvent::PayoutCancelled),
+            ("bounced_back", IncomingWebhookEvent::PayoutFailure),
+            ("charged_back", IncomingWebhookEvent::PayoutReversed),
+            ("some_new_state", IncomingWebhookEvent::EventNotSupported),
+        ];
+
+        for (current_state, expected_event) in cases {
+            let body = get_payout_webhook_body(current_state);
+            let request = get_request_details(&headers, &body);
+
+            assert_eq!(
+                Wise.get_webhook_event_type(&request).unwrap(),
+                expected_event,
+                "state {current_state}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_payout_webhook_object_reference_id() {
+        let headers = actix_web::http::header::HeaderMap::new();
+        let body = get_payout_webhook_body("outgoing_payment_sent");
+        let request = get_request_details(&headers, &body);
+
+        assert!(matches!(
+            Wise.get_webhook_object_reference_id(&request).unwrap(),
+            ObjectReferenceId::PayoutId(PayoutIdType::ConnectorPayoutId(id)) if id == "50500593"
+        ));
+    }
+}
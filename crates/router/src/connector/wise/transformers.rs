@@ -610,3 +610,59 @@ impl TryFrom<PayoutMethodData> for RecipientType {
         }
     }
 }
+
+// Payouts webhook transforms
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WisePayoutsWebhookBody {
+    pub data: WisePayoutsWebhookData,
+    pub event_type: String,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WisePayoutsWebhookData {
+    pub resource: WisePayoutsWebhookResource,
+    pub current_state: WiseTransferState,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WisePayoutsWebhookResource {
+    pub id: u64,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WiseTransferState {
+    IncomingPaymentWaiting,
+    Processing,
+    FundsConverted,
+    OutgoingPaymentSent,
+    Cancelled,
+    FundsRefunded,
+    BouncedBack,
+    ChargedBack,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(feature = "payouts")]
+impl From<WiseTransferState> for api_models::webhooks::IncomingWebhookEvent {
+    fn from(state: WiseTransferState) -> Self {
+        match state {
+            WiseTransferState::IncomingPaymentWaiting => Self::PayoutCreated,
+            WiseTransferState::Processing | WiseTransferState::FundsConverted => {
+                Self::PayoutProcessing
+            }
+            WiseTransferState::OutgoingPaymentSent => Self::PayoutSuccess,
+            WiseTransferState::Cancelled => Self::PayoutCancelled,
+            WiseTransferState::FundsRefunded | WiseTransferState::BouncedBack => {
+                Self::PayoutFailure
+            }
+            WiseTransferState::ChargedBack => Self::PayoutReversed,
+            WiseTransferState::Unknown => Self::EventNotSupported,
+        }
+    }
+}
@@ -111,6 +111,47 @@ fn fetch_payment_instrument(
     }
 }
 
+impl TryFrom<&api_models::payments::L2L3Data> for Level23Data {
+    type Error = error_stack::Report<errors::ConnectorError>;
+
+    fn try_from(l2_l3_data: &api_models::payments::L2L3Data) -> Result<Self, Self::Error> {
+        Ok(Self {
+            customer_reference: l2_l3_data.customer_code.clone(),
+            sales_tax: l2_l3_data.tax_amount,
+            duty_amount: l2_l3_data.duty_amount,
+            shipping_amount: l2_l3_data.shipping_amount,
+            order_discount_amount: l2_l3_data.discount_amount,
+            shipping_from_postal_code: l2_l3_data.ship_from_postal_code.clone(),
+            items: l2_l3_data
+                .line_items
+                .as_ref()
+                .map(|line_items| {
+                    line_items
+                        .iter()
+                        .map(|line_item| {
+                            Ok(Level23Item {
+                                description: line_item.product_name.clone(),
+                                product_code: line_item.product_code.clone(),
+                                commodity_code: line_item.commodity_code.clone(),
+                                quantity: line_item.quantity,
+                                unit_cost: line_item.unit_price,
+                                unit_of_measure: line_item.unit_of_measure.clone(),
+                                item_discount_amount: line_item.discount_amount,
+                                tax_amount: line_item.tax_amount,
+                                item_total_with_tax: line_item.get_total_amount().ok_or(
+                                    errors::ConnectorError::InvalidDataFormat {
+                                        field_name: "l2_l3_data.line_items",
+                                    },
+                                )?,
+                            })
+                        })
+                        .collect::<Result<Vec<_>, Self::Error>>()
+                })
+                .transpose()?,
+        })
+    }
+}
+
 impl
     TryFrom<
         &WorldpayRouterData<
@@ -147,6 +188,13 @@ impl
                     item.router_data.request.payment_method_data.clone(),
                 )?,
                 debt_repayment: None,
+                level23_data: item
+                    .router_data
+                    .request
+                    .l2_l3_data
+                    .as_ref()
+                    .map(Level23Data::try_from)
+                    .transpose()?,
             },
             merchant: Merchant {
                 entity: item
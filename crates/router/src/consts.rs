@@ -100,6 +100,162 @@ pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 /// Min payment session expiry
 pub const MIN_SESSION_EXPIRY: u32 = 60;
 
+/// Max time after which an unconfirmed payment intent expires
+pub const MAX_INTENT_EXPIRY: u32 = 2592000;
+
+/// Min time after which an unconfirmed payment intent expires
+pub const MIN_INTENT_EXPIRY: u32 = 3600;
+
+/// Interval at which the stale payment intents of a business profile are expired
+pub const INTENT_EXPIRY_SWEEP_INTERVAL_IN_SECONDS: i64 = 900;
+
+/// Max number of stale payment intents expired by a single run of the intent expiry sweeper
+pub const INTENT_EXPIRY_SWEEP_BATCH_SIZE: i64 = 100;
+
+/// Max percentage of the capturable amount by which a payment can be over-captured
+pub const MAX_OVER_CAPTURE_PERCENTAGE: u8 = 100;
+
+/// Max length of the customer code sent as Level 2 data
+pub const MAX_L2_CUSTOMER_CODE_LENGTH: usize = 25;
+
+/// Max number of line items sent as Level 3 data
+pub const MAX_L3_LINE_ITEMS: usize = 99;
+
+/// Max validity of a payment authorization in days
+pub const MAX_AUTHORIZATION_VALIDITY_DAYS: u16 = 30;
+
+/// Default number of hours after which a refund pending approval is rejected automatically
+pub const DEFAULT_REFUND_APPROVAL_EXPIRY_HOURS: u16 = 72;
+
+/// Max number of hours for which a refund can stay pending approval
+pub const MAX_REFUND_APPROVAL_EXPIRY_HOURS: u16 = 720;
+
+/// Delay after which a refund approval expiry is retried when the refund is locked by another request
+pub const REFUND_APPROVAL_EXPIRY_LOCK_RETRY_DELAY_IN_SECONDS: i64 = 60;
+
+/// Error code and message of refunds rejected by a dashboard user
+pub const REFUND_APPROVAL_REJECTED_ERROR_CODE: &str = "refund_approval_rejected";
+pub const REFUND_APPROVAL_REJECTED_ERROR_MESSAGE: &str = "The refund was rejected by an approver";
+
+/// Error code and message of refunds which were not approved before their approval expired
+pub const REFUND_APPROVAL_EXPIRED_ERROR_CODE: &str = "refund_approval_expired";
+pub const REFUND_APPROVAL_EXPIRED_ERROR_MESSAGE: &str =
+    "The refund was not approved before its approval expired";
+
+/// Delay before the first retry of a failed authorization expiry action, doubled on every retry
+/// until the authorization expires
+pub const AUTHORIZATION_EXPIRY_ACTION_RETRY_BASE_DELAY_IN_MINUTES: i64 = 15;
+
+/// Max number of times a failed scheduled capture is retried
+pub const SCHEDULED_CAPTURE_MAX_RETRIES: i32 = 5;
+
+/// Delay before the first retry of a failed scheduled capture, doubled on every retry
+pub const SCHEDULED_CAPTURE_RETRY_BASE_DELAY_IN_MINUTES: i64 = 15;
+
+/// Business status of a scheduled capture task cancelled through a payment update
+pub const SCHEDULED_CAPTURE_CANCELLED: &str = "CANCELLED_BY_MERCHANT";
+
+/// Business status of a scheduled capture task which is waiting to be executed
+pub const SCHEDULED_CAPTURE_PENDING: &str = "Pending";
+
+/// Business statuses of a webhook watchdog task, recording whether the expected webhook arrived
+pub const WEBHOOK_WATCHDOG_RECEIVED: &str = "WEBHOOK_RECEIVED";
+pub const WEBHOOK_WATCHDOG_RECEIVED_LATE: &str = "WEBHOOK_RECEIVED_LATE";
+pub const WEBHOOK_WATCHDOG_MISSED: &str = "WEBHOOK_MISSED";
+pub const WEBHOOK_WATCHDOG_RESOLVED_WITHOUT_WEBHOOK: &str = "RESOLVED_WITHOUT_WEBHOOK";
+
+/// Amount in the minor unit authorized and voided to verify a card, with connectors that do not support zero-amount authorizations
+pub const CARD_VERIFICATION_AUTHORIZATION_AMOUNT: i64 = 100;
+
+/// Cancellation reason of the authorization voided after verifying a card
+pub const CARD_VERIFICATION_CANCELLATION_REASON: &str = "card_verification";
+
+/// Max size of a bulk operations file in bytes
+pub const BULK_OPERATION_MAX_FILE_SIZE: i32 = 10 * 1024 * 1024; // 10 MB
+
+/// Max number of rows in a bulk operations file
+pub const BULK_OPERATION_MAX_ROWS: usize = 10000;
+
+/// Number of rows of a bulk operations file processed in a single execution of the workflow
+pub const BULK_OPERATION_CHUNK_SIZE: usize = 500;
+
+/// Max number of rows of a bulk operations file processed concurrently
+pub const BULK_OPERATION_MAX_CONCURRENCY: usize = 10;
+
+/// Max number of times a chunk of rows of a bulk operations file is retried after an error
+pub const BULK_OPERATION_MAX_RETRIES: i32 = 3;
+
+/// Time in seconds after which a chunk of rows of a bulk operations file is retried
+pub const BULK_OPERATION_RETRY_DELAY: i64 = 60;
+
+/// Number of payment methods processed in a single execution of the vault migration workflow
+pub const VAULT_MIGRATION_CHUNK_SIZE: i64 = 100;
+
+/// Max number of times a chunk of payment methods of a vault migration is retried after an error
+pub const VAULT_MIGRATION_MAX_RETRIES: i32 = 3;
+
+/// Time in seconds after which a chunk of payment methods of a vault migration is retried
+pub const VAULT_MIGRATION_RETRY_DELAY: i64 = 60;
+
+/// Max number of times a dispute deadline reminder is retried after an error
+pub const DISPUTE_DEADLINE_REMINDER_MAX_RETRIES: i32 = 3;
+
+/// Time in seconds after which a failed dispute deadline reminder is retried
+pub const DISPUTE_DEADLINE_REMINDER_RETRY_DELAY: i64 = 300;
+
+/// Max number of consecutive times a failed dispute sync is retried, before the sync of the
+/// merchant connector account is stopped
+pub const DISPUTE_SYNC_MAX_RETRIES: i32 = 24;
+
+/// Payments created within these many hours of the transaction date of a pre-dispute alert are
+/// considered when matching the alert by card and amount
+pub const DISPUTE_ALERT_MATCH_WINDOW_HOURS: i64 = 48;
+
+/// Max number of webhook endpoints which can be configured for a business profile
+pub const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 10;
+
+/// Max length of the identifier of a business profile webhook endpoint
+pub const MAX_WEBHOOK_ENDPOINT_ID_LENGTH: usize = 32;
+
+/// Max length of the name of the topic or stream a business profile webhook endpoint publishes
+/// webhooks to, excluding the prefix
+pub const MAX_WEBHOOK_MESSAGE_QUEUE_NAME_LENGTH: usize = 64;
+
+/// Prefix of the names of the topics and streams webhooks are published to, followed by the
+/// merchant ID, so that merchants cannot publish to topics and streams used internally or by
+/// other merchants
+pub const WEBHOOK_MESSAGE_QUEUE_NAME_PREFIX: &str = "merchant_webhooks";
+
+/// Approximate max number of entries retained in a Redis stream webhooks are published to, older
+/// entries are trimmed when webhooks are appended to the stream
+pub const WEBHOOK_REDIS_STREAM_MAX_LENGTH: i64 = 100_000;
+
+/// Default number of hours for which webhooks continue to be signed with the previous secret after
+/// the webhook signing secret of a business profile is rotated
+pub const DEFAULT_WEBHOOK_SECRET_EXPIRY_HOURS: u16 = 24;
+
+/// Max number of hours for which webhooks can continue to be signed with the previous secret after
+/// the webhook signing secret of a business profile is rotated
+pub const MAX_WEBHOOK_SECRET_EXPIRY_HOURS: u16 = 168;
+
+/// Max number of previous webhook signing secrets of a business profile which can be valid at a
+/// time
+pub const MAX_WEBHOOK_PREVIOUS_SECRETS: usize = 5;
+
+/// Headers of incoming webhooks which are returned by the dead letter APIs. The remaining headers
+/// may carry signatures or credentials, and are only stored encrypted for replaying the webhook.
+pub const INCOMING_WEBHOOK_DEAD_LETTER_ALLOWED_HEADERS: [&str; 4] = [
+    "content-type",
+    "content-length",
+    "user-agent",
+    "x-request-id",
+];
+
+/// Time for which incoming webhooks which are processed successfully are checked against the dead
+/// letters of the merchant and connector, after a webhook from the connector last failed.
+// 7 days = 604800 seconds
+pub const INCOMING_WEBHOOK_DEAD_LETTER_MARKER_TTL: i64 = 604800;
+
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
@@ -3,6 +3,7 @@ pub mod api_keys;
 pub mod api_locking;
 pub mod authentication;
 pub mod blocklist;
+pub mod bulk_operations;
 pub mod cache;
 pub mod cards_info;
 pub mod conditional_config;
@@ -20,13 +20,15 @@ use uuid::Uuid;
 use crate::{
     consts,
     core::{
+        api_locking, disputes,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
-        payments::helpers,
+        payments::{helpers, intent_expiry},
         routing::helpers as routing_helpers,
         utils as core_utils,
+        webhooks::utils as webhooks_utils,
     },
     db::StorageInterface,
-    routes::{metrics, AppState},
+    routes::{lock_utils, metrics, AppState},
     services::{self, api as service_api},
     types::{
         self, api,
@@ -440,6 +442,14 @@ pub async fn update_business_profile_cascade(
             session_expiry: None,
             authentication_connector_details: None,
             extended_card_info_config: None,
+            authorization_expiry_config: None,
+            intent_expiry: None,
+            max_over_capture_percentage: None,
+            refund_approval_policy: None,
+            dispute_alert_policy: None,
+            webhook_endpoints: None,
+            webhook_secret_versions: None,
+            refund_payout_fallback_policy: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -1022,6 +1032,11 @@ pub async fn create_payment_connector(
         }
     }
 
+    disputes::sync::add_dispute_sync_task(&state, &mca)
+        .await
+        .map_err(|error| crate::logger::error!(?error, "Failed to add dispute sync task"))
+        .ok();
+
     metrics::MCA_CREATE.add(
         &metrics::CONTEXT,
         1,
@@ -1308,6 +1323,11 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
+    disputes::sync::add_dispute_sync_task(&state, &updated_mca)
+        .await
+        .map_err(|error| crate::logger::error!(?error, "Failed to add dispute sync task"))
+        .ok();
+
     let response = updated_mca.try_into()?;
 
     Ok(service_api::ApplicationResponse::Json(response))
@@ -1498,6 +1518,33 @@ pub async fn create_business_profile(
     if let Some(session_expiry) = &request.session_expiry {
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
+
+    if let Some(authorization_expiry_config) = &request.authorization_expiry_config {
+        helpers::validate_authorization_expiry_config(
+            authorization_expiry_config,
+            &state.conf.authorization_expiry,
+        )?;
+    }
+
+    if let Some(intent_expiry) = request.intent_expiry {
+        helpers::validate_intent_expiry(intent_expiry)?;
+    }
+
+    if let Some(max_over_capture_percentage) = request.max_over_capture_percentage {
+        helpers::validate_max_over_capture_percentage(max_over_capture_percentage)?;
+    }
+
+    if let Some(refund_approval_policy) = &request.refund_approval_policy {
+        helpers::validate_refund_approval_policy(refund_approval_policy)?;
+    }
+
+    if let Some(dispute_alert_policy) = &request.dispute_alert_policy {
+        helpers::validate_dispute_alert_policy(dispute_alert_policy)?;
+    }
+
+    if let Some(webhook_endpoints) = &request.webhook_endpoints {
+        helpers::validate_webhook_endpoints(webhook_endpoints)?;
+    }
     let db = state.store.as_ref();
     let key_store = db
         .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
@@ -1524,6 +1571,8 @@ pub async fn create_business_profile(
     let business_profile =
         create_and_insert_business_profile(db, request, merchant_account.clone()).await?;
 
+    intent_expiry::add_intent_expiry_task(db, &business_profile).await?;
+
     if merchant_account.default_profile.is_some() {
         let unset_default_profile = domain::MerchantAccountUpdate::UnsetDefaultProfile;
         db.update_merchant(merchant_account, unset_default_profile, &key_store)
@@ -1615,6 +1664,33 @@ pub async fn update_business_profile(
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
 
+    if let Some(authorization_expiry_config) = &request.authorization_expiry_config {
+        helpers::validate_authorization_expiry_config(
+            authorization_expiry_config,
+            &state.conf.authorization_expiry,
+        )?;
+    }
+
+    if let Some(intent_expiry) = request.intent_expiry {
+        helpers::validate_intent_expiry(intent_expiry)?;
+    }
+
+    if let Some(max_over_capture_percentage) = request.max_over_capture_percentage {
+        helpers::validate_max_over_capture_percentage(max_over_capture_percentage)?;
+    }
+
+    if let Some(refund_approval_policy) = &request.refund_approval_policy {
+        helpers::validate_refund_approval_policy(refund_approval_policy)?;
+    }
+
+    if let Some(dispute_alert_policy) = &request.dispute_alert_policy {
+        helpers::validate_dispute_alert_policy(dispute_alert_policy)?;
+    }
+
+    if let Some(webhook_endpoints) = &request.webhook_endpoints {
+        helpers::validate_webhook_endpoints(webhook_endpoints)?;
+    }
+
     let webhook_details = request
         .webhook_details
         .as_ref()
@@ -1627,6 +1703,32 @@ pub async fn update_business_profile(
         })
         .transpose()?;
 
+    // Until the webhook signing secret is first rotated, webhooks are signed with the
+    // `payment_response_hash_key`. Replacing it directly does not keep the previous secret valid,
+    // the new key is recorded as a new version of the webhook signing secret
+    let current_webhook_secret_versions =
+        webhooks_utils::get_webhook_secret_versions(&business_profile)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse webhook secret versions")?;
+    let webhook_secret_versions = request
+        .payment_response_hash_key
+        .as_ref()
+        .filter(|payment_response_hash_key| {
+            current_webhook_secret_versions.current_secret.is_none()
+                && business_profile.payment_response_hash_key.as_ref()
+                    != Some(*payment_response_hash_key)
+        })
+        .map(|_| {
+            diesel_models::business_profile::WebhookSecretVersions {
+                current_version: current_webhook_secret_versions.current_version + 1,
+                ..Default::default()
+            }
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode webhook secret versions")
+        })
+        .transpose()?;
+
     if let Some(ref routing_algorithm) = request.routing_algorithm {
         let _: api_models::routing::RoutingAlgorithm = routing_algorithm
             .clone()
@@ -1691,6 +1793,49 @@ pub async fn update_business_profile(
                 field_name: "authentication_connector_details",
             })?,
         extended_card_info_config,
+        authorization_expiry_config: request
+            .authorization_expiry_config
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "authorization_expiry_config",
+            })?,
+        intent_expiry: request.intent_expiry.map(i64::from),
+        max_over_capture_percentage: request.max_over_capture_percentage.map(i16::from),
+        refund_approval_policy: request
+            .refund_approval_policy
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "refund_approval_policy",
+            })?,
+        dispute_alert_policy: request
+            .dispute_alert_policy
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "dispute_alert_policy",
+            })?,
+        webhook_endpoints: request
+            .webhook_endpoints
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "webhook_endpoints",
+            })?,
+        webhook_secret_versions,
+        refund_payout_fallback_policy: request
+            .refund_payout_fallback_policy
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "refund_payout_fallback_policy",
+            })?,
     };
 
     let updated_business_profile = db
@@ -1700,6 +1845,8 @@ pub async fn update_business_profile(
             id: profile_id.to_owned(),
         })?;
 
+    intent_expiry::add_intent_expiry_task(db, &updated_business_profile).await?;
+
     Ok(service_api::ApplicationResponse::Json(
         api_models::admin::BusinessProfileResponse::foreign_try_from(updated_business_profile)
             .change_context(errors::ApiErrorResponse::InternalServerError)?,
@@ -1780,6 +1927,242 @@ pub async fn connector_agnostic_mit_toggle(
     ))
 }
 
+pub async fn retrieve_webhook_secret(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResponse<admin_types::WebhookSecretResponse> {
+    let db = state.store.as_ref();
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    if business_profile.merchant_id != merchant_id {
+        Err(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id.to_string(),
+        })?
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_webhook_secret_response(business_profile)?,
+    ))
+}
+
+pub async fn rotate_webhook_secret(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    request: admin_types::WebhookSecretRotateRequest,
+) -> RouterResponse<admin_types::WebhookSecretResponse> {
+    let previous_secret_expiry_hours = request
+        .previous_secret_expiry_hours
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SECRET_EXPIRY_HOURS);
+    if previous_secret_expiry_hours > consts::MAX_WEBHOOK_SECRET_EXPIRY_HOURS {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "previous_secret_expiry_hours should not be greater than {}",
+                consts::MAX_WEBHOOK_SECRET_EXPIRY_HOURS
+            ),
+        })?
+    }
+
+    // Concurrent rotations would each read the same versions, and the secret generated by one of
+    // them would be dropped without being kept valid as a previous secret
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: format!("webhook_secret_{profile_id}"),
+            api_identifier: lock_utils::ApiIdentifier::Business,
+            override_lock_retries: None,
+        },
+    };
+    lock_action
+        .clone()
+        .perform_locking_action(&state, merchant_id.to_owned())
+        .await?;
+    let rotate_result = Box::pin(rotate_webhook_secret_with_lock_held(
+        &state,
+        merchant_id,
+        profile_id,
+        previous_secret_expiry_hours,
+    ))
+    .await;
+    lock_action
+        .free_lock_action(&state, merchant_id.to_owned())
+        .await?;
+
+    Ok(service_api::ApplicationResponse::Json(rotate_result?))
+}
+
+async fn rotate_webhook_secret_with_lock_held(
+    state: &AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    previous_secret_expiry_hours: u16,
+) -> RouterResult<admin_types::WebhookSecretResponse> {
+    let db = state.store.as_ref();
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    if business_profile.merchant_id != merchant_id {
+        Err(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id.to_string(),
+        })?
+    }
+
+    let webhook_secret_versions = webhooks_utils::get_webhook_secret_versions(&business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook secret versions")?;
+    let current_secret =
+        webhooks_utils::get_current_webhook_secret(&business_profile, &webhook_secret_versions);
+    let updated_webhook_secret_versions = get_rotated_webhook_secret_versions(
+        webhook_secret_versions,
+        current_secret,
+        Secret::new(generate_cryptographically_secure_random_string(64)),
+        previous_secret_expiry_hours,
+        date_time::now(),
+    )?;
+
+    let business_profile_update =
+        storage::business_profile::BusinessProfileUpdate::WebhookSecretUpdate {
+            webhook_secret_versions: updated_webhook_secret_versions
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode webhook secret versions")?,
+        };
+
+    let updated_business_profile = db
+        .update_business_profile_by_profile_id(business_profile, business_profile_update)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    get_webhook_secret_response(updated_business_profile)
+}
+
+/// Make the new secret the current version of the webhook signing secret. The current secret and
+/// the unexpired previous secrets remain valid until they expire, so that merchants can switch to
+/// the new secret without failing to verify webhooks. No previous secret is kept if
+/// `previous_secret_expiry_hours` is 0.
+fn get_rotated_webhook_secret_versions(
+    webhook_secret_versions: diesel_models::business_profile::WebhookSecretVersions,
+    current_secret: Option<Secret<String>>,
+    new_secret: Secret<String>,
+    previous_secret_expiry_hours: u16,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<diesel_models::business_profile::WebhookSecretVersions> {
+    let previous_secrets = if previous_secret_expiry_hours > 0 {
+        let expires_at = now.saturating_add(time::Duration::hours(i64::from(
+            previous_secret_expiry_hours,
+        )));
+        current_secret
+            .map(
+                |current_secret| diesel_models::business_profile::PreviousWebhookSecret {
+                    version: webhook_secret_versions.current_version,
+                    secret: current_secret,
+                    expires_at,
+                },
+            )
+            .into_iter()
+            .chain(
+                webhook_secret_versions
+                    .previous_secrets
+                    .into_iter()
+                    .filter(|previous_secret| previous_secret.expires_at > now),
+            )
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    if previous_secrets.len() > consts::MAX_WEBHOOK_PREVIOUS_SECRETS {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "At most {} previous webhook secrets can be valid at a time, the secret can be \
+                 rotated again once one of them expires",
+                consts::MAX_WEBHOOK_PREVIOUS_SECRETS
+            ),
+        })?
+    }
+
+    Ok(diesel_models::business_profile::WebhookSecretVersions {
+        current_version: webhook_secret_versions.current_version + 1,
+        current_secret: Some(new_secret),
+        previous_secrets,
+    })
+}
+
+fn get_webhook_secret_response(
+    business_profile: storage::business_profile::BusinessProfile,
+) -> RouterResult<admin_types::WebhookSecretResponse> {
+    let webhook_secret_versions = webhooks_utils::get_webhook_secret_versions(&business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook secret versions")?;
+    let secret =
+        webhooks_utils::get_current_webhook_secret(&business_profile, &webhook_secret_versions)
+            .get_required_value("payment_response_hash_key")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let now = date_time::now();
+    let previous_versions = webhook_secret_versions
+        .previous_secrets
+        .into_iter()
+        .filter(|previous_secret| previous_secret.expires_at > now)
+        .map(
+            |previous_secret| admin_types::PreviousWebhookSecretVersion {
+                version: previous_secret.version,
+                expires_at: previous_secret.expires_at,
+            },
+        )
+        .collect();
+
+    let endpoint_secrets = get_webhook_endpoint_secrets(business_profile.webhook_endpoints)?;
+
+    Ok(admin_types::WebhookSecretResponse {
+        merchant_id: business_profile.merchant_id,
+        profile_id: business_profile.profile_id,
+        version: webhook_secret_versions.current_version,
+        secret,
+        previous_versions,
+        endpoint_secrets,
+    })
+}
+
+/// The signing secrets of the webhook endpoints which have one, which are left out of the
+/// business profile response
+fn get_webhook_endpoint_secrets(
+    webhook_endpoints: Option<serde_json::Value>,
+) -> RouterResult<Vec<admin_types::WebhookEndpointSecret>> {
+    Ok(webhook_endpoints
+        .map(|webhook_endpoints| {
+            webhook_endpoints
+                .parse_value::<Vec<admin_types::WebhookEndpoint>>("Vec<WebhookEndpoint>")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook endpoints")?
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|webhook_endpoint| {
+            webhook_endpoint
+                .secret
+                .map(|secret| admin_types::WebhookEndpointSecret {
+                    endpoint_id: webhook_endpoint.endpoint_id,
+                    secret,
+                })
+        })
+        .collect())
+}
+
 pub(crate) fn validate_auth_and_metadata_type(
     connector_name: api_models::enums::Connector,
     val: &types::ConnectorAuthType,
@@ -2099,3 +2482,168 @@ pub fn validate_status_and_disabled(
 
     Ok((connector_status, disabled))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::ExposeInterface;
+
+    use super::*;
+
+    fn get_previous_secret(
+        version: i32,
+        expires_at: time::PrimitiveDateTime,
+    ) -> diesel_models::business_profile::PreviousWebhookSecret {
+        diesel_models::business_profile::PreviousWebhookSecret {
+            version,
+            secret: Secret::new(format!("secret_v{version}")),
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_rotated_webhook_secret_versions_keep_unexpired_secrets() {
+        let now = date_time::now();
+        let webhook_secret_versions = diesel_models::business_profile::WebhookSecretVersions {
+            current_version: 3,
+            current_secret: Some(Secret::new("secret_v3".to_string())),
+            previous_secrets: vec![
+                get_previous_secret(2, now.saturating_add(time::Duration::hours(1))),
+                get_previous_secret(1, now.saturating_sub(time::Duration::hours(1))),
+            ],
+        };
+
+        let rotated_versions = get_rotated_webhook_secret_versions(
+            webhook_secret_versions.clone(),
+            webhook_secret_versions.current_secret.clone(),
+            Secret::new("secret_v4".to_string()),
+            24,
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(rotated_versions.current_version, 4);
+        assert_eq!(
+            rotated_versions.current_secret.unwrap().expose(),
+            "secret_v4"
+        );
+        // A secret rotated shortly before remains valid along with the secret being replaced
+        assert_eq!(
+            rotated_versions
+                .previous_secrets
+                .iter()
+                .map(|previous_secret| previous_secret.version)
+                .collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+        assert_eq!(
+            rotated_versions.previous_secrets[0].expires_at,
+            now.saturating_add(time::Duration::hours(24))
+        );
+    }
+
+    #[test]
+    fn test_first_webhook_secret_rotation_keeps_payment_response_hash_key_valid() {
+        let now = date_time::now();
+
+        let rotated_versions = get_rotated_webhook_secret_versions(
+            diesel_models::business_profile::WebhookSecretVersions::default(),
+            Some(Secret::new("payment_response_hash_key".to_string())),
+            Secret::new("secret_v2".to_string()),
+            24,
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(rotated_versions.current_version, 2);
+        assert_eq!(rotated_versions.previous_secrets.len(), 1);
+        assert_eq!(rotated_versions.previous_secrets[0].version, 1);
+        assert_eq!(
+            rotated_versions.previous_secrets[0].secret.peek(),
+            "payment_response_hash_key"
+        );
+    }
+
+    #[test]
+    fn test_rotated_webhook_secret_versions_without_expiry_drop_previous_secrets() {
+        let now = date_time::now();
+        let webhook_secret_versions = diesel_models::business_profile::WebhookSecretVersions {
+            current_version: 2,
+            current_secret: Some(Secret::new("secret_v2".to_string())),
+            previous_secrets: vec![get_previous_secret(
+                1,
+                now.saturating_add(time::Duration::hours(1)),
+            )],
+        };
+
+        let rotated_versions = get_rotated_webhook_secret_versions(
+            webhook_secret_versions.clone(),
+            webhook_secret_versions.current_secret,
+            Secret::new("secret_v3".to_string()),
+            0,
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(rotated_versions.current_version, 3);
+        assert!(rotated_versions.previous_secrets.is_empty());
+    }
+
+    #[test]
+    fn test_webhook_endpoint_secrets_are_only_returned_by_the_secret_endpoint() {
+        let webhook_endpoints = serde_json::json!([
+            {
+                "endpoint_id": "finance",
+                "url": "https://finance.example.com/webhooks",
+                "secret": "finance_secret",
+            },
+            {
+                "endpoint_id": "support",
+                "url": "https://support.example.com/webhooks",
+            },
+        ]);
+
+        let endpoint_secrets =
+            get_webhook_endpoint_secrets(Some(webhook_endpoints.clone())).unwrap();
+        assert_eq!(endpoint_secrets.len(), 1);
+        assert_eq!(endpoint_secrets[0].endpoint_id, "finance");
+        assert_eq!(endpoint_secrets[0].secret.peek(), "finance_secret");
+        assert!(get_webhook_endpoint_secrets(None).unwrap().is_empty());
+
+        let endpoint_responses = webhook_endpoints
+            .parse_value::<Vec<admin_types::WebhookEndpoint>>("Vec<WebhookEndpoint>")
+            .unwrap()
+            .into_iter()
+            .map(admin_types::WebhookEndpointResponse::from)
+            .collect::<Vec<_>>();
+        assert!(endpoint_responses[0].has_secret);
+        assert!(!endpoint_responses[1].has_secret);
+        let serialized_responses = serde_json::to_string(&endpoint_responses).unwrap();
+        assert!(!serialized_responses.contains("finance_secret"));
+    }
+
+    #[test]
+    fn test_rotated_webhook_secret_versions_limit_previous_secrets() {
+        let now = date_time::now();
+        let previous_secrets = (1..=i32::try_from(consts::MAX_WEBHOOK_PREVIOUS_SECRETS).unwrap())
+            .map(|version| {
+                get_previous_secret(version, now.saturating_add(time::Duration::hours(1)))
+            })
+            .collect::<Vec<_>>();
+        let current_version = i32::try_from(previous_secrets.len()).unwrap() + 1;
+        let webhook_secret_versions = diesel_models::business_profile::WebhookSecretVersions {
+            current_version,
+            current_secret: Some(Secret::new(format!("secret_v{current_version}"))),
+            previous_secrets,
+        };
+
+        assert!(get_rotated_webhook_secret_versions(
+            webhook_secret_versions.clone(),
+            webhook_secret_versions.current_secret,
+            Secret::new("new_secret".to_string()),
+            24,
+            now,
+        )
+        .is_err());
+    }
+}
@@ -49,6 +49,23 @@ impl LockingInput {
 impl LockAction {
     #[instrument(skip_all)]
     pub async fn perform_locking_action<A>(self, state: &A, merchant_id: String) -> RouterResult<()>
+    where
+        A: AppStateInfo,
+    {
+        let lock_holder = state.get_request_id();
+        self.perform_locking_action_for_holder(state, merchant_id, lock_holder)
+            .await
+    }
+
+    /// Acquires the lock on behalf of `lock_holder`, used by callers which do not serve a request,
+    /// such as scheduler workflows
+    #[instrument(skip_all)]
+    pub async fn perform_locking_action_for_holder<A>(
+        self,
+        state: &A,
+        merchant_id: String,
+        lock_holder: Option<String>,
+    ) -> RouterResult<()>
     where
         A: AppStateInfo,
     {
@@ -73,7 +90,7 @@ impl LockAction {
                     let redis_lock_result = redis_conn
                         .set_key_if_not_exists_with_expiry(
                             redis_locking_key.as_str(),
-                            state.get_request_id(),
+                            lock_holder.clone(),
                             Some(i64::from(redis_lock_expiry_seconds)),
                         )
                         .await;
@@ -110,6 +127,22 @@ impl LockAction {
 
     #[instrument(skip_all)]
     pub async fn free_lock_action<A>(self, state: &A, merchant_id: String) -> RouterResult<()>
+    where
+        A: AppStateInfo,
+    {
+        let lock_holder = state.get_request_id();
+        self.free_lock_action_for_holder(state, merchant_id, lock_holder)
+            .await
+    }
+
+    /// Releases the lock acquired on behalf of `lock_holder`
+    #[instrument(skip_all)]
+    pub async fn free_lock_action_for_holder<A>(
+        self,
+        state: &A,
+        merchant_id: String,
+        lock_holder: Option<String>,
+    ) -> RouterResult<()>
     where
         A: AppStateInfo,
     {
@@ -127,7 +160,7 @@ impl LockAction {
                     .await
                 {
                     Ok(val) => {
-                        if val == state.get_request_id() {
+                        if val == lock_holder {
                             match redis_conn.delete_key(redis_locking_key.as_str()).await {
                                 Ok(redis::types::DelReply::KeyDeleted) => {
                                     logger::info!("Lock freed for locking input {:?}", input);
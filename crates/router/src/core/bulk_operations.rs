@@ -0,0 +1,1038 @@
+use api_models::{bulk_operations as bulk_operations_api, refunds as refunds_api};
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::{report, ResultExt};
+use futures::StreamExt;
+use router_env::logger;
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::Oss,
+        payments, refunds,
+    },
+    routes::{metrics, AppState},
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::OptionExt,
+};
+
+pub const BULK_OPERATION_TASK: &str = "BULK_OPERATION";
+
+pub const JSON_LINES_MIME_TYPE: &str = "application/jsonl";
+
+/// Formats supported for bulk operation files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    Csv,
+    JsonLines,
+}
+
+impl TryFrom<&mime::Mime> for FileFormat {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(file_type: &mime::Mime) -> Result<Self, Self::Error> {
+        match file_type.essence_str() {
+            "text/csv" => Ok(Self::Csv),
+            JSON_LINES_MIME_TYPE => Ok(Self::JsonLines),
+            _ => Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+                reason: "file_type does not match CSV or JSON Lines format".to_owned(),
+            })),
+        }
+    }
+}
+
+/// Tracking data stored against the bulk operation process tracker entry, which also serves as
+/// the state of the job
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BulkOperationTrackingData {
+    pub job_id: String,
+    pub merchant_id: String,
+    pub file_id: String,
+    pub total_rows: usize,
+    pub processed_rows: usize,
+    pub succeeded_rows: usize,
+    pub failed_rows: usize,
+    /// Number of parts of the results file stored so far, one for every processed chunk of rows
+    pub result_parts: usize,
+    pub result_file_id: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl BulkOperationTrackingData {
+    pub fn is_completed(&self) -> bool {
+        self.processed_rows >= self.total_rows
+    }
+}
+
+fn get_process_tracker_id(job_id: &str, merchant_id: &str) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::BulkOperationWorkflow,
+        BULK_OPERATION_TASK,
+        job_id,
+        merchant_id,
+    )
+}
+
+fn get_result_part_key(tracking_data: &BulkOperationTrackingData, part: usize) -> String {
+    format!(
+        "{}/{}/results_{}",
+        tracking_data.merchant_id, tracking_data.job_id, part
+    )
+}
+
+fn get_rows_part_key(tracking_data: &BulkOperationTrackingData, part: usize) -> String {
+    format!(
+        "{}/{}/rows_{}",
+        tracking_data.merchant_id, tracking_data.job_id, part
+    )
+}
+
+fn get_rows_parts_count(tracking_data: &BulkOperationTrackingData) -> usize {
+    (0..tracking_data.total_rows)
+        .step_by(consts::BULK_OPERATION_CHUNK_SIZE)
+        .count()
+}
+
+/// Identifier of the payment or refund created for a row, derived from the job so that the row
+/// can be retried without creating it again
+fn get_row_resource_id(job_id: &str, row_number: usize) -> String {
+    format!("{job_id}_{row_number}")
+}
+
+/// Fills in the identifier of the payment or refund to be created for a row, when the file does
+/// not provide one
+fn set_row_resource_id(
+    job_id: &str,
+    row_number: usize,
+    mut row: bulk_operations_api::BulkOperationRow,
+) -> bulk_operations_api::BulkOperationRow {
+    match row.operation {
+        bulk_operations_api::BulkOperationType::PaymentCreate => {
+            row.payment_id
+                .get_or_insert_with(|| get_row_resource_id(job_id, row_number));
+        }
+        bulk_operations_api::BulkOperationType::Refund => {
+            row.refund_id
+                .get_or_insert_with(|| get_row_resource_id(job_id, row_number));
+        }
+        bulk_operations_api::BulkOperationType::PaymentCapture
+        | bulk_operations_api::BulkOperationType::PaymentVoid => (),
+    }
+    row
+}
+
+fn get_bulk_operation_status(
+    process: &storage::ProcessTracker,
+    tracking_data: &BulkOperationTrackingData,
+) -> bulk_operations_api::BulkOperationStatus {
+    match process.status {
+        storage_enums::ProcessTrackerStatus::Finish
+            if tracking_data.is_completed() && tracking_data.error_message.is_none() =>
+        {
+            bulk_operations_api::BulkOperationStatus::Completed
+        }
+        storage_enums::ProcessTrackerStatus::Finish => {
+            bulk_operations_api::BulkOperationStatus::Failed
+        }
+        storage_enums::ProcessTrackerStatus::New | storage_enums::ProcessTrackerStatus::Pending
+            if tracking_data.processed_rows == 0 =>
+        {
+            bulk_operations_api::BulkOperationStatus::Pending
+        }
+        _ => bulk_operations_api::BulkOperationStatus::Processing,
+    }
+}
+
+fn get_bulk_operation_response(
+    process: &storage::ProcessTracker,
+    tracking_data: BulkOperationTrackingData,
+) -> bulk_operations_api::BulkOperationResponse {
+    bulk_operations_api::BulkOperationResponse {
+        status: get_bulk_operation_status(process, &tracking_data),
+        job_id: tracking_data.job_id,
+        file_id: tracking_data.file_id,
+        total_rows: tracking_data.total_rows,
+        processed_rows: tracking_data.processed_rows,
+        succeeded_rows: tracking_data.succeeded_rows,
+        failed_rows: tracking_data.failed_rows,
+        result_file_id: tracking_data.result_file_id,
+        error_message: tracking_data.error_message,
+        created_at: process.created_at,
+        updated_at: process.updated_at,
+    }
+}
+
+/// Splits CSV content into records, handling quoted fields which may contain delimiters,
+/// escaped quotes and line breaks
+fn parse_csv_records(content: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        if in_quotes {
+            match character {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(character),
+            }
+        } else {
+            match character {
+                '"' if field.is_empty() => in_quotes = true,
+                ',' => record.push(std::mem::take(&mut field)),
+                '\r' if chars.peek() == Some(&'\n') => (),
+                '\n' => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                _ => field.push(character),
+            }
+        }
+    }
+
+    if in_quotes {
+        return Err("Unterminated quoted field".to_string());
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records.retain(|record| !(record.len() == 1 && record[0].trim().is_empty()));
+    Ok(records)
+}
+
+fn parse_csv_rows(content: &str) -> Result<Vec<bulk_operations_api::BulkOperationRow>, String> {
+    let mut records = parse_csv_records(content)?.into_iter();
+    let header = records
+        .next()
+        .ok_or_else(|| "The file does not contain a header row".to_string())?;
+
+    records
+        .enumerate()
+        .map(|(index, record)| {
+            let row_number = index + 1;
+            if record.len() != header.len() {
+                return Err(format!(
+                    "Row {row_number} has {} fields, but the header has {}",
+                    record.len(),
+                    header.len()
+                ));
+            }
+            let row = header
+                .iter()
+                .map(|column| column.trim())
+                .zip(record.iter().map(|value| value.trim()))
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(column, value)| {
+                    let value = match (column, value.parse::<i64>()) {
+                        ("amount", Ok(amount)) => serde_json::Value::from(amount),
+                        _ => serde_json::Value::from(value),
+                    };
+                    (column.to_string(), value)
+                })
+                .collect::<serde_json::Map<_, _>>();
+            serde_json::from_value(serde_json::Value::Object(row))
+                .map_err(|error| format!("Row {row_number} is invalid: {error}"))
+        })
+        .collect()
+}
+
+fn parse_json_lines_rows(
+    content: &str,
+) -> Result<Vec<bulk_operations_api::BulkOperationRow>, String> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map_err(|error| format!("Row {} is invalid: {error}", index + 1))
+        })
+        .collect()
+}
+
+fn parse_bulk_operation_file(
+    file_format: FileFormat,
+    file: &[u8],
+) -> RouterResult<Vec<bulk_operations_api::BulkOperationRow>> {
+    let content = std::str::from_utf8(file)
+        .map_err(|_| errors::ApiErrorResponse::InvalidRequestData {
+            message: "The bulk operations file is not UTF-8 encoded".to_string(),
+        })?
+        .trim_start_matches('\u{feff}');
+
+    let rows = match file_format {
+        FileFormat::Csv => parse_csv_rows(content),
+        FileFormat::JsonLines => parse_json_lines_rows(content),
+    }
+    .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })?;
+
+    if rows.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The bulk operations file does not contain any rows".to_string(),
+        })?
+    }
+    if rows.len() > consts::BULK_OPERATION_MAX_ROWS {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The bulk operations file contains more than {} rows",
+                consts::BULK_OPERATION_MAX_ROWS
+            ),
+        })?
+    }
+    Ok(rows)
+}
+
+async fn retrieve_bulk_operation_file(
+    state: &AppState,
+    merchant_id: &str,
+    file_id: &str,
+) -> RouterResult<(FileFormat, Vec<u8>)> {
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(merchant_id, file_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::FileNotFound)?;
+    let file_format = file_metadata
+        .file_type
+        .parse::<mime::Mime>()
+        .ok()
+        .and_then(|file_type| FileFormat::try_from(&file_type).ok())
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The file was not uploaded with the purpose bulk_operations".to_string(),
+        })?;
+    let provider_file_id = match (
+        file_metadata.file_upload_provider,
+        file_metadata.provider_file_id,
+        file_metadata.available,
+    ) {
+        (Some(api_enums::FileUploadProvider::Router), Some(provider_file_id), true) => {
+            provider_file_id
+        }
+        _ => Err(errors::ApiErrorResponse::FileNotAvailable)
+            .attach_printable("Bulk operations file not available")?,
+    };
+    let file = state
+        .file_storage_client
+        .retrieve_file(&provider_file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve bulk operations file")?;
+    Ok((file_format, file))
+}
+
+/// Stores the parsed rows of the file in chunks, so that the workflow processes every chunk
+/// without parsing the whole file again
+async fn store_bulk_operation_rows(
+    state: &AppState,
+    tracking_data: &BulkOperationTrackingData,
+    rows: &[bulk_operations_api::BulkOperationRow],
+) -> RouterResult<()> {
+    for (part, chunk) in rows.chunks(consts::BULK_OPERATION_CHUNK_SIZE).enumerate() {
+        let content = chunk
+            .iter()
+            .map(|row| row.encode_to_string_of_json())
+            .collect::<Result<Vec<_>, _>>()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize bulk operation rows")?
+            .join("\n");
+        state
+            .file_storage_client
+            .upload_file(
+                &get_rows_part_key(tracking_data, part),
+                content.into_bytes(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store bulk operation rows")?;
+    }
+    Ok(())
+}
+
+/// Validates the rows of an uploaded file and adds a task which processes them
+pub async fn create_bulk_operation(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: bulk_operations_api::BulkOperationCreateRequest,
+) -> RouterResponse<bulk_operations_api::BulkOperationResponse> {
+    let (file_format, file) =
+        retrieve_bulk_operation_file(&state, &merchant_account.merchant_id, &req.file_id).await?;
+    let rows = parse_bulk_operation_file(file_format, &file)?;
+
+    let job_id = common_utils::generate_id(consts::ID_LENGTH, "bulk_op");
+    let rows = rows
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| set_row_resource_id(&job_id, index + 1, row))
+        .collect::<Vec<_>>();
+    let tracking_data = BulkOperationTrackingData {
+        job_id: job_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        file_id: req.file_id,
+        total_rows: rows.len(),
+        processed_rows: 0,
+        succeeded_rows: 0,
+        failed_rows: 0,
+        result_parts: 0,
+        result_file_id: None,
+        error_message: None,
+    };
+    store_bulk_operation_rows(&state, &tracking_data, &rows).await?;
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_process_tracker_id(&job_id, &merchant_account.merchant_id),
+        BULK_OPERATION_TASK,
+        storage::ProcessTrackerRunner::BulkOperationWorkflow,
+        ["BULK_OPERATIONS"],
+        tracking_data.clone(),
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct bulk operation process tracker task")?;
+
+    let process = state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert bulk operation task in process tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "BulkOperation")],
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        get_bulk_operation_response(&process, tracking_data),
+    ))
+}
+
+pub async fn retrieve_bulk_operation(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: bulk_operations_api::BulkOperationJobId,
+) -> RouterResponse<bulk_operations_api::BulkOperationResponse> {
+    let process = state
+        .store
+        .find_process_by_id(&get_process_tracker_id(
+            &req.job_id,
+            &merchant_account.merchant_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch bulk operation task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Bulk operation job not found".to_string(),
+        })?;
+    let tracking_data: BulkOperationTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("BulkOperationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_bulk_operation_response(&process, tracking_data),
+    ))
+}
+
+fn get_payments_response(
+    response: services::ApplicationResponse<api::PaymentsResponse>,
+) -> RouterResult<api::PaymentsResponse> {
+    match response {
+        services::ApplicationResponse::Json(response)
+        | services::ApplicationResponse::JsonWithHeaders((response, _)) => Ok(response),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to get the payments response in json"),
+    }
+}
+
+async fn create_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    row: bulk_operations_api::BulkOperationRow,
+) -> RouterResult<api::PaymentsResponse> {
+    let recurring_details = match (row.mandate_id, row.payment_method_id) {
+        (Some(mandate_id), _) => api_models::mandates::RecurringDetails::MandateId(mandate_id),
+        (None, Some(payment_method_id)) => {
+            api_models::mandates::RecurringDetails::PaymentMethodId(payment_method_id)
+        }
+        (None, None) => Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payment_method_id",
+        })?,
+    };
+    let payment_id = row.payment_id.get_required_value("payment_id")?;
+    let request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id.clone())),
+        amount: Some(row.amount.get_required_value("amount")?.into()),
+        currency: Some(row.currency.get_required_value("currency")?),
+        customer_id: Some(row.customer_id.get_required_value("customer_id")?),
+        description: row.description,
+        confirm: Some(true),
+        off_session: Some(true),
+        recurring_details: Some(recurring_details),
+        ..Default::default()
+    };
+
+    let response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payments::PaymentCreate::default(),
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await;
+
+    match response {
+        // The payment was already created by a previous execution of the chunk
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicatePayment { .. }
+            ) =>
+        {
+            retrieve_payment(state, merchant_account, key_store, payment_id).await
+        }
+        response => response.and_then(get_payments_response),
+    }
+}
+
+async fn retrieve_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+) -> RouterResult<api::PaymentsResponse> {
+    let request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_id),
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+
+    Box::pin(payments::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account,
+        key_store,
+        payments::PaymentStatus,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Avoid,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await
+    .and_then(get_payments_response)
+}
+
+async fn capture_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    row: bulk_operations_api::BulkOperationRow,
+) -> RouterResult<api::PaymentsResponse> {
+    let payment_id = row.payment_id.get_required_value("payment_id")?;
+
+    // The payment was already captured by a previous execution of the chunk
+    let payment = retrieve_payment(
+        state,
+        merchant_account.clone(),
+        key_store.clone(),
+        payment_id.clone(),
+    )
+    .await?;
+    if matches!(
+        payment.status,
+        api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::PartiallyCaptured
+            | api_enums::IntentStatus::Processing
+    ) {
+        return Ok(payment);
+    }
+
+    let request = api::PaymentsCaptureRequest {
+        payment_id,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        amount_to_capture: row.amount,
+        ..Default::default()
+    };
+
+    Box::pin(payments::payments_core::<
+        api::Capture,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account,
+        key_store,
+        payments::PaymentCapture,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await
+    .and_then(get_payments_response)
+}
+
+async fn void_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    row: bulk_operations_api::BulkOperationRow,
+) -> RouterResult<api::PaymentsResponse> {
+    let payment_id = row.payment_id.get_required_value("payment_id")?;
+
+    // The payment was already voided by a previous execution of the chunk
+    let payment = retrieve_payment(
+        state,
+        merchant_account.clone(),
+        key_store.clone(),
+        payment_id.clone(),
+    )
+    .await?;
+    if payment.status == api_enums::IntentStatus::Cancelled {
+        return Ok(payment);
+    }
+
+    let request = api::PaymentsCancelRequest {
+        payment_id,
+        cancellation_reason: row.reason,
+        merchant_connector_details: None,
+    };
+
+    Box::pin(payments::payments_core::<
+        api::Void,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account,
+        key_store,
+        payments::PaymentCancel,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await
+    .and_then(get_payments_response)
+}
+
+async fn create_refund(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    row: bulk_operations_api::BulkOperationRow,
+) -> RouterResult<refunds_api::RefundResponse> {
+    let refund_id = row.refund_id.get_required_value("refund_id")?;
+
+    // The refund was already created by a previous execution of the chunk
+    match state
+        .store
+        .find_refund_by_merchant_id_refund_id(
+            &merchant_account.merchant_id,
+            &refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(refund) => return Ok(refund.foreign_into()),
+        Err(error) if error.current_context().is_db_not_found() => (),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refund of the bulk operation row")?,
+    }
+
+    let request = refunds_api::RefundRequest {
+        payment_id: row.payment_id.get_required_value("payment_id")?,
+        refund_id: Some(refund_id),
+        amount: row.amount,
+        reason: row.reason,
+        ..Default::default()
+    };
+
+    match Box::pin(refunds::refund_create_core(
+        state.clone(),
+        merchant_account,
+        key_store,
+        None,
+        request,
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(response) => Ok(response),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to get the refund response in json"),
+    }
+}
+
+fn get_payment_row_result(
+    row_number: usize,
+    operation: bulk_operations_api::BulkOperationType,
+    payment: api::PaymentsResponse,
+) -> bulk_operations_api::BulkOperationRowResult {
+    bulk_operations_api::BulkOperationRowResult {
+        row_number,
+        operation,
+        status: if payment.status == api_enums::IntentStatus::Failed {
+            bulk_operations_api::BulkOperationRowStatus::Failed
+        } else {
+            bulk_operations_api::BulkOperationRowStatus::Succeeded
+        },
+        payment_id: payment.payment_id,
+        refund_id: None,
+        payment_status: Some(payment.status),
+        refund_status: None,
+        error_code: payment.error_code,
+        error_message: payment.error_message,
+    }
+}
+
+fn get_refund_row_result(
+    row_number: usize,
+    operation: bulk_operations_api::BulkOperationType,
+    refund: refunds_api::RefundResponse,
+) -> bulk_operations_api::BulkOperationRowResult {
+    bulk_operations_api::BulkOperationRowResult {
+        row_number,
+        operation,
+        status: if refund.status == refunds_api::RefundStatus::Failed {
+            bulk_operations_api::BulkOperationRowStatus::Failed
+        } else {
+            bulk_operations_api::BulkOperationRowStatus::Succeeded
+        },
+        payment_id: Some(refund.payment_id),
+        refund_id: Some(refund.refund_id),
+        payment_status: None,
+        refund_status: Some(refund.status),
+        error_code: refund.error_code,
+        error_message: refund.error_message,
+    }
+}
+
+fn get_failed_row_result(
+    row_number: usize,
+    row: &bulk_operations_api::BulkOperationRow,
+    error: &errors::ApiErrorResponse,
+) -> bulk_operations_api::BulkOperationRowResult {
+    bulk_operations_api::BulkOperationRowResult {
+        row_number,
+        operation: row.operation,
+        status: bulk_operations_api::BulkOperationRowStatus::Failed,
+        payment_id: row.payment_id.clone(),
+        refund_id: row.refund_id.clone(),
+        payment_status: None,
+        refund_status: None,
+        error_code: Some(error.error_code()),
+        error_message: Some(error.error_message()),
+    }
+}
+
+async fn execute_bulk_operation_row(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    row_number: usize,
+    row: bulk_operations_api::BulkOperationRow,
+) -> bulk_operations_api::BulkOperationRowResult {
+    let operation = row.operation;
+    let result = match operation {
+        bulk_operations_api::BulkOperationType::PaymentCreate => {
+            create_payment(state, merchant_account, key_store, row.clone())
+                .await
+                .map(|payment| get_payment_row_result(row_number, operation, payment))
+        }
+        bulk_operations_api::BulkOperationType::PaymentCapture => {
+            capture_payment(state, merchant_account, key_store, row.clone())
+                .await
+                .map(|payment| get_payment_row_result(row_number, operation, payment))
+        }
+        bulk_operations_api::BulkOperationType::PaymentVoid => {
+            void_payment(state, merchant_account, key_store, row.clone())
+                .await
+                .map(|payment| get_payment_row_result(row_number, operation, payment))
+        }
+        bulk_operations_api::BulkOperationType::Refund => {
+            create_refund(state, merchant_account, key_store, row.clone())
+                .await
+                .map(|refund| get_refund_row_result(row_number, operation, refund))
+        }
+    };
+
+    result.unwrap_or_else(|error| {
+        logger::info!(?error, row_number, "Bulk operation row failed");
+        get_failed_row_result(row_number, &row, error.current_context())
+    })
+}
+
+/// Processes the next chunk of rows of the job with bounded concurrency, and stores their
+/// results as a part of the results file
+pub async fn process_bulk_operation_chunk(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &mut BulkOperationTrackingData,
+) -> RouterResult<()> {
+    let rows_part = tracking_data.processed_rows / consts::BULK_OPERATION_CHUNK_SIZE;
+    let file = state
+        .file_storage_client
+        .retrieve_file(&get_rows_part_key(tracking_data, rows_part))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve bulk operation rows")?;
+    let rows = std::str::from_utf8(&file)
+        .map_err(|error| error.to_string())
+        .and_then(parse_json_lines_rows)
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(format!("Failed to parse bulk operation rows: {error}"))
+        })?;
+
+    let processed_rows = tracking_data.processed_rows;
+    let results = futures::stream::iter(rows.into_iter().enumerate())
+        .map(|(index, row)| {
+            execute_bulk_operation_row(
+                state,
+                merchant_account.clone(),
+                key_store.clone(),
+                processed_rows + index + 1,
+                row,
+            )
+        })
+        .buffered(consts::BULK_OPERATION_MAX_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let content = results
+        .iter()
+        .map(|result| result.encode_to_string_of_json())
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize bulk operation results")?
+        .join("\n");
+    state
+        .file_storage_client
+        .upload_file(
+            &get_result_part_key(tracking_data, tracking_data.result_parts),
+            content.into_bytes(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store bulk operation results")?;
+
+    let succeeded_rows = results
+        .iter()
+        .filter(|result| result.status == bulk_operations_api::BulkOperationRowStatus::Succeeded)
+        .count();
+    tracking_data.processed_rows += results.len();
+    tracking_data.succeeded_rows += succeeded_rows;
+    tracking_data.failed_rows += results.len() - succeeded_rows;
+    tracking_data.result_parts += 1;
+    Ok(())
+}
+
+/// Combines the parts of the results file into a single file, which can be downloaded using the
+/// files API
+pub async fn store_bulk_operation_results(
+    state: &AppState,
+    tracking_data: &mut BulkOperationTrackingData,
+) -> RouterResult<()> {
+    let part_keys = (0..tracking_data.result_parts)
+        .map(|part| get_result_part_key(tracking_data, part))
+        .collect::<Vec<_>>();
+
+    let mut content = Vec::new();
+    for part_key in &part_keys {
+        let part = state
+            .file_storage_client
+            .retrieve_file(part_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve bulk operation results")?;
+        content.extend(part);
+        content.push(b'\n');
+    }
+
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!("{}/{}", tracking_data.merchant_id, file_id);
+    let file_size = i32::try_from(content.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Bulk operation results file size error")?;
+    state
+        .file_storage_client
+        .upload_file(&file_key, content)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store bulk operation results file")?;
+    state
+        .store
+        .insert_file_metadata(diesel_models::file::FileMetadataNew {
+            file_id: file_id.clone(),
+            merchant_id: tracking_data.merchant_id.clone(),
+            file_name: Some(format!("{}_results.jsonl", tracking_data.job_id)),
+            file_size,
+            file_type: JSON_LINES_MIME_TYPE.to_string(),
+            provider_file_id: Some(file_key),
+            file_upload_provider: Some(api_enums::FileUploadProvider::Router),
+            available: true,
+            connector_label: None,
+            profile_id: None,
+            merchant_connector_id: None,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file_metadata")?;
+
+    let rows_part_keys =
+        (0..get_rows_parts_count(tracking_data)).map(|part| get_rows_part_key(tracking_data, part));
+    for part_key in part_keys.into_iter().chain(rows_part_keys) {
+        state
+            .file_storage_client
+            .delete_file(&part_key)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to delete bulk operation file"))
+            .ok();
+    }
+
+    tracking_data.result_file_id = Some(file_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_records() {
+        let content = "operation,reason\r\nrefund,\"duplicate, \"\"charged twice\"\"\"\n\nrefund,\"multi\nline\"";
+        let records = parse_csv_records(content).unwrap();
+        assert_eq!(
+            records,
+            vec![
+                vec!["operation", "reason"],
+                vec!["refund", "duplicate, \"charged twice\""],
+                vec!["refund", "multi\nline"],
+            ]
+        );
+        assert!(parse_csv_records("operation\n\"refund").is_err());
+    }
+
+    #[test]
+    fn test_parse_csv_rows() {
+        let content =
+            "operation,payment_id,amount,currency\npayment_capture,pay_1,100,\nrefund,pay_2,,USD\n";
+        let rows = parse_csv_rows(content).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0].operation,
+            bulk_operations_api::BulkOperationType::PaymentCapture
+        );
+        assert_eq!(rows[0].amount, Some(100));
+        assert_eq!(rows[1].amount, None);
+        assert_eq!(rows[1].currency, Some(api_enums::Currency::USD));
+        assert!(parse_csv_rows("operation,payment_id\nrefund\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_json_lines_rows() {
+        let content = "{\"operation\":\"payment_void\",\"payment_id\":\"pay_1\"}\n\n{\"operation\":\"refund\",\"payment_id\":\"pay_2\",\"amount\":50}\n";
+        let rows = parse_json_lines_rows(content).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].amount, Some(50));
+        assert!(parse_json_lines_rows("{\"operation\":\"unknown\"}").is_err());
+    }
+
+    #[test]
+    fn test_set_row_resource_id() {
+        let content = "{\"operation\":\"payment_create\"}\n{\"operation\":\"payment_create\",\"payment_id\":\"pay_1\"}\n{\"operation\":\"refund\",\"payment_id\":\"pay_2\"}\n{\"operation\":\"payment_capture\",\"payment_id\":\"pay_3\"}";
+        let rows = parse_json_lines_rows(content)
+            .unwrap()
+            .into_iter()
+            .enumerate()
+            .map(|(index, row)| set_row_resource_id("bulk_op_1", index + 1, row))
+            .collect::<Vec<_>>();
+        assert_eq!(rows[0].payment_id.as_deref(), Some("bulk_op_1_1"));
+        assert_eq!(rows[1].payment_id.as_deref(), Some("pay_1"));
+        assert_eq!(rows[2].payment_id.as_deref(), Some("pay_2"));
+        assert_eq!(rows[2].refund_id.as_deref(), Some("bulk_op_1_3"));
+        assert_eq!(rows[3].refund_id, None);
+
+        // The identifiers do not change when the rows are parsed again in a later execution
+        let content = rows
+            .iter()
+            .map(|row| row.encode_to_string_of_json().unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let parsed_rows = parse_json_lines_rows(&content).unwrap();
+        assert_eq!(parsed_rows[0].payment_id.as_deref(), Some("bulk_op_1_1"));
+        assert_eq!(parsed_rows[2].refund_id.as_deref(), Some("bulk_op_1_3"));
+    }
+
+    #[test]
+    fn test_get_rows_parts_count() {
+        let mut tracking_data = BulkOperationTrackingData {
+            job_id: "bulk_op_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            file_id: "file_1".to_string(),
+            total_rows: consts::BULK_OPERATION_CHUNK_SIZE,
+            processed_rows: 0,
+            succeeded_rows: 0,
+            failed_rows: 0,
+            result_parts: 0,
+            result_file_id: None,
+            error_message: None,
+        };
+        assert_eq!(get_rows_parts_count(&tracking_data), 1);
+        tracking_data.total_rows = consts::BULK_OPERATION_CHUNK_SIZE + 1;
+        assert_eq!(get_rows_parts_count(&tracking_data), 2);
+        assert_eq!(
+            get_rows_part_key(&tracking_data, 1),
+            "merchant_1/bulk_op_1/rows_1"
+        );
+    }
+}
@@ -2,6 +2,11 @@ use api_models::{disputes as dispute_models, files as files_api_models};
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
+pub mod alerts;
+pub mod deadline_reminders;
+pub mod evidence_assembly;
+pub mod policies;
+pub mod sync;
 pub mod transformers;
 
 use super::{
@@ -0,0 +1,569 @@
+use api_models::{admin::DisputeAlertPolicy, disputes as dispute_models, refunds as refunds_api};
+use common_utils::ext_traits::ValueExt;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        metrics, refunds,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+};
+
+/// The payment attempt a pre-dispute alert refers to, as found from the details of the alert
+enum AlertMatch {
+    Matched(Box<storage::PaymentAttempt>),
+    NotFound,
+    Ambiguous(usize),
+}
+
+/// Records a pre-dispute alert, matches it to the disputed payment and refunds the payment if the
+/// dispute alert policy of the business profile allows it, so that the cardholder does not go on
+/// to raise a chargeback. An alert which was already received is not processed again.
+#[instrument(skip(state))]
+pub async fn receive_dispute_alert(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: dispute_models::DisputeAlertRequest,
+) -> RouterResponse<dispute_models::DisputeAlertResponse> {
+    validate_dispute_alert_request(&req)?;
+    let db = state.store.as_ref();
+    let merchant_id = &merchant_account.merchant_id;
+
+    let dispute_alert = match db
+        .find_dispute_alert_by_merchant_id_provider_alert_id(
+            merchant_id,
+            req.provider,
+            &req.provider_alert_id,
+        )
+        .await
+    {
+        // Alerts whose processing did not complete are processed again when the provider retries
+        Ok(dispute_alert) if dispute_alert.outcome.is_none() => dispute_alert,
+        Ok(dispute_alert) => {
+            return Ok(services::ApplicationResponse::Json(
+                dispute_alert.foreign_into(),
+            ))
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let dispute_alert_new = storage::DisputeAlertNew {
+                alert_id: common_utils::generate_id(consts::ID_LENGTH, "alert"),
+                merchant_id: merchant_id.to_owned(),
+                provider: req.provider,
+                provider_alert_id: req.provider_alert_id,
+                arn: req.arn,
+                card_last4: req.card_last4,
+                amount: req.amount,
+                currency: req.currency,
+                transaction_date: req.transaction_date,
+                reason: req.reason,
+            };
+            // The insert fails if the same alert is being received concurrently
+            db.insert_dispute_alert(dispute_alert_new)
+                .await
+                .to_duplicate_response(errors::ApiErrorResponse::ResourceBusy)?
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch dispute alert")?,
+    };
+
+    let dispute_alert_update =
+        process_dispute_alert(&state, &merchant_account, &key_store, &dispute_alert).await?;
+    let outcome = match &dispute_alert_update {
+        storage::DisputeAlertUpdate::OutcomeUpdate { outcome, .. } => *outcome,
+    };
+    let dispute_alert = db
+        .update_dispute_alert(dispute_alert, dispute_alert_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record dispute alert outcome")?;
+    metrics::DISPUTE_ALERT_OUTCOME_METRIC.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::KeyValue::new("provider", dispute_alert.provider.to_string()),
+            metrics::KeyValue::new("outcome", outcome.to_string()),
+        ],
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_alert.foreign_into(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_dispute_alert(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: dispute_models::DisputeAlertId,
+) -> RouterResponse<dispute_models::DisputeAlertResponse> {
+    let dispute_alert = state
+        .store
+        .find_dispute_alert_by_merchant_id_alert_id(&merchant_account.merchant_id, &req.alert_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_alert.foreign_into(),
+    ))
+}
+
+fn validate_dispute_alert_request(req: &dispute_models::DisputeAlertRequest) -> RouterResult<()> {
+    if req.amount <= 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string(),
+        }));
+    }
+    if let Some(card_last4) = &req.card_last4 {
+        if card_last4.len() != 4 || !card_last4.chars().all(|c| c.is_ascii_digit()) {
+            return Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "card_last4".to_string(),
+                expected_format: "last four digits of the card".to_string(),
+            }));
+        }
+    }
+    if req.arn.is_none() && req.card_last4.is_none() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Either arn or card_last4 is required to match the alert to a payment"
+                .to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+async fn process_dispute_alert(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute_alert: &storage::DisputeAlert,
+) -> RouterResult<storage::DisputeAlertUpdate> {
+    let db = state.store.as_ref();
+    let payment_attempt = match find_payment_attempt(db, merchant_account, dispute_alert).await? {
+        AlertMatch::Matched(payment_attempt) => payment_attempt,
+        AlertMatch::NotFound => {
+            return Ok(get_outcome_update(
+                None,
+                None,
+                None,
+                storage_enums::DisputeAlertOutcome::NotMatched,
+                Some("No payment matches the details of the alert".to_string()),
+            ))
+        }
+        AlertMatch::Ambiguous(count) => {
+            return Ok(get_outcome_update(
+                None,
+                None,
+                None,
+                storage_enums::DisputeAlertOutcome::NotMatched,
+                Some(format!("{count} payments match the details of the alert")),
+            ))
+        }
+    };
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_attempt.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let outcome_update = |refund_id, outcome, error_message| {
+        get_outcome_update(
+            Some(payment_attempt.as_ref()),
+            payment_intent.profile_id.clone(),
+            refund_id,
+            outcome,
+            error_message,
+        )
+    };
+
+    // The refund of an alert whose processing did not complete may already have been created
+    let refund_id = get_dispute_alert_refund_id(&dispute_alert.alert_id);
+    match db
+        .find_refund_by_merchant_id_refund_id(
+            &merchant_account.merchant_id,
+            &refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(refund) => {
+            let (outcome, error_message) = get_refund_outcome(&refund.clone().foreign_into());
+            return Ok(outcome_update(
+                Some(refund.refund_id),
+                outcome,
+                error_message,
+            ));
+        }
+        Err(error) if error.current_context().is_db_not_found() => {}
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch refund of dispute alert")?,
+    }
+
+    // Refunding a payment which is already disputed would only add to the loss, as the
+    // chargeback is not prevented anymore
+    let disputes = db
+        .find_disputes_by_merchant_id_payment_id(
+            &merchant_account.merchant_id,
+            &payment_attempt.payment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch disputes of the payment")?;
+    if !disputes.is_empty() {
+        return Ok(outcome_update(
+            None,
+            storage_enums::DisputeAlertOutcome::AlreadyDisputed,
+            None,
+        ));
+    }
+
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_attempt.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch refunds of the payment")?;
+    let refunded_amount: i64 = refunds
+        .iter()
+        .filter(|refund| {
+            !matches!(
+                refund.refund_status,
+                storage_enums::RefundStatus::Failure
+                    | storage_enums::RefundStatus::TransactionFailure
+            )
+        })
+        .map(|refund| refund.refund_amount)
+        .sum();
+    let refundable_amount = payment_intent
+        .amount_captured
+        .unwrap_or(payment_attempt.amount)
+        - refunded_amount;
+    if refundable_amount <= 0 {
+        return Ok(outcome_update(
+            None,
+            storage_enums::DisputeAlertOutcome::AlreadyRefunded,
+            None,
+        ));
+    }
+    let refund_amount = std::cmp::min(dispute_alert.amount, refundable_amount);
+
+    let dispute_alert_policy =
+        find_dispute_alert_policy(db, payment_intent.profile_id.as_deref()).await?;
+    let is_auto_refund_allowed = dispute_alert_policy.is_some_and(|policy| {
+        policy.auto_refund
+            && policy
+                .max_refund_amount
+                .map_or(true, |max_refund_amount| refund_amount <= max_refund_amount)
+    });
+    if !is_auto_refund_allowed {
+        return Ok(outcome_update(
+            None,
+            storage_enums::DisputeAlertOutcome::ManualReview,
+            None,
+        ));
+    }
+
+    let refund_request = refunds_api::RefundRequest {
+        // A deterministic refund id prevents refunding the payment twice for the same alert
+        refund_id: Some(refund_id),
+        payment_id: payment_attempt.payment_id.clone(),
+        amount: Some(refund_amount),
+        reason: Some(format!(
+            "Pre-dispute alert {} from {}",
+            dispute_alert.provider_alert_id, dispute_alert.provider
+        )),
+        ..Default::default()
+    };
+    let refund_result = Box::pin(refunds::refund_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        None,
+        refund_request,
+    ))
+    .await;
+
+    Ok(match refund_result {
+        Ok(services::ApplicationResponse::Json(refund)) => {
+            let (outcome, error_message) = get_refund_outcome(&refund);
+            outcome_update(Some(refund.refund_id), outcome, error_message)
+        }
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to get the refund response in json")?,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                alert_id = %dispute_alert.alert_id,
+                "Failed to refund payment for dispute alert"
+            );
+            outcome_update(
+                None,
+                storage_enums::DisputeAlertOutcome::RefundFailed,
+                Some(error.current_context().to_string()),
+            )
+        }
+    })
+}
+
+fn get_dispute_alert_refund_id(alert_id: &str) -> String {
+    format!("{alert_id}_refund")
+}
+
+fn get_refund_outcome(
+    refund: &refunds_api::RefundResponse,
+) -> (storage_enums::DisputeAlertOutcome, Option<String>) {
+    match refund.status {
+        refunds_api::RefundStatus::Failed | refunds_api::RefundStatus::Review => (
+            storage_enums::DisputeAlertOutcome::RefundFailed,
+            refund.error_message.clone(),
+        ),
+        // The refund is only made once it has been approved
+        refunds_api::RefundStatus::PendingApproval => {
+            (storage_enums::DisputeAlertOutcome::ManualReview, None)
+        }
+        refunds_api::RefundStatus::Succeeded | refunds_api::RefundStatus::Pending => {
+            (storage_enums::DisputeAlertOutcome::Refunded, None)
+        }
+    }
+}
+
+/// Matches the alert to a payment attempt by the acquirer reference number returned by the
+/// connector first, and then by the card, amount and date of the transaction
+async fn find_payment_attempt(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    dispute_alert: &storage::DisputeAlert,
+) -> RouterResult<AlertMatch> {
+    if let Some(arn) = &dispute_alert.arn {
+        match db
+            .find_payment_attempt_by_merchant_id_acquirer_reference_number(
+                &merchant_account.merchant_id,
+                arn,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_attempt) if payment_attempt.currency == Some(dispute_alert.currency) => {
+                return Ok(AlertMatch::Matched(Box::new(payment_attempt)))
+            }
+            Ok(_) => {}
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch payment attempt by acquirer reference")?,
+        }
+    }
+
+    let Some(card_last4) = &dispute_alert.card_last4 else {
+        return Ok(AlertMatch::NotFound);
+    };
+    let match_window = time::Duration::hours(consts::DISPUTE_ALERT_MATCH_WINDOW_HOURS);
+    let payment_attempts = db
+        .find_attempts_by_merchant_id_amount_currency_time_range(
+            &merchant_account.merchant_id,
+            dispute_alert.amount,
+            dispute_alert.currency,
+            dispute_alert.transaction_date.saturating_sub(match_window),
+            dispute_alert.transaction_date.saturating_add(match_window),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment attempts by amount")?;
+    let mut matched_attempts = payment_attempts
+        .into_iter()
+        .filter(|payment_attempt| {
+            matches!(
+                payment_attempt.status,
+                storage_enums::AttemptStatus::Charged
+                    | storage_enums::AttemptStatus::PartialCharged
+            ) && get_card_last4(payment_attempt).as_ref() == Some(card_last4)
+        })
+        .collect::<Vec<_>>();
+
+    Ok(match matched_attempts.len() {
+        0 => AlertMatch::NotFound,
+        1 => matched_attempts
+            .pop()
+            .map(|payment_attempt| AlertMatch::Matched(Box::new(payment_attempt)))
+            .unwrap_or(AlertMatch::NotFound),
+        count => AlertMatch::Ambiguous(count),
+    })
+}
+
+fn get_card_last4(payment_attempt: &storage::PaymentAttempt) -> Option<String> {
+    payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => card_info.last4,
+            _ => None,
+        })
+}
+
+async fn find_dispute_alert_policy(
+    db: &dyn StorageInterface,
+    profile_id: Option<&str>,
+) -> RouterResult<Option<DisputeAlertPolicy>> {
+    let Some(profile_id) = profile_id else {
+        return Ok(None);
+    };
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    business_profile
+        .dispute_alert_policy
+        .map(|dispute_alert_policy| {
+            dispute_alert_policy.parse_value::<DisputeAlertPolicy>("DisputeAlertPolicy")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse dispute alert policy")
+}
+
+fn get_outcome_update(
+    payment_attempt: Option<&storage::PaymentAttempt>,
+    profile_id: Option<String>,
+    refund_id: Option<String>,
+    outcome: storage_enums::DisputeAlertOutcome,
+    error_message: Option<String>,
+) -> storage::DisputeAlertUpdate {
+    storage::DisputeAlertUpdate::OutcomeUpdate {
+        payment_id: payment_attempt.map(|payment_attempt| payment_attempt.payment_id.clone()),
+        attempt_id: payment_attempt.map(|payment_attempt| payment_attempt.attempt_id.clone()),
+        profile_id,
+        refund_id,
+        outcome,
+        error_message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_dispute_alert_request(
+        arn: Option<&str>,
+        card_last4: Option<&str>,
+        amount: i64,
+    ) -> dispute_models::DisputeAlertRequest {
+        serde_json::from_value(serde_json::json!({
+            "provider": "ethoca",
+            "provider_alert_id": "ETH-1234567890",
+            "arn": arn,
+            "card_last4": card_last4,
+            "amount": amount,
+            "currency": "USD",
+            "transaction_date": "2024-05-10T10:11:12Z"
+        }))
+        .unwrap()
+    }
+
+    fn get_refund_response(status: &str) -> refunds_api::RefundResponse {
+        serde_json::from_value(serde_json::json!({
+            "refund_id": "alert_1_refund",
+            "payment_id": "pay_1",
+            "amount": 6540,
+            "currency": "USD",
+            "status": status,
+            "error_message": "Refund declined",
+            "created_at": null,
+            "updated_at": null,
+            "connector": "stripe"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_dispute_alert_refund_id_is_deterministic() {
+        assert_eq!(
+            get_dispute_alert_refund_id("alert_abc"),
+            get_dispute_alert_refund_id("alert_abc")
+        );
+        assert_ne!(
+            get_dispute_alert_refund_id("alert_abc"),
+            get_dispute_alert_refund_id("alert_def")
+        );
+    }
+
+    #[test]
+    fn test_get_refund_outcome() {
+        assert_eq!(
+            get_refund_outcome(&get_refund_response("succeeded")),
+            (storage_enums::DisputeAlertOutcome::Refunded, None)
+        );
+        assert_eq!(
+            get_refund_outcome(&get_refund_response("pending")),
+            (storage_enums::DisputeAlertOutcome::Refunded, None)
+        );
+        assert_eq!(
+            get_refund_outcome(&get_refund_response("pending_approval")),
+            (storage_enums::DisputeAlertOutcome::ManualReview, None)
+        );
+        assert_eq!(
+            get_refund_outcome(&get_refund_response("failed")),
+            (
+                storage_enums::DisputeAlertOutcome::RefundFailed,
+                Some("Refund declined".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_validate_dispute_alert_request() {
+        assert!(validate_dispute_alert_request(&get_dispute_alert_request(
+            Some("74537604221431003881865"),
+            None,
+            6540
+        ))
+        .is_ok());
+        assert!(validate_dispute_alert_request(&get_dispute_alert_request(
+            None,
+            Some("4242"),
+            6540
+        ))
+        .is_ok());
+        assert!(
+            validate_dispute_alert_request(&get_dispute_alert_request(None, None, 6540)).is_err()
+        );
+        assert!(validate_dispute_alert_request(&get_dispute_alert_request(
+            None,
+            Some("42a2"),
+            6540
+        ))
+        .is_err());
+        assert!(
+            validate_dispute_alert_request(&get_dispute_alert_request(None, Some("4242"), 0))
+                .is_err()
+        );
+    }
+}
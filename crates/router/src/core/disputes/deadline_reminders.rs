@@ -0,0 +1,210 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::{metrics, AppState},
+    types::storage::{self, enums as storage_enums},
+};
+
+pub const DISPUTE_DEADLINE_REMINDER_TASK: &str = "DISPUTE_DEADLINE_REMINDER";
+
+/// Tracking data stored against the dispute deadline reminder process tracker entry
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DisputeDeadlineReminderTrackingData {
+    pub dispute_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub challenge_required_by: PrimitiveDateTime,
+    /// Offsets in hours before the deadline at which reminders are sent, in decreasing order
+    pub reminder_offset_hours: Vec<u16>,
+    /// Index of the offset whose reminder is sent on the next run of the task. This is tracked
+    /// separately from the retry count of the task, so that retries after errors do not skip
+    /// reminders.
+    #[serde(default)]
+    pub next_reminder_index: usize,
+}
+
+impl DisputeDeadlineReminderTrackingData {
+    pub fn get_reminder_time(&self, offset_hours: u16) -> PrimitiveDateTime {
+        self.challenge_required_by
+            .saturating_sub(time::Duration::hours(i64::from(offset_hours)))
+    }
+
+    pub fn get_next_reminder_offset_hours(&self) -> Option<u16> {
+        self.reminder_offset_hours
+            .get(self.next_reminder_index)
+            .copied()
+    }
+}
+
+/// Schedules reminders ahead of the challenge deadline of an open dispute. If a reminder task
+/// already exists for the dispute and the connector has moved the deadline, the task is reset to
+/// follow the new deadline.
+pub async fn add_or_update_dispute_deadline_reminder_task(
+    state: &AppState,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let (Some(challenge_required_by), Some(profile_id)) =
+        (dispute.challenge_required_by, dispute.profile_id.clone())
+    else {
+        return Ok(());
+    };
+    let current_time = common_utils::date_time::now();
+    if dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+        || challenge_required_by <= current_time
+    {
+        return Ok(());
+    }
+
+    let configured_offsets = &state.conf.dispute_reminders.reminder_offset_hours;
+    let mut tracking_data = DisputeDeadlineReminderTrackingData {
+        dispute_id: dispute.dispute_id.clone(),
+        merchant_id: dispute.merchant_id.clone(),
+        profile_id,
+        challenge_required_by,
+        reminder_offset_hours: Vec::new(),
+        next_reminder_index: 0,
+    };
+    // Reminders whose time has already passed are skipped, other than the closest one to the
+    // deadline, which is sent right away
+    tracking_data.reminder_offset_hours = configured_offsets
+        .iter()
+        .copied()
+        .filter(|offset| tracking_data.get_reminder_time(*offset) > current_time)
+        .collect();
+    if tracking_data.reminder_offset_hours.is_empty() {
+        match configured_offsets.last() {
+            Some(offset) => tracking_data.reminder_offset_hours.push(*offset),
+            None => return Ok(()),
+        }
+    }
+    let schedule_time = std::cmp::max(
+        tracking_data.get_reminder_time(tracking_data.reminder_offset_hours[0]),
+        current_time,
+    );
+
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::DisputeDeadlineReminderWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        DISPUTE_DEADLINE_REMINDER_TASK,
+        &dispute.dispute_id,
+        &dispute.merchant_id,
+    );
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch dispute deadline reminder task")?;
+
+    match existing_process {
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                DISPUTE_DEADLINE_REMINDER_TASK,
+                runner,
+                ["DISPUTES", "DEADLINE_REMINDER"],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to construct dispute deadline reminder process tracker task",
+            )?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert dispute deadline reminder task")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "flow",
+                    "DisputeDeadlineReminder",
+                )],
+            );
+        }
+        Some(process) => {
+            let existing_tracking_data = process
+                .tracking_data
+                .clone()
+                .parse_value::<DisputeDeadlineReminderTrackingData>(
+                    "DisputeDeadlineReminderTrackingData",
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            if existing_tracking_data.challenge_required_by == challenge_required_by {
+                return Ok(());
+            }
+
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(
+                    tracking_data
+                        .encode_to_value()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                ),
+                business_status: Some("Pending".to_string()),
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.as_scheduler()
+                .update_process(process, updated_process_tracker_data)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reset dispute deadline reminder task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "flow",
+                    "DisputeDeadlineReminder",
+                )],
+            );
+        }
+    }
+
+    logger::debug!(
+        dispute_id = %dispute.dispute_id,
+        ?challenge_required_by,
+        "Scheduled dispute deadline reminder task"
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_next_reminder_index_is_independent_of_retries() {
+        let mut tracking_data: DisputeDeadlineReminderTrackingData =
+            serde_json::from_value(serde_json::json!({
+                "dispute_id": "dp_123",
+                "merchant_id": "merchant_123",
+                "profile_id": "pro_123",
+                "challenge_required_by": "2024-06-10T00:00:00.000Z",
+                "reminder_offset_hours": [72, 24],
+            }))
+            .unwrap();
+
+        assert_eq!(tracking_data.next_reminder_index, 0);
+        assert_eq!(tracking_data.get_next_reminder_offset_hours(), Some(72));
+
+        tracking_data.next_reminder_index += 1;
+        assert_eq!(tracking_data.get_next_reminder_offset_hours(), Some(24));
+
+        tracking_data.next_reminder_index += 1;
+        assert_eq!(tracking_data.get_next_reminder_offset_hours(), None);
+    }
+}
@@ -0,0 +1,468 @@
+use api_models::{disputes as dispute_models, payments as payment_types};
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as files_helpers,
+        payment_methods::Oss,
+        payments,
+    },
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, disputes},
+        domain, storage,
+    },
+};
+
+/// The maximum number of characters in a line of the receipt
+const RECEIPT_LINE_WIDTH: usize = 90;
+/// The maximum number of lines which fit on the single page of the receipt
+const RECEIPT_MAX_LINES: usize = 55;
+
+/// Pre-fills the evidence for a dispute from the details of the disputed payment: a receipt file,
+/// the addresses and contact details of the customer, and the 3DS and address / card security
+/// code verification results. The assembled evidence is returned for the merchant to review and
+/// complete before submitting it.
+#[instrument(skip(state))]
+pub async fn assemble_dispute_evidence(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: disputes::DisputeId,
+) -> RouterResponse<dispute_models::SubmitEvidenceRequest> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id,
+        })?;
+    let evidence = assemble_evidence(&state, &merchant_account, &key_store, &dispute).await?;
+
+    Ok(services::ApplicationResponse::Json(evidence))
+}
+
+pub async fn assemble_evidence(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+) -> RouterResult<dispute_models::SubmitEvidenceRequest> {
+    let payments_response = retrieve_payment(state, merchant_account, key_store, dispute).await?;
+    let receipt = store_receipt(
+        state,
+        merchant_account,
+        key_store,
+        dispute,
+        &payments_response,
+    )
+    .await?;
+
+    let customer = payments_response.customer.as_ref();
+    let billing = payments_response.billing.as_ref();
+    let customer_email_address = customer
+        .and_then(|customer| customer.email.as_ref())
+        .or_else(|| billing.and_then(|billing| billing.email.as_ref()))
+        .map(|email| email.peek().to_owned());
+    let customer_name = customer
+        .and_then(|customer| customer.name.as_ref())
+        .map(|name| name.peek().to_owned())
+        .or_else(|| {
+            billing
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.get_optional_full_name())
+                .map(|name| name.peek().to_owned())
+        });
+    let customer_purchase_ip = payments_response
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            browser_info
+                .parse_value::<types::BrowserInformation>("BrowserInformation")
+                .ok()
+        })
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| ip_address.to_string());
+
+    Ok(dispute_models::SubmitEvidenceRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        receipt: Some(receipt),
+        billing_address: billing.and_then(format_address),
+        shipping_address: payments_response.shipping.as_ref().and_then(format_address),
+        customer_email_address,
+        customer_name,
+        customer_purchase_ip,
+        product_description: payments_response.description.clone(),
+        uncategorized_text: get_verification_details(&payments_response),
+        ..Default::default()
+    })
+}
+
+async fn retrieve_payment(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+) -> RouterResult<api::PaymentsResponse> {
+    let payments_response = Box::pin(payments::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payments::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentAttemptId(dispute.attempt_id.clone()),
+            merchant_id: Some(dispute.merchant_id.clone()),
+            force_sync: false,
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Avoid,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    match payments_response {
+        services::ApplicationResponse::JsonWithHeaders((payments_response, _))
+        | services::ApplicationResponse::Json(payments_response) => Ok(payments_response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payments retrieve core"),
+    }
+}
+
+/// Returns the id of the receipt file of a dispute, which is generated once and reused every
+/// time the evidence of the dispute is assembled
+fn get_receipt_file_id(dispute_id: &str) -> String {
+    format!("file_{dispute_id}_receipt")
+}
+
+/// Generates a PDF receipt of the disputed payment and uploads it to the file storage of the
+/// connector, so that it can be submitted as the receipt evidence. A receipt which was already
+/// uploaded for the dispute is reused.
+async fn store_receipt(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+    payments_response: &api::PaymentsResponse,
+) -> RouterResult<String> {
+    let db = &*state.store;
+    let file_id = get_receipt_file_id(&dispute.dispute_id);
+    let file_name = format!("{}_receipt.pdf", dispute.payment_id);
+    let content = generate_receipt_pdf(&get_receipt_lines(dispute, payments_response));
+    let file_size = i32::try_from(content.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Dispute receipt file size error")?;
+
+    let file_metadata = match db
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+    {
+        Ok(file_metadata) if file_metadata.available => return Ok(file_id),
+        Ok(file_metadata) => file_metadata,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_file_metadata(diesel_models::file::FileMetadataNew {
+                file_id: file_id.clone(),
+                merchant_id: dispute.merchant_id.clone(),
+                file_name: Some(file_name.clone()),
+                file_size,
+                file_type: mime::APPLICATION_PDF.to_string(),
+                provider_file_id: None,
+                file_upload_provider: None,
+                available: false,
+                connector_label: None,
+                profile_id: dispute.profile_id.clone(),
+                merchant_connector_id: dispute.merchant_connector_id.clone(),
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to insert file_metadata")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the dispute receipt file_metadata")?,
+    };
+
+    let create_file_request = api::CreateFileRequest {
+        file: content,
+        file_name: Some(file_name),
+        file_size,
+        file_type: mime::APPLICATION_PDF,
+        purpose: api::FilePurpose::DisputeEvidence,
+        dispute_id: Some(dispute.dispute_id.clone()),
+    };
+    files_helpers::validate_file_upload(
+        state,
+        merchant_account.clone(),
+        create_file_request.clone(),
+    )
+    .await?;
+    let (provider_file_id, file_upload_provider, profile_id, merchant_connector_id) =
+        files_helpers::upload_and_get_provider_provider_file_id_profile_id(
+            state,
+            merchant_account,
+            key_store,
+            &create_file_request,
+            format!("{}/{}", dispute.merchant_id, file_id),
+        )
+        .await?;
+    db.update_file_metadata(
+        file_metadata,
+        diesel_models::file::FileMetadataUpdate::Update {
+            provider_file_id: Some(provider_file_id),
+            file_upload_provider: Some(file_upload_provider),
+            available: true,
+            profile_id,
+            merchant_connector_id,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to update file_metadata")?;
+
+    Ok(file_id)
+}
+
+fn get_receipt_lines(
+    dispute: &storage::Dispute,
+    payments_response: &api::PaymentsResponse,
+) -> Vec<String> {
+    let mut lines = vec![
+        "Payment receipt".to_string(),
+        String::new(),
+        format!("Payment ID: {}", dispute.payment_id),
+        format!(
+            "Amount: {} {}",
+            payments_response.amount, payments_response.currency
+        ),
+        format!("Status: {}", payments_response.status),
+    ];
+    if let Some(created) = payments_response.created {
+        lines.push(format!("Date: {created} UTC"));
+    }
+    if let Some(payment_method) = payments_response.payment_method {
+        lines.push(format!("Payment method: {payment_method}"));
+    }
+    if let Some(connector_transaction_id) = &payments_response.connector_transaction_id {
+        lines.push(format!("Transaction reference: {connector_transaction_id}"));
+    }
+    if let Some(description) = &payments_response.description {
+        lines.push(format!("Description: {description}"));
+    }
+    if let Some(statement_descriptor_name) = &payments_response.statement_descriptor_name {
+        lines.push(format!("Statement descriptor: {statement_descriptor_name}"));
+    }
+    lines
+}
+
+/// Renders the lines as a single page PDF document using the standard Helvetica font, as the
+/// connectors only accept images and PDF documents as dispute evidence. Characters which are not
+/// printable ASCII are replaced, and lines which do not fit on the page are wrapped.
+fn generate_receipt_pdf(lines: &[String]) -> Vec<u8> {
+    let mut text = String::from("BT\n/F1 11 Tf\n14 TL\n50 800 Td\n");
+    for line in lines
+        .iter()
+        .flat_map(|line| wrap_receipt_line(line))
+        .take(RECEIPT_MAX_LINES)
+    {
+        text.push('(');
+        for character in line.chars() {
+            match character {
+                '\\' | '(' | ')' => {
+                    text.push('\\');
+                    text.push(character);
+                }
+                ' '..='~' => text.push(character),
+                _ => text.push('?'),
+            }
+        }
+        text.push_str(") Tj T*\n");
+    }
+    text.push_str("ET");
+
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 595 842] /Contents 4 0 R \
+         /Resources << /Font << /F1 5 0 R >> >> >>"
+            .to_string(),
+        format!("<< /Length {} >>\nstream\n{text}\nendstream", text.len()),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+    ];
+
+    let mut document = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(document.len());
+        document.push_str(&format!("{} 0 obj\n{object}\nendobj\n", index + 1));
+    }
+    let xref_offset = document.len();
+    document.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+    for offset in offsets {
+        document.push_str(&format!("{offset:010} 00000 n \n"));
+    }
+    document.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        objects.len() + 1
+    ));
+
+    document.into_bytes()
+}
+
+fn wrap_receipt_line(line: &str) -> Vec<String> {
+    let characters = line.chars().collect::<Vec<_>>();
+    if characters.is_empty() {
+        return vec![String::new()];
+    }
+    characters
+        .chunks(RECEIPT_LINE_WIDTH)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+fn format_address(address: &payment_types::Address) -> Option<String> {
+    let details = address.address.as_ref()?;
+    let parts = [
+        details.line1.as_ref().map(|line| line.peek().to_owned()),
+        details.line2.as_ref().map(|line| line.peek().to_owned()),
+        details.line3.as_ref().map(|line| line.peek().to_owned()),
+        details.city.clone(),
+        details.state.as_ref().map(|state| state.peek().to_owned()),
+        details.zip.as_ref().map(|zip| zip.peek().to_owned()),
+        details.country.map(|country| country.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|part| !part.is_empty())
+    .collect::<Vec<_>>();
+
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Describes the 3DS authentication and the address / card security code verification results of
+/// the payment, which are commonly used to contest fraud disputes
+fn get_verification_details(payments_response: &api::PaymentsResponse) -> Option<String> {
+    let mut lines = Vec::new();
+    if let Some(authentication) = &payments_response.authentication_details {
+        lines.push(format!(
+            "The payment was authenticated using 3DS{}.",
+            authentication
+                .version
+                .as_ref()
+                .map(|version| format!(" version {version}"))
+                .unwrap_or_default()
+        ));
+        if let Some(trans_status) = &authentication.trans_status {
+            lines.push(format!("3DS transaction status: {trans_status}"));
+        }
+        if let Some(eci) = &authentication.electronic_commerce_indicator {
+            lines.push(format!("Electronic Commerce Indicator (ECI): {eci}"));
+        }
+        if let Some(ds_transaction_id) = &authentication.ds_transaction_id {
+            lines.push(format!(
+                "Directory server transaction ID: {ds_transaction_id}"
+            ));
+        }
+        if let Some(liability_shift) = authentication.liability_shift {
+            lines.push(format!("Liability shifted to issuer: {liability_shift}"));
+        }
+    }
+    if let Some(verification) = &payments_response.card_verification_results {
+        let checks = [
+            (
+                "Address verification (street)",
+                verification.avs_street_match,
+            ),
+            (
+                "Address verification (postal code)",
+                verification.avs_postal_code_match,
+            ),
+            ("Card security code verification", verification.cvv_match),
+        ];
+        for (check, result) in checks {
+            if let Some(result) = result {
+                lines.push(format!("{check}: {}", describe_check_result(result)));
+            }
+        }
+        if let Some(avs_raw_code) = &verification.avs_raw_code {
+            lines.push(format!("AVS result code: {avs_raw_code}"));
+        }
+        if let Some(cvv_raw_code) = &verification.cvv_raw_code {
+            lines.push(format!("CVV result code: {cvv_raw_code}"));
+        }
+    }
+
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+fn describe_check_result(result: payment_types::CardVerificationCheckResult) -> &'static str {
+    match result {
+        payment_types::CardVerificationCheckResult::Match => "Match",
+        payment_types::CardVerificationCheckResult::NoMatch => "No match",
+        payment_types::CardVerificationCheckResult::NotChecked => "Not checked",
+        payment_types::CardVerificationCheckResult::Unavailable => "Unavailable",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_generate_receipt_pdf() {
+        let pdf = generate_receipt_pdf(&[
+            "Payment ID: pay_123".to_string(),
+            "Description: (gift) \\ caf\u{e9}".to_string(),
+        ]);
+        let document = String::from_utf8(pdf).unwrap();
+
+        assert!(document.starts_with("%PDF-1.4\n"));
+        assert!(document.ends_with("%%EOF\n"));
+        assert!(document.contains("(Payment ID: pay_123) Tj"));
+        assert!(document.contains("(Description: \\(gift\\) \\\\ caf?) Tj"));
+
+        let xref_offset = document.find("xref\n").unwrap();
+        assert!(document.contains(&format!("startxref\n{xref_offset}\n")));
+        for (index, entry) in document[xref_offset..].lines().skip(3).take(5).enumerate() {
+            let offset = entry[..10].parse::<usize>().unwrap();
+            assert!(document[offset..].starts_with(&format!("{} 0 obj", index + 1)));
+        }
+    }
+
+    #[test]
+    fn test_wrap_receipt_line() {
+        assert_eq!(wrap_receipt_line(""), vec![String::new()]);
+        let wrapped = wrap_receipt_line(&"a".repeat(RECEIPT_LINE_WIDTH + 1));
+        assert_eq!(wrapped.len(), 2);
+        assert_eq!(wrapped[1], "a");
+    }
+
+    #[test]
+    fn test_describe_check_result() {
+        assert_eq!(
+            describe_check_result(payment_types::CardVerificationCheckResult::NoMatch),
+            "No match"
+        );
+        assert_eq!(
+            describe_check_result(payment_types::CardVerificationCheckResult::NotChecked),
+            "Not checked"
+        );
+    }
+}
@@ -0,0 +1,557 @@
+use std::str::FromStr;
+
+use api_models::disputes::{
+    DisputePolicyAction, DisputePolicyDecision, DisputePolicyOutput, DisputePolicyRecord,
+    DisputePolicyRequest, DisputePolicyResponse,
+};
+use common_utils::ext_traits::{Encode, StringExt, ValueExt};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use euclid::{
+    backend::{self, inputs as dsl_inputs, EuclidBackend},
+    enums as euclid_enums,
+    frontend::ast,
+};
+use router_env::{instrument, logger, tracing};
+
+use super::evidence_assembly;
+use crate::{
+    consts,
+    core::{
+        api_locking, disputes,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        metrics, utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{lock_utils, AppState},
+    services,
+    types::{
+        api::disputes as dispute_types,
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{self, OptionExt},
+};
+
+fn get_dispute_policy_key(profile_id: &str) -> String {
+    format!("dispute_policy_{profile_id}")
+}
+
+async fn find_dispute_policy(
+    db: &dyn StorageInterface,
+    profile_id: &str,
+) -> RouterResult<Option<DisputePolicyRecord>> {
+    match db
+        .find_config_by_key(&get_dispute_policy_key(profile_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("DisputePolicyRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse dispute policy record")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch dispute policy"),
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn upsert_dispute_policy(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: String,
+    request: DisputePolicyRequest,
+) -> RouterResponse<DisputePolicyRecord> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        Some(&profile_id),
+        &merchant_account.merchant_id,
+    )
+    .await?;
+
+    let program = request
+        .program
+        .get_required_value("program")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "program",
+        })
+        .attach_printable("Program for dispute policy not given")?;
+    ast::lowering::lower_program(program.clone())
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid Request Data".to_string(),
+        })
+        .attach_printable("The Request has an Invalid Comparison")?;
+
+    let key = get_dispute_policy_key(&profile_id);
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let record = match find_dispute_policy(db, &profile_id).await? {
+        Some(previous_record) => {
+            let record = DisputePolicyRecord {
+                name: request.name.unwrap_or(previous_record.name),
+                profile_id,
+                program,
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+            let config = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize dispute policy to string")?;
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update dispute policy")?;
+            record
+        }
+        None => {
+            let record = DisputePolicyRecord {
+                name: request.name.get_required_value("name").change_context(
+                    errors::ApiErrorResponse::MissingRequiredField { field_name: "name" },
+                )?,
+                profile_id,
+                program,
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+            let config = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize dispute policy to string")?;
+            db.insert_config(configs::ConfigNew { key, config })
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert dispute policy")?;
+            record
+        }
+    };
+
+    Ok(services::ApplicationResponse::Json(record))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_dispute_policy(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: String,
+) -> RouterResponse<DisputePolicyResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        Some(&profile_id),
+        &merchant_account.merchant_id,
+    )
+    .await?;
+
+    let record = find_dispute_policy(db, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("The dispute policy was not found in the DB")?;
+
+    Ok(services::ApplicationResponse::Json(record))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_dispute_policy(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: String,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        Some(&profile_id),
+        &merchant_account.merchant_id,
+    )
+    .await?;
+
+    db.delete_config_by_key(&get_dispute_policy_key(&profile_id))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Failed to delete dispute policy from DB")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Builds the input for evaluating the dispute policy. Apart from the payment method details, the
+/// connector and reason code of the dispute are made available as metadata keys.
+fn make_dsl_input(
+    dispute: &storage::Dispute,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<dsl_inputs::BackendInput> {
+    let currency = euclid_enums::Currency::from_str(&dispute.currency)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the currency of the dispute")?;
+    let card_network = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => card_info.card_network,
+            _ => None,
+        });
+
+    Ok(dsl_inputs::BackendInput {
+        metadata: Some(get_dsl_metadata(
+            &dispute.connector,
+            dispute.connector_reason_code.as_deref(),
+        )),
+        payment: dsl_inputs::PaymentInput {
+            amount: dispute.dispute_amount,
+            currency,
+            authentication_type: None,
+            card_bin: None,
+            capture_method: None,
+            business_country: None,
+            billing_country: None,
+            business_label: None,
+            setup_future_usage: None,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: payment_attempt.payment_method,
+            payment_method_type: payment_attempt.payment_method_type,
+            card_network,
+        },
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: None,
+        },
+    })
+}
+
+fn get_dsl_metadata(
+    connector: &str,
+    reason_code: Option<&str>,
+) -> rustc_hash::FxHashMap<String, String> {
+    [
+        Some(("connector".to_string(), connector.to_owned())),
+        reason_code.map(|reason_code| ("reason_code".to_string(), reason_code.to_owned())),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn evaluate_dispute_policy(
+    program: ast::Program<DisputePolicyOutput>,
+    input: dsl_inputs::BackendInput,
+) -> RouterResult<backend::BackendOutput<DisputePolicyOutput>> {
+    let interpreter: backend::VirInterpreterBackend<DisputePolicyOutput> =
+        backend::VirInterpreterBackend::with_program(program)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error initializing dispute policy interpreter backend")?;
+    interpreter
+        .execute(input)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error executing dispute policy")
+}
+
+/// A dispute policy acts once on a dispute, when it is opened
+fn is_dispute_policy_applicable(
+    dispute_stage: storage_enums::DisputeStage,
+    dispute_status: storage_enums::DisputeStatus,
+    has_policy_decision: bool,
+) -> bool {
+    dispute_stage == storage_enums::DisputeStage::Dispute
+        && dispute_status == storage_enums::DisputeStatus::DisputeOpened
+        && !has_policy_decision
+}
+
+fn get_dispute_policy_lock_action(dispute_id: &str) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: dispute_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Disputes,
+            override_lock_retries: None,
+        },
+    }
+}
+
+/// Evaluates the dispute policy of the business profile against a newly opened dispute, performs
+/// the resulting action and records the decision against the dispute for audit
+#[instrument(skip_all)]
+pub async fn apply_dispute_policy(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_attempt: &storage::PaymentAttempt,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    if !is_dispute_policy_applicable(
+        dispute.dispute_stage,
+        dispute.dispute_status,
+        dispute.policy_decision.is_some(),
+    ) {
+        return Ok(());
+    }
+
+    // The policy may be applied concurrently by the incoming webhook and the dispute sync, hence
+    // the lock is held until the decision is recorded, so that the action is performed only once
+    let lock_action = get_dispute_policy_lock_action(&dispute.dispute_id);
+    let lock_holder = Some(utils::generate_id(consts::ID_LENGTH, "dispute_policy"));
+    lock_action
+        .clone()
+        .perform_locking_action_for_holder(
+            state,
+            merchant_account.merchant_id.clone(),
+            lock_holder.clone(),
+        )
+        .await?;
+
+    let result = Box::pin(apply_dispute_policy_under_lock(
+        state,
+        merchant_account,
+        key_store,
+        payment_attempt,
+        dispute,
+    ))
+    .await;
+
+    lock_action
+        .free_lock_action_for_holder(state, merchant_account.merchant_id.clone(), lock_holder)
+        .await?;
+    result
+}
+
+async fn apply_dispute_policy_under_lock(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_attempt: &storage::PaymentAttempt,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+
+    // The dispute is fetched again after acquiring the lock, since the policy may have been
+    // applied in the meantime
+    let dispute = &db
+        .find_dispute_by_merchant_id_dispute_id(&dispute.merchant_id, &dispute.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        })?;
+    if !is_dispute_policy_applicable(
+        dispute.dispute_stage,
+        dispute.dispute_status,
+        dispute.policy_decision.is_some(),
+    ) {
+        return Ok(());
+    }
+    let Some(profile_id) = dispute.profile_id.as_deref() else {
+        return Ok(());
+    };
+    let Some(policy) = find_dispute_policy(db, profile_id).await? else {
+        return Ok(());
+    };
+
+    let output =
+        evaluate_dispute_policy(policy.program, make_dsl_input(dispute, payment_attempt)?)?;
+    let Some(action) = output.connector_selection.action else {
+        return Ok(());
+    };
+
+    let action_result = match action {
+        DisputePolicyAction::AutoAccept => Box::pin(disputes::accept_dispute(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            dispute_types::DisputeId {
+                dispute_id: dispute.dispute_id.clone(),
+            },
+        ))
+        .await
+        .map(|_| ()),
+        DisputePolicyAction::AutoSubmitEvidence => {
+            match evidence_assembly::assemble_evidence(state, merchant_account, key_store, dispute)
+                .await
+            {
+                Ok(evidence) => Box::pin(disputes::submit_evidence(
+                    state.clone(),
+                    merchant_account.clone(),
+                    key_store.clone(),
+                    evidence,
+                ))
+                .await
+                .map(|_| ()),
+                Err(error) => Err(error),
+            }
+        }
+        DisputePolicyAction::ManualReview => Ok(()),
+    };
+    if let Err(error) = &action_result {
+        logger::error!(
+            ?error,
+            dispute_id = %dispute.dispute_id,
+            %action,
+            "Failed to perform dispute policy action"
+        );
+    }
+    metrics::DISPUTE_POLICY_ACTION_METRIC.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::KeyValue::new("action", action.to_string()),
+            metrics::KeyValue::new("succeeded", action_result.is_ok()),
+        ],
+    );
+
+    let policy_decision = DisputePolicyDecision {
+        policy_name: policy.name,
+        rule_name: output.rule_name,
+        action,
+        succeeded: action_result.is_ok(),
+        error_message: action_result
+            .err()
+            .map(|error| error.current_context().to_string()),
+        decided_at: common_utils::date_time::now(),
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize dispute policy decision")?;
+
+    // The dispute would have been updated by the action, hence the latest record is fetched
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(&dispute.merchant_id, &dispute.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        })?;
+    db.update_dispute(
+        dispute,
+        storage::DisputeUpdate::PolicyDecisionUpdate { policy_decision },
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to record dispute policy decision")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_test_program() -> ast::Program<DisputePolicyOutput> {
+        serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "action": "manual_review" },
+            "rules": [{
+                "name": "low_value_fraud",
+                "connectorSelection": { "action": "auto_accept" },
+                "statements": [{
+                    "condition": [
+                        {
+                            "lhs": "amount",
+                            "comparison": "less_than",
+                            "value": { "type": "number", "value": 1000 },
+                            "metadata": {}
+                        },
+                        {
+                            "lhs": "metadata",
+                            "comparison": "equal",
+                            "value": {
+                                "type": "metadata_variant",
+                                "value": { "key": "reason_code", "value": "10.4" }
+                            },
+                            "metadata": {}
+                        }
+                    ],
+                    "nested": null
+                }]
+            }],
+            "metadata": {}
+        }))
+        .unwrap()
+    }
+
+    fn get_test_input(amount: i64, reason_code: Option<&str>) -> dsl_inputs::BackendInput {
+        dsl_inputs::BackendInput {
+            metadata: Some(get_dsl_metadata("stripe", reason_code)),
+            payment: dsl_inputs::PaymentInput {
+                amount,
+                currency: euclid_enums::Currency::USD,
+                authentication_type: None,
+                card_bin: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: dsl_inputs::PaymentMethodInput {
+                payment_method: Some(euclid_enums::PaymentMethod::Card),
+                payment_method_type: None,
+                card_network: None,
+            },
+            mandate: dsl_inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_evaluate_dispute_policy() {
+        let output =
+            evaluate_dispute_policy(get_test_program(), get_test_input(500, Some("10.4"))).unwrap();
+        assert_eq!(output.rule_name.as_deref(), Some("low_value_fraud"));
+        assert_eq!(
+            output.connector_selection.action,
+            Some(DisputePolicyAction::AutoAccept)
+        );
+
+        let output =
+            evaluate_dispute_policy(get_test_program(), get_test_input(5000, Some("10.4")))
+                .unwrap();
+        assert_eq!(output.rule_name, None);
+        assert_eq!(
+            output.connector_selection.action,
+            Some(DisputePolicyAction::ManualReview)
+        );
+
+        let output =
+            evaluate_dispute_policy(get_test_program(), get_test_input(500, None)).unwrap();
+        assert_eq!(
+            output.connector_selection.action,
+            Some(DisputePolicyAction::ManualReview)
+        );
+    }
+
+    #[test]
+    fn test_is_dispute_policy_applicable() {
+        assert!(is_dispute_policy_applicable(
+            storage_enums::DisputeStage::Dispute,
+            storage_enums::DisputeStatus::DisputeOpened,
+            false,
+        ));
+        assert!(!is_dispute_policy_applicable(
+            storage_enums::DisputeStage::Dispute,
+            storage_enums::DisputeStatus::DisputeOpened,
+            true,
+        ));
+        assert!(!is_dispute_policy_applicable(
+            storage_enums::DisputeStage::PreDispute,
+            storage_enums::DisputeStatus::DisputeOpened,
+            false,
+        ));
+        assert!(!is_dispute_policy_applicable(
+            storage_enums::DisputeStage::Dispute,
+            storage_enums::DisputeStatus::DisputeAccepted,
+            false,
+        ));
+    }
+}
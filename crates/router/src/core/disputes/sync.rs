@@ -0,0 +1,396 @@
+use std::str::FromStr;
+
+use api_models::{enums as api_enums, webhooks};
+use common_utils::ext_traits::Encode;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use super::policies;
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResult, StorageErrorExt},
+        metrics,
+        payments::{self, access_token},
+        utils as core_utils, webhooks as webhooks_core,
+    },
+    routes::{metrics as route_metrics, AppState},
+    services,
+    types::{
+        self,
+        api::{self, disputes},
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+pub const DISPUTE_SYNC_TASK: &str = "DISPUTE_SYNC";
+
+/// Tracking data stored against the dispute sync process tracker entry of a merchant connector
+/// account
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DisputeSyncTrackingData {
+    pub merchant_id: String,
+    pub merchant_connector_id: String,
+    /// Disputes are fetched from this time onwards on the next run of the task
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_synced_at: PrimitiveDateTime,
+}
+
+/// Schedules the periodic dispute sync for a merchant connector account, if the connector is
+/// configured for dispute sync. A task which was finished because the connector account was
+/// disabled is resumed once the account is enabled again.
+pub async fn add_dispute_sync_task(
+    state: &AppState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> RouterResult<()> {
+    let is_sync_enabled =
+        api_enums::Connector::from_str(&merchant_connector_account.connector_name)
+            .map(|connector| state.conf.dispute_sync.connector_list.contains(&connector))
+            .unwrap_or(false);
+    if !is_sync_enabled || merchant_connector_account.disabled.unwrap_or(false) {
+        return Ok(());
+    }
+
+    let current_time = common_utils::date_time::now();
+    let tracking_data = DisputeSyncTrackingData {
+        merchant_id: merchant_connector_account.merchant_id.clone(),
+        merchant_connector_id: merchant_connector_account.merchant_connector_id.clone(),
+        last_synced_at: current_time.saturating_sub(time::Duration::hours(i64::from(
+            state.conf.dispute_sync.initial_lookback_hours,
+        ))),
+    };
+
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::DisputeSyncWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        DISPUTE_SYNC_TASK,
+        &merchant_connector_account.merchant_connector_id,
+        &merchant_connector_account.merchant_id,
+    );
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch dispute sync task")?;
+
+    match existing_process {
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                DISPUTE_SYNC_TASK,
+                runner,
+                ["DISPUTES", "SYNC"],
+                tracking_data,
+                current_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct dispute sync process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert dispute sync task")?;
+            route_metrics::TASKS_ADDED_COUNT.add(
+                &route_metrics::CONTEXT,
+                1,
+                &[route_metrics::request::add_attributes(
+                    "flow",
+                    "DisputeSync",
+                )],
+            );
+        }
+        Some(process) if process.status == storage_enums::ProcessTrackerStatus::Finish => {
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(current_time),
+                tracking_data: Some(
+                    tracking_data
+                        .encode_to_value()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                ),
+                business_status: Some("Pending".to_string()),
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.as_scheduler()
+                .update_process(process, updated_process_tracker_data)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to resume dispute sync task")?;
+            route_metrics::TASKS_RESET_COUNT.add(
+                &route_metrics::CONTEXT,
+                1,
+                &[route_metrics::request::add_attributes(
+                    "flow",
+                    "DisputeSync",
+                )],
+            );
+        }
+        Some(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Pulls the disputes of the merchant connector account which were raised or updated between
+/// `start_time` and `end_time`, and reconciles them into the dispute table. Disputes which are
+/// new or have changed are followed up on as if they were received through a webhook. An error is
+/// returned if any of the disputes could not be fetched or reconciled, so that the same time range
+/// is synced again. Reconciling a dispute more than once has no effect.
+#[instrument(skip_all)]
+pub async fn sync_disputes(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    start_time: PrimitiveDateTime,
+    end_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let connector_name = merchant_connector_account.connector_name.as_str();
+    let profile_id = merchant_connector_account
+        .profile_id
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing `profile_id` in merchant connector account")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.merchant_connector_id.clone()),
+    )?;
+
+    let mut failed_disputes_count = 0;
+    let mut cursor = None;
+    loop {
+        let response = fetch_disputes(
+            state,
+            merchant_account,
+            merchant_connector_account,
+            &connector_data,
+            types::FetchDisputesRequestData {
+                start_time,
+                end_time,
+                cursor,
+                connector_dispute_id: None,
+            },
+        )
+        .await?;
+
+        let mut fetched_disputes = response.disputes;
+        for connector_dispute_id in response.connector_dispute_ids_to_retrieve {
+            match fetch_disputes(
+                state,
+                merchant_account,
+                merchant_connector_account,
+                &connector_data,
+                types::FetchDisputesRequestData {
+                    start_time,
+                    end_time,
+                    cursor: None,
+                    connector_dispute_id: Some(connector_dispute_id.clone()),
+                },
+            )
+            .await
+            {
+                Ok(response) => fetched_disputes.extend(response.disputes),
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        %connector_dispute_id,
+                        "Failed to fetch dispute from connector"
+                    );
+                    failed_disputes_count += 1;
+                }
+            }
+        }
+
+        for fetched_dispute in fetched_disputes {
+            let connector_dispute_id = fetched_dispute.details.connector_dispute_id.clone();
+            if let Err(error) = reconcile_dispute(
+                state,
+                merchant_account,
+                key_store,
+                &business_profile,
+                connector_name,
+                fetched_dispute,
+            )
+            .await
+            {
+                logger::error!(
+                    ?error,
+                    %connector_dispute_id,
+                    "Failed to reconcile dispute fetched from connector"
+                );
+                failed_disputes_count += 1;
+            }
+        }
+
+        cursor = response.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    if failed_disputes_count > 0 {
+        return Err(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+            "Failed to fetch or reconcile {failed_disputes_count} disputes"
+        ));
+    }
+
+    Ok(())
+}
+
+async fn fetch_disputes(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    connector_data: &api::ConnectorData,
+    fetch_disputes_request_data: types::FetchDisputesRequestData,
+) -> RouterResult<types::FetchDisputesResponse> {
+    let mut router_data = core_utils::construct_fetch_disputes_router_data(
+        merchant_account,
+        merchant_connector_account,
+        fetch_disputes_request_data,
+    )?;
+    let add_access_token_result =
+        access_token::add_access_token(state, connector_data, merchant_account, &router_data)
+            .await?;
+    access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &payments::CallConnectorAction::Trigger,
+    );
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Fetch,
+        types::FetchDisputesRequestData,
+        types::FetchDisputesResponse,
+    > = connector_data.connector.get_connector_integration();
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_dispute_failed_response()
+    .attach_printable("Failed while calling fetch disputes connector api")?;
+
+    let fetch_disputes_response =
+        response
+            .response
+            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: merchant_connector_account.connector_name.clone(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+
+    Ok(fetch_disputes_response)
+}
+
+async fn reconcile_dispute(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    connector_name: &str,
+    fetched_dispute: disputes::FetchedDispute,
+) -> RouterResult<()> {
+    let payment_attempt = match webhooks_core::get_payment_attempt_from_object_reference_id(
+        state,
+        webhooks::ObjectReferenceId::PaymentId(fetched_dispute.payment_reference),
+        merchant_account,
+    )
+    .await
+    {
+        Ok(payment_attempt) => payment_attempt,
+        // Disputes on payments which were not made through us are not tracked
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::WebhookResourceNotFound
+            ) =>
+        {
+            logger::info!(
+                connector_dispute_id = %fetched_dispute.details.connector_dispute_id,
+                "Skipping fetched dispute as the disputed payment was not found"
+            );
+            return Ok(());
+        }
+        Err(error) => return Err(error),
+    };
+
+    let option_dispute = state
+        .store
+        .find_by_merchant_id_payment_id_connector_dispute_id(
+            &merchant_account.merchant_id,
+            &payment_attempt.payment_id,
+            &fetched_dispute.details.connector_dispute_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch dispute")?;
+    if let Some(dispute) = &option_dispute {
+        if dispute.dispute_stage == fetched_dispute.details.dispute_stage
+            && dispute.dispute_status == fetched_dispute.dispute_status
+            && dispute.challenge_required_by == fetched_dispute.details.challenge_required_by
+        {
+            // The dispute policy is applied to unchanged disputes as well, in case it could not
+            // be applied when the dispute was received
+            return Box::pin(policies::apply_dispute_policy(
+                state,
+                merchant_account,
+                key_store,
+                &payment_attempt,
+                dispute,
+            ))
+            .await;
+        }
+    }
+
+    let dispute_object = webhooks_core::get_or_update_dispute_object(
+        state.clone(),
+        option_dispute,
+        fetched_dispute.details,
+        &merchant_account.merchant_id,
+        &payment_attempt,
+        fetched_dispute.dispute_status,
+        business_profile,
+        connector_name,
+    )
+    .await?;
+    metrics::DISPUTE_SYNC_RECONCILED_METRIC.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new(
+            "connector",
+            connector_name.to_owned(),
+        )],
+    );
+
+    Box::pin(webhooks_core::handle_dispute_update(
+        state,
+        merchant_account,
+        business_profile.clone(),
+        key_store,
+        &payment_attempt,
+        &dispute_object,
+    ))
+    .await
+}
@@ -254,6 +254,10 @@ pub enum VaultError {
     SavePaymentMethodFailed,
     #[error("Failed to generate fingerprint")]
     GenerateFingerprintFailed,
+    #[error("Failed to delete card from card vault")]
+    DeleteCardFailed,
+    #[error("Failed to fetch the card vault configured for the merchant")]
+    FetchVaultProviderFailed,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -276,6 +280,8 @@ pub enum WebhooksFlowError {
     MerchantWebhookDetailsNotFound,
     #[error("Merchant does not have a webhook URL configured")]
     MerchantWebhookUrlNotConfigured,
+    #[error("Webhook endpoint is not configured or is disabled for the business profile")]
+    WebhookEndpointNotConfigured,
     #[error("Webhook event updation failed")]
     WebhookEventUpdationFailed,
     #[error("Outgoing webhook body signing failed")]
@@ -294,6 +300,10 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Message queue for publishing webhooks is not configured")]
+    MessageQueueNotConfigured,
+    #[error("Failed to publish webhook to message queue")]
+    PublishToMessageQueueFailed,
 }
 
 impl WebhooksFlowError {
@@ -302,7 +312,9 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::WebhookEndpointNotConfigured
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::MessageQueueNotConfigured => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
@@ -311,7 +323,8 @@ impl WebhooksFlowError {
             | Self::DisputeWebhookValidationFailed
             | Self::OutgoingWebhookEncodingFailed
             | Self::OutgoingWebhookProcessTrackerTaskUpdateFailed
-            | Self::OutgoingWebhookRetrySchedulingFailed => true,
+            | Self::OutgoingWebhookRetrySchedulingFailed
+            | Self::PublishToMessageQueueFailed => true,
         }
     }
 }
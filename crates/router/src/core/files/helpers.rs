@@ -4,7 +4,9 @@ use error_stack::ResultExt;
 use futures::TryStreamExt;
 
 use crate::{
+    consts,
     core::{
+        bulk_operations,
         errors::{self, StorageErrorExt},
         payments, utils,
     },
@@ -26,6 +28,7 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("bulk_operations") => Some(api::FilePurpose::BulkOperations),
         _ => None,
     }
 }
@@ -77,6 +80,27 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        api::FilePurpose::BulkOperations => {
+            if create_file_request.file_size > consts::BULK_OPERATION_MAX_FILE_SIZE {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: format!(
+                        "file_size exceeded the max file size of {} bytes",
+                        consts::BULK_OPERATION_MAX_FILE_SIZE
+                    ),
+                })?
+            }
+            bulk_operations::FileFormat::try_from(&create_file_request.file_type).map(|_| ())
+        }
+    }
+}
+
+/// Bulk operation files are plain text, so their type is determined from the extension of the file name
+pub fn get_bulk_operation_file_type(file_name: Option<&str>) -> Option<mime::Mime> {
+    let extension = file_name?.rsplit_once('.')?.1.to_lowercase();
+    match extension.as_str() {
+        "csv" => Some(mime::TEXT_CSV),
+        "jsonl" | "ndjson" => bulk_operations::JSON_LINES_MIME_TYPE.parse().ok(),
+        _ => None,
     }
 }
 
@@ -339,5 +363,18 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        api::FilePurpose::BulkOperations => {
+            state
+                .file_storage_client
+                .upload_file(&file_key, create_file_request.file.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
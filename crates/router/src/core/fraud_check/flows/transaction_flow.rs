@@ -53,6 +53,16 @@ impl
 
         let payment_method = self.payment_attempt.payment_method;
         let currency = self.payment_attempt.currency;
+        let card_verification_results = self
+            .payment_attempt
+            .card_verification_results
+            .clone()
+            .map(|card_verification_results| {
+                card_verification_results.parse_value("CardVerificationResults")
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the card verification results of the payment")?;
 
         let router_data = RouterData {
             flow: std::marker::PhantomData,
@@ -82,6 +92,7 @@ impl
                 error_message: self.payment_attempt.error_message.clone(),
                 connector_transaction_id: self.payment_attempt.connector_transaction_id.clone(),
                 connector: self.payment_attempt.connector.clone(),
+                card_verification_results,
             }, // self.order_details
             response: Ok(FraudCheckResponseData::TransactionResponse {
                 resource_id: ResponseId::ConnectorTransactionId("".to_string()),
@@ -171,6 +171,7 @@ impl<F: Send + Clone> Domain<F> for FraudCheckPre {
                 error_message: router_data.request.error_message,
                 connector_transaction_id: router_data.request.connector_transaction_id,
                 connector: router_data.request.connector,
+                card_verification_results: router_data.request.card_verification_results,
             }),
             response: FrmResponse::Transaction(router_data.response),
         }))
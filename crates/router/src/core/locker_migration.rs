@@ -1,17 +1,36 @@
-use api_models::{enums as api_enums, locker_migration::MigrateCardResponse};
-use common_utils::errors::CustomResult;
-use diesel_models::{enums as storage_enums, PaymentMethod};
-use error_stack::{FutureExt, ResultExt};
-use futures::TryFutureExt;
+use std::collections::HashMap;
 
-use super::{errors::StorageErrorExt, payment_methods::cards};
+use api_models::{
+    enums as api_enums,
+    locker_migration::{
+        MigrateCardResponse, VaultMigrationRequest, VaultMigrationResponse, VaultMigrationStatus,
+        VaultProviderResponse,
+    },
+};
+use common_utils::{errors::CustomResult, ext_traits::ValueExt, fp_utils::when};
+use diesel_models::{encryption::Encryption, enums as storage_enums, PaymentMethod};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use super::{
+    errors::{RouterResponse, RouterResult, StorageErrorExt},
+    payment_methods::{
+        card_vault::{self, CardVault},
+        transformers as payment_methods,
+    },
+};
 use crate::{
-    errors,
-    routes::AppState,
+    consts, errors,
+    routes::{metrics, AppState},
     services::{self, logger},
-    types::{api, domain},
+    types::{domain, domain::types::encrypt, storage},
 };
 
+pub const VAULT_MIGRATION_TASK: &str = "VAULT_MIGRATION";
+
 pub async fn rust_locker_migration(
     state: AppState,
     merchant_id: &str,
@@ -27,41 +46,47 @@ pub async fn rust_locker_migration(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
-    let merchant_account = db
-        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+    let domain_customers = db
+        .list_customers_by_merchant_id(merchant_id, &key_store)
         .await
-        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
-    let domain_customers = db
-        .list_customers_by_merchant_id(merchant_id, &key_store)
+    let source_vault = card_vault::get_merchant_card_vault(&state, merchant_id)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let target_vault = card_vault::get_card_vault(api_enums::VaultProvider::HyperswitchCardVault);
 
     let mut customers_moved = 0;
     let mut cards_moved = 0;
 
     for customer in domain_customers {
-        let result = db
+        let payment_methods = db
             .find_payment_method_by_customer_id_merchant_id_list(
                 &customer.customer_id,
                 merchant_id,
                 None,
             )
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .and_then(|pm| {
-                call_to_locker(
-                    &state,
-                    pm,
-                    &customer.customer_id,
-                    merchant_id,
-                    &merchant_account,
-                )
-            })
-            .await?;
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        for pm in payment_methods
+            .into_iter()
+            .filter(|pm| matches!(pm.payment_method, Some(storage_enums::PaymentMethod::Card)))
+        {
+            match move_card(&state, source_vault, target_vault, &pm).await {
+                Ok(()) => {
+                    cards_moved += 1;
+                    logger::info!(
+                        "Card migrated for merchant_id: {merchant_id}, customer_id: {}, payment_method_id: {} ",
+                        pm.customer_id,
+                        pm.payment_method_id
+                    );
+                }
+                Err(err) => logger::error!("Failed to add card to Rust locker : {:?}", err),
+            }
+        }
 
         customers_moved += 1;
-        cards_moved += result;
     }
 
     Ok(services::api::ApplicationResponse::Json(
@@ -74,95 +99,1028 @@ pub async fn rust_locker_migration(
     ))
 }
 
-pub async fn call_to_locker(
+/// Stages of a vault migration, every execution of the workflow processing a single chunk of
+/// payment methods of the merchant in the current stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultMigrationStage {
+    /// The stored data is being copied to the target vault, while the merchant keeps using the
+    /// source vault
+    Copying,
+    /// The merchant has been switched to the target vault, and the data saved in the source vault
+    /// after the last copied chunk is being moved
+    MovingRemaining,
+    /// The moved data is being deleted from the source vault
+    DeletingFromSource,
+    Completed,
+}
+
+/// Payment method data other than a card, which is stored with a new reference in the target
+/// vault
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MovedPaymentMethodData {
+    pub payment_method_id: String,
+    pub source_reference: String,
+    pub target_reference: String,
+}
+
+/// Tracking data stored against the vault migration process tracker entry, which also serves as
+/// the state of the migration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VaultMigrationTrackingData {
+    pub migration_id: String,
+    pub merchant_id: String,
+    pub source_vault_provider: api_enums::VaultProvider,
+    pub vault_provider: api_enums::VaultProvider,
+    pub delete_from_source: bool,
+    pub stage: VaultMigrationStage,
+    /// Row ID of the last payment method processed, after which the next chunk starts
+    pub cursor: Option<i32>,
+    /// Row ID of the last payment method processed before the data is deleted from the source
+    /// vault
+    pub last_moved_id: Option<i32>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub switched_at: Option<PrimitiveDateTime>,
+    pub cards_moved: usize,
+    pub payment_methods_moved: usize,
+    pub payment_methods_failed: Vec<String>,
+    /// Payment methods which could not be moved after the merchant was switched to the target
+    /// vault. Their data is read from the source vault until they are moved.
+    #[serde(default)]
+    pub payment_methods_not_moved: Vec<String>,
+    /// Number of times the payment methods which could not be moved after the switch were retried
+    #[serde(default)]
+    pub move_retry_count: i32,
+    /// Payment method data other than cards copied to the target vault. The locker IDs of their
+    /// payment methods are updated to the references in the target vault when the merchant is
+    /// switched to it.
+    pub moved_payment_method_data: Vec<MovedPaymentMethodData>,
+    pub error_message: Option<String>,
+}
+
+impl VaultMigrationTrackingData {
+    /// Whether the workflow has nothing left to do, either because the migration is completed or
+    /// because it was stopped
+    pub fn is_finished(&self) -> bool {
+        self.stage == VaultMigrationStage::Completed || self.error_message.is_some()
+    }
+
+    /// Time at which the workflow processes the next chunk. Payment methods which could not be
+    /// moved after the switch are retried with an exponential backoff, once all the other
+    /// payment methods have been processed.
+    pub fn get_next_schedule_time(&self) -> PrimitiveDateTime {
+        let now = common_utils::date_time::now();
+        if self.stage != VaultMigrationStage::MovingRemaining
+            || self.move_retry_count <= 0
+            || self.payment_methods_not_moved.is_empty()
+        {
+            return now;
+        }
+
+        let backoff = 2_i64.saturating_pow(u32::try_from(self.move_retry_count - 1).unwrap_or(0));
+        now.saturating_add(time::Duration::seconds(
+            consts::VAULT_MIGRATION_RETRY_DELAY.saturating_mul(backoff),
+        ))
+    }
+}
+
+fn get_process_tracker_id(migration_id: &str, merchant_id: &str) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::VaultMigrationWorkflow,
+        VAULT_MIGRATION_TASK,
+        migration_id,
+        merchant_id,
+    )
+}
+
+fn get_latest_vault_migration_config_key(merchant_id: &str) -> String {
+    format!("vault_migration_{merchant_id}")
+}
+
+fn get_vault_migration_status(
+    process: &storage::ProcessTracker,
+    tracking_data: &VaultMigrationTrackingData,
+) -> VaultMigrationStatus {
+    match (process.status, tracking_data.stage) {
+        (_, VaultMigrationStage::Completed) => VaultMigrationStatus::Completed,
+        (storage_enums::ProcessTrackerStatus::Finish, _) => VaultMigrationStatus::Failed,
+        (_, VaultMigrationStage::Copying) => VaultMigrationStatus::Copying,
+        (_, VaultMigrationStage::MovingRemaining | VaultMigrationStage::DeletingFromSource) => {
+            VaultMigrationStatus::Switched
+        }
+    }
+}
+
+fn get_vault_migration_response(
+    process: &storage::ProcessTracker,
+    tracking_data: VaultMigrationTrackingData,
+) -> VaultMigrationResponse {
+    VaultMigrationResponse {
+        status: get_vault_migration_status(process, &tracking_data),
+        migration_id: tracking_data.migration_id,
+        merchant_id: tracking_data.merchant_id,
+        source_vault_provider: tracking_data.source_vault_provider,
+        vault_provider: tracking_data.vault_provider,
+        delete_from_source: tracking_data.delete_from_source,
+        cards_moved: tracking_data.cards_moved,
+        payment_methods_moved: tracking_data.payment_methods_moved,
+        payment_methods_failed: tracking_data
+            .payment_methods_failed
+            .into_iter()
+            .chain(tracking_data.payment_methods_not_moved)
+            .collect(),
+        error_message: tracking_data.error_message,
+        switched_at: tracking_data.switched_at,
+        created_at: process.created_at,
+        updated_at: process.updated_at,
+    }
+}
+
+/// The latest vault migration of the merchant, along with its tracking data
+async fn find_latest_vault_migration(
     state: &AppState,
-    payment_methods: Vec<PaymentMethod>,
-    customer_id: &String,
     merchant_id: &str,
-    merchant_account: &domain::MerchantAccount,
-) -> CustomResult<usize, errors::ApiErrorResponse> {
-    let mut cards_moved = 0;
-
-    for pm in payment_methods
-        .into_iter()
-        .filter(|pm| matches!(pm.payment_method, Some(storage_enums::PaymentMethod::Card)))
+) -> RouterResult<Option<(storage::ProcessTracker, VaultMigrationTrackingData)>> {
+    let migration_id = match state
+        .store
+        .find_config_by_key(&get_latest_vault_migration_config_key(merchant_id))
+        .await
     {
-        let card = cards::get_card_from_locker(
-            state,
-            customer_id,
-            merchant_id,
-            pm.locker_id.as_ref().unwrap_or(&pm.payment_method_id),
+        Ok(config) => config.config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch latest vault migration of the merchant")?,
+    };
+
+    state
+        .store
+        .find_process_by_id(&get_process_tracker_id(&migration_id, merchant_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch vault migration task")?
+        .map(|process| {
+            let tracking_data = process
+                .tracking_data
+                .clone()
+                .parse_value("VaultMigrationTrackingData")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Ok((process, tracking_data))
+        })
+        .transpose()
+}
+
+pub async fn retrieve_vault_provider(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<VaultProviderResponse> {
+    state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &merchant_id,
+            &state.store.get_master_key().to_vec().into(),
         )
-        .await;
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-        let card = match card {
-            Ok(card) => card,
-            Err(err) => {
-                logger::error!("Failed to fetch card from Basilisk HS locker : {:?}", err);
-                continue;
+    let vault_provider = card_vault::get_vault_provider(&state, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let migration = find_latest_vault_migration(&state, &merchant_id)
+        .await?
+        .map(|(process, tracking_data)| get_vault_migration_response(&process, tracking_data));
+
+    Ok(services::ApplicationResponse::Json(VaultProviderResponse {
+        merchant_id,
+        vault_provider,
+        migration,
+    }))
+}
+
+/// Adds a task which moves the cards and other payment method data stored for the merchant to
+/// another vault, and switches the merchant to that vault.
+///
+/// The stored data is copied to the new vault in chunks while the merchant keeps using the
+/// previous vault, and the merchant is switched only once all of it has been copied. Cards keep
+/// their references in the new vault. Other payment method data is stored with a new reference,
+/// and the locker ID of its payment method is updated when the merchant is switched. Payment
+/// methods saved in the previous vault just before the switch are moved right after it, and are
+/// read from the previous vault until then.
+pub async fn migrate_vault(
+    state: AppState,
+    merchant_id: String,
+    request: VaultMigrationRequest,
+) -> RouterResponse<VaultMigrationResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let source_vault_provider = card_vault::get_vault_provider(&state, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let vault_provider = request.vault_provider;
+    when(source_vault_provider == vault_provider, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Cards of the merchant are already stored in the `{vault_provider}` vault"
+            ),
+        }))
+    })?;
+    when(
+        vault_provider == api_enums::VaultProvider::MockLocker && !state.conf.locker.mock_locker,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The mock locker is not enabled".to_string(),
+            }))
+        },
+    )?;
+    when(
+        vault_provider == api_enums::VaultProvider::External
+            && !state.conf.external_vault.get_inner().is_enabled(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The external vault is not configured".to_string(),
+            }))
+        },
+    )?;
+
+    if let Some((process, _)) = find_latest_vault_migration(&state, &merchant_id).await? {
+        when(
+            process.status != storage_enums::ProcessTrackerStatus::Finish,
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message:
+                        "A migration of the stored cards of the merchant is already in progress"
+                            .to_string(),
+                }))
+            },
+        )?;
+    }
+
+    let migration_id = common_utils::generate_id(consts::ID_LENGTH, "vault_migration");
+    let tracking_data = VaultMigrationTrackingData {
+        migration_id: migration_id.clone(),
+        merchant_id: merchant_id.clone(),
+        source_vault_provider,
+        vault_provider,
+        delete_from_source: request.delete_from_source,
+        stage: VaultMigrationStage::Copying,
+        cursor: None,
+        last_moved_id: None,
+        switched_at: None,
+        cards_moved: 0,
+        payment_methods_moved: 0,
+        payment_methods_failed: Vec::new(),
+        payment_methods_not_moved: Vec::new(),
+        move_retry_count: 0,
+        moved_payment_method_data: Vec::new(),
+        error_message: None,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_process_tracker_id(&migration_id, &merchant_id),
+        VAULT_MIGRATION_TASK,
+        storage::ProcessTrackerRunner::VaultMigrationWorkflow,
+        ["VAULT_MIGRATION"],
+        tracking_data.clone(),
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct vault migration process tracker task")?;
+    let process = db
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert vault migration task in process tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "VaultMigration")],
+    );
+
+    card_vault::upsert_config(
+        &state,
+        get_latest_vault_migration_config_key(&merchant_id),
+        migration_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store latest vault migration of the merchant")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_vault_migration_response(&process, tracking_data),
+    ))
+}
+
+/// Processes the next chunk of payment methods of the merchant in the current stage of the
+/// migration, moving on to the next stage once all the payment methods have been processed
+pub async fn process_vault_migration_chunk(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &mut VaultMigrationTrackingData,
+) -> RouterResult<()> {
+    let payment_methods = state
+        .store
+        .list_payment_methods_by_merchant_id_after_id(
+            &tracking_data.merchant_id,
+            tracking_data.cursor,
+            consts::VAULT_MIGRATION_CHUNK_SIZE,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payment methods of the merchant")?;
+    let last_id = payment_methods.last().map(|pm| pm.id);
+    let migration = VaultMigration {
+        state,
+        key_store,
+        storage_scheme: merchant_account.storage_scheme,
+        source_vault: card_vault::get_card_vault(tracking_data.source_vault_provider),
+        target_vault: card_vault::get_card_vault(tracking_data.vault_provider),
+    };
+
+    match tracking_data.stage {
+        VaultMigrationStage::Copying if payment_methods.is_empty() => {
+            return migration.switch_vault_provider(tracking_data).await;
+        }
+        VaultMigrationStage::Copying => {
+            for pm in &payment_methods {
+                migration.copy_payment_method(pm, tracking_data).await;
             }
-        };
+        }
+        VaultMigrationStage::MovingRemaining
+            if payment_methods.is_empty()
+                && !tracking_data.payment_methods_not_moved.is_empty() =>
+        {
+            return migration
+                .retry_remaining_payment_methods(tracking_data)
+                .await;
+        }
+        VaultMigrationStage::MovingRemaining if payment_methods.is_empty() => {
+            // All the data saved in the source vault has been moved, so that it no longer has to
+            // be read from it
+            card_vault::remove_fallback_vault_provider(state, &tracking_data.merchant_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to remove fallback vault provider of the merchant")?;
+            tracking_data.last_moved_id = tracking_data.cursor;
+            tracking_data.cursor = None;
+            tracking_data.stage = if tracking_data.delete_from_source {
+                VaultMigrationStage::DeletingFromSource
+            } else {
+                VaultMigrationStage::Completed
+            };
+            return Ok(());
+        }
+        VaultMigrationStage::MovingRemaining => {
+            for pm in payment_methods {
+                migration
+                    .move_or_track_remaining_payment_method(pm, tracking_data)
+                    .await;
+            }
+        }
+        VaultMigrationStage::DeletingFromSource => {
+            // Payment methods saved after the remaining data was moved are not stored in the
+            // source vault
+            let payment_methods = payment_methods
+                .into_iter()
+                .filter(|pm| tracking_data.last_moved_id.is_some_and(|id| pm.id <= id))
+                .collect::<Vec<_>>();
+            if payment_methods.is_empty() {
+                tracking_data.stage = VaultMigrationStage::Completed;
+                return Ok(());
+            }
+            migration
+                .delete_from_source_vault(&payment_methods, tracking_data)
+                .await;
+        }
+        VaultMigrationStage::Completed => return Ok(()),
+    }
 
-        let card_details = api::CardDetail {
-            card_number: card.card_number,
-            card_exp_month: card.card_exp_month,
-            card_exp_year: card.card_exp_year,
-            card_holder_name: card.name_on_card,
-            nick_name: card.nick_name.map(masking::Secret::new),
-            card_issuing_country: None,
-            card_network: None,
-            card_issuer: None,
-            card_type: None,
-        };
+    tracking_data.cursor = last_id.or(tracking_data.cursor);
+    Ok(())
+}
 
-        let pm_create = api::PaymentMethodCreate {
-            payment_method: pm.payment_method,
-            payment_method_type: pm.payment_method_type,
-            payment_method_issuer: pm.payment_method_issuer,
-            payment_method_issuer_code: pm.payment_method_issuer_code,
-            card: Some(card_details.clone()),
-            #[cfg(feature = "payouts")]
-            wallet: None,
-            #[cfg(feature = "payouts")]
-            bank_transfer: None,
-            metadata: pm.metadata,
-            customer_id: Some(pm.customer_id),
-            card_network: card.card_brand,
-            client_secret: None,
-            payment_method_data: None,
-        };
+struct VaultMigration<'a> {
+    state: &'a AppState,
+    key_store: &'a domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    source_vault: &'static dyn CardVault,
+    target_vault: &'static dyn CardVault,
+}
 
-        let add_card_result = cards::add_card_hs(
-                state,
-                pm_create,
-                &card_details,
-                customer_id.to_string(),
-                merchant_account,
-                api_enums::LockerChoice::HyperswitchCardVault,
-                Some(pm.locker_id.as_ref().unwrap_or(&pm.payment_method_id)),
+impl VaultMigration<'_> {
+    async fn copy_payment_method(
+        &self,
+        pm: &PaymentMethod,
+        tracking_data: &mut VaultMigrationTrackingData,
+    ) {
+        if matches!(pm.payment_method, Some(storage_enums::PaymentMethod::Card)) {
+            match move_card(self.state, self.source_vault, self.target_vault, pm).await {
+                Ok(()) => tracking_data.cards_moved += 1,
+                Err(error) => {
+                    logger::error!(payment_method_id = %pm.payment_method_id, ?error, "Failed to copy card to vault");
+                    tracking_data
+                        .payment_methods_failed
+                        .push(pm.payment_method_id.clone());
+                }
+            }
+        } else if let Some(source_reference) = pm.locker_id.clone() {
+            match move_payment_method_data(
+                self.state,
+                self.key_store,
+                self.source_vault,
+                self.target_vault,
+                pm,
             )
             .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable(format!(
-                "Card migration failed for merchant_id: {merchant_id}, customer_id: {customer_id}, payment_method_id: {} ",
-                pm.payment_method_id
+            {
+                Ok(target_reference) => {
+                    tracking_data
+                        .moved_payment_method_data
+                        .push(MovedPaymentMethodData {
+                            payment_method_id: pm.payment_method_id.clone(),
+                            source_reference,
+                            target_reference,
+                        })
+                }
+                Err(error) => {
+                    logger::error!(payment_method_id = %pm.payment_method_id, ?error, "Failed to copy payment method data to vault");
+                    tracking_data
+                        .payment_methods_failed
+                        .push(pm.payment_method_id.clone());
+                }
+            }
+        }
+    }
+
+    /// Switches the merchant to the target vault once all the stored data has been copied,
+    /// pointing the payment methods whose data was stored with a new reference to it first. The
+    /// migration is stopped without switching the merchant when any payment method could not be
+    /// copied or pointed to its data in the target vault.
+    async fn switch_vault_provider(
+        &self,
+        tracking_data: &mut VaultMigrationTrackingData,
+    ) -> RouterResult<()> {
+        if !tracking_data.payment_methods_failed.is_empty() {
+            tracking_data.error_message = Some(format!(
+                "{} payment methods could not be copied to the new vault, the merchant was not switched to it",
+                tracking_data.payment_methods_failed.len()
+            ));
+            return Ok(());
+        }
+
+        let mut updated_payment_method_data = Vec::new();
+        for moved_data in &tracking_data.moved_payment_method_data {
+            match self
+                .update_locker_id(&moved_data.payment_method_id, &moved_data.target_reference)
+                .await
+            {
+                Ok(()) => updated_payment_method_data.push(moved_data),
+                Err(error) => {
+                    logger::error!(payment_method_id = %moved_data.payment_method_id, ?error, "Failed to update locker ID of payment method");
+                    self.restore_locker_ids(&updated_payment_method_data).await;
+                    let payment_method_id = moved_data.payment_method_id.clone();
+                    tracking_data.payment_methods_failed.push(payment_method_id);
+                    tracking_data.error_message = Some(
+                        "Payment methods could not be pointed to their data in the new vault, the merchant was not switched to it"
+                            .to_string(),
+                    );
+                    return Ok(());
+                }
+            }
+        }
+
+        // Data saved in the source vault after the last copied chunk is read from it until it has
+        // been moved
+        if let Err(error) = card_vault::set_fallback_vault_provider(
+            self.state,
+            &tracking_data.merchant_id,
+            tracking_data.source_vault_provider,
+        )
+        .await
+        {
+            self.restore_locker_ids(&updated_payment_method_data).await;
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to set fallback vault provider of the merchant");
+        }
+
+        if let Err(error) = card_vault::set_vault_provider(
+            self.state,
+            &tracking_data.merchant_id,
+            tracking_data.vault_provider,
+        )
+        .await
+        {
+            self.restore_locker_ids(&updated_payment_method_data).await;
+            if let Err(error) =
+                card_vault::remove_fallback_vault_provider(self.state, &tracking_data.merchant_id)
+                    .await
+            {
+                logger::error!(
+                    ?error,
+                    "Failed to remove fallback vault provider of the merchant"
+                );
+            }
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update vault provider of the merchant");
+        }
+        logger::info!(
+            merchant_id = %tracking_data.merchant_id,
+            source_vault_provider = %tracking_data.source_vault_provider,
+            vault_provider = %tracking_data.vault_provider,
+            "Switched vault provider of the merchant"
+        );
+
+        tracking_data.payment_methods_moved += updated_payment_method_data.len();
+        tracking_data.stage = VaultMigrationStage::MovingRemaining;
+        tracking_data.switched_at = Some(common_utils::date_time::now());
+        Ok(())
+    }
+
+    /// Moves the data of a payment method saved in the source vault after the last copied chunk,
+    /// keeping track of the payment method to retry it later if it could not be moved
+    async fn move_or_track_remaining_payment_method(
+        &self,
+        pm: PaymentMethod,
+        tracking_data: &mut VaultMigrationTrackingData,
+    ) {
+        let payment_method_id = pm.payment_method_id.clone();
+        if let Err(error) = self.move_remaining_payment_method(pm, tracking_data).await {
+            logger::error!(%payment_method_id, ?error, "Failed to move payment method data to vault");
+            tracking_data
+                .payment_methods_not_moved
+                .push(payment_method_id);
+        }
+    }
+
+    /// Retries moving the payment methods which could not be moved after the switch. The
+    /// migration is stopped once the retries are exhausted, the payment methods which still
+    /// could not be moved being read from the source vault.
+    async fn retry_remaining_payment_methods(
+        &self,
+        tracking_data: &mut VaultMigrationTrackingData,
+    ) -> RouterResult<()> {
+        if tracking_data.move_retry_count >= consts::VAULT_MIGRATION_MAX_RETRIES {
+            tracking_data.error_message = Some(format!(
+                "Payment methods {} could not be moved to the new vault, they are read from the previous vault until they are moved",
+                tracking_data.payment_methods_not_moved.join(", ")
             ));
+            return Ok(());
+        }
+
+        tracking_data.move_retry_count += 1;
+        for payment_method_id in std::mem::take(&mut tracking_data.payment_methods_not_moved) {
+            match self
+                .state
+                .store
+                .find_payment_method(&payment_method_id, self.storage_scheme)
+                .await
+            {
+                Ok(pm) => {
+                    self.move_or_track_remaining_payment_method(pm, tracking_data)
+                        .await
+                }
+                // Deleted since it could not be moved
+                Err(error) if error.current_context().is_db_not_found() => {}
+                Err(error) => {
+                    logger::error!(%payment_method_id, ?error, "Failed to fetch payment method");
+                    tracking_data
+                        .payment_methods_not_moved
+                        .push(payment_method_id);
+                }
+            }
+        }
+
+        Ok(())
+    }
 
-        let (_add_card_rs_resp, _is_duplicate) = match add_card_result {
-            Ok(output) => output,
-            Err(err) => {
-                logger::error!("Failed to add card to Rust locker : {:?}", err);
+    /// Moves the data of a payment method saved in the source vault after the last copied chunk,
+    /// unless it was saved in the target vault after the switch
+    async fn move_remaining_payment_method(
+        &self,
+        pm: PaymentMethod,
+        tracking_data: &mut VaultMigrationTrackingData,
+    ) -> CustomResult<(), errors::VaultError> {
+        if self.is_stored_in_target_vault(&pm).await {
+            return Ok(());
+        }
+
+        if matches!(pm.payment_method, Some(storage_enums::PaymentMethod::Card)) {
+            move_card(self.state, self.source_vault, self.target_vault, &pm).await?;
+            tracking_data.cards_moved += 1;
+        } else if let Some(source_reference) = pm.locker_id.clone() {
+            let payment_method_id = pm.payment_method_id.clone();
+            let target_reference = move_payment_method_data(
+                self.state,
+                self.key_store,
+                self.source_vault,
+                self.target_vault,
+                &pm,
+            )
+            .await?;
+            self.state
+                .store
+                .update_payment_method(
+                    pm,
+                    get_locker_id_update(target_reference.clone()),
+                    self.storage_scheme,
+                )
+                .await
+                .change_context(errors::VaultError::SavePaymentMethodFailed)?;
+
+            tracking_data.payment_methods_moved += 1;
+            tracking_data
+                .moved_payment_method_data
+                .push(MovedPaymentMethodData {
+                    payment_method_id,
+                    source_reference,
+                    target_reference,
+                });
+        }
+
+        Ok(())
+    }
+
+    async fn delete_from_source_vault(
+        &self,
+        payment_methods: &[PaymentMethod],
+        tracking_data: &VaultMigrationTrackingData,
+    ) {
+        let moved_payment_method_data = tracking_data
+            .moved_payment_method_data
+            .iter()
+            .map(|moved_data| {
+                (
+                    moved_data.payment_method_id.as_str(),
+                    moved_data.source_reference.as_str(),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        for pm in payment_methods {
+            if tracking_data
+                .payment_methods_failed
+                .contains(&pm.payment_method_id)
+            {
                 continue;
             }
+            let reference = if matches!(pm.payment_method, Some(storage_enums::PaymentMethod::Card))
+            {
+                get_locker_reference(pm)
+            } else if let Some(reference) =
+                moved_payment_method_data.get(pm.payment_method_id.as_str())
+            {
+                (*reference).to_owned()
+            } else {
+                continue;
+            };
+
+            if let Err(error) = self
+                .source_vault
+                .delete(self.state, &pm.customer_id, &pm.merchant_id, &reference)
+                .await
+            {
+                logger::error!(payment_method_id = %pm.payment_method_id, ?error, "Failed to delete moved data from vault");
+            }
+        }
+    }
+
+    async fn is_stored_in_target_vault(&self, pm: &PaymentMethod) -> bool {
+        let reference = get_locker_reference(pm);
+        if matches!(pm.payment_method, Some(storage_enums::PaymentMethod::Card)) {
+            self.target_vault
+                .retrieve_card(self.state, &pm.customer_id, &pm.merchant_id, &reference)
+                .await
+                .is_ok()
+        } else {
+            self.target_vault
+                .retrieve_payment_method_data(
+                    self.state,
+                    self.key_store,
+                    &pm.customer_id,
+                    &pm.merchant_id,
+                    &reference,
+                )
+                .await
+                .is_ok()
+        }
+    }
+
+    /// Points the payment method to the given locker ID. Payment methods which have been deleted
+    /// since their data was copied are skipped.
+    async fn update_locker_id(
+        &self,
+        payment_method_id: &str,
+        locker_id: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        let pm = match self
+            .state
+            .store
+            .find_payment_method(payment_method_id, self.storage_scheme)
+            .await
+        {
+            Ok(pm) => pm,
+            Err(error) if error.current_context().is_db_not_found() => return Ok(()),
+            Err(error) => return Err(error),
         };
+        self.state
+            .store
+            .update_payment_method(
+                pm,
+                get_locker_id_update(locker_id.to_owned()),
+                self.storage_scheme,
+            )
+            .await
+            .map(|_| ())
+    }
 
-        cards_moved += 1;
+    async fn restore_locker_ids(&self, moved_payment_method_data: &[&MovedPaymentMethodData]) {
+        for moved_data in moved_payment_method_data {
+            if let Err(error) = self
+                .update_locker_id(&moved_data.payment_method_id, &moved_data.source_reference)
+                .await
+            {
+                logger::error!(payment_method_id = %moved_data.payment_method_id, ?error, "Failed to restore locker ID of payment method");
+            }
+        }
+    }
+}
 
-        logger::info!(
-                "Card migrated for merchant_id: {merchant_id}, customer_id: {customer_id}, payment_method_id: {} ",
-                pm.payment_method_id
+fn get_locker_id_update(locker_id: String) -> storage::PaymentMethodUpdate {
+    storage::PaymentMethodUpdate::AdditionalDataUpdate {
+        payment_method_data: None,
+        status: None,
+        locker_id: Some(locker_id),
+        payment_method: None,
+        payment_method_type: None,
+        payment_method_issuer: None,
+    }
+}
+
+fn get_locker_reference(pm: &PaymentMethod) -> String {
+    pm.locker_id
+        .clone()
+        .unwrap_or_else(|| pm.payment_method_id.clone())
+}
+
+/// Copies the card of the payment method to the target vault, keeping its reference
+async fn move_card(
+    state: &AppState,
+    source_vault: &dyn CardVault,
+    target_vault: &dyn CardVault,
+    pm: &PaymentMethod,
+) -> CustomResult<(), errors::VaultError> {
+    let card_reference = get_locker_reference(pm);
+    let card = source_vault
+        .retrieve_card(state, &pm.customer_id, &pm.merchant_id, &card_reference)
+        .await?;
+
+    let payload = payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+        merchant_id: &pm.merchant_id,
+        merchant_customer_id: pm.customer_id.clone(),
+        requestor_card_reference: Some(card_reference.clone()),
+        card,
+    });
+    let stored_card = target_vault.store(state, &payload, &pm.customer_id).await?;
+
+    when(stored_card.card_reference != card_reference, || {
+        Err(report!(errors::VaultError::SaveCardFailed)).attach_printable(format!(
+            "Card was stored with reference {} instead of the requested reference",
+            stored_card.card_reference
+        ))
+    })
+}
+
+/// Copies the payment method data of the payment method to the target vault, returning its
+/// reference in the target vault
+async fn move_payment_method_data(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    source_vault: &dyn CardVault,
+    target_vault: &dyn CardVault,
+    pm: &PaymentMethod,
+) -> CustomResult<String, errors::VaultError> {
+    let payment_method_data = source_vault
+        .retrieve_payment_method_data(
+            state,
+            key_store,
+            &pm.customer_id,
+            &pm.merchant_id,
+            &get_locker_reference(pm),
+        )
+        .await?;
+
+    let enc_data = encrypt(payment_method_data, key_store.key.get_inner().peek())
+        .await
+        .change_context(errors::VaultError::SavePaymentMethodFailed)
+        .attach_printable("Failed to encrypt payment method data")?;
+    let payload =
+        payment_methods::StoreLockerReq::LockerGeneric(payment_methods::StoreGenericReq {
+            merchant_id: &pm.merchant_id,
+            merchant_customer_id: pm.customer_id.clone(),
+            enc_data: hex::encode(Encryption::from(enc_data).into_inner().peek()),
+        });
+
+    target_vault
+        .store(state, &payload, &pm.customer_id)
+        .await
+        .map(|stored_data| stored_data.card_reference)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_tracking_data(stage: VaultMigrationStage) -> VaultMigrationTrackingData {
+        VaultMigrationTrackingData {
+            migration_id: "vault_migration_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            source_vault_provider: api_enums::VaultProvider::HyperswitchCardVault,
+            vault_provider: api_enums::VaultProvider::LocalEncrypted,
+            delete_from_source: true,
+            stage,
+            cursor: Some(100),
+            last_moved_id: None,
+            switched_at: None,
+            cards_moved: 10,
+            payment_methods_moved: 2,
+            payment_methods_failed: Vec::new(),
+            payment_methods_not_moved: Vec::new(),
+            move_retry_count: 0,
+            moved_payment_method_data: Vec::new(),
+            error_message: None,
+        }
+    }
+
+    fn get_process(
+        status: storage_enums::ProcessTrackerStatus,
+        tracking_data: &VaultMigrationTrackingData,
+    ) -> storage::ProcessTracker {
+        serde_json::from_value(serde_json::json!({
+            "id": get_process_tracker_id(&tracking_data.migration_id, &tracking_data.merchant_id),
+            "name": VAULT_MIGRATION_TASK,
+            "tag": ["VAULT_MIGRATION"],
+            "runner": "VAULT_MIGRATION_WORKFLOW",
+            "retry_count": 0,
+            "schedule_time": "2024-01-01T00:00:00.000Z",
+            "rule": "",
+            "tracking_data": serde_json::to_value(tracking_data).unwrap(),
+            "business_status": "Pending",
+            "status": status,
+            "event": [],
+            "created_at": "2024-01-01T00:00:00.000Z",
+            "updated_at": "2024-01-01T00:00:00.000Z",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_vault_migration_status() {
+        for (process_status, stage, expected_status) in [
+            (
+                storage_enums::ProcessTrackerStatus::New,
+                VaultMigrationStage::Copying,
+                VaultMigrationStatus::Copying,
+            ),
+            (
+                storage_enums::ProcessTrackerStatus::Pending,
+                VaultMigrationStage::MovingRemaining,
+                VaultMigrationStatus::Switched,
+            ),
+            (
+                storage_enums::ProcessTrackerStatus::Pending,
+                VaultMigrationStage::DeletingFromSource,
+                VaultMigrationStatus::Switched,
+            ),
+            (
+                storage_enums::ProcessTrackerStatus::Finish,
+                VaultMigrationStage::Completed,
+                VaultMigrationStatus::Completed,
+            ),
+            // Stopped because payment methods could not be copied, or after the retries were
+            // exhausted
+            (
+                storage_enums::ProcessTrackerStatus::Finish,
+                VaultMigrationStage::Copying,
+                VaultMigrationStatus::Failed,
+            ),
+            (
+                storage_enums::ProcessTrackerStatus::Finish,
+                VaultMigrationStage::MovingRemaining,
+                VaultMigrationStatus::Failed,
+            ),
+        ] {
+            let tracking_data = get_tracking_data(stage);
+            let process = get_process(process_status, &tracking_data);
+            assert_eq!(
+                get_vault_migration_status(&process, &tracking_data),
+                expected_status
             );
+        }
+    }
+
+    #[test]
+    fn test_vault_migration_response_from_tracking_data() {
+        let mut tracking_data = get_tracking_data(VaultMigrationStage::Copying);
+        tracking_data.payment_methods_failed = vec!["pm_1".to_string()];
+        tracking_data.error_message = Some("Failed".to_string());
+        let process = get_process(storage_enums::ProcessTrackerStatus::Finish, &tracking_data);
+
+        let response = get_vault_migration_response(&process, tracking_data);
+        assert_eq!(response.status, VaultMigrationStatus::Failed);
+        assert_eq!(response.migration_id, "vault_migration_1");
+        assert_eq!(response.cards_moved, 10);
+        assert_eq!(response.payment_methods_failed, vec!["pm_1".to_string()]);
+        assert!(response.switched_at.is_none());
+    }
+
+    #[test]
+    fn test_vault_migration_is_finished() {
+        assert!(!get_tracking_data(VaultMigrationStage::Copying).is_finished());
+        assert!(!get_tracking_data(VaultMigrationStage::MovingRemaining).is_finished());
+        assert!(!get_tracking_data(VaultMigrationStage::DeletingFromSource).is_finished());
+        assert!(get_tracking_data(VaultMigrationStage::Completed).is_finished());
+
+        let mut tracking_data = get_tracking_data(VaultMigrationStage::Copying);
+        tracking_data.error_message = Some("Failed".to_string());
+        assert!(tracking_data.is_finished());
     }
 
-    Ok(cards_moved)
+    #[test]
+    fn test_vault_migration_retries_remaining_payment_methods_with_backoff() {
+        let mut tracking_data = get_tracking_data(VaultMigrationStage::MovingRemaining);
+        let now = common_utils::date_time::now();
+        // The remaining payment methods are processed right away
+        tracking_data.payment_methods_not_moved = vec!["pm_1".to_string()];
+        assert!(tracking_data.get_next_schedule_time() - now < time::Duration::seconds(1));
+
+        for (move_retry_count, delay) in [(1, 60), (2, 120), (3, 240)] {
+            tracking_data.move_retry_count = move_retry_count;
+            let schedule_time = tracking_data.get_next_schedule_time();
+            assert!(schedule_time - now >= time::Duration::seconds(delay));
+            assert!(schedule_time - now < time::Duration::seconds(delay + 1));
+        }
+
+        // Nothing left to retry
+        tracking_data.payment_methods_not_moved = Vec::new();
+        assert!(tracking_data.get_next_schedule_time() - now < time::Duration::seconds(1));
+    }
+
+    #[test]
+    fn test_vault_migration_stopped_with_payment_methods_not_moved_is_failed() {
+        let mut tracking_data = get_tracking_data(VaultMigrationStage::MovingRemaining);
+        tracking_data.payment_methods_not_moved = vec!["pm_2".to_string()];
+        tracking_data.move_retry_count = consts::VAULT_MIGRATION_MAX_RETRIES;
+        tracking_data.error_message = Some("Payment methods pm_2 could not be moved".to_string());
+        assert!(tracking_data.is_finished());
+
+        let process = get_process(storage_enums::ProcessTrackerStatus::Finish, &tracking_data);
+        let response = get_vault_migration_response(&process, tracking_data);
+        assert_eq!(response.status, VaultMigrationStatus::Failed);
+        assert_eq!(response.payment_methods_failed, vec!["pm_2".to_string()]);
+    }
+
+    #[test]
+    fn test_vault_migration_tracking_data_without_retries_is_parsed() {
+        let mut value =
+            serde_json::to_value(get_tracking_data(VaultMigrationStage::MovingRemaining)).unwrap();
+        let object = value.as_object_mut().unwrap();
+        object.remove("payment_methods_not_moved");
+        object.remove("move_retry_count");
+
+        let parsed: VaultMigrationTrackingData =
+            value.parse_value("VaultMigrationTrackingData").unwrap();
+        assert!(parsed.payment_methods_not_moved.is_empty());
+        assert_eq!(parsed.move_retry_count, 0);
+    }
+
+    #[test]
+    fn test_vault_migration_tracking_data_round_trip() {
+        let mut tracking_data = get_tracking_data(VaultMigrationStage::MovingRemaining);
+        tracking_data.switched_at = Some(common_utils::date_time::now());
+        tracking_data.moved_payment_method_data = vec![MovedPaymentMethodData {
+            payment_method_id: "pm_1".to_string(),
+            source_reference: "source_ref".to_string(),
+            target_reference: "target_ref".to_string(),
+        }];
+
+        let value = serde_json::to_value(&tracking_data).unwrap();
+        assert_eq!(value["stage"], "moving_remaining");
+        assert_eq!(value["cursor"], 100);
+
+        let parsed: VaultMigrationTrackingData =
+            value.parse_value("VaultMigrationTrackingData").unwrap();
+        assert_eq!(parsed.stage, VaultMigrationStage::MovingRemaining);
+        assert_eq!(parsed.cursor, Some(100));
+        assert!(parsed.switched_at.is_some());
+        assert_eq!(
+            parsed.moved_payment_method_data[0].target_reference,
+            "target_ref"
+        );
+    }
 }
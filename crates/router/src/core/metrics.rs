@@ -5,6 +5,7 @@ metrics_context!(CONTEXT);
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
 counter_metric!(INCOMING_DISPUTE_WEBHOOK_METRIC, GLOBAL_METER); // No. of incoming dispute webhooks
+counter_metric!(INCOMING_PAYOUT_WEBHOOK_METRIC, GLOBAL_METER); // No. of incoming payout webhooks
 counter_metric!(
     INCOMING_DISPUTE_WEBHOOK_SIGNATURE_FAILURE_METRIC,
     GLOBAL_METER
@@ -32,6 +33,9 @@ counter_metric!(
     ATTACH_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
     GLOBAL_METER
 );
+counter_metric!(DISPUTE_POLICY_ACTION_METRIC, GLOBAL_METER); // No. of disputes acted upon by dispute policies
+counter_metric!(DISPUTE_SYNC_RECONCILED_METRIC, GLOBAL_METER); // No. of disputes created or updated by the dispute sync workflow
+counter_metric!(DISPUTE_ALERT_OUTCOME_METRIC, GLOBAL_METER); // No. of pre-dispute alerts processed, by outcome
 
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
@@ -40,6 +44,7 @@ counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_PAYMENT_NOT_FOUND, GLOBAL_METER);
+counter_metric!(WEBHOOK_WATCHDOG_OUTCOME_METRIC, GLOBAL_METER); // No. of expected payment webhooks received, received late or missed
 counter_metric!(
     WEBHOOK_EVENT_TYPE_IDENTIFICATION_FAILURE_COUNT,
     GLOBAL_METER
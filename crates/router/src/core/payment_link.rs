@@ -139,9 +139,11 @@ pub async fn initiate_payment_link_flow(
         &payment_intent.status,
         &[
             storage_enums::IntentStatus::Cancelled,
+            storage_enums::IntentStatus::Expired,
             storage_enums::IntentStatus::Failed,
             storage_enums::IntentStatus::Processing,
             storage_enums::IntentStatus::RequiresCapture,
+            storage_enums::IntentStatus::PartiallyAuthorized,
             storage_enums::IntentStatus::RequiresMerchantAction,
             storage_enums::IntentStatus::Succeeded,
             storage_enums::IntentStatus::PartiallyCaptured,
@@ -0,0 +1,1107 @@
+use api_models::{enums as api_enums, payment_methods::Card};
+use common_utils::{
+    consts,
+    crypto::{self, SignMessage},
+    ext_traits::{Encode, StringExt},
+    fp_utils::when,
+    generate_id,
+};
+use diesel_models::{configs, encryption::Encryption};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::{cards, transformers as payment_methods};
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    headers,
+    routes::AppState,
+    services,
+    types::{
+        domain::{
+            self,
+            types::{decrypt, encrypt},
+        },
+        storage,
+    },
+    utils::{ConnectorResponseExt, OptionExt},
+};
+
+/// A vault storing the cards and other payment method data saved by merchants. Stored data is
+/// identified by the reference returned when it is stored, which is saved as the locker ID of the
+/// payment method.
+#[async_trait::async_trait]
+pub trait CardVault: Send + Sync {
+    /// Stores the card or payment method data in the vault. If the card is already stored for the
+    /// customer, the reference of the stored card is returned along with the result of the
+    /// duplication check, and the stored card is left unchanged.
+    async fn store(
+        &self,
+        state: &AppState,
+        payload: &payment_methods::StoreLockerReq<'_>,
+        customer_id: &str,
+    ) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError>;
+
+    async fn retrieve_card(
+        &self,
+        state: &AppState,
+        customer_id: &str,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<Card, errors::VaultError>;
+
+    /// Retrieves the payment method data stored as generic data, decrypted using the merchant key
+    async fn retrieve_payment_method_data(
+        &self,
+        state: &AppState,
+        key_store: &domain::MerchantKeyStore,
+        customer_id: &str,
+        merchant_id: &str,
+        payment_method_reference: &str,
+    ) -> CustomResult<Secret<String>, errors::VaultError>;
+
+    async fn delete(
+        &self,
+        state: &AppState,
+        customer_id: &str,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError>;
+}
+
+fn get_vault_provider_config_key(merchant_id: &str) -> String {
+    format!("vault_provider_{merchant_id}")
+}
+
+/// Vault used by merchants which have not been moved to a specific vault
+pub fn get_default_vault_provider(locker: &settings::Locker) -> api_enums::VaultProvider {
+    if locker.mock_locker {
+        api_enums::VaultProvider::MockLocker
+    } else {
+        api_enums::VaultProvider::HyperswitchCardVault
+    }
+}
+
+/// Vault in which the cards of the merchant are stored. The vault is read through the config
+/// cache, and merchants which have not been moved to a specific vault are recorded with the
+/// default vault so that the lookup is cached for them as well.
+#[instrument(skip(state))]
+pub async fn get_vault_provider(
+    state: &AppState,
+    merchant_id: &str,
+) -> CustomResult<api_enums::VaultProvider, errors::VaultError> {
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &get_vault_provider_config_key(merchant_id),
+            Some(get_default_vault_provider(&state.conf.locker).to_string()),
+        )
+        .await
+        .change_context(errors::VaultError::FetchVaultProviderFailed)
+        .attach_printable("Failed to fetch vault provider of the merchant")?
+        .config
+        .parse_enum("VaultProvider")
+        .change_context(errors::VaultError::FetchVaultProviderFailed)
+        .attach_printable("Failed to parse vault provider of the merchant")
+}
+
+/// Switches the vault in which the cards of the merchant are stored. Cards which are already
+/// stored are not moved to the new vault.
+#[instrument(skip(state))]
+pub async fn set_vault_provider(
+    state: &AppState,
+    merchant_id: &str,
+    vault_provider: api_enums::VaultProvider,
+) -> CustomResult<(), errors::StorageError> {
+    upsert_config(
+        state,
+        get_vault_provider_config_key(merchant_id),
+        vault_provider.to_string(),
+    )
+    .await
+}
+
+fn get_fallback_vault_provider_config_key(merchant_id: &str) -> String {
+    format!("fallback_vault_provider_{merchant_id}")
+}
+
+/// Vault from which the merchant was switched by a vault migration which has not yet moved all
+/// the data saved in it. Data which cannot be read from the vault of the merchant is read from
+/// it until the data has been moved. Merchants without such a vault are recorded with an empty
+/// config, so that the lookup is cached for them as well.
+#[instrument(skip(state))]
+pub async fn get_fallback_vault_provider(
+    state: &AppState,
+    merchant_id: &str,
+) -> CustomResult<Option<api_enums::VaultProvider>, errors::VaultError> {
+    let config = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &get_fallback_vault_provider_config_key(merchant_id),
+            Some(String::new()),
+        )
+        .await
+        .change_context(errors::VaultError::FetchVaultProviderFailed)
+        .attach_printable("Failed to fetch fallback vault provider of the merchant")?
+        .config;
+
+    (!config.is_empty())
+        .then(|| config.parse_enum("VaultProvider"))
+        .transpose()
+        .change_context(errors::VaultError::FetchVaultProviderFailed)
+        .attach_printable("Failed to parse fallback vault provider of the merchant")
+}
+
+#[instrument(skip(state))]
+pub async fn set_fallback_vault_provider(
+    state: &AppState,
+    merchant_id: &str,
+    vault_provider: api_enums::VaultProvider,
+) -> CustomResult<(), errors::StorageError> {
+    upsert_config(
+        state,
+        get_fallback_vault_provider_config_key(merchant_id),
+        vault_provider.to_string(),
+    )
+    .await
+}
+
+#[instrument(skip(state))]
+pub async fn remove_fallback_vault_provider(
+    state: &AppState,
+    merchant_id: &str,
+) -> CustomResult<(), errors::StorageError> {
+    match state
+        .store
+        .delete_config_by_key(&get_fallback_vault_provider_config_key(merchant_id))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+pub(crate) async fn upsert_config(
+    state: &AppState,
+    key: String,
+    config: String,
+) -> CustomResult<(), errors::StorageError> {
+    let db = &*state.store;
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn get_card_vault(vault_provider: api_enums::VaultProvider) -> &'static dyn CardVault {
+    match vault_provider {
+        api_enums::VaultProvider::HyperswitchCardVault => &HyperswitchCardVault,
+        api_enums::VaultProvider::MockLocker => &MockLocker,
+        api_enums::VaultProvider::LocalEncrypted => &LocalEncryptedVault,
+        api_enums::VaultProvider::External => &ExternalVault,
+    }
+}
+
+/// Vault in which the cards of the merchant are stored
+pub async fn get_merchant_card_vault(
+    state: &AppState,
+    merchant_id: &str,
+) -> CustomResult<&'static dyn CardVault, errors::VaultError> {
+    get_vault_provider(state, merchant_id)
+        .await
+        .map(get_card_vault)
+}
+
+/// Vault to read from when the data cannot be read from the vault of the merchant, while a vault
+/// migration of the merchant is moving the data saved just before it was switched
+pub async fn get_fallback_card_vault(
+    state: &AppState,
+    merchant_id: &str,
+) -> CustomResult<Option<&'static dyn CardVault>, errors::VaultError> {
+    get_fallback_vault_provider(state, merchant_id)
+        .await
+        .map(|vault_provider| vault_provider.map(get_card_vault))
+}
+
+/// Hyperswitch card vault, reached over JWE encrypted HTTP requests
+pub struct HyperswitchCardVault;
+
+#[async_trait::async_trait]
+impl CardVault for HyperswitchCardVault {
+    #[instrument(skip_all)]
+    async fn store(
+        &self,
+        state: &AppState,
+        payload: &payment_methods::StoreLockerReq<'_>,
+        _customer_id: &str,
+    ) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
+        let locker = &state.conf.locker;
+        let jwekey = state.conf.jwekey.get_inner();
+        let locker_choice = api_enums::LockerChoice::HyperswitchCardVault;
+
+        let request =
+            payment_methods::mk_add_locker_request_hs(jwekey, locker, payload, locker_choice)
+                .await?;
+        let response = services::call_connector_api(state, request, "add_card_to_hs_locker")
+            .await
+            .change_context(errors::VaultError::SaveCardFailed);
+
+        let jwe_body: services::JweBody = response
+            .get_response_inner("JweBody")
+            .change_context(errors::VaultError::FetchCardFailed)?;
+
+        let decrypted_payload =
+            payment_methods::get_decrypted_response_payload(jwekey, jwe_body, Some(locker_choice))
+                .await
+                .change_context(errors::VaultError::SaveCardFailed)
+                .attach_printable("Error getting decrypted response payload")?;
+        let stored_card_resp: payment_methods::StoreCardResp = decrypted_payload
+            .parse_struct("StoreCardResp")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+        stored_card_resp
+            .payload
+            .get_required_value("StoreCardRespPayload")
+            .change_context(errors::VaultError::SaveCardFailed)
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve_card(
+        &self,
+        state: &AppState,
+        customer_id: &str,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<Card, errors::VaultError> {
+        let locker = &state.conf.locker;
+        let jwekey = &state.conf.jwekey.get_inner();
+        let locker_choice = api_enums::LockerChoice::HyperswitchCardVault;
+
+        let request = payment_methods::mk_get_card_request_hs(
+            jwekey,
+            locker,
+            customer_id,
+            merchant_id,
+            card_reference,
+            Some(locker_choice),
+        )
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Making get card request failed")?;
+        let response = services::call_connector_api(state, request, "get_card_from_locker")
+            .await
+            .change_context(errors::VaultError::FetchCardFailed)
+            .attach_printable("Failed while executing call_connector_api for get_card");
+        let jwe_body: services::JweBody = response
+            .get_response_inner("JweBody")
+            .change_context(errors::VaultError::FetchCardFailed)?;
+        let decrypted_payload =
+            payment_methods::get_decrypted_response_payload(jwekey, jwe_body, Some(locker_choice))
+                .await
+                .change_context(errors::VaultError::FetchCardFailed)
+                .attach_printable("Error getting decrypted response payload for get card")?;
+        let get_card_resp: payment_methods::RetrieveCardResp = decrypted_payload
+            .parse_struct("RetrieveCardResp")
+            .change_context(errors::VaultError::FetchCardFailed)?;
+        let retrieve_card_resp = get_card_resp
+            .payload
+            .get_required_value("RetrieveCardRespPayload")
+            .change_context(errors::VaultError::FetchCardFailed)?;
+        retrieve_card_resp
+            .card
+            .get_required_value("Card")
+            .change_context(errors::VaultError::FetchCardFailed)
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve_payment_method_data(
+        &self,
+        state: &AppState,
+        key_store: &domain::MerchantKeyStore,
+        customer_id: &str,
+        merchant_id: &str,
+        payment_method_reference: &str,
+    ) -> CustomResult<Secret<String>, errors::VaultError> {
+        let locker = &state.conf.locker;
+        let jwekey = state.conf.jwekey.get_inner();
+        let locker_choice = Some(api_enums::LockerChoice::HyperswitchCardVault);
+
+        let request = payment_methods::mk_get_card_request_hs(
+            jwekey,
+            locker,
+            customer_id,
+            merchant_id,
+            payment_method_reference,
+            locker_choice,
+        )
+        .await
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)
+        .attach_printable("Making get payment method request failed")?;
+        let response = services::call_connector_api(state, request, "add_card_to_locker")
+            .await
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)
+            .attach_printable("Failed while executing call_connector_api for get_card");
+        let jwe_body: services::JweBody = response
+            .get_response_inner("JweBody")
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
+        let decrypted_payload =
+            payment_methods::get_decrypted_response_payload(jwekey, jwe_body, locker_choice)
+                .await
+                .change_context(errors::VaultError::FetchPaymentMethodFailed)
+                .attach_printable("Error getting decrypted response payload for get card")?;
+        let get_card_resp: payment_methods::RetrieveCardResp = decrypted_payload
+            .parse_struct("RetrieveCardResp")
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)
+            .attach_printable("Failed to parse struct to RetrieveCardResp")?;
+        let retrieve_card_resp = get_card_resp
+            .payload
+            .get_required_value("RetrieveCardRespPayload")
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)
+            .attach_printable("Failed to retrieve field - payload from RetrieveCardResp")?;
+        let enc_card_data = retrieve_card_resp
+            .enc_card_data
+            .get_required_value("enc_card_data")
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)
+            .attach_printable(
+                "Failed to retrieve field - enc_card_data from RetrieveCardRespPayload",
+            )?;
+        cards::decode_and_decrypt_locker_data(key_store, enc_card_data.peek().to_string()).await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete(
+        &self,
+        state: &AppState,
+        customer_id: &str,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+        let locker = &state.conf.locker;
+        let jwekey = &state.conf.jwekey.get_inner();
+
+        let request = payment_methods::mk_delete_card_request_hs(
+            jwekey,
+            locker,
+            customer_id,
+            merchant_id,
+            card_reference,
+        )
+        .await
+        .change_context(errors::VaultError::DeleteCardFailed)
+        .attach_printable("Making delete card request failed")?;
+        let response = services::call_connector_api(state, request, "delete_card_from_locker")
+            .await
+            .change_context(errors::VaultError::DeleteCardFailed)
+            .attach_printable("Failed while executing call_connector_api for delete card");
+        let jwe_body: services::JweBody = response
+            .get_response_inner("JweBody")
+            .change_context(errors::VaultError::DeleteCardFailed)?;
+        let decrypted_payload = payment_methods::get_decrypted_response_payload(
+            jwekey,
+            jwe_body,
+            Some(api_enums::LockerChoice::HyperswitchCardVault),
+        )
+        .await
+        .change_context(errors::VaultError::DeleteCardFailed)
+        .attach_printable("Error getting decrypted response payload for delete card")?;
+        decrypted_payload
+            .parse_struct("DeleteCardResp")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+    }
+}
+
+/// The `locker_mock_up` table, which stores card details in plain text and is meant for local
+/// development and testing only
+pub struct MockLocker;
+
+impl MockLocker {
+    /// Cards are stored under the requested reference when one is given, so that a vault
+    /// migration to the mock locker keeps the locker ID of the payment methods it moves
+    fn get_card_id(payload: &payment_methods::StoreLockerReq<'_>) -> String {
+        payload
+            .get_requestor_card_reference()
+            .map(ToOwned::to_owned)
+            .unwrap_or_else(|| generate_id(consts::ID_LENGTH, "card"))
+    }
+}
+
+#[async_trait::async_trait]
+impl CardVault for MockLocker {
+    #[instrument(skip_all)]
+    async fn store(
+        &self,
+        state: &AppState,
+        payload: &payment_methods::StoreLockerReq<'_>,
+        customer_id: &str,
+    ) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
+        let card_id = Self::get_card_id(payload);
+
+        cards::mock_call_to_locker_hs(
+            &*state.store,
+            &card_id,
+            payload,
+            None,
+            None,
+            Some(customer_id),
+        )
+        .await?
+        .payload
+        .get_required_value("StoreCardRespPayload")
+        .change_context(errors::VaultError::SaveCardFailed)
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve_card(
+        &self,
+        state: &AppState,
+        _customer_id: &str,
+        _merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<Card, errors::VaultError> {
+        let (get_card_resp, _) = cards::mock_get_card(&*state.store, card_reference).await?;
+        payment_methods::mk_get_card_response(get_card_resp)
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve_payment_method_data(
+        &self,
+        state: &AppState,
+        key_store: &domain::MerchantKeyStore,
+        _customer_id: &str,
+        _merchant_id: &str,
+        payment_method_reference: &str,
+    ) -> CustomResult<Secret<String>, errors::VaultError> {
+        Ok(
+            cards::mock_get_payment_method(&*state.store, key_store, payment_method_reference)
+                .await?
+                .payment_method
+                .payment_method_data,
+        )
+    }
+
+    #[instrument(skip_all)]
+    async fn delete(
+        &self,
+        state: &AppState,
+        _customer_id: &str,
+        _merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+        cards::mock_delete_card_hs(&*state.store, card_reference)
+            .await
+            .change_context(errors::VaultError::DeleteCardFailed)
+    }
+}
+
+/// A third party vault, reached over HTTP at the configured `external_vault` URL. Requests are
+/// authenticated with the configured API key sent as a bearer token, and are made to the following
+/// endpoints:
+///
+/// - `POST /vault/data` stores a card or the payment method data already encrypted with the
+///   merchant key, under the requested reference when one is given, and returns the reference of
+///   the stored data along with the result of the duplication check
+/// - `POST /vault/data/retrieve` returns the data stored under a reference
+/// - `POST /vault/data/delete` deletes the data stored under a reference
+pub struct ExternalVault;
+
+#[derive(Debug, serde::Serialize)]
+struct ExternalVaultStoreRequest {
+    merchant_id: String,
+    customer_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<Card>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enc_data: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ExternalVaultStoreResponse {
+    reference: String,
+    duplication_check: Option<payment_methods::DataDuplicationCheck>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ExternalVaultReferenceRequest {
+    merchant_id: String,
+    customer_id: String,
+    reference: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ExternalVaultRetrieveResponse {
+    card: Option<Card>,
+    enc_data: Option<Secret<String>>,
+}
+
+impl ExternalVault {
+    fn build_request(
+        state: &AppState,
+        path: &str,
+        body: common_utils::request::RequestContent,
+    ) -> CustomResult<services::Request, errors::VaultError> {
+        let external_vault = state.conf.external_vault.get_inner();
+        when(!external_vault.is_enabled(), || {
+            Err(report!(errors::VaultError::FetchVaultProviderFailed))
+                .attach_printable("External vault is not configured")
+        })?;
+
+        let url = format!("{}{path}", external_vault.base_url.trim_end_matches('/'));
+        let mut request = services::Request::new(services::Method::Post, &url);
+        request.add_header(headers::CONTENT_TYPE, "application/json".into());
+        request.add_header(
+            headers::AUTHORIZATION,
+            format!("Bearer {}", external_vault.api_key.peek()).into_masked(),
+        );
+        request.set_body(body);
+        Ok(request)
+    }
+
+    async fn retrieve(
+        state: &AppState,
+        customer_id: &str,
+        merchant_id: &str,
+        reference: &str,
+    ) -> CustomResult<ExternalVaultRetrieveResponse, errors::VaultError> {
+        let request = Self::build_request(
+            state,
+            "/vault/data/retrieve",
+            common_utils::request::RequestContent::Json(Box::new(ExternalVaultReferenceRequest {
+                merchant_id: merchant_id.to_owned(),
+                customer_id: customer_id.to_owned(),
+                reference: reference.to_owned(),
+            })),
+        )?;
+        services::call_connector_api(state, request, "retrieve_from_external_vault")
+            .await
+            .get_response_inner("ExternalVaultRetrieveResponse")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Failed to retrieve data from external vault")
+    }
+}
+
+#[async_trait::async_trait]
+impl CardVault for ExternalVault {
+    #[instrument(skip_all)]
+    async fn store(
+        &self,
+        state: &AppState,
+        payload: &payment_methods::StoreLockerReq<'_>,
+        customer_id: &str,
+    ) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
+        let (card, enc_data) = match payload {
+            payment_methods::StoreLockerReq::LockerCard(store_card_req) => {
+                (Some(store_card_req.card.clone()), None)
+            }
+            payment_methods::StoreLockerReq::LockerGeneric(store_generic_req) => {
+                (None, Some(store_generic_req.enc_data.clone()))
+            }
+        };
+        let request = Self::build_request(
+            state,
+            "/vault/data",
+            common_utils::request::RequestContent::Json(Box::new(ExternalVaultStoreRequest {
+                merchant_id: payload.get_merchant_id().to_owned(),
+                customer_id: customer_id.to_owned(),
+                reference: payload
+                    .get_requestor_card_reference()
+                    .map(ToOwned::to_owned),
+                card,
+                enc_data,
+            })),
+        )?;
+
+        let stored_data: ExternalVaultStoreResponse =
+            services::call_connector_api(state, request, "store_in_external_vault")
+                .await
+                .get_response_inner("ExternalVaultStoreResponse")
+                .change_context(errors::VaultError::SaveCardFailed)
+                .attach_printable("Failed to store data in external vault")?;
+
+        Ok(payment_methods::StoreCardRespPayload {
+            card_reference: stored_data.reference,
+            duplication_check: stored_data.duplication_check,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve_card(
+        &self,
+        state: &AppState,
+        customer_id: &str,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<Card, errors::VaultError> {
+        Self::retrieve(state, customer_id, merchant_id, card_reference)
+            .await
+            .change_context(errors::VaultError::FetchCardFailed)?
+            .card
+            .get_required_value("card")
+            .change_context(errors::VaultError::FetchCardFailed)
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve_payment_method_data(
+        &self,
+        state: &AppState,
+        key_store: &domain::MerchantKeyStore,
+        customer_id: &str,
+        merchant_id: &str,
+        payment_method_reference: &str,
+    ) -> CustomResult<Secret<String>, errors::VaultError> {
+        let enc_data = Self::retrieve(state, customer_id, merchant_id, payment_method_reference)
+            .await
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)?
+            .enc_data
+            .get_required_value("enc_data")
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
+        cards::decode_and_decrypt_locker_data(key_store, enc_data.expose()).await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete(
+        &self,
+        state: &AppState,
+        customer_id: &str,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+        let request = Self::build_request(
+            state,
+            "/vault/data/delete",
+            common_utils::request::RequestContent::Json(Box::new(ExternalVaultReferenceRequest {
+                merchant_id: merchant_id.to_owned(),
+                customer_id: customer_id.to_owned(),
+                reference: card_reference.to_owned(),
+            })),
+        )?;
+        services::call_connector_api(state, request, "delete_from_external_vault")
+            .await
+            .get_response()
+            .change_context(errors::VaultError::DeleteCardFailed)
+            .attach_printable("Failed to delete data from external vault")?;
+
+        Ok(payment_methods::DeleteCardResp {
+            status: "Ok".to_string(),
+            error_message: None,
+            error_code: None,
+        })
+    }
+}
+
+/// Label from which the card fingerprint key of a merchant is derived
+const CARD_FINGERPRINT_KEY_DERIVATION_LABEL: &[u8] = b"local_encrypted_vault_card_fingerprint";
+
+/// The `vault_entry` table in the application database, which stores the payment method data
+/// encrypted with the merchant key
+pub struct LocalEncryptedVault;
+
+impl LocalEncryptedVault {
+    async fn get_merchant_key_store(
+        state: &AppState,
+        merchant_id: &str,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        state
+            .store
+            .get_merchant_key_store_by_merchant_id(
+                merchant_id,
+                &state.store.get_master_key().to_vec().into(),
+            )
+            .await
+    }
+
+    /// Key used to compute the card fingerprints of the merchant, derived from the merchant key so
+    /// that the key encrypting the card data is never used as a MAC key as well
+    fn get_fingerprint_key(merchant_key: &[u8]) -> CustomResult<Vec<u8>, errors::VaultError> {
+        crypto::HmacSha256
+            .sign_message(merchant_key, CARD_FINGERPRINT_KEY_DERIVATION_LABEL)
+            .change_context(errors::VaultError::GenerateFingerprintFailed)
+            .attach_printable("Failed to derive card fingerprint key")
+    }
+
+    fn get_card_fingerprint(
+        merchant_key: &[u8],
+        card_number: &[u8],
+    ) -> CustomResult<String, errors::VaultError> {
+        let fingerprint_key = Self::get_fingerprint_key(merchant_key)?;
+        crypto::HmacSha512
+            .sign_message(&fingerprint_key, card_number)
+            .change_context(errors::VaultError::GenerateFingerprintFailed)
+            .map(hex::encode)
+    }
+
+    async fn decrypt_vault_entry(
+        key_store: &domain::MerchantKeyStore,
+        vault_entry: storage::VaultEntry,
+    ) -> CustomResult<Secret<String>, errors::VaultError> {
+        decrypt(Some(vault_entry.enc_data), key_store.key.get_inner().peek())
+            .await
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)?
+            .map_or(
+                Err(report!(errors::VaultError::FetchPaymentMethodFailed)),
+                |decrypted| Ok(decrypted.into_inner()),
+            )
+    }
+
+    async fn get_duplication_check(
+        key_store: &domain::MerchantKeyStore,
+        existing_entry: storage::VaultEntry,
+        card: Option<&Card>,
+    ) -> CustomResult<payment_methods::DataDuplicationCheck, errors::VaultError> {
+        let Some(card) = card else {
+            return Ok(payment_methods::DataDuplicationCheck::Duplicated);
+        };
+        let existing_card: Card = Self::decrypt_vault_entry(key_store, existing_entry)
+            .await?
+            .expose()
+            .parse_struct("Card")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+        if existing_card.card_number.peek() != card.card_number.peek() {
+            Err(report!(errors::VaultError::SaveCardFailed))
+                .attach_printable("Card reference is already in use by a different card")?
+        }
+
+        let is_metadata_unchanged = existing_card.card_exp_month.peek()
+            == card.card_exp_month.peek()
+            && existing_card.card_exp_year.peek() == card.card_exp_year.peek()
+            && existing_card.name_on_card.as_ref().map(PeekInterface::peek)
+                == card.name_on_card.as_ref().map(PeekInterface::peek)
+            && existing_card.nick_name == card.nick_name;
+
+        Ok(if is_metadata_unchanged {
+            payment_methods::DataDuplicationCheck::Duplicated
+        } else {
+            payment_methods::DataDuplicationCheck::MetaDataChanged
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl CardVault for LocalEncryptedVault {
+    #[instrument(skip_all)]
+    async fn store(
+        &self,
+        state: &AppState,
+        payload: &payment_methods::StoreLockerReq<'_>,
+        customer_id: &str,
+    ) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
+        let db = &*state.store;
+        let merchant_id = payload.get_merchant_id();
+        let key_store = Self::get_merchant_key_store(state, merchant_id)
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)
+            .attach_printable("Failed to fetch merchant key store")?;
+        let key = key_store.key.get_inner().peek();
+
+        let (card, fingerprint, enc_data) = match payload {
+            payment_methods::StoreLockerReq::LockerCard(store_card_req) => {
+                let card = &store_card_req.card;
+                let fingerprint =
+                    Self::get_card_fingerprint(key, card.card_number.peek().as_bytes())?;
+                let card_data = card
+                    .encode_to_string_of_json()
+                    .change_context(errors::VaultError::RequestEncodingFailed)?;
+                let enc_data = encrypt(Secret::<String>::new(card_data), key)
+                    .await
+                    .change_context(errors::VaultError::SaveCardFailed)
+                    .attach_printable("Failed to encrypt card data")?;
+                (Some(card), Some(fingerprint), Encryption::from(enc_data))
+            }
+            // Generic data is already encrypted with the merchant key by the caller
+            payment_methods::StoreLockerReq::LockerGeneric(store_generic_req) => {
+                let enc_data = hex::decode(&store_generic_req.enc_data)
+                    .change_context(errors::VaultError::RequestEncodingFailed)
+                    .attach_printable("Failed to decode hex string into bytes")?;
+                (None, None, Encryption::new(enc_data.into()))
+            }
+        };
+
+        let existing_entry = match (payload.get_requestor_card_reference(), &fingerprint) {
+            (Some(card_reference), _) => {
+                match db
+                    .find_vault_entry_by_merchant_id_customer_id_vault_reference(
+                        merchant_id,
+                        customer_id,
+                        card_reference,
+                    )
+                    .await
+                {
+                    Ok(vault_entry) => Some(vault_entry),
+                    Err(error) if error.current_context().is_db_not_found() => None,
+                    Err(error) => Err(error)
+                        .change_context(errors::VaultError::SaveCardFailed)
+                        .attach_printable("Failed to fetch vault entry")?,
+                }
+            }
+            (None, Some(fingerprint)) => db
+                .find_vault_entry_by_merchant_id_customer_id_fingerprint(
+                    merchant_id,
+                    customer_id,
+                    fingerprint,
+                )
+                .await
+                .change_context(errors::VaultError::SaveCardFailed)
+                .attach_printable("Failed to fetch vault entry")?,
+            (None, None) => None,
+        };
+
+        if let Some(existing_entry) = existing_entry {
+            let card_reference = existing_entry.vault_reference.clone();
+            let duplication_check =
+                Self::get_duplication_check(&key_store, existing_entry, card).await?;
+            return Ok(payment_methods::StoreCardRespPayload {
+                card_reference,
+                duplication_check: Some(duplication_check),
+            });
+        }
+
+        let now = common_utils::date_time::now();
+        let vault_entry = db
+            .insert_vault_entry(storage::VaultEntryNew {
+                merchant_id: merchant_id.to_owned(),
+                vault_reference: payload
+                    .get_requestor_card_reference()
+                    .map(ToOwned::to_owned)
+                    .unwrap_or_else(|| generate_id(consts::ID_LENGTH, "card")),
+                customer_id: customer_id.to_owned(),
+                fingerprint,
+                enc_data,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)
+            .attach_printable("Failed to insert vault entry")?;
+
+        Ok(payment_methods::StoreCardRespPayload {
+            card_reference: vault_entry.vault_reference,
+            duplication_check: None,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve_card(
+        &self,
+        state: &AppState,
+        customer_id: &str,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<Card, errors::VaultError> {
+        let key_store = Self::get_merchant_key_store(state, merchant_id)
+            .await
+            .change_context(errors::VaultError::FetchCardFailed)
+            .attach_printable("Failed to fetch merchant key store")?;
+        let vault_entry = state
+            .store
+            .find_vault_entry_by_merchant_id_customer_id_vault_reference(
+                merchant_id,
+                customer_id,
+                card_reference,
+            )
+            .await
+            .change_context(errors::VaultError::FetchCardFailed)?;
+
+        Self::decrypt_vault_entry(&key_store, vault_entry)
+            .await
+            .change_context(errors::VaultError::FetchCardFailed)?
+            .expose()
+            .parse_struct("Card")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve_payment_method_data(
+        &self,
+        state: &AppState,
+        key_store: &domain::MerchantKeyStore,
+        customer_id: &str,
+        merchant_id: &str,
+        payment_method_reference: &str,
+    ) -> CustomResult<Secret<String>, errors::VaultError> {
+        let vault_entry = state
+            .store
+            .find_vault_entry_by_merchant_id_customer_id_vault_reference(
+                merchant_id,
+                customer_id,
+                payment_method_reference,
+            )
+            .await
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
+
+        Self::decrypt_vault_entry(key_store, vault_entry).await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete(
+        &self,
+        state: &AppState,
+        customer_id: &str,
+        merchant_id: &str,
+        card_reference: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+        state
+            .store
+            .delete_vault_entry_by_merchant_id_customer_id_vault_reference(
+                merchant_id,
+                customer_id,
+                card_reference,
+            )
+            .await
+            .change_context(errors::VaultError::DeleteCardFailed)?;
+        logger::debug!("card deleted from local encrypted vault");
+
+        Ok(payment_methods::DeleteCardResp {
+            status: "Ok".to_string(),
+            error_code: None,
+            error_message: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const CARD_NUMBER: &[u8] = b"4242424242424242";
+
+    #[test]
+    fn test_card_fingerprint_is_not_keyed_with_merchant_key() {
+        let merchant_key = [7u8; 32];
+        let fingerprint =
+            LocalEncryptedVault::get_card_fingerprint(&merchant_key, CARD_NUMBER).unwrap();
+        let merchant_key_mac = hex::encode(
+            crypto::HmacSha512
+                .sign_message(&merchant_key, CARD_NUMBER)
+                .unwrap(),
+        );
+
+        assert_ne!(fingerprint, merchant_key_mac);
+        assert_ne!(
+            LocalEncryptedVault::get_fingerprint_key(&merchant_key).unwrap(),
+            merchant_key.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_card_fingerprint_is_stable_per_merchant() {
+        let merchant_key = [7u8; 32];
+        let other_merchant_key = [8u8; 32];
+
+        assert_eq!(
+            LocalEncryptedVault::get_card_fingerprint(&merchant_key, CARD_NUMBER).unwrap(),
+            LocalEncryptedVault::get_card_fingerprint(&merchant_key, CARD_NUMBER).unwrap()
+        );
+        assert_ne!(
+            LocalEncryptedVault::get_card_fingerprint(&merchant_key, CARD_NUMBER).unwrap(),
+            LocalEncryptedVault::get_card_fingerprint(&other_merchant_key, CARD_NUMBER).unwrap()
+        );
+        assert_ne!(
+            LocalEncryptedVault::get_card_fingerprint(&merchant_key, CARD_NUMBER).unwrap(),
+            LocalEncryptedVault::get_card_fingerprint(&merchant_key, b"4000056655665556").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_mock_locker_card_id() {
+        let card: Card = serde_json::from_value(serde_json::json!({
+            "card_number": "4242424242424242",
+            "name_on_card": "John Doe",
+            "card_exp_month": "10",
+            "card_exp_year": "2030",
+            "card_brand": null,
+            "card_isin": null,
+            "nick_name": null,
+        }))
+        .unwrap();
+        let mut payload =
+            payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+                merchant_id: "merchant_1",
+                merchant_customer_id: "cus_1".to_string(),
+                requestor_card_reference: None,
+                card,
+            });
+
+        let card_id = MockLocker::get_card_id(&payload);
+        assert!(card_id.starts_with("card_"));
+        assert_ne!(card_id, MockLocker::get_card_id(&payload));
+
+        // A card moved by a vault migration keeps its reference
+        payload.update_requestor_card_reference(Some("card_1".to_string()));
+        assert_eq!(MockLocker::get_card_id(&payload), "card_1");
+    }
+
+    #[test]
+    fn test_external_vault_store_request_for_generic_data() {
+        let request = ExternalVaultStoreRequest {
+            merchant_id: "merchant_1".to_string(),
+            customer_id: "cus_1".to_string(),
+            reference: None,
+            card: None,
+            enc_data: Some("abcd".to_string()),
+        };
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({
+                "merchant_id": "merchant_1",
+                "customer_id": "cus_1",
+                "enc_data": "abcd",
+            })
+        );
+    }
+
+    #[test]
+    fn test_external_vault_store_response() {
+        let response: ExternalVaultStoreResponse = serde_json::from_value(serde_json::json!({
+            "reference": "card_1",
+            "duplication_check": "meta_data_changed",
+        }))
+        .unwrap();
+        assert_eq!(response.reference, "card_1");
+        assert_eq!(
+            response.duplication_check,
+            Some(payment_methods::DataDuplicationCheck::MetaDataChanged)
+        );
+
+        let response: ExternalVaultStoreResponse =
+            serde_json::from_value(serde_json::json!({ "reference": "card_2" })).unwrap();
+        assert_eq!(response.duplication_check, None);
+    }
+
+    #[test]
+    fn test_external_vault_retrieve_response_with_card() {
+        let response: ExternalVaultRetrieveResponse = serde_json::from_value(serde_json::json!({
+            "card": {
+                "card_number": "4242424242424242",
+                "name_on_card": "John Doe",
+                "card_exp_month": "10",
+                "card_exp_year": "2030",
+                "card_brand": null,
+                "card_isin": null,
+                "nick_name": null,
+            },
+        }))
+        .unwrap();
+
+        assert!(response.enc_data.is_none());
+        assert_eq!(response.card.unwrap().card_exp_year.peek(), "2030");
+    }
+}
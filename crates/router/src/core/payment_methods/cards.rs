@@ -44,7 +44,7 @@ use crate::{
     configs::settings,
     core::{
         errors::{self, StorageErrorExt},
-        payment_methods::{transformers as payment_methods, vault},
+        payment_methods::{card_vault, transformers as payment_methods, vault},
         payments::{
             helpers,
             routing::{self, SessionFlowRoutingInput},
@@ -68,7 +68,7 @@ use crate::{
         storage::{self, enums, PaymentMethodListContext, PaymentTokenData},
         transformers::ForeignFrom,
     },
-    utils::{self, ConnectorResponseExt, OptionExt},
+    utils::{self, OptionExt},
 };
 
 #[instrument(skip_all)]
@@ -600,7 +600,6 @@ pub async fn add_payment_method(
                         &card,
                         customer_id.clone(),
                         merchant_account,
-                        api::enums::LockerChoice::HyperswitchCardVault,
                         Some(
                             existing_pm
                                 .locker_id
@@ -1037,13 +1036,7 @@ pub async fn add_bank_to_locker(
             merchant_customer_id: customer_id.to_owned(),
             enc_data,
         });
-    let store_resp = call_to_locker_hs(
-        state,
-        &payload,
-        customer_id,
-        api_enums::LockerChoice::HyperswitchCardVault,
-    )
-    .await?;
+    let store_resp = call_to_locker_hs(state, &payload, customer_id).await?;
     let payment_method_resp = payment_methods::mk_add_bank_response_hs(
         bank.clone(),
         store_resp.card_reference,
@@ -1077,7 +1070,6 @@ pub async fn add_card_to_locker(
                 card,
                 customer_id.to_string(),
                 merchant_account,
-                api_enums::LockerChoice::HyperswitchCardVault,
                 card_reference,
             )
             .await
@@ -1112,27 +1104,21 @@ pub async fn get_card_from_locker(
 
     let get_card_from_rs_locker_resp = request::record_operation_time(
         async {
-            get_card_from_hs_locker(
-                state,
-                customer_id,
-                merchant_id,
-                card_reference,
-                api_enums::LockerChoice::HyperswitchCardVault,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while getting card from hyperswitch card vault")
-            .map_err(|error| {
-                metrics::CARD_LOCKER_FAILURES.add(
-                    &metrics::CONTEXT,
-                    1,
-                    &[
-                        router_env::opentelemetry::KeyValue::new("locker", "rust"),
-                        router_env::opentelemetry::KeyValue::new("operation", "get"),
-                    ],
-                );
-                error
-            })
+            get_card_from_hs_locker(state, customer_id, merchant_id, card_reference)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while getting card from vault")
+                .map_err(|error| {
+                    metrics::CARD_LOCKER_FAILURES.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &[
+                            router_env::opentelemetry::KeyValue::new("locker", "rust"),
+                            router_env::opentelemetry::KeyValue::new("operation", "get"),
+                        ],
+                    );
+                    error
+                })
         },
         &metrics::CARD_GET_TIME,
         &[router_env::opentelemetry::KeyValue::new("locker", "rust")],
@@ -1173,7 +1159,6 @@ pub async fn add_card_hs(
     card: &api::CardDetail,
     customer_id: String,
     merchant_account: &domain::MerchantAccount,
-    locker_choice: api_enums::LockerChoice,
     card_reference: Option<&str>,
 ) -> errors::CustomResult<
     (
@@ -1197,8 +1182,7 @@ pub async fn add_card_hs(
         },
     });
 
-    let store_card_payload =
-        call_to_locker_hs(state, &payload, &customer_id, locker_choice).await?;
+    let store_card_payload = call_to_locker_hs(state, &payload, &customer_id).await?;
 
     let payment_method_resp = payment_methods::mk_add_card_response_hs(
         card.clone(),
@@ -1237,59 +1221,36 @@ pub async fn get_payment_method_from_hs_locker<'a>(
     customer_id: &str,
     merchant_id: &str,
     payment_method_reference: &'a str,
-    locker_choice: Option<api_enums::LockerChoice>,
 ) -> errors::CustomResult<Secret<String>, errors::VaultError> {
-    let locker = &state.conf.locker;
-    let jwekey = state.conf.jwekey.get_inner();
-
-    let payment_method_data = if !locker.mock_locker {
-        let request = payment_methods::mk_get_card_request_hs(
-            jwekey,
-            locker,
+    let result = card_vault::get_merchant_card_vault(state, merchant_id)
+        .await?
+        .retrieve_payment_method_data(
+            state,
+            key_store,
             customer_id,
             merchant_id,
             payment_method_reference,
-            locker_choice,
         )
-        .await
-        .change_context(errors::VaultError::FetchPaymentMethodFailed)
-        .attach_printable("Making get payment method request failed")?;
-        let response = services::call_connector_api(state, request, "add_card_to_locker")
-            .await
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)
-            .attach_printable("Failed while executing call_connector_api for get_card");
-        let jwe_body: services::JweBody = response
-            .get_response_inner("JweBody")
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
-        let decrypted_payload =
-            payment_methods::get_decrypted_response_payload(jwekey, jwe_body, locker_choice)
+        .await;
+
+    // Data saved just before the merchant was switched to another vault may not have been moved
+    // to it yet
+    match result {
+        Err(error) => match card_vault::get_fallback_card_vault(state, merchant_id).await? {
+            Some(fallback_vault) => fallback_vault
+                .retrieve_payment_method_data(
+                    state,
+                    key_store,
+                    customer_id,
+                    merchant_id,
+                    payment_method_reference,
+                )
                 .await
-                .change_context(errors::VaultError::FetchPaymentMethodFailed)
-                .attach_printable("Error getting decrypted response payload for get card")?;
-        let get_card_resp: payment_methods::RetrieveCardResp = decrypted_payload
-            .parse_struct("RetrieveCardResp")
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)
-            .attach_printable("Failed to parse struct to RetrieveCardResp")?;
-        let retrieve_card_resp = get_card_resp
-            .payload
-            .get_required_value("RetrieveCardRespPayload")
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)
-            .attach_printable("Failed to retrieve field - payload from RetrieveCardResp")?;
-        let enc_card_data = retrieve_card_resp
-            .enc_card_data
-            .get_required_value("enc_card_data")
-            .change_context(errors::VaultError::FetchPaymentMethodFailed)
-            .attach_printable(
-                "Failed to retrieve field - enc_card_data from RetrieveCardRespPayload",
-            )?;
-        decode_and_decrypt_locker_data(key_store, enc_card_data.peek().to_string()).await?
-    } else {
-        mock_get_payment_method(&*state.store, key_store, payment_method_reference)
-            .await?
-            .payment_method
-            .payment_method_data
-    };
-    Ok(payment_method_data)
+                .map_err(|_| error),
+            None => Err(error),
+        },
+        payment_method_data => payment_method_data,
+    }
 }
 
 #[instrument(skip_all)]
@@ -1297,42 +1258,11 @@ pub async fn call_to_locker_hs<'a>(
     state: &routes::AppState,
     payload: &payment_methods::StoreLockerReq<'a>,
     customer_id: &str,
-    locker_choice: api_enums::LockerChoice,
 ) -> errors::CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
-    let locker = &state.conf.locker;
-    let jwekey = state.conf.jwekey.get_inner();
-    let db = &*state.store;
-    let stored_card_response = if !locker.mock_locker {
-        let request =
-            payment_methods::mk_add_locker_request_hs(jwekey, locker, payload, locker_choice)
-                .await?;
-        let response = services::call_connector_api(state, request, "add_card_to_hs_locker")
-            .await
-            .change_context(errors::VaultError::SaveCardFailed);
-
-        let jwe_body: services::JweBody = response
-            .get_response_inner("JweBody")
-            .change_context(errors::VaultError::FetchCardFailed)?;
-
-        let decrypted_payload =
-            payment_methods::get_decrypted_response_payload(jwekey, jwe_body, Some(locker_choice))
-                .await
-                .change_context(errors::VaultError::SaveCardFailed)
-                .attach_printable("Error getting decrypted response payload")?;
-        let stored_card_resp: payment_methods::StoreCardResp = decrypted_payload
-            .parse_struct("StoreCardResp")
-            .change_context(errors::VaultError::ResponseDeserializationFailed)?;
-        stored_card_resp
-    } else {
-        let card_id = generate_id(consts::ID_LENGTH, "card");
-        mock_call_to_locker_hs(db, &card_id, payload, None, None, Some(customer_id)).await?
-    };
-
-    let stored_card = stored_card_response
-        .payload
-        .get_required_value("StoreCardRespPayload")
-        .change_context(errors::VaultError::SaveCardFailed)?;
-    Ok(stored_card)
+    card_vault::get_merchant_card_vault(state, payload.get_merchant_id())
+        .await?
+        .store(state, payload, customer_id)
+        .await
 }
 
 pub async fn update_payment_method(
@@ -1371,50 +1301,23 @@ pub async fn get_card_from_hs_locker<'a>(
     customer_id: &str,
     merchant_id: &str,
     card_reference: &'a str,
-    locker_choice: api_enums::LockerChoice,
 ) -> errors::CustomResult<Card, errors::VaultError> {
-    let locker = &state.conf.locker;
-    let jwekey = &state.conf.jwekey.get_inner();
+    let result = card_vault::get_merchant_card_vault(state, merchant_id)
+        .await?
+        .retrieve_card(state, customer_id, merchant_id, card_reference)
+        .await;
 
-    if !locker.mock_locker {
-        let request = payment_methods::mk_get_card_request_hs(
-            jwekey,
-            locker,
-            customer_id,
-            merchant_id,
-            card_reference,
-            Some(locker_choice),
-        )
-        .await
-        .change_context(errors::VaultError::FetchCardFailed)
-        .attach_printable("Making get card request failed")?;
-        let response = services::call_connector_api(state, request, "get_card_from_locker")
-            .await
-            .change_context(errors::VaultError::FetchCardFailed)
-            .attach_printable("Failed while executing call_connector_api for get_card");
-        let jwe_body: services::JweBody = response
-            .get_response_inner("JweBody")
-            .change_context(errors::VaultError::FetchCardFailed)?;
-        let decrypted_payload =
-            payment_methods::get_decrypted_response_payload(jwekey, jwe_body, Some(locker_choice))
+    // Cards saved just before the merchant was switched to another vault may not have been moved
+    // to it yet
+    match result {
+        Err(error) => match card_vault::get_fallback_card_vault(state, merchant_id).await? {
+            Some(fallback_vault) => fallback_vault
+                .retrieve_card(state, customer_id, merchant_id, card_reference)
                 .await
-                .change_context(errors::VaultError::FetchCardFailed)
-                .attach_printable("Error getting decrypted response payload for get card")?;
-        let get_card_resp: payment_methods::RetrieveCardResp = decrypted_payload
-            .parse_struct("RetrieveCardResp")
-            .change_context(errors::VaultError::FetchCardFailed)?;
-        let retrieve_card_resp = get_card_resp
-            .payload
-            .get_required_value("RetrieveCardRespPayload")
-            .change_context(errors::VaultError::FetchCardFailed)?;
-        retrieve_card_resp
-            .card
-            .get_required_value("Card")
-            .change_context(errors::VaultError::FetchCardFailed)
-    } else {
-        let (get_card_resp, _) = mock_get_card(&*state.store, card_reference).await?;
-        payment_methods::mk_get_card_response(get_card_resp)
-            .change_context(errors::VaultError::ResponseDeserializationFailed)
+                .map_err(|_| error),
+            None => Err(error),
+        },
+        card => card,
     }
 }
 
@@ -1425,44 +1328,32 @@ pub async fn delete_card_from_hs_locker<'a>(
     merchant_id: &str,
     card_reference: &'a str,
 ) -> errors::RouterResult<payment_methods::DeleteCardResp> {
-    let locker = &state.conf.locker;
-    let jwekey = &state.conf.jwekey.get_inner();
-
-    let request = payment_methods::mk_delete_card_request_hs(
-        jwekey,
-        locker,
-        customer_id,
-        merchant_id,
-        card_reference,
-    )
-    .await
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Making delete card request failed")?;
+    let result = card_vault::get_merchant_card_vault(state, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .delete(state, customer_id, merchant_id, card_reference)
+        .await;
 
-    if !locker.mock_locker {
-        let response = services::call_connector_api(state, request, "delete_card_from_locker")
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while executing call_connector_api for delete card");
-        let jwe_body: services::JweBody = response.get_response_inner("JweBody")?;
-        let decrypted_payload = payment_methods::get_decrypted_response_payload(
-            jwekey,
-            jwe_body,
-            Some(api_enums::LockerChoice::HyperswitchCardVault),
-        )
+    // Cards saved just before the merchant was switched to another vault may not have been moved
+    // to it yet, or may not have been deleted from the previous vault after being moved
+    let fallback_result = match card_vault::get_fallback_card_vault(state, merchant_id)
         .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Error getting decrypted response payload for delete card")?;
-        let delete_card_resp: payment_methods::DeleteCardResp = decrypted_payload
-            .parse_struct("DeleteCardResp")
-            .change_context(errors::ApiErrorResponse::InternalServerError)?;
-        Ok(delete_card_resp)
-    } else {
-        Ok(mock_delete_card_hs(&*state.store, card_reference)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("card_delete_failure_message")?)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+    {
+        Some(fallback_vault) => Some(
+            fallback_vault
+                .delete(state, customer_id, merchant_id, card_reference)
+                .await,
+        ),
+        None => None,
+    };
+
+    match (result, fallback_result) {
+        (Err(_), Some(Ok(delete_card_resp))) => Ok(delete_card_resp),
+        (result, _) => result,
     }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to delete card from vault")
 }
 
 ///Mock api for local testing
@@ -3887,17 +3778,11 @@ pub async fn get_bank_from_hs_locker(
     merchant_id: &str,
     token_ref: &str,
 ) -> errors::RouterResult<api::BankPayout> {
-    let payment_method = get_payment_method_from_hs_locker(
-        state,
-        key_store,
-        customer_id,
-        merchant_id,
-        token_ref,
-        None,
-    )
-    .await
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Error getting payment method from locker")?;
+    let payment_method =
+        get_payment_method_from_hs_locker(state, key_store, customer_id, merchant_id, token_ref)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error getting payment method from locker")?;
     let pm_parsed: api::PayoutMethodData = payment_method
         .peek()
         .to_string()
@@ -34,6 +34,20 @@ impl StoreLockerReq<'_> {
             Self::LockerGeneric(_) => (),
         }
     }
+
+    pub fn get_requestor_card_reference(&self) -> Option<&str> {
+        match self {
+            Self::LockerCard(c) => c.requestor_card_reference.as_deref(),
+            Self::LockerGeneric(_) => None,
+        }
+    }
+
+    pub fn get_merchant_id(&self) -> &str {
+        match self {
+            Self::LockerCard(c) => c.merchant_id,
+            Self::LockerGeneric(g) => g.merchant_id,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
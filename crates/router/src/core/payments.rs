@@ -1,15 +1,25 @@
 pub mod access_token;
+pub mod authorization_expiry;
+pub mod card_verification;
 pub mod conditional_configs;
 pub mod customers;
 pub mod flows;
 pub mod helpers;
+pub mod intent_expiry;
 pub mod operations;
+pub mod over_capture;
+pub mod partial_authorization;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+pub mod scheduled_capture;
+pub mod split_tender;
+#[cfg(feature = "olap")]
+pub mod timeline;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
+pub mod webhook_watchdog;
 
 #[cfg(feature = "olap")]
 use std::collections::HashMap;
@@ -266,6 +276,7 @@ where
                 }
                 _ => (),
             };
+            let intent_status_before_connector_call = payment_data.payment_intent.status;
             payment_data = match connector_details {
                 ConnectorCallType::PreDetermined(connector) => {
                     let schedule_time = if should_add_task_to_process_tracker {
@@ -320,7 +331,7 @@ where
                         )
                         .await?;
 
-                    operation
+                    let payment_data = operation
                         .to_post_update_tracker()?
                         .update_tracker(
                             state,
@@ -329,7 +340,17 @@ where
                             router_data,
                             merchant_account.storage_scheme,
                         )
-                        .await?
+                        .await?;
+
+                    webhook_watchdog::add_webhook_watchdog_task(
+                        state,
+                        &payment_data.payment_attempt,
+                    )
+                    .await
+                    .map_err(|error| logger::error!(webhook_watchdog_task_error=?error))
+                    .ok();
+
+                    payment_data
                 }
 
                 ConnectorCallType::Retryable(connectors) => {
@@ -422,7 +443,7 @@ where
                         )
                         .await?;
 
-                    operation
+                    let payment_data = operation
                         .to_post_update_tracker()?
                         .update_tracker(
                             state,
@@ -431,7 +452,17 @@ where
                             router_data,
                             merchant_account.storage_scheme,
                         )
-                        .await?
+                        .await?;
+
+                    webhook_watchdog::add_webhook_watchdog_task(
+                        state,
+                        &payment_data.payment_attempt,
+                    )
+                    .await
+                    .map_err(|error| logger::error!(webhook_watchdog_task_error=?error))
+                    .ok();
+
+                    payment_data
                 }
 
                 ConnectorCallType::SessionMultiple(connectors) => {
@@ -457,6 +488,18 @@ where
                 }
             };
 
+            Box::pin(split_tender::reverse_tenders_of_failed_payment(
+                state,
+                &merchant_account,
+                &key_store,
+                intent_status_before_connector_call,
+                &payment_data.payment_intent,
+                &payment_data.payment_attempt,
+            ))
+            .await
+            .map_err(|error| logger::error!(split_tender_reversal_error=?error))
+            .ok();
+
             #[cfg(feature = "frm")]
             if let Some(fraud_info) = &mut frm_info {
                 Box::pin(frm_core::post_payment_frm_core(
@@ -1025,7 +1068,7 @@ impl<Ctx: PaymentMethodRetrieve> PaymentRedirectFlow<Ctx> for PaymentRedirectCom
             // If the status is terminal status, then redirect to merchant return url to provide status
             enums::IntentStatus::Succeeded
             | enums::IntentStatus::Failed
-            | enums::IntentStatus::Cancelled | enums::IntentStatus::RequiresCapture| enums::IntentStatus::Processing=> helpers::get_handle_response_url(
+            | enums::IntentStatus::Cancelled | enums::IntentStatus::Expired | enums::IntentStatus::RequiresCapture | enums::IntentStatus::PartiallyAuthorized | enums::IntentStatus::Processing=> helpers::get_handle_response_url(
                 payment_id,
                 &payment_flow_response.business_profile,
                 payments_response,
@@ -2539,6 +2582,7 @@ where
     pub frm_metadata: Option<serde_json::Value>,
     pub recurring_details: Option<RecurringDetails>,
     pub poll_config: Option<router_types::PollConfig>,
+    pub is_card_verification: bool,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -2668,18 +2712,21 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
                     | storage_enums::IntentStatus::RequiresMerchantAction
                     | storage_enums::IntentStatus::RequiresCapture
                     | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+                    | storage_enums::IntentStatus::PartiallyAuthorized
             ) && payment_data.force_sync.unwrap_or(false)
         }
         "PaymentCancel" => matches!(
             payment_data.payment_intent.status,
             storage_enums::IntentStatus::RequiresCapture
                 | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+                | storage_enums::IntentStatus::PartiallyAuthorized
         ),
         "PaymentCapture" => {
             matches!(
                 payment_data.payment_intent.status,
                 storage_enums::IntentStatus::RequiresCapture
                     | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+                    | storage_enums::IntentStatus::PartiallyAuthorized
             ) || (matches!(
                 payment_data.payment_intent.status,
                 storage_enums::IntentStatus::Processing
@@ -0,0 +1,390 @@
+use std::str::FromStr;
+
+use api_models::{
+    admin::AuthorizationExpiryConfig,
+    enums::{AuthorizationExpiryAction, CardNetwork},
+};
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::AuthorizationExpiry,
+    consts,
+    core::errors::{self, RouterResult, StorageErrorExt},
+    routes::{metrics, AppState},
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+    },
+    utils::OptionExt,
+};
+
+pub const AUTHORIZATION_EXPIRY_TASK: &str = "AUTHORIZATION_EXPIRY";
+
+/// Tracking data stored against the authorization expiry process tracker entry
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuthorizationExpiryTrackingData {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub authorization_expires_at: PrimitiveDateTime,
+    pub expiry_action: AuthorizationExpiryAction,
+    pub action_offset_hours: u16,
+}
+
+fn get_card_network(payment_attempt: &storage::PaymentAttempt) -> Option<CardNetwork> {
+    payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => card_info.card_network,
+            _ => None,
+        })
+}
+
+/// Validity of the authorization in days, resolved in the order of business profile, connector,
+/// card network and finally the application default
+fn get_authorization_validity_days(
+    config: &AuthorizationExpiry,
+    profile_config: Option<&AuthorizationExpiryConfig>,
+    connector: Option<&str>,
+    card_network: Option<&CardNetwork>,
+) -> u16 {
+    let connector_validity_days = || {
+        connector
+            .and_then(|connector| api_models::enums::Connector::from_str(connector).ok())
+            .and_then(|connector| config.connector_validity_days.get(&connector).copied())
+    };
+
+    let network_validity_days = || {
+        card_network.and_then(|card_network| {
+            config
+                .network_validity_days
+                .get(&card_network.to_string().to_lowercase())
+                .copied()
+        })
+    };
+
+    profile_config
+        .and_then(|profile_config| profile_config.validity_days)
+        .or_else(connector_validity_days)
+        .or_else(network_validity_days)
+        .unwrap_or(config.default_validity_days)
+}
+
+/// Time at which the merchant is notified about the expiring authorization. Authorizations which
+/// are about to expire are notified right away.
+fn get_warning_schedule_time(
+    authorization_expires_at: PrimitiveDateTime,
+    warning_offset_hours: u16,
+    current_time: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    std::cmp::max(
+        authorization_expires_at
+            .saturating_sub(time::Duration::hours(i64::from(warning_offset_hours))),
+        current_time,
+    )
+}
+
+/// Time at which the payment attempt was authorized. It is recorded when the attempt moves to
+/// `Authorized`, which is also the attempt status of partially authorized payments, and is not
+/// changed by later updates of the attempt.
+pub fn get_authorized_at(
+    authorized_at: Option<PrimitiveDateTime>,
+    updated_status: storage_enums::AttemptStatus,
+    current_time: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    authorized_at
+        .or((updated_status == storage_enums::AttemptStatus::Authorized).then_some(current_time))
+}
+
+/// Computes the expiry of an authorized, manually captured payment attempt from the time at which
+/// it was authorized and schedules a task which notifies the merchant and performs the configured
+/// expiry action before the expiry. Returns the updated payment attempt if a task was scheduled.
+pub async fn add_authorization_expiry_task(
+    state: &AppState,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<Option<storage::PaymentAttempt>> {
+    if payment_attempt.status != storage_enums::AttemptStatus::Authorized
+        || payment_attempt.capture_method != Some(storage_enums::CaptureMethod::Manual)
+        || payment_attempt.authorization_expires_at.is_some()
+    {
+        return Ok(None);
+    }
+
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?;
+    let db = &*state.store;
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    let profile_config = business_profile
+        .authorization_expiry_config
+        .clone()
+        .map(|config| config.parse_value::<AuthorizationExpiryConfig>("AuthorizationExpiryConfig"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse authorization expiry config from business profile")?;
+
+    let config = &state.conf.authorization_expiry;
+    let validity_days = get_authorization_validity_days(
+        config,
+        profile_config.as_ref(),
+        payment_attempt.connector.as_deref(),
+        get_card_network(payment_attempt).as_ref(),
+    );
+    let warning_offset_hours = profile_config
+        .as_ref()
+        .and_then(|profile_config| profile_config.warning_offset_hours)
+        .unwrap_or(config.warning_offset_hours);
+    let action_offset_hours = profile_config
+        .as_ref()
+        .and_then(|profile_config| profile_config.action_offset_hours)
+        .unwrap_or(config.action_offset_hours);
+    let expiry_action = profile_config
+        .map(|profile_config| profile_config.expiry_action)
+        .unwrap_or_default();
+
+    let authorized_at = payment_attempt
+        .authorized_at
+        .get_required_value("authorized_at")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("authorized_at is not set in the authorized payment attempt")?;
+    let authorization_expires_at =
+        authorized_at.saturating_add(time::Duration::days(i64::from(validity_days)));
+    let schedule_time = get_warning_schedule_time(
+        authorization_expires_at,
+        warning_offset_hours,
+        common_utils::date_time::now(),
+    );
+
+    let tracking_data = AuthorizationExpiryTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        profile_id: business_profile.profile_id.clone(),
+        authorization_expires_at,
+        expiry_action,
+        action_offset_hours,
+    };
+
+    let runner = storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow;
+    let tag = ["PAYMENTS", "AUTHORIZATION_EXPIRY"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        AUTHORIZATION_EXPIRY_TASK,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        AUTHORIZATION_EXPIRY_TASK,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct authorization expiry process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert authorization expiry task in process tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "AuthorizationExpiry",
+        )],
+    );
+
+    logger::debug!(
+        payment_id = %payment_attempt.payment_id,
+        ?authorization_expires_at,
+        "Scheduled authorization expiry task"
+    );
+
+    let updated_payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::AuthorizationExpiryUpdate {
+                authorization_expires_at: Some(authorization_expires_at),
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update authorization expiry in payment attempt")?;
+
+    Ok(Some(updated_payment_attempt))
+}
+
+/// Time at which a failed expiry action is retried, backing off exponentially. The action is not
+/// retried once the retry would be scheduled after the authorization has expired.
+///
+/// The retry count of the task is 1 when the action is first performed, right after the merchant
+/// has been notified about the expiring authorization.
+pub fn get_action_retry_schedule_time(
+    retry_count: i32,
+    authorization_expires_at: PrimitiveDateTime,
+    current_time: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    let delay_in_minutes = consts::AUTHORIZATION_EXPIRY_ACTION_RETRY_BASE_DELAY_IN_MINUTES
+        .saturating_mul(2_i64.saturating_pow(retry_count.saturating_sub(1).unsigned_abs()));
+    let schedule_time = current_time.saturating_add(time::Duration::minutes(delay_in_minutes));
+
+    (schedule_time < authorization_expires_at).then_some(schedule_time)
+}
+
+/// Builds the request used to retrieve the payment before the expiry actions are performed
+pub fn get_payments_retrieve_request(
+    tracking_data: &AuthorizationExpiryTrackingData,
+) -> api::PaymentsRetrieveRequest {
+    api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+        merchant_id: Some(tracking_data.merchant_id.clone()),
+        force_sync: false,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn get_config() -> AuthorizationExpiry {
+        AuthorizationExpiry {
+            network_validity_days: HashMap::from([("visa".to_string(), 5)]),
+            connector_validity_days: HashMap::from([(api_models::enums::Connector::Adyen, 28)]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_authorization_validity_days_precedence() {
+        let config = get_config();
+        let profile_config = AuthorizationExpiryConfig {
+            validity_days: Some(3),
+            expiry_action: AuthorizationExpiryAction::AutoVoid,
+            warning_offset_hours: None,
+            action_offset_hours: None,
+        };
+
+        assert_eq!(
+            get_authorization_validity_days(
+                &config,
+                Some(&profile_config),
+                Some("adyen"),
+                Some(&CardNetwork::Visa)
+            ),
+            3
+        );
+        assert_eq!(
+            get_authorization_validity_days(&config, None, Some("adyen"), Some(&CardNetwork::Visa)),
+            28
+        );
+        assert_eq!(
+            get_authorization_validity_days(
+                &config,
+                None,
+                Some("stripe"),
+                Some(&CardNetwork::Visa)
+            ),
+            5
+        );
+        assert_eq!(
+            get_authorization_validity_days(&config, None, None, Some(&CardNetwork::Mastercard)),
+            config.default_validity_days
+        );
+    }
+
+    #[test]
+    fn test_authorized_at() {
+        let current_time = common_utils::date_time::now();
+        let authorized_at = current_time.saturating_sub(time::Duration::days(2));
+
+        assert_eq!(
+            get_authorized_at(None, storage_enums::AttemptStatus::Authorized, current_time),
+            Some(current_time)
+        );
+        assert_eq!(
+            get_authorized_at(None, storage_enums::AttemptStatus::Pending, current_time),
+            None
+        );
+        // Later updates of the attempt do not change the time of the authorization
+        assert_eq!(
+            get_authorized_at(
+                Some(authorized_at),
+                storage_enums::AttemptStatus::Authorized,
+                current_time
+            ),
+            Some(authorized_at)
+        );
+    }
+
+    #[test]
+    fn test_warning_schedule_time() {
+        let current_time = common_utils::date_time::now();
+        let authorization_expires_at = current_time.saturating_add(time::Duration::days(7));
+
+        assert_eq!(
+            get_warning_schedule_time(authorization_expires_at, 24, current_time),
+            current_time.saturating_add(time::Duration::days(6))
+        );
+        // Authorizations expiring within the warning offset are notified right away
+        assert_eq!(
+            get_warning_schedule_time(authorization_expires_at, 24 * 8, current_time),
+            current_time
+        );
+    }
+
+    #[test]
+    fn test_action_retry_schedule_time() {
+        let current_time = common_utils::date_time::now();
+        let authorization_expires_at = current_time.saturating_add(time::Duration::hours(2));
+
+        assert_eq!(
+            get_action_retry_schedule_time(1, authorization_expires_at, current_time),
+            Some(current_time.saturating_add(time::Duration::minutes(15)))
+        );
+        assert_eq!(
+            get_action_retry_schedule_time(3, authorization_expires_at, current_time),
+            Some(current_time.saturating_add(time::Duration::minutes(60)))
+        );
+        // The action is not retried after the authorization has expired
+        assert_eq!(
+            get_action_retry_schedule_time(5, authorization_expires_at, current_time),
+            None
+        );
+        assert_eq!(
+            get_action_retry_schedule_time(1, current_time, current_time),
+            None
+        );
+    }
+}
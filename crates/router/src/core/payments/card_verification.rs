@@ -0,0 +1,480 @@
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::PaymentMethodRetrieve,
+        payments::{self, helpers},
+    },
+    routes::{app::ReqState, AppState},
+    services,
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        domain,
+        storage::enums as storage_enums,
+    },
+    utils::{self, OptionExt},
+};
+
+impl From<api_enums::IntentStatus> for api::CardVerificationStatus {
+    fn from(status: api_enums::IntentStatus) -> Self {
+        match status {
+            api_enums::IntentStatus::Succeeded => Self::Verified,
+            api_enums::IntentStatus::Failed | api_enums::IntentStatus::Cancelled => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+}
+
+fn validate_card_verification_request(req: &api::CardVerificationRequest) -> RouterResult<()> {
+    if !matches!(
+        req.payment_method_data.payment_method_data,
+        Some(api::PaymentMethodData::Card(_))
+    ) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Only card payment methods can be verified".to_string(),
+        }));
+    }
+
+    if req.save_payment_method == Some(true) {
+        utils::when(req.customer_id.is_none(), || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "customer_id",
+            })
+        })?;
+        utils::when(req.customer_acceptance.is_none(), || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "customer_acceptance",
+            })
+        })?;
+    }
+
+    Ok(())
+}
+
+/// The zero-amount payment through which the card is verified. The card is saved for off-session
+/// use only if requested, as the customer acceptance decides whether the payment method is saved.
+fn get_payments_request(req: api::CardVerificationRequest) -> api::PaymentsRequest {
+    let save_payment_method = req.save_payment_method.unwrap_or(false);
+    api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(utils::generate_id(
+            consts::ID_LENGTH,
+            "pay",
+        ))),
+        amount: Some(api::Amount::Zero),
+        currency: Some(req.currency),
+        confirm: Some(true),
+        customer_id: req.customer_id,
+        payment_method: Some(api_enums::PaymentMethod::Card),
+        payment_method_data: Some(req.payment_method_data),
+        billing: req.billing,
+        setup_future_usage: Some(if save_payment_method {
+            api_enums::FutureUsage::OffSession
+        } else {
+            api_enums::FutureUsage::OnSession
+        }),
+        customer_acceptance: req.customer_acceptance.filter(|_| save_payment_method),
+        payment_type: Some(api_enums::PaymentType::SetupMandate),
+        browser_info: req.browser_info,
+        profile_id: req.profile_id,
+        metadata: req.metadata,
+        ..Default::default()
+    }
+}
+
+/// Verifies a card through a zero-amount payment, routed like any other payment of the merchant
+pub async fn payments_verify_card<Ctx: PaymentMethodRetrieve>(
+    state: AppState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::CardVerificationRequest,
+) -> RouterResponse<api::CardVerificationResponse> {
+    validate_card_verification_request(&req)?;
+
+    let storage_scheme = merchant_account.storage_scheme;
+    let eligible_connectors = req.connector.clone();
+    let response = Box::pin(payments::payments_core::<
+        api::SetupMandate,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Ctx,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account,
+        key_store,
+        payments::PaymentCreate::for_card_verification(),
+        get_payments_request(req),
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        eligible_connectors,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    let payments_response = match response {
+        services::ApplicationResponse::Json(response) => Ok(response),
+        services::ApplicationResponse::JsonWithHeaders((response, _)) => Ok(response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get the response in json"),
+    }?;
+
+    // The network transaction id is recorded only against the saved payment method
+    let network_transaction_id = match payments_response.payment_method_id.as_deref() {
+        Some(payment_method_id) => {
+            state
+                .store
+                .find_payment_method(payment_method_id, storage_scheme)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?
+                .network_transaction_id
+        }
+        None => None,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        api::CardVerificationResponse {
+            payment_id: payments_response
+                .payment_id
+                .get_required_value("payment_id")?,
+            merchant_id: payments_response
+                .merchant_id
+                .get_required_value("merchant_id")?,
+            status: payments_response.status.into(),
+            connector: payments_response.connector,
+            connector_transaction_id: payments_response.connector_transaction_id,
+            network_transaction_id,
+            payment_method_id: payments_response.payment_method_id,
+            payment_method_data: payments_response.payment_method_data,
+            card_verification_results: payments_response.card_verification_results,
+            error_code: payments_response.error_code,
+            error_message: payments_response.error_message,
+            created: payments_response.created,
+        },
+    ))
+}
+
+fn should_authorize_and_void(
+    is_card_verification: bool,
+    payment_method: storage_enums::PaymentMethod,
+    error: &errors::ConnectorError,
+) -> bool {
+    is_card_verification
+        && payment_method == storage_enums::PaymentMethod::Card
+        && matches!(error, errors::ConnectorError::NotImplemented(_))
+}
+
+/// Whether the card of a card verification has to be verified through an authorization that is
+/// voided, as the connector does not support zero-amount authorizations. Other zero-amount
+/// payments are never authorized for an amount.
+pub fn is_authorization_and_void_required(
+    router_data: &types::SetupMandateRouterData,
+    error: &error_stack::Report<errors::ConnectorError>,
+) -> bool {
+    should_authorize_and_void(
+        router_data.request.is_card_verification,
+        router_data.payment_method,
+        error.current_context(),
+    )
+}
+
+/// Voids the authorization of the card verification, returning the error of the connector if it
+/// declined to void the authorization
+async fn void_authorization(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    router_data: &types::PaymentsAuthorizeRouterData,
+) -> RouterResult<Result<(), types::ErrorResponse>> {
+    let (connector_transaction_id, connector_meta) = match &router_data.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id,
+            connector_metadata,
+            ..
+        }) => (
+            resource_id
+                .get_connector_transaction_id()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to get the connector transaction id of the authorization",
+                )?,
+            connector_metadata.clone(),
+        ),
+        _ => Err(errors::ApiErrorResponse::InternalServerError).attach_printable(
+            "Unexpected response for the authorization of the card verification",
+        )?,
+    };
+
+    let void_router_data = types::PaymentsCancelRouterData::from((
+        router_data,
+        types::PaymentsCancelData {
+            amount: Some(router_data.request.amount),
+            currency: Some(router_data.request.currency),
+            connector_transaction_id,
+            cancellation_reason: Some(consts::CARD_VERIFICATION_CANCELLATION_REASON.to_string()),
+            connector_meta,
+            browser_info: router_data.request.browser_info.clone(),
+            metadata: None,
+        },
+    ));
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Void,
+        types::PaymentsCancelData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+
+    let void_router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &void_router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_payment_failed_response()?;
+
+    Ok(void_router_data.response.map(|_| ()))
+}
+
+/// The error of the card verification when the connector declined to void its authorization. The
+/// attempt is marked as void failed, so that the merchant can void the authorization through the
+/// payment cancel API.
+fn get_void_failed_error_response(
+    error: types::ErrorResponse,
+    connector_transaction_id: Option<String>,
+) -> types::ErrorResponse {
+    types::ErrorResponse {
+        reason: Some(error.reason.unwrap_or_else(|| error.message.clone())),
+        message: "Failed to void the authorization of the card verification".to_string(),
+        attempt_status: Some(storage_enums::AttemptStatus::VoidFailed),
+        connector_transaction_id: error.connector_transaction_id.or(connector_transaction_id),
+        ..error
+    }
+}
+
+/// Verifies the card of a card verification through an authorization of a minimal amount, which is
+/// voided right after. If the connector declines to void the authorization, the verification fails
+/// with the error of the connector.
+pub async fn verify_by_authorization_and_void(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    router_data: &types::SetupMandateRouterData,
+    call_connector_action: payments::CallConnectorAction,
+) -> RouterResult<types::SetupMandateRouterData> {
+    let authorize_router_data = types::PaymentsAuthorizeRouterData::from((
+        router_data,
+        types::PaymentsAuthorizeData {
+            amount: consts::CARD_VERIFICATION_AUTHORIZATION_AMOUNT,
+            capture_method: Some(storage_enums::CaptureMethod::Manual),
+            ..types::PaymentsAuthorizeData::from(router_data)
+        },
+    ));
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Authorize,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+
+    let authorize_router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &authorize_router_data,
+        call_connector_action,
+        None,
+    )
+    .await
+    .to_setup_mandate_failed_response()?;
+
+    // The authorization of the card verification is voided, so the zero-amount payment is
+    // considered to be charged like with connectors that support zero-amount authorizations
+    let (status, response) = match authorize_router_data.status {
+        storage_enums::AttemptStatus::Authorized => {
+            match void_authorization(state, connector, &authorize_router_data).await? {
+                Ok(()) => (
+                    storage_enums::AttemptStatus::Charged,
+                    authorize_router_data.response.clone(),
+                ),
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        "Connector declined to void the authorization of the card verification"
+                    );
+                    let connector_transaction_id = authorize_router_data
+                        .response
+                        .as_ref()
+                        .ok()
+                        .and_then(|response| match response {
+                            types::PaymentsResponseData::TransactionResponse {
+                                resource_id,
+                                ..
+                            } => resource_id.get_connector_transaction_id().ok(),
+                            _ => None,
+                        });
+                    (
+                        storage_enums::AttemptStatus::VoidFailed,
+                        Err(get_void_failed_error_response(
+                            error,
+                            connector_transaction_id,
+                        )),
+                    )
+                }
+            }
+        }
+        status => (status, authorize_router_data.response.clone()),
+    };
+
+    let mut router_data = helpers::router_data_type_conversion::<_, api::SetupMandate, _, _, _, _>(
+        authorize_router_data,
+        router_data.request.clone(),
+        response,
+    );
+    router_data.status = status;
+
+    Ok(router_data)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_card_verification_request(
+        payment_method_data: serde_json::Value,
+        save_payment_method: bool,
+    ) -> api::CardVerificationRequest {
+        serde_json::from_value(serde_json::json!({
+            "payment_method_data": payment_method_data,
+            "currency": "USD",
+            "save_payment_method": save_payment_method,
+        }))
+        .unwrap()
+    }
+
+    fn get_card() -> serde_json::Value {
+        serde_json::json!({
+            "card": {
+                "card_number": "4242424242424242",
+                "card_exp_month": "10",
+                "card_exp_year": "35",
+                "card_holder_name": "John Doe",
+                "card_cvc": "123"
+            }
+        })
+    }
+
+    #[test]
+    fn test_validate_card_verification_request() {
+        assert!(
+            validate_card_verification_request(&get_card_verification_request(get_card(), false))
+                .is_ok()
+        );
+
+        // Saving the card requires the customer and the customer acceptance
+        assert!(
+            validate_card_verification_request(&get_card_verification_request(get_card(), true))
+                .is_err()
+        );
+
+        assert!(
+            validate_card_verification_request(&get_card_verification_request(
+                serde_json::json!({ "crypto": { "pay_currency": "BTC" } }),
+                false
+            ))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_get_payments_request() {
+        let payments_request =
+            get_payments_request(get_card_verification_request(get_card(), false));
+        assert_eq!(payments_request.amount, Some(api::Amount::Zero));
+        assert_eq!(payments_request.confirm, Some(true));
+        assert_eq!(
+            payments_request.setup_future_usage,
+            Some(api_enums::FutureUsage::OnSession)
+        );
+        assert_eq!(
+            payments_request.payment_type,
+            Some(api_enums::PaymentType::SetupMandate)
+        );
+    }
+
+    #[test]
+    fn test_should_authorize_and_void() {
+        let not_implemented = errors::ConnectorError::NotImplemented("zero auth".to_string());
+
+        assert!(should_authorize_and_void(
+            true,
+            storage_enums::PaymentMethod::Card,
+            &not_implemented
+        ));
+        // Only the card verification API falls back to an authorization
+        assert!(!should_authorize_and_void(
+            false,
+            storage_enums::PaymentMethod::Card,
+            &not_implemented
+        ));
+        assert!(!should_authorize_and_void(
+            true,
+            storage_enums::PaymentMethod::Wallet,
+            &not_implemented
+        ));
+        assert!(!should_authorize_and_void(
+            true,
+            storage_enums::PaymentMethod::Card,
+            &errors::ConnectorError::RequestEncodingFailed
+        ));
+    }
+
+    #[test]
+    fn test_get_void_failed_error_response() {
+        let error_response = get_void_failed_error_response(
+            types::ErrorResponse {
+                code: "void_declined".to_string(),
+                message: "Void declined".to_string(),
+                reason: None,
+                status_code: 402,
+                attempt_status: None,
+                connector_transaction_id: None,
+            },
+            Some("txn_123".to_string()),
+        );
+
+        assert_eq!(error_response.code, "void_declined");
+        assert_eq!(error_response.reason.as_deref(), Some("Void declined"));
+        assert_eq!(
+            error_response.attempt_status,
+            Some(storage_enums::AttemptStatus::VoidFailed)
+        );
+        assert_eq!(
+            error_response.connector_transaction_id.as_deref(),
+            Some("txn_123")
+        );
+    }
+
+    #[test]
+    fn test_card_verification_status() {
+        assert_eq!(
+            api::CardVerificationStatus::from(api_enums::IntentStatus::Succeeded),
+            api::CardVerificationStatus::Verified
+        );
+        assert_eq!(
+            api::CardVerificationStatus::from(api_enums::IntentStatus::Failed),
+            api::CardVerificationStatus::Failed
+        );
+        assert_eq!(
+            api::CardVerificationStatus::from(api_enums::IntentStatus::RequiresCapture),
+            api::CardVerificationStatus::Pending
+        );
+    }
+}
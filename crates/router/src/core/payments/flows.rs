@@ -880,6 +880,92 @@ default_imp_for_defend_dispute!(
     connector::Zsl
 );
 
+macro_rules! default_imp_for_fetch_disputes {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::FetchDisputes for $path::$connector {}
+            impl
+                services::ConnectorIntegration<
+                api::Fetch,
+                types::FetchDisputesRequestData,
+                types::FetchDisputesResponse,
+            > for $path::$connector
+            {}
+        )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::FetchDisputes for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::Fetch,
+        types::FetchDisputesRequestData,
+        types::FetchDisputesResponse,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_fetch_disputes!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bankofamerica,
+    connector::Billwerk,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Cashtocode,
+    connector::Cybersource,
+    connector::Coinbase,
+    connector::Cryptopay,
+    connector::Dlocal,
+    connector::Ebanx,
+    connector::Fiserv,
+    connector::Globepay,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Gocardless,
+    connector::Helcim,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Netcetera,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Payeezy,
+    connector::Payme,
+    connector::Payu,
+    connector::Placetopay,
+    connector::Powertranz,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Riskified,
+    connector::Shift4,
+    connector::Signifyd,
+    connector::Square,
+    connector::Stax,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Tsys,
+    connector::Volt,
+    connector::Opennode,
+    connector::Wise,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen,
+    connector::Zsl
+);
+
 macro_rules! default_imp_for_pre_processing_steps{
     ($($path:ident::$connector:ident),*)=> {
         $(
@@ -6,7 +6,8 @@ use crate::{
         errors::{self, ConnectorErrorExt, RouterResult},
         mandate,
         payments::{
-            self, access_token, customers, helpers, tokenization, transformers, PaymentData,
+            self, access_token, card_verification, customers, helpers, tokenization, transformers,
+            PaymentData,
         },
     },
     routes::AppState,
@@ -70,9 +71,20 @@ impl Feature<api::SetupMandate, types::SetupMandateRequestData> for types::Setup
             call_connector_action.clone(),
             connector_request,
         )
-        .await
-        .to_setup_mandate_failed_response()?;
-        Ok(resp)
+        .await;
+
+        match resp {
+            Err(error) if card_verification::is_authorization_and_void_required(&self, &error) => {
+                card_verification::verify_by_authorization_and_void(
+                    state,
+                    connector,
+                    &self,
+                    call_connector_action,
+                )
+                .await
+            }
+            resp => resp.to_setup_mandate_failed_response(),
+        }
     }
 
     async fn add_access_token<'a>(
@@ -122,6 +134,12 @@ impl Feature<api::SetupMandate, types::SetupMandateRequestData> for types::Setup
         call_connector_action: payments::CallConnectorAction,
     ) -> RouterResult<(Option<services::Request>, bool)> {
         match call_connector_action {
+            // The request of a card verification is built while deciding the flow, so that
+            // connectors without zero-amount authorizations can fall back to an authorization
+            // that is voided
+            payments::CallConnectorAction::Trigger if self.request.is_card_verification => {
+                Ok((None, true))
+            }
             payments::CallConnectorAction::Trigger => {
                 let connector_integration: services::BoxedConnectorIntegration<
                     '_,
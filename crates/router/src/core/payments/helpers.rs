@@ -1,4 +1,4 @@
-use std::{borrow::Cow, str::FromStr};
+use std::{borrow::Cow, collections::HashSet, str::FromStr};
 
 use api_models::{
     mandates::RecurringDetails,
@@ -32,7 +32,7 @@ use super::{
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::{ConnectorRequestReferenceIdConfig, Server, TempLockerEnableConfig},
+    configs::settings::{self, ConnectorRequestReferenceIdConfig, Server, TempLockerEnableConfig},
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
@@ -2098,13 +2098,14 @@ pub(crate) fn validate_status_with_capture_method(
     utils::when(
         status != storage_enums::IntentStatus::RequiresCapture
             && status != storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+            && status != storage_enums::IntentStatus::PartiallyAuthorized
             && status != storage_enums::IntentStatus::Processing,
         || {
             Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
                 field_name: "payment.status".to_string(),
                 current_flow: "captured".to_string(),
                 current_value: status.to_string(),
-                states: "requires_capture, partially_captured_and_capturable, processing"
+                states: "requires_capture, partially_captured_and_capturable, partially_authorized, processing"
                     .to_string()
             }))
         },
@@ -2948,6 +2949,8 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
+            allow_partial_authorization: None,
+            l2_l3_data: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3006,6 +3009,8 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
+            allow_partial_authorization: None,
+            l2_l3_data: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3063,10 +3068,121 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
+            allow_partial_authorization: None,
+            l2_l3_data: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    fn get_l2_l3_data() -> api_models::payments::L2L3Data {
+        api_models::payments::L2L3Data {
+            tax_amount: Some(100),
+            shipping_amount: Some(200),
+            line_items: Some(vec![api_models::payments::L2L3LineItem {
+                product_name: "Printer paper".to_string(),
+                quantity: 2,
+                unit_price: 350,
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_l2_l3_data() {
+        let l2_l3_data = get_l2_l3_data();
+        assert!(validate_l2_l3_data(&l2_l3_data, Some(1000)).is_ok());
+        assert!(validate_l2_l3_data(&l2_l3_data, Some(250)).is_err());
+
+        let tax_exempt_l2_l3_data = api_models::payments::L2L3Data {
+            tax_exempt: Some(true),
+            ..get_l2_l3_data()
+        };
+        assert!(validate_l2_l3_data(&tax_exempt_l2_l3_data, Some(1000)).is_err());
+    }
+
+    #[test]
+    fn test_validate_l2_l3_data_line_items_total() {
+        let l2_l3_data = get_l2_l3_data();
+        // Line items total to 700, which is within the amount
+        assert!(validate_l2_l3_data(&l2_l3_data, Some(700)).is_ok());
+        assert!(validate_l2_l3_data(&l2_l3_data, Some(699)).is_err());
+
+        let overflowing_l2_l3_data = api_models::payments::L2L3Data {
+            line_items: Some(vec![api_models::payments::L2L3LineItem {
+                product_name: "Printer paper".to_string(),
+                quantity: 2,
+                unit_price: i64::MAX,
+                ..Default::default()
+            }]),
+            ..get_l2_l3_data()
+        };
+        assert!(validate_l2_l3_data(&overflowing_l2_l3_data, None).is_err());
+
+        let overflowing_order_charges = api_models::payments::L2L3Data {
+            tax_amount: Some(i64::MAX),
+            shipping_amount: Some(1),
+            ..get_l2_l3_data()
+        };
+        assert!(validate_l2_l3_data(&overflowing_order_charges, None).is_err());
+    }
+
+    #[test]
+    fn test_updated_l2_l3_data_is_validated_against_the_amount() {
+        let l2_l3_data = get_l2_l3_data();
+        let stored_l2_l3_data = Some(l2_l3_data.encode_to_value().unwrap());
+
+        assert_eq!(
+            get_updated_l2_l3_data(None, stored_l2_l3_data.clone(), 1000).unwrap(),
+            stored_l2_l3_data
+        );
+        // Lowering the amount below the stored order charges is rejected
+        assert!(get_updated_l2_l3_data(None, stored_l2_l3_data.clone(), 250).is_err());
+        // The data sent with the update replaces the stored one
+        let updated_l2_l3_data = api_models::payments::L2L3Data {
+            tax_amount: Some(50),
+            line_items: None,
+            ..get_l2_l3_data()
+        };
+        assert_eq!(
+            get_updated_l2_l3_data(Some(&updated_l2_l3_data), stored_l2_l3_data.clone(), 250)
+                .unwrap(),
+            Some(updated_l2_l3_data.encode_to_value().unwrap())
+        );
+        assert!(get_updated_l2_l3_data(Some(&updated_l2_l3_data), stored_l2_l3_data, 200).is_err());
+        assert_eq!(get_updated_l2_l3_data(None, None, 250).unwrap(), None);
+    }
+
+    #[test]
+    fn test_validate_l2_l3_data_for_card_network() {
+        let l2_l3_data = get_l2_l3_data();
+        assert!(validate_l2_l3_data_for_card_network(
+            &l2_l3_data,
+            Some(&api_enums::CardNetwork::Visa)
+        )
+        .is_ok());
+        assert!(validate_l2_l3_data_for_card_network(
+            &l2_l3_data,
+            Some(&api_enums::CardNetwork::AmericanExpress)
+        )
+        .is_err());
+
+        let level_two_data = api_models::payments::L2L3Data {
+            line_items: None,
+            ..get_l2_l3_data()
+        };
+        assert!(validate_l2_l3_data_for_card_network(
+            &level_two_data,
+            Some(&api_enums::CardNetwork::AmericanExpress)
+        )
+        .is_ok());
+        assert!(validate_l2_l3_data_for_card_network(
+            &level_two_data,
+            Some(&api_enums::CardNetwork::JCB)
+        )
+        .is_err());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -3383,8 +3499,19 @@ pub fn get_attempt_type(
                     ))
             }
         }
+        // The remaining amount of a partially authorized payment is paid on a new payment attempt
+        enums::IntentStatus::PartiallyCaptured
+            if payments::partial_authorization::is_remaining_amount_collectable(
+                payment_intent,
+                payment_attempt,
+            ) =>
+        {
+            Ok(AttemptType::New)
+        }
         enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Expired
         | enums::IntentStatus::RequiresCapture
+        | enums::IntentStatus::PartiallyAuthorized
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
         | enums::IntentStatus::Processing
@@ -3495,6 +3622,13 @@ impl AttemptType {
             // New payment method billing address can be passed for a retry
             payment_method_billing_address_id: None,
             fingerprint_id: None,
+            authorization_expires_at: None,
+            card_verification_results: None,
+            authentication_details: None,
+            // The next tender of a split tender payment is made on a new payment attempt
+            is_split_tender: old_payment_attempt.is_split_tender,
+            acquirer_reference_number: None,
+            authorized_at: None,
         }
     }
 
@@ -3603,9 +3737,11 @@ pub fn is_manual_retry_allowed(
             | storage_enums::AttemptStatus::Failure => Some(true),
         },
         enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Expired
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
+        | enums::IntentStatus::PartiallyAuthorized
         | enums::IntentStatus::Processing
         | enums::IntentStatus::Succeeded => Some(false),
 
@@ -3644,6 +3780,116 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    #[test]
+    fn test_validate_authorization_expiry_config() {
+        let default_config = crate::configs::settings::AuthorizationExpiry::default();
+        let config = |validity_days, warning_offset_hours, action_offset_hours| {
+            api_models::admin::AuthorizationExpiryConfig {
+                validity_days,
+                expiry_action: api_models::enums::AuthorizationExpiryAction::AutoCapture,
+                warning_offset_hours,
+                action_offset_hours,
+            }
+        };
+
+        assert!(super::validate_authorization_expiry_config(
+            &config(Some(7), Some(48), Some(4)),
+            &default_config
+        )
+        .is_ok());
+        assert!(super::validate_authorization_expiry_config(
+            &config(Some(31), None, None),
+            &default_config
+        )
+        .is_err());
+        // Offsets missing on the profile are compared against the application defaults
+        assert!(super::validate_authorization_expiry_config(
+            &config(None, Some(1), None),
+            &default_config
+        )
+        .is_err());
+        assert!(super::validate_authorization_expiry_config(
+            &config(None, None, Some(default_config.warning_offset_hours)),
+            &default_config
+        )
+        .is_err());
+        // The warning must be sent within the validity of the authorization
+        assert!(super::validate_authorization_expiry_config(
+            &config(Some(1), Some(24), None),
+            &default_config
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_max_over_capture_percentage() {
+        assert!(super::validate_max_over_capture_percentage(1).is_ok());
+        assert!(super::validate_max_over_capture_percentage(100).is_ok());
+        assert!(super::validate_max_over_capture_percentage(0).is_err());
+        assert!(super::validate_max_over_capture_percentage(101).is_err());
+    }
+    #[test]
+    fn test_validate_webhook_endpoints() {
+        let webhook_endpoints = |webhook_endpoints: serde_json::Value| {
+            serde_json::from_value::<Vec<api_models::admin::WebhookEndpoint>>(webhook_endpoints)
+                .unwrap()
+        };
+        let validate = |endpoints: serde_json::Value| {
+            super::validate_webhook_endpoints(&webhook_endpoints(endpoints))
+        };
+
+        assert!(validate(serde_json::json!([
+            { "endpoint_id": "finance", "url": "https://finance.example.com/webhooks" },
+            {
+                "endpoint_id": "events-stream",
+                "message_queue": { "type": "redis_stream", "stream": "payments.v1" }
+            }
+        ]))
+        .is_ok());
+
+        // Duplicate endpoint identifiers
+        assert!(validate(serde_json::json!([
+            { "endpoint_id": "finance", "url": "https://finance.example.com/webhooks" },
+            { "endpoint_id": "finance", "url": "https://support.example.com/webhooks" }
+        ]))
+        .is_err());
+
+        // Invalid endpoint identifier
+        assert!(validate(serde_json::json!([
+            { "endpoint_id": "finance team", "url": "https://finance.example.com/webhooks" }
+        ]))
+        .is_err());
+
+        // URL with a scheme other than http or https
+        assert!(validate(serde_json::json!([
+            { "endpoint_id": "finance", "url": "ftp://finance.example.com/webhooks" }
+        ]))
+        .is_err());
+
+        // Both a URL and a message queue
+        assert!(validate(serde_json::json!([
+            {
+                "endpoint_id": "finance",
+                "url": "https://finance.example.com/webhooks",
+                "message_queue": { "type": "kafka", "topic": "payments" }
+            }
+        ]))
+        .is_err());
+
+        // Neither a URL nor a message queue
+        assert!(validate(serde_json::json!([{ "endpoint_id": "finance" }])).is_err());
+
+        let too_many_endpoints = (0..=crate::consts::MAX_WEBHOOK_ENDPOINTS_PER_PROFILE)
+            .map(|index| {
+                serde_json::json!({
+                    "endpoint_id": format!("endpoint_{index}"),
+                    "url": "https://merchant.example.com/webhooks"
+                })
+            })
+            .collect::<Vec<_>>();
+        assert!(validate(serde_json::Value::Array(too_many_endpoints)).is_err());
+    }
 }
 
 #[instrument(skip_all)]
@@ -4147,6 +4393,176 @@ pub fn validate_order_details_amount(
     }
 }
 
+pub fn validate_l2_l3_data(
+    l2_l3_data: &api_models::payments::L2L3Data,
+    amount: Option<i64>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let invalid_request = |message: &str| errors::ApiErrorResponse::InvalidRequestData {
+        message: message.to_string(),
+    };
+    let order_amounts = [
+        l2_l3_data.tax_amount,
+        l2_l3_data.duty_amount,
+        l2_l3_data.shipping_amount,
+        l2_l3_data.discount_amount,
+    ];
+
+    if order_amounts.iter().flatten().any(|amount| *amount < 0) {
+        return Err(invalid_request(
+            "l2_l3_data amounts should be greater than or equal to 0",
+        ));
+    }
+
+    if l2_l3_data.tax_exempt == Some(true) && l2_l3_data.tax_amount.unwrap_or(0) > 0 {
+        return Err(invalid_request(
+            "l2_l3_data.tax_amount should not be set for a tax exempt order",
+        ));
+    }
+
+    let order_charges = l2_l3_data
+        .tax_amount
+        .unwrap_or(0)
+        .checked_add(l2_l3_data.duty_amount.unwrap_or(0))
+        .and_then(|charges| charges.checked_add(l2_l3_data.shipping_amount.unwrap_or(0)))
+        .ok_or_else(|| invalid_request("l2_l3_data amounts are too large"))?;
+    if amount.is_some_and(|amount| order_charges > amount) {
+        return Err(invalid_request(
+            "Total of l2_l3_data tax, duty and shipping amounts should not exceed the amount",
+        ));
+    }
+
+    if l2_l3_data
+        .customer_code
+        .as_ref()
+        .is_some_and(|customer_code| customer_code.len() > consts::MAX_L2_CUSTOMER_CODE_LENGTH)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "l2_l3_data.customer_code should not exceed {} characters",
+                consts::MAX_L2_CUSTOMER_CODE_LENGTH
+            ),
+        });
+    }
+
+    if let Some(line_items) = &l2_l3_data.line_items {
+        if !(1..=consts::MAX_L3_LINE_ITEMS).contains(&line_items.len()) {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "l2_l3_data.line_items should contain between 1 to {} items",
+                    consts::MAX_L3_LINE_ITEMS
+                ),
+            });
+        }
+
+        let mut line_items_total: i64 = 0;
+        for line_item in line_items {
+            if line_item.quantity == 0 {
+                return Err(invalid_request(
+                    "l2_l3_data.line_items quantity should be greater than 0",
+                ));
+            }
+            let line_item_total = line_item
+                .get_total_amount()
+                .ok_or_else(|| invalid_request("l2_l3_data.line_items amounts are too large"))?;
+            if line_item.unit_price < 0
+                || line_item.discount_amount.unwrap_or(0) < 0
+                || line_item.tax_amount.unwrap_or(0) < 0
+                || line_item_total < 0
+            {
+                return Err(invalid_request(
+                    "l2_l3_data.line_items amounts should be greater than or equal to 0",
+                ));
+            }
+            line_items_total = line_items_total
+                .checked_add(line_item_total)
+                .ok_or_else(|| invalid_request("l2_l3_data.line_items amounts are too large"))?;
+        }
+
+        if amount.is_some_and(|amount| line_items_total > amount) {
+            return Err(invalid_request(
+                "Total of l2_l3_data.line_items amounts should not exceed the amount",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// The Level 2 / Level 3 data of a payment being updated or confirmed, taken from the request or
+/// else from the payment. Either is validated against the amount of the payment, since the amount
+/// may have changed since the data was stored.
+pub fn get_updated_l2_l3_data(
+    request_l2_l3_data: Option<&api_models::payments::L2L3Data>,
+    stored_l2_l3_data: Option<serde_json::Value>,
+    amount: i64,
+) -> RouterResult<Option<serde_json::Value>> {
+    if let Some(l2_l3_data) = request_l2_l3_data {
+        validate_l2_l3_data(l2_l3_data, Some(amount))?;
+        return l2_l3_data
+            .encode_to_value()
+            .map(Some)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert l2_l3_data to value");
+    }
+
+    if let Some(stored_l2_l3_data) = &stored_l2_l3_data {
+        let l2_l3_data = stored_l2_l3_data
+            .clone()
+            .parse_value::<api_models::payments::L2L3Data>("L2L3Data")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to parse L2L3Data")?;
+        validate_l2_l3_data(&l2_l3_data, Some(amount))?;
+    }
+
+    Ok(stored_l2_l3_data)
+}
+
+/// Resolves the card network from the card info of the card BIN. The card network passed in the
+/// payment request is not used, since it is optional and not verified.
+pub async fn get_card_network_from_card_bin(
+    db: &dyn StorageInterface,
+    payment_method_data: Option<&api_models::payments::PaymentMethodData>,
+) -> RouterResult<Option<api_enums::CardNetwork>> {
+    let Some(api_models::payments::PaymentMethodData::Card(card)) = payment_method_data else {
+        return Ok(None);
+    };
+
+    Ok(db
+        .get_card_info(&card.card_number.clone().get_card_isin())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the card info of the card BIN")?
+        .and_then(|card_info| card_info.card_network))
+}
+
+/// Level 2 data is supported for all major card networks, whereas Level 3 line items are
+/// supported only for Visa and Mastercard
+pub fn validate_l2_l3_data_for_card_network(
+    l2_l3_data: &api_models::payments::L2L3Data,
+    card_network: Option<&api_enums::CardNetwork>,
+) -> Result<(), errors::ApiErrorResponse> {
+    match card_network {
+        None | Some(api_enums::CardNetwork::Visa | api_enums::CardNetwork::Mastercard) => Ok(()),
+        Some(
+            card_network @ (api_enums::CardNetwork::AmericanExpress
+            | api_enums::CardNetwork::Discover),
+        ) => {
+            if l2_l3_data.line_items.is_some() {
+                Err(errors::ApiErrorResponse::NotSupported {
+                    message: format!(
+                        "Level 3 line items are not supported for {card_network} cards"
+                    ),
+                })
+            } else {
+                Ok(())
+            }
+        }
+        Some(card_network) => Err(errors::ApiErrorResponse::NotSupported {
+            message: format!("Level 2 and Level 3 data is not supported for {card_network} cards"),
+        }),
+    }
+}
+
 // This function validates the client secret expiry set by the merchant in the request
 pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErrorResponse> {
     if !(consts::MIN_SESSION_EXPIRY..=consts::MAX_SESSION_EXPIRY).contains(&session_expiry) {
@@ -4158,6 +4574,222 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+pub fn validate_intent_expiry(intent_expiry: u32) -> Result<(), errors::ApiErrorResponse> {
+    if !(consts::MIN_INTENT_EXPIRY..=consts::MAX_INTENT_EXPIRY).contains(&intent_expiry) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "intent_expiry should be between 3600(1 hour) to 2592000(30 days)."
+                .to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub fn validate_max_over_capture_percentage(
+    max_over_capture_percentage: u8,
+) -> Result<(), errors::ApiErrorResponse> {
+    if !(1..=consts::MAX_OVER_CAPTURE_PERCENTAGE).contains(&max_over_capture_percentage) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "max_over_capture_percentage should be between 1 to {}.",
+                consts::MAX_OVER_CAPTURE_PERCENTAGE
+            ),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates the authorization expiry config of a business profile. Offsets which are not
+/// configured on the profile are resolved from the application defaults, and the warning offset
+/// must fall within the shortest validity the profile may be applied to.
+pub fn validate_authorization_expiry_config(
+    authorization_expiry_config: &api_models::admin::AuthorizationExpiryConfig,
+    default_config: &settings::AuthorizationExpiry,
+) -> Result<(), errors::ApiErrorResponse> {
+    if let Some(validity_days) = authorization_expiry_config.validity_days {
+        if !(1..=consts::MAX_AUTHORIZATION_VALIDITY_DAYS).contains(&validity_days) {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "authorization_expiry_config.validity_days should be between 1 and {}",
+                    consts::MAX_AUTHORIZATION_VALIDITY_DAYS
+                ),
+            });
+        }
+    }
+
+    let warning_offset_hours = authorization_expiry_config
+        .warning_offset_hours
+        .unwrap_or(default_config.warning_offset_hours);
+    let action_offset_hours = authorization_expiry_config
+        .action_offset_hours
+        .unwrap_or(default_config.action_offset_hours);
+    if action_offset_hours >= warning_offset_hours {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "authorization_expiry_config.action_offset_hours ({action_offset_hours}) should be less than warning_offset_hours ({warning_offset_hours})"
+            ),
+        });
+    }
+
+    // Without a validity on the profile, the offsets apply to every connector and network validity
+    let shortest_validity_days = authorization_expiry_config
+        .validity_days
+        .unwrap_or_else(|| {
+            default_config
+                .network_validity_days
+                .values()
+                .chain(default_config.connector_validity_days.values())
+                .copied()
+                .chain(std::iter::once(default_config.default_validity_days))
+                .min()
+                .unwrap_or(default_config.default_validity_days)
+        });
+    if u32::from(warning_offset_hours) >= u32::from(shortest_validity_days) * 24 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "authorization_expiry_config.warning_offset_hours ({warning_offset_hours}) should be less than the authorization validity of {shortest_validity_days} days"
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn validate_refund_approval_policy(
+    refund_approval_policy: &api_models::admin::RefundApprovalPolicy,
+) -> Result<(), errors::ApiErrorResponse> {
+    let has_reasons = refund_approval_policy
+        .reasons
+        .as_ref()
+        .is_some_and(|reasons| !reasons.is_empty());
+    if refund_approval_policy.amount_threshold.is_none() && !has_reasons {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "refund_approval_policy should have either an amount_threshold or reasons"
+                .to_string(),
+        });
+    }
+
+    if let Some(amount_threshold) = refund_approval_policy.amount_threshold {
+        if amount_threshold <= 0 {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "refund_approval_policy.amount_threshold should be greater than 0"
+                    .to_string(),
+            });
+        }
+    }
+
+    if let Some(approval_expiry_hours) = refund_approval_policy.approval_expiry_hours {
+        if !(1..=consts::MAX_REFUND_APPROVAL_EXPIRY_HOURS).contains(&approval_expiry_hours) {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "refund_approval_policy.approval_expiry_hours should be between 1 and {}",
+                    consts::MAX_REFUND_APPROVAL_EXPIRY_HOURS
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_dispute_alert_policy(
+    dispute_alert_policy: &api_models::admin::DisputeAlertPolicy,
+) -> Result<(), errors::ApiErrorResponse> {
+    if let Some(max_refund_amount) = dispute_alert_policy.max_refund_amount {
+        if max_refund_amount <= 0 {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "dispute_alert_policy.max_refund_amount should be greater than 0"
+                    .to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_webhook_endpoints(
+    webhook_endpoints: &[api_models::admin::WebhookEndpoint],
+) -> Result<(), errors::ApiErrorResponse> {
+    if webhook_endpoints.len() > consts::MAX_WEBHOOK_ENDPOINTS_PER_PROFILE {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "At most {} webhook_endpoints can be configured for a business profile",
+                consts::MAX_WEBHOOK_ENDPOINTS_PER_PROFILE
+            ),
+        });
+    }
+
+    let mut endpoint_ids = HashSet::new();
+    for webhook_endpoint in webhook_endpoints {
+        let endpoint_id = &webhook_endpoint.endpoint_id;
+        if endpoint_id.is_empty()
+            || endpoint_id.len() > consts::MAX_WEBHOOK_ENDPOINT_ID_LENGTH
+            || !endpoint_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "webhook_endpoints.endpoint_id should be between 1 and {} characters long and \
+                     contain only alphanumeric characters, `_` or `-`",
+                    consts::MAX_WEBHOOK_ENDPOINT_ID_LENGTH
+                ),
+            });
+        }
+
+        if !endpoint_ids.insert(endpoint_id.as_str()) {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("webhook_endpoints.endpoint_id `{endpoint_id}` is duplicated"),
+            });
+        }
+
+        match (&webhook_endpoint.url, &webhook_endpoint.message_queue) {
+            (Some(webhook_url), None) => {
+                let is_valid_url = url::Url::parse(webhook_url.peek())
+                    .map(|url| matches!(url.scheme(), "http" | "https"))
+                    .unwrap_or(false);
+                if !is_valid_url {
+                    return Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "webhook_endpoints.url of endpoint `{endpoint_id}` should be a valid \
+                             http or https URL"
+                        ),
+                    });
+                }
+            }
+            (None, Some(message_queue)) => {
+                let queue_name = message_queue.get_name();
+                if queue_name.is_empty()
+                    || queue_name.len() > consts::MAX_WEBHOOK_MESSAGE_QUEUE_NAME_LENGTH
+                    || !queue_name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+                {
+                    return Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "webhook_endpoints.message_queue of endpoint `{endpoint_id}` should \
+                             have a name between 1 and {} characters long and contain only \
+                             alphanumeric characters, `_`, `-` or `.`",
+                            consts::MAX_WEBHOOK_MESSAGE_QUEUE_NAME_LENGTH
+                        ),
+                    });
+                }
+            }
+            (Some(_), Some(_)) | (None, None) => {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Exactly one of webhook_endpoints.url and webhook_endpoints.message_queue \
+                         should be specified for endpoint `{endpoint_id}`"
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub fn add_connector_response_to_additional_payment_data(
     additional_payment_data: api_models::payments::AdditionalPaymentData,
     connector_response_payment_method_data: core_types::AdditionalPaymentMethodConnectorResponse,
@@ -4171,6 +4803,7 @@ pub fn add_connector_response_to_additional_payment_data(
             core_types::AdditionalPaymentMethodConnectorResponse::Card {
                 authentication_data,
                 payment_checks,
+                ..
             },
         ) => api_models::payments::AdditionalPaymentData::Card(Box::new(
             api_models::payments::AdditionalCardInfo {
@@ -4217,6 +4850,65 @@ pub fn update_additional_payment_data_with_connector_response_pm_data(
         .attach_printable("Failed to encode additional pm data")
 }
 
+/// The card verification results in the payment method response of the connector, to be stored
+/// on the payment attempt
+pub fn get_card_verification_results_from_connector_response_pm_data(
+    connector_response_pm_data: Option<&core_types::AdditionalPaymentMethodConnectorResponse>,
+) -> RouterResult<Option<serde_json::Value>> {
+    connector_response_pm_data
+        .and_then(
+            |connector_response_pm_data| match connector_response_pm_data {
+                core_types::AdditionalPaymentMethodConnectorResponse::Card {
+                    card_verification_results,
+                    ..
+                } => card_verification_results.as_ref(),
+            },
+        )
+        .map(Encode::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode card verification results")
+}
+
+/// The 3DS authentication details of the payment attempt, to be stored on it. The details of an
+/// external authentication take precedence over the ones reported by the connector, except for
+/// the liability shift, which is decided by the connector that processed the payment.
+pub fn get_authentication_details(
+    connector_response_pm_data: Option<&core_types::AdditionalPaymentMethodConnectorResponse>,
+    authentication: Option<&storage::Authentication>,
+) -> RouterResult<Option<serde_json::Value>> {
+    let connector_authentication_details =
+        connector_response_pm_data.and_then(|connector_response_pm_data| {
+            match connector_response_pm_data {
+                core_types::AdditionalPaymentMethodConnectorResponse::Card {
+                    authentication_details,
+                    ..
+                } => authentication_details.clone(),
+            }
+        });
+
+    let authentication_details = match authentication {
+        Some(authentication) => {
+            let external_authentication_details =
+                api_models::payments::PaymentAuthenticationDetails::foreign_from(authentication);
+            Some(api_models::payments::PaymentAuthenticationDetails {
+                liability_shift: connector_authentication_details
+                    .and_then(|authentication_details| authentication_details.liability_shift)
+                    .or(external_authentication_details.liability_shift),
+                ..external_authentication_details
+            })
+        }
+        None => connector_authentication_details,
+    };
+
+    authentication_details
+        .as_ref()
+        .map(Encode::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode authentication details")
+}
+
 pub async fn get_payment_method_details_from_payment_token(
     state: &AppState,
     payment_attempt: &PaymentAttempt,
@@ -0,0 +1,200 @@
+use common_utils::fp_utils::when;
+use error_stack::{report, ResultExt};
+use router_env::logger;
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::{
+        api_locking,
+        errors::{self, RouterResult},
+    },
+    db::StorageInterface,
+    routes::{lock_utils, metrics},
+    types::storage::{self, enums as storage_enums},
+};
+
+pub const INTENT_EXPIRY_TASK: &str = "INTENT_EXPIRY";
+
+/// Tracking data stored against the intent expiry process tracker entry of a business profile
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IntentExpiryTrackingData {
+    pub merchant_id: String,
+    pub profile_id: String,
+}
+
+/// Intent statuses in which the payment is considered abandoned once the intent expiry elapses
+pub const EXPIRABLE_INTENT_STATUSES: [storage_enums::IntentStatus; 2] = [
+    storage_enums::IntentStatus::RequiresPaymentMethod,
+    storage_enums::IntentStatus::RequiresConfirmation,
+];
+
+/// Lock held on the payment while it is expired, the same lock taken by payment API requests
+pub fn get_payment_lock_action(payment_id: &str) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payment_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Payments,
+            override_lock_retries: None,
+        },
+    }
+}
+
+fn get_intent_expires_at(
+    intent_created_at: PrimitiveDateTime,
+    intent_expiry: Option<i64>,
+) -> Option<PrimitiveDateTime> {
+    intent_expiry.map(|intent_expiry| {
+        intent_created_at.saturating_add(time::Duration::seconds(intent_expiry))
+    })
+}
+
+fn is_intent_expired(
+    intent_created_at: PrimitiveDateTime,
+    intent_expiry: Option<i64>,
+    current_time: PrimitiveDateTime,
+) -> bool {
+    get_intent_expires_at(intent_created_at, intent_expiry)
+        .is_some_and(|expires_at| expires_at <= current_time)
+}
+
+/// Intents of the business profile created before this time, which are still unconfirmed, have
+/// expired
+pub fn get_stale_intents_created_before(
+    intent_expiry: i64,
+    current_time: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    current_time.saturating_sub(time::Duration::seconds(intent_expiry))
+}
+
+/// Schedules the periodic sweep of the stale payment intents of a business profile which has an
+/// intent expiry configured. A sweep which was finished because the intent expiry was removed is
+/// resumed once it is configured again.
+pub async fn add_intent_expiry_task(
+    db: &dyn StorageInterface,
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> RouterResult<()> {
+    if business_profile.intent_expiry.is_none() {
+        return Ok(());
+    }
+
+    let tracking_data = IntentExpiryTrackingData {
+        merchant_id: business_profile.merchant_id.clone(),
+        profile_id: business_profile.profile_id.clone(),
+    };
+
+    let current_time = common_utils::date_time::now();
+    let runner = storage::ProcessTrackerRunner::IntentExpiryWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        INTENT_EXPIRY_TASK,
+        &business_profile.profile_id,
+        &business_profile.merchant_id,
+    );
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch intent expiry task")?;
+
+    match existing_process {
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                INTENT_EXPIRY_TASK,
+                runner,
+                ["PAYMENTS", "INTENT_EXPIRY"],
+                tracking_data,
+                current_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct intent expiry process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert intent expiry task in process tracker")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("flow", "IntentExpiry")],
+            );
+        }
+        Some(process) if process.status == storage_enums::ProcessTrackerStatus::Finish => {
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(current_time),
+                tracking_data: None,
+                business_status: Some("Pending".to_string()),
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.as_scheduler()
+                .update_process(process, updated_process_tracker_data)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to resume intent expiry task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("flow", "IntentExpiry")],
+            );
+        }
+        Some(_) => {}
+    }
+    logger::debug!(profile_id = %business_profile.profile_id, "Scheduled intent expiry sweep");
+
+    Ok(())
+}
+
+/// Rejects the payment if its intent has expired, but has not been swept yet
+pub fn validate_intent_not_expired(
+    payment_intent: &storage::PaymentIntent,
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> RouterResult<()> {
+    let is_expired = is_intent_expired(
+        payment_intent.created_at,
+        business_profile.intent_expiry,
+        common_utils::date_time::now(),
+    );
+
+    when(is_expired, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "You cannot confirm this payment because it has expired".to_string(),
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_intent_expired() {
+        let created_at = common_utils::date_time::now();
+        let before_expiry = created_at.saturating_add(time::Duration::minutes(59));
+        let after_expiry = created_at.saturating_add(time::Duration::minutes(61));
+
+        assert!(!is_intent_expired(created_at, Some(3600), before_expiry));
+        assert!(is_intent_expired(created_at, Some(3600), after_expiry));
+        // Intents of profiles without an intent expiry never expire
+        assert!(!is_intent_expired(created_at, None, after_expiry));
+    }
+
+    #[test]
+    fn test_stale_intents_created_before() {
+        let current_time = common_utils::date_time::now();
+        let created_before = get_stale_intents_created_before(3600, current_time);
+
+        assert_eq!(created_before, current_time - time::Duration::hours(1));
+        // The intents picked up by the sweeper are the ones which are rejected on confirm
+        assert!(is_intent_expired(created_before, Some(3600), current_time));
+        assert!(!is_intent_expired(
+            created_before.saturating_add(time::Duration::seconds(1)),
+            Some(3600),
+            current_time
+        ));
+    }
+}
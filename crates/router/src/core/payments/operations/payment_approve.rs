@@ -176,6 +176,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            is_card_verification: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -63,6 +63,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 enums::IntentStatus::Failed,
                 enums::IntentStatus::Succeeded,
                 enums::IntentStatus::Cancelled,
+                enums::IntentStatus::Expired,
                 enums::IntentStatus::Processing,
                 enums::IntentStatus::RequiresMerchantAction,
             ],
@@ -191,6 +192,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            is_card_verification: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -77,10 +77,28 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         helpers::validate_status_with_capture_method(payment_intent.status, capture_method)?;
 
-        helpers::validate_amount_to_capture(
-            payment_attempt.amount_capturable,
+        let profile_id = payment_intent
+            .profile_id
+            .as_ref()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("'profile_id' not set in payment intent")?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_string(),
+            })?;
+
+        payments::over_capture::validate_amount_to_capture(
+            db,
+            &state.conf.connectors,
+            &business_profile,
+            &payment_attempt,
             request.amount_to_capture,
-        )?;
+        )
+        .await?;
 
         helpers::validate_capture_method(capture_method)?;
 
@@ -89,11 +107,6 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 .amount_to_capture
                 .get_required_value("amount_to_capture")?;
 
-            helpers::validate_amount_to_capture(
-                payment_attempt.amount_capturable,
-                Some(amount_to_capture),
-            )?;
-
             let previous_captures = db
                 .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
                     &payment_attempt.merchant_id,
@@ -175,20 +188,6 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .await
             .transpose()?;
 
-        let profile_id = payment_intent
-            .profile_id
-            .as_ref()
-            .get_required_value("profile_id")
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("'profile_id' not set in payment intent")?;
-
-        let business_profile = db
-            .find_business_profile_by_profile_id(profile_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-                id: profile_id.to_string(),
-            })?;
-
         let payment_data = payments::PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -232,6 +231,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            is_card_verification: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
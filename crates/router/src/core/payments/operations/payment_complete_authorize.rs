@@ -308,6 +308,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             frm_metadata: None,
             recurring_details,
             poll_config: None,
+            is_card_verification: false,
         };
 
         let customer_details = Some(CustomerDetails {
@@ -96,9 +96,11 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 &payment_intent.status,
                 &[
                     storage_enums::IntentStatus::Cancelled,
+                    storage_enums::IntentStatus::Expired,
                     storage_enums::IntentStatus::Succeeded,
                     storage_enums::IntentStatus::Processing,
                     storage_enums::IntentStatus::RequiresCapture,
+                    storage_enums::IntentStatus::PartiallyAuthorized,
                     storage_enums::IntentStatus::RequiresMerchantAction,
                 ],
                 "confirm",
@@ -108,9 +110,11 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 &payment_intent.status,
                 &[
                     storage_enums::IntentStatus::Cancelled,
+                    storage_enums::IntentStatus::Expired,
                     storage_enums::IntentStatus::Succeeded,
                     storage_enums::IntentStatus::Processing,
                     storage_enums::IntentStatus::RequiresCapture,
+                    storage_enums::IntentStatus::PartiallyAuthorized,
                     storage_enums::IntentStatus::RequiresMerchantAction,
                     storage_enums::IntentStatus::RequiresCustomerAction,
                 ],
@@ -255,14 +259,32 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 | api_models::enums::IntentStatus::RequiresConfirmation => {
                     // Normal payment
                     // Parallel calls - level 1
-                    let (payment_attempt, shipping_address, billing_address, business_profile, _) =
-                        tokio::try_join!(
-                            utils::flatten_join_error(payment_attempt_fut),
-                            utils::flatten_join_error(shipping_address_fut),
-                            utils::flatten_join_error(billing_address_fut),
-                            utils::flatten_join_error(business_profile_fut),
-                            utils::flatten_join_error(config_update_fut)
-                        )?;
+                    let (
+                        mut payment_attempt,
+                        shipping_address,
+                        billing_address,
+                        business_profile,
+                        _,
+                    ) = tokio::try_join!(
+                        utils::flatten_join_error(payment_attempt_fut),
+                        utils::flatten_join_error(shipping_address_fut),
+                        utils::flatten_join_error(billing_address_fut),
+                        utils::flatten_join_error(business_profile_fut),
+                        utils::flatten_join_error(config_update_fut)
+                    )?;
+
+                    // The next tender of a split tender payment is made on a new payment attempt
+                    if payments::split_tender::requires_new_tender_attempt(&payment_attempt) {
+                        (payment_intent, payment_attempt) = helpers::AttemptType::New
+                            .modify_payment_intent_and_payment_attempt(
+                                request,
+                                payment_intent,
+                                payment_attempt,
+                                &*state.store,
+                                storage_scheme,
+                            )
+                            .await?;
+                    }
 
                     (
                         payment_attempt,
@@ -294,6 +316,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                         "confirm",
                     )?;
 
+                    let collects_remaining_amount =
+                        payments::partial_authorization::is_remaining_amount_collectable(
+                            &payment_intent,
+                            &payment_attempt,
+                        );
+
                     // 3
                     (payment_intent, payment_attempt) = attempt_type
                         .modify_payment_intent_and_payment_attempt(
@@ -305,6 +333,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                         )
                         .await?;
 
+                    if collects_remaining_amount {
+                        payment_attempt.capture_method =
+                            payments::partial_authorization::get_remainder_capture_method(
+                                request.capture_method,
+                            );
+                    }
+
                     (
                         payment_attempt,
                         shipping_address,
@@ -314,12 +349,20 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 }
             };
 
+        payments::intent_expiry::validate_intent_not_expired(&payment_intent, &business_profile)?;
+
         payment_intent.order_details = request
             .get_order_details_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to convert order details to value")?
             .or(payment_intent.order_details);
 
+        payment_intent.l2_l3_data = helpers::get_updated_l2_l3_data(
+            request.l2_l3_data.as_ref(),
+            payment_intent.l2_l3_data,
+            payment_intent.amount,
+        )?;
+
         payment_intent.setup_future_usage = request
             .setup_future_usage
             .or(payment_intent.setup_future_usage);
@@ -353,6 +396,19 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         payment_attempt.capture_method = request.capture_method.or(payment_attempt.capture_method);
 
+        payment_attempt.amount = payments::split_tender::get_tender_amount(
+            &*state.store,
+            &payment_intent,
+            &payment_attempt,
+            request,
+            storage_scheme,
+        )
+        .await?;
+        payment_attempt.is_split_tender = Some(payments::split_tender::is_partial_tender(
+            payment_intent.amount,
+            payment_attempt.amount,
+        ));
+
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
 
@@ -636,6 +692,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             authentication: None,
             recurring_details,
             poll_config: None,
+            is_card_verification: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1142,6 +1199,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         payment_method_billing_address_id,
                         fingerprint_id: m_fingerprint_id,
                         payment_method_id: m_payment_method_id,
+                        is_split_tender: payment_data.payment_attempt.is_split_tender,
                     },
                     storage_scheme,
                 )
@@ -1162,6 +1220,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         let m_statement_descriptor_suffix = statement_descriptor_suffix.clone();
         let m_order_details = order_details.clone();
         let m_metadata = metadata.clone();
+        let m_l2_l3_data = payment_data.payment_intent.l2_l3_data.clone();
         let m_db = state.clone().store;
         let m_storage_scheme = storage_scheme.to_string();
         let session_expiry = m_payment_data_payment_intent.session_expiry;
@@ -1191,6 +1250,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         fingerprint_id: None,
                         session_expiry,
                         request_external_three_ds_authentication: None,
+                        l2_l3_data: m_l2_l3_data,
                     },
                     storage_scheme,
                 )
@@ -41,9 +41,11 @@ use crate::{
     utils::{self, OptionExt},
 };
 
-#[derive(Debug, Clone, Copy, PaymentOperation)]
+#[derive(Debug, Clone, Copy, Default, PaymentOperation)]
 #[operation(operations = "all", flow = "authorize")]
-pub struct PaymentCreate;
+pub struct PaymentCreate {
+    is_card_verification: bool,
+}
 
 /// The `get_trackers` function for `PaymentsCreate` is an entrypoint for new payments
 /// This will create all the entities required for a new payment from the request
@@ -451,6 +453,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             frm_metadata: request.frm_metadata.clone(),
             recurring_details,
             poll_config: None,
+            is_card_verification: self.is_card_verification,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -701,6 +704,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
         })?;
 
         helpers::validate_amount_to_capture_and_capture_method(None, request)?;
+        if let Some(l2_l3_data) = &request.l2_l3_data {
+            helpers::validate_l2_l3_data(l2_l3_data, request.amount.map(i64::from))?;
+        }
+        payments::scheduled_capture::validate_capture_on(
+            request.capture_on,
+            request.capture_method,
+        )?;
         helpers::validate_card_data(
             request
                 .payment_method_data
@@ -757,6 +767,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
 }
 
 impl PaymentCreate {
+    /// Creates the zero-amount payment through which the card verification API verifies a card
+    pub fn for_card_verification() -> Self {
+        Self {
+            is_card_verification: true,
+        }
+    }
+
     #[instrument(skip_all)]
     #[allow(clippy::too_many_arguments)]
     pub async fn make_payment_attempt(
@@ -939,6 +956,12 @@ impl PaymentCreate {
                 fingerprint_id: None,
                 authentication_connector: None,
                 authentication_id: None,
+                authorization_expires_at: None,
+                card_verification_results: None,
+                authentication_details: None,
+                is_split_tender: None,
+                acquirer_reference_number: None,
+                authorized_at: None,
             },
             additional_pm_data,
         ))
@@ -978,6 +1001,11 @@ impl PaymentCreate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to convert order details to value")?;
 
+        let l2_l3_data = request
+            .get_l2_l3_data_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert l2_l3_data to value")?;
+
         let allowed_payment_method_types = request
             .get_allowed_payment_method_types_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1046,6 +1074,8 @@ impl PaymentCreate {
             session_expiry: Some(session_expiry),
             request_external_three_ds_authentication: request
                 .request_external_three_ds_authentication,
+            allow_partial_authorization: request.allow_partial_authorization,
+            l2_l3_data,
         })
     }
 
@@ -58,6 +58,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             &payment_intent.status,
             &[
                 enums::IntentStatus::Cancelled,
+                enums::IntentStatus::Expired,
                 enums::IntentStatus::Failed,
                 enums::IntentStatus::Succeeded,
                 enums::IntentStatus::Processing,
@@ -175,6 +176,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             frm_metadata: None,
             recurring_details: None,
             poll_config: None,
+            is_card_verification: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -19,11 +19,12 @@ use crate::{
         mandate,
         payment_methods::{self, PaymentMethodRetrieve},
         payments::{
+            authorization_expiry,
             helpers::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
             },
-            tokenization,
+            partial_authorization, scheduled_capture, split_tender, tokenization,
             types::MultipleCaptureData,
             PaymentData,
         },
@@ -78,7 +79,7 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
         ))
         .await?;
 
-        Ok(payment_data)
+        Ok(add_authorized_payment_tasks(db, payment_data, storage_scheme).await)
     }
 
     async fn save_pm_and_mandate<'b>(
@@ -371,14 +372,24 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsSyncData> for
     where
         F: 'b + Send,
     {
-        Box::pin(payment_response_update_tracker(
+        let previous_attempt_status = payment_data.payment_attempt.status;
+        let payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_id,
             payment_data,
             router_data,
             storage_scheme,
         ))
-        .await
+        .await?;
+
+        // Payments authorized after a redirection or 3DS are authorized by the sync or incoming
+        // webhook which follows the customer action. The tasks are only scheduled when the payment
+        // is authorized by this response, so that a scheduled capture which ran out of retries is
+        // not reopened by a later sync.
+        if payment_data.payment_attempt.status == previous_attempt_status {
+            return Ok(payment_data);
+        }
+        Ok(add_authorized_payment_tasks(db, payment_data, storage_scheme).await)
     }
 
     async fn save_pm_and_mandate<'b>(
@@ -659,14 +670,16 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     where
         F: 'b + Send,
     {
-        Box::pin(payment_response_update_tracker(
+        let payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_id,
             payment_data,
             response,
             storage_scheme,
         ))
-        .await
+        .await?;
+
+        Ok(add_authorized_payment_tasks(db, payment_data, storage_scheme).await)
     }
 
     async fn save_pm_and_mandate<'b>(
@@ -694,6 +707,34 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     }
 }
 
+/// Schedules the authorization expiry and scheduled capture tasks if the payment was authorized by
+/// the connector response. Failing to schedule the tasks does not fail the payment.
+async fn add_authorized_payment_tasks<F: Clone>(
+    state: &AppState,
+    mut payment_data: PaymentData<F>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> PaymentData<F> {
+    scheduled_capture::add_scheduled_capture_task(&*state.store, &payment_data.payment_attempt)
+        .await
+        .map_err(|error| logger::error!(scheduled_capture_task_error=?error))
+        .ok();
+
+    if let Some(updated_payment_attempt) = authorization_expiry::add_authorization_expiry_task(
+        state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        storage_scheme,
+    )
+    .await
+    .map_err(|error| logger::error!(authorization_expiry_task_error=?error))
+    .ok()
+    .flatten()
+    {
+        payment_data.payment_attempt = updated_payment_attempt;
+    }
+    payment_data
+}
+
 #[instrument(skip_all)]
 async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     state: &AppState,
@@ -702,16 +743,31 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     router_data: types::RouterData<F, T, types::PaymentsResponseData>,
     storage_scheme: enums::MerchantStorageScheme,
 ) -> RouterResult<PaymentData<F>> {
+    let connector_response_pm_data = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.additional_payment_method_data.clone());
+    let authorized_amount = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.authorized_amount);
+    let acquirer_reference_number = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.acquirer_reference_number.clone());
+    let card_verification_results =
+        payments_helpers::get_card_verification_results_from_connector_response_pm_data(
+            connector_response_pm_data.as_ref(),
+        )?;
+    let authentication_details = payments_helpers::get_authentication_details(
+        connector_response_pm_data.as_ref(),
+        payment_data.authentication.as_ref(),
+    )?;
     // Update additional payment data with the payment method response that we received from connector
     let additional_payment_method_data =
         update_additional_payment_data_with_connector_response_pm_data(
             payment_data.payment_attempt.payment_method_data.clone(),
-            router_data
-                .connector_response
-                .as_ref()
-                .and_then(|connector_response| {
-                    connector_response.additional_payment_method_data.clone()
-                }),
+            connector_response_pm_data,
         )?;
 
     router_data.payment_method_status.and_then(|status| {
@@ -794,6 +850,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             unified_message: option_gsm.map(|gsm| gsm.unified_message),
                             connector_transaction_id: err.connector_transaction_id,
                             payment_method_data: additional_payment_method_data,
+                            card_verification_results,
+                            authentication_details,
                         }),
                     )
                 }
@@ -904,6 +962,21 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
                     let payment_method_id = payment_data.payment_attempt.payment_method_id.clone();
 
+                    // A partial authorization reduces the amount of the payment attempt to the
+                    // amount approved by the connector
+                    let partially_authorized_amount = matches!(
+                        updated_attempt_status,
+                        enums::AttemptStatus::Authorized | enums::AttemptStatus::Charged
+                    )
+                    .then(|| {
+                        partial_authorization::get_partially_authorized_amount(
+                            &payment_data.payment_intent,
+                            &payment_data.payment_attempt,
+                            authorized_amount,
+                        )
+                    })
+                    .flatten();
+
                     utils::add_apple_pay_payment_status_metrics(
                         router_data.status,
                         router_data.apple_pay_flow.clone(),
@@ -935,7 +1008,19 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 authentication_type: None,
                                 amount_capturable: router_data
                                     .request
-                                    .get_amount_capturable(&payment_data, updated_attempt_status),
+                                    .get_amount_capturable(&payment_data, updated_attempt_status)
+                                    .map(|amount_capturable| {
+                                        partially_authorized_amount.map_or(
+                                            amount_capturable,
+                                            |partially_authorized_amount| {
+                                                amount_capturable.min(partially_authorized_amount)
+                                            },
+                                        )
+                                    }),
+                                amount: partially_authorized_amount,
+                                // The remaining amount of a partially authorized payment is paid
+                                // by another tender
+                                is_split_tender: partially_authorized_amount.map(|_| true),
                                 payment_method_id,
                                 mandate_id: payment_data.payment_attempt.mandate_id.clone(),
                                 connector_metadata,
@@ -950,6 +1035,14 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 authentication_data,
                                 encoded_data,
                                 payment_method_data: additional_payment_method_data,
+                                card_verification_results,
+                                authentication_details,
+                                acquirer_reference_number,
+                                authorized_at: authorization_expiry::get_authorized_at(
+                                    payment_data.payment_attempt.authorized_at,
+                                    updated_attempt_status,
+                                    common_utils::date_time::now(),
+                                ),
                             }),
                         ),
                     };
@@ -1098,19 +1191,32 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         &payment_data,
     );
 
+    let (intent_status, amount_captured) = split_tender::get_intent_status_and_amount_captured(
+        &*state.store,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        api_models::enums::IntentStatus::foreign_from(payment_data.payment_attempt.status),
+        amount_captured,
+        storage_scheme,
+    )
+    .await?;
+    let (intent_status, amount_captured) =
+        partial_authorization::get_intent_status_and_amount_captured(
+            &payment_data.payment_intent,
+            &payment_data.payment_attempt,
+            intent_status,
+            amount_captured,
+        );
+
     let payment_intent_update = match &router_data.response {
         Err(_) => storage::PaymentIntentUpdate::PGStatusUpdate {
-            status: api_models::enums::IntentStatus::foreign_from(
-                payment_data.payment_attempt.status,
-            ),
+            status: intent_status,
             updated_by: storage_scheme.to_string(),
             // make this false only if initial payment fails, if incremental authorization call fails don't make it false
             incremental_authorization_allowed: Some(false),
         },
         Ok(_) => storage::PaymentIntentUpdate::ResponseUpdate {
-            status: api_models::enums::IntentStatus::foreign_from(
-                payment_data.payment_attempt.status,
-            ),
+            status: intent_status,
             return_url: router_data.return_url.clone(),
             amount_captured,
             updated_by: storage_scheme.to_string(),
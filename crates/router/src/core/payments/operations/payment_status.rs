@@ -491,6 +491,7 @@ async fn get_tracker_for_sync<
         frm_metadata: None,
         recurring_details: None,
         poll_config: None,
+        is_card_verification: false,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
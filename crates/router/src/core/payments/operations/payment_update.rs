@@ -15,7 +15,10 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payment_methods::PaymentMethodRetrieve,
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, helpers, operations, scheduled_capture, CustomerDetails, PaymentAddress,
+            PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
@@ -26,7 +29,7 @@ use crate::{
         domain,
         storage::{self, enums as storage_enums, payment_attempt::PaymentAttemptExt},
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
@@ -90,7 +93,8 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
                 storage_enums::IntentStatus::PartiallyCaptured,
-                storage_enums::IntentStatus::RequiresCapture,
+                storage_enums::IntentStatus::PartiallyAuthorized,
+                storage_enums::IntentStatus::Expired,
             ],
             "update",
         )?;
@@ -118,6 +122,36 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        // An authorized payment can only be updated to reschedule or cancel its scheduled capture
+        let is_capture_schedule_update =
+            payment_intent.status == storage_enums::IntentStatus::RequiresCapture;
+        if is_capture_schedule_update {
+            if !scheduled_capture::is_capture_schedule_update_request(request) {
+                helpers::validate_payment_status_against_not_allowed_statuses(
+                    &payment_intent.status,
+                    &[storage_enums::IntentStatus::RequiresCapture],
+                    "update",
+                )?;
+            }
+            utils::when(
+                request.capture_method.is_some_and(|capture_method| {
+                    capture_method != storage_enums::CaptureMethod::Manual
+                }),
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message:
+                            "capture_method of an authorized payment can only be updated to manual"
+                                .to_string(),
+                    })
+                },
+            )?;
+        }
+
+        scheduled_capture::validate_capture_on(
+            request.capture_on,
+            request.capture_method.or(payment_attempt.capture_method),
+        )?;
+
         let customer_acceptance = request.customer_acceptance.clone().map(From::from);
         let recurring_details = request.recurring_details.clone();
 
@@ -177,6 +211,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .amount
             .unwrap_or_else(|| payment_attempt.amount.into());
 
+        payment_intent.l2_l3_data = helpers::get_updated_l2_l3_data(
+            request.l2_l3_data.as_ref(),
+            payment_intent.l2_l3_data,
+            i64::from(amount),
+        )?;
+
         if request.confirm.unwrap_or(false) {
             helpers::validate_customer_id_mandatory_cases(
                 request.setup_future_usage.is_some(),
@@ -349,6 +389,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             };
 
         payment_intent.status = match request.payment_method_data.as_ref() {
+            _ if is_capture_schedule_update => payment_intent.status,
             Some(_) => {
                 if request.confirm.unwrap_or(false) {
                     payment_intent.status
@@ -363,6 +404,10 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .or(payment_intent.request_external_three_ds_authentication);
 
         Self::populate_payment_attempt_with_request(&mut payment_attempt, request);
+        if is_capture_schedule_update {
+            // Removing `capture_on` from an authorized payment cancels the scheduled capture
+            payment_attempt.capture_on = request.capture_on;
+        }
 
         let creds_identifier = request
             .merchant_connector_details
@@ -454,6 +499,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             frm_metadata: request.frm_metadata.clone(),
             recurring_details,
             poll_config: None,
+            is_card_verification: false,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -579,6 +625,12 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
     where
         F: 'b + Send,
     {
+        if payment_data.payment_intent.status == storage_enums::IntentStatus::RequiresCapture {
+            return self
+                .update_capture_schedule(state, payment_data, storage_scheme)
+                .await;
+        }
+
         let is_payment_method_unavailable =
             payment_data.payment_attempt.payment_method_id.is_none()
                 && payment_data.payment_intent.status
@@ -621,6 +673,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         let payment_experience = payment_data.payment_attempt.payment_experience;
         let amount_to_capture = payment_data.payment_attempt.amount_to_capture;
         let capture_method = payment_data.payment_attempt.capture_method;
+        let capture_on = payment_data.payment_attempt.capture_on;
 
         let surcharge_amount = payment_data
             .surcharge_details
@@ -647,6 +700,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     business_sub_label,
                     amount_to_capture,
                     capture_method,
+                    capture_on,
                     surcharge_amount,
                     tax_amount,
                     fingerprint_id: None,
@@ -721,6 +775,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     request_external_three_ds_authentication: payment_data
                         .payment_intent
                         .request_external_three_ds_authentication,
+                    l2_l3_data: payment_data.payment_intent.l2_l3_data.clone(),
                 },
                 storage_scheme,
             )
@@ -798,6 +853,41 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
 }
 
 impl PaymentUpdate {
+    async fn update_capture_schedule<'b, F, Ctx>(
+        &'b self,
+        state: &AppState,
+        mut payment_data: PaymentData<F>,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsRequest, Ctx>,
+        PaymentData<F>,
+    )>
+    where
+        F: 'b + Clone + Send,
+        Ctx: PaymentMethodRetrieve,
+    {
+        let capture_method = payment_data.payment_attempt.capture_method;
+        let capture_on = payment_data.payment_attempt.capture_on;
+        payment_data.payment_attempt = state
+            .store
+            .update_payment_attempt_with_attempt_id(
+                payment_data.payment_attempt,
+                storage::PaymentAttemptUpdate::CaptureScheduleUpdate {
+                    capture_method,
+                    capture_on,
+                    updated_by: storage_scheme.to_string(),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        scheduled_capture::update_scheduled_capture_task(state, &payment_data.payment_attempt)
+            .await?;
+
+        Ok((Box::new(self), payment_data))
+    }
+
     fn populate_payment_attempt_with_request(
         payment_attempt: &mut storage::PaymentAttempt,
         request: &api::PaymentsRequest,
@@ -818,6 +908,7 @@ impl PaymentUpdate {
         request
             .capture_method
             .map(|i| payment_attempt.capture_method.replace(i));
+        payment_attempt.capture_on = request.capture_on.or(payment_attempt.capture_on);
     }
     fn populate_payment_intent_with_request(
         payment_intent: &mut storage::PaymentIntent,
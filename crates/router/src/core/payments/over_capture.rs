@@ -0,0 +1,162 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::{report, ResultExt};
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResult},
+        payments::helpers,
+    },
+    db::StorageInterface,
+    services::ConnectorValidation,
+    types::{api, storage},
+};
+
+fn get_card_isin(payment_attempt: &storage::PaymentAttempt) -> Option<String> {
+    payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => card_info.card_isin,
+            _ => None,
+        })
+}
+
+/// The card network of the payment attempt, resolved from the card info of the card BIN. The card
+/// network passed in the payment request is not used, since it decides whether the payment can be
+/// over-captured.
+async fn get_card_network(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<api_models::enums::CardNetwork>> {
+    let Some(card_isin) = get_card_isin(payment_attempt) else {
+        return Ok(None);
+    };
+
+    Ok(db
+        .get_card_info(&card_isin)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the card info of the card BIN")?
+        .and_then(|card_info| card_info.card_network))
+}
+
+/// The maximum amount that can be captured on the payment attempt, which is the capturable amount
+/// increased by the over-capture tolerance configured in the business profile
+fn get_max_amount_to_capture(
+    max_over_capture_percentage: Option<i16>,
+    amount_capturable: i64,
+) -> Option<i64> {
+    max_over_capture_percentage.map(|max_over_capture_percentage| {
+        amount_capturable + amount_capturable * i64::from(max_over_capture_percentage) / 100
+    })
+}
+
+/// Validates the amount to capture against the capturable amount of the payment attempt. An amount
+/// greater than the capturable amount is allowed only within the over-capture tolerance of the
+/// business profile, and only if the connector supports over-capture for the card network.
+pub async fn validate_amount_to_capture(
+    db: &dyn StorageInterface,
+    connectors: &settings::Connectors,
+    business_profile: &storage::business_profile::BusinessProfile,
+    payment_attempt: &storage::PaymentAttempt,
+    amount_to_capture: Option<i64>,
+) -> RouterResult<()> {
+    let amount_capturable = payment_attempt.amount_capturable;
+    let amount_to_capture = match amount_to_capture {
+        Some(amount_to_capture) if amount_to_capture > amount_capturable => amount_to_capture,
+        _ => return Ok(()),
+    };
+
+    // Without an over-capture tolerance the amount to capture is limited to the capturable amount
+    let Some(max_amount_to_capture) = get_max_amount_to_capture(
+        business_profile.max_over_capture_percentage,
+        amount_capturable,
+    ) else {
+        return helpers::validate_amount_to_capture(amount_capturable, Some(amount_to_capture));
+    };
+
+    if amount_to_capture > max_amount_to_capture {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "amount_to_capture exceeds the maximum over-capture amount of {max_amount_to_capture}"
+            ),
+        }));
+    }
+
+    let connector_name = payment_attempt
+        .connector
+        .as_deref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("connector not found in payment attempt")?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        connectors,
+        connector_name,
+        api::GetToken::Connector,
+        payment_attempt.merchant_connector_id.clone(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Invalid connector name received in payment attempt")?;
+
+    let card_network = get_card_network(db, payment_attempt).await?;
+
+    connector_data
+        .connector
+        .validate_over_capture(card_network)
+        .map_err(|error| {
+            let message = match error.current_context() {
+                errors::ConnectorError::NotSupported { message, connector } => {
+                    format!("{message} is not supported by {connector}")
+                }
+                _ => "Over-capture is not supported for this payment".to_string(),
+            };
+            error.change_context(errors::ApiErrorResponse::NotSupported { message })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_amount_to_capture() {
+        assert_eq!(get_max_amount_to_capture(Some(20), 10000), Some(12000));
+        assert_eq!(get_max_amount_to_capture(Some(100), 999), Some(1998));
+        // Over-capture is disabled without a tolerance
+        assert_eq!(get_max_amount_to_capture(None, 10000), None);
+    }
+
+    #[test]
+    fn test_over_capture_is_limited_to_supported_card_networks() {
+        let supported_card_networks = [
+            api_models::enums::CardNetwork::Visa,
+            api_models::enums::CardNetwork::Mastercard,
+        ];
+
+        assert!(crate::connector::utils::validate_over_capture_card_network(
+            Some(api_models::enums::CardNetwork::Visa),
+            &supported_card_networks,
+            "adyen",
+        )
+        .is_ok());
+        assert!(crate::connector::utils::validate_over_capture_card_network(
+            Some(api_models::enums::CardNetwork::AmericanExpress),
+            &supported_card_networks,
+            "adyen",
+        )
+        .is_err());
+        // Payments whose card BIN could not be resolved to a card network are not over-captured
+        assert!(crate::connector::utils::validate_over_capture_card_network(
+            None,
+            &supported_card_networks,
+            "adyen",
+        )
+        .is_err());
+    }
+}
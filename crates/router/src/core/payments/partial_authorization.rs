@@ -0,0 +1,217 @@
+use crate::{
+    core::payments::split_tender,
+    types::storage::{self, enums as storage_enums},
+};
+
+fn is_partial_authorization_allowed(payment_intent: &storage::PaymentIntent) -> bool {
+    payment_intent.allow_partial_authorization == Some(true)
+}
+
+/// The amount approved by the connector, if it is lower than the amount requested on the payment
+/// attempt and the payment allows partial authorization
+pub fn get_partially_authorized_amount(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    authorized_amount: Option<i64>,
+) -> Option<i64> {
+    is_partial_authorization_allowed(payment_intent)
+        .then(|| filter_partially_authorized_amount(payment_attempt.amount, authorized_amount))
+        .flatten()
+}
+
+fn filter_partially_authorized_amount(
+    requested_amount: i64,
+    authorized_amount: Option<i64>,
+) -> Option<i64> {
+    authorized_amount
+        .filter(|authorized_amount| *authorized_amount > 0 && *authorized_amount < requested_amount)
+}
+
+/// The amount authorized on the active payment attempt of a partially authorized payment
+pub fn get_amount_authorized(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<i64> {
+    (is_partial_authorization_allowed(payment_intent)
+        && matches!(
+            payment_intent.status,
+            storage_enums::IntentStatus::PartiallyAuthorized
+                | storage_enums::IntentStatus::PartiallyCaptured
+        )
+        && split_tender::is_split_tender_attempt(payment_attempt))
+    .then_some(payment_attempt.amount)
+}
+
+/// Whether the remaining amount of a captured partial authorization can be collected by
+/// confirming the payment with another payment method
+pub fn is_remaining_amount_collectable(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    is_partial_authorization_allowed(payment_intent)
+        && payment_intent.status == storage_enums::IntentStatus::PartiallyCaptured
+        && split_tender::requires_new_tender_attempt(payment_attempt)
+}
+
+/// The remaining amount of a partially authorized payment is paid in full by a single payment
+/// attempt, which is captured automatically unless the confirm request asks otherwise. The
+/// capture method of the partial authorization is not carried over, since a manually captured
+/// tender paying only a part of the payment cannot be confirmed.
+pub fn get_remainder_capture_method(
+    requested_capture_method: Option<storage_enums::CaptureMethod>,
+) -> Option<storage_enums::CaptureMethod> {
+    Some(requested_capture_method.unwrap_or(storage_enums::CaptureMethod::Automatic))
+}
+
+/// A partially authorized payment can be captured for the authorized amount, after which it stays
+/// partially captured until the remaining amount is paid by another payment method. A failed
+/// payment of the remaining amount leaves the captured amount of the payment as is.
+pub fn get_intent_status_and_amount_captured(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    intent_status: storage_enums::IntentStatus,
+    amount_captured: Option<i64>,
+) -> (storage_enums::IntentStatus, Option<i64>) {
+    if !is_partial_authorization_allowed(payment_intent)
+        || !split_tender::is_split_tender_attempt(payment_attempt)
+    {
+        return (intent_status, amount_captured);
+    }
+
+    get_remainder_intent_status_and_amount_captured(
+        intent_status,
+        amount_captured,
+        payment_intent.amount_captured,
+    )
+}
+
+fn get_remainder_intent_status_and_amount_captured(
+    intent_status: storage_enums::IntentStatus,
+    amount_captured: Option<i64>,
+    previously_captured_amount: Option<i64>,
+) -> (storage_enums::IntentStatus, Option<i64>) {
+    match intent_status {
+        storage_enums::IntentStatus::RequiresCapture => (
+            storage_enums::IntentStatus::PartiallyAuthorized,
+            amount_captured,
+        ),
+        storage_enums::IntentStatus::RequiresPaymentMethod
+            if amount_captured.is_some_and(|amount_captured| amount_captured > 0) =>
+        {
+            (
+                storage_enums::IntentStatus::PartiallyCaptured,
+                amount_captured,
+            )
+        }
+        storage_enums::IntentStatus::Failed
+            if previously_captured_amount.is_some_and(|amount_captured| amount_captured > 0) =>
+        {
+            (
+                storage_enums::IntentStatus::PartiallyCaptured,
+                previously_captured_amount,
+            )
+        }
+        _ => (intent_status, amount_captured),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partially_authorized_amount() {
+        assert_eq!(
+            filter_partially_authorized_amount(10000, Some(6000)),
+            Some(6000)
+        );
+        // A full authorization is not a partial authorization
+        assert_eq!(filter_partially_authorized_amount(10000, Some(10000)), None);
+        assert_eq!(filter_partially_authorized_amount(10000, Some(0)), None);
+        assert_eq!(filter_partially_authorized_amount(10000, None), None);
+    }
+
+    #[test]
+    fn test_partially_authorized_payment_waits_for_capture() {
+        assert_eq!(
+            get_remainder_intent_status_and_amount_captured(
+                storage_enums::IntentStatus::RequiresCapture,
+                None,
+                None,
+            ),
+            (storage_enums::IntentStatus::PartiallyAuthorized, None)
+        );
+    }
+
+    #[test]
+    fn test_captured_partial_authorization_allows_collecting_the_remainder() {
+        // The split tender status of a captured tender which does not complete the payment
+        assert_eq!(
+            get_remainder_intent_status_and_amount_captured(
+                storage_enums::IntentStatus::RequiresPaymentMethod,
+                Some(6000),
+                None,
+            ),
+            (storage_enums::IntentStatus::PartiallyCaptured, Some(6000))
+        );
+    }
+
+    #[test]
+    fn test_failed_collection_of_remainder_keeps_captured_amount() {
+        assert_eq!(
+            get_remainder_intent_status_and_amount_captured(
+                storage_enums::IntentStatus::Failed,
+                None,
+                Some(6000),
+            ),
+            (storage_enums::IntentStatus::PartiallyCaptured, Some(6000))
+        );
+        // A partially authorized payment which was never captured fails as usual
+        assert_eq!(
+            get_remainder_intent_status_and_amount_captured(
+                storage_enums::IntentStatus::Failed,
+                None,
+                None,
+            ),
+            (storage_enums::IntentStatus::Failed, None)
+        );
+    }
+
+    #[test]
+    fn test_remainder_of_manually_captured_partial_authorization_is_collectable() {
+        // The partial authorization of 6000 out of 10000 was captured manually, the remaining
+        // amount of 4000 is collected on a new payment attempt
+        let capture_method = get_remainder_capture_method(None);
+        assert_eq!(
+            capture_method,
+            Some(storage_enums::CaptureMethod::Automatic)
+        );
+        assert!(split_tender::validate_tender_capture_method(10000, 4000, capture_method).is_ok());
+
+        // Carrying over the manual capture method would reject the remainder
+        assert!(split_tender::validate_tender_capture_method(
+            10000,
+            4000,
+            Some(storage_enums::CaptureMethod::Manual)
+        )
+        .is_err());
+        assert!(split_tender::validate_tender_capture_method(
+            10000,
+            4000,
+            get_remainder_capture_method(Some(storage_enums::CaptureMethod::Manual))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_successful_collection_of_remainder_completes_payment() {
+        assert_eq!(
+            get_remainder_intent_status_and_amount_captured(
+                storage_enums::IntentStatus::Succeeded,
+                Some(10000),
+                Some(6000),
+            ),
+            (storage_enums::IntentStatus::Succeeded, Some(10000))
+        );
+    }
+}
@@ -344,13 +344,26 @@ where
     );
 
     let db = &*state.store;
+    let acquirer_reference_number = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.acquirer_reference_number.clone());
+    let connector_response_pm_data = router_data
+        .connector_response
+        .clone()
+        .and_then(|connector_response| connector_response.additional_payment_method_data);
+    let card_verification_results =
+        payments::helpers::get_card_verification_results_from_connector_response_pm_data(
+            connector_response_pm_data.as_ref(),
+        )?;
+    let authentication_details = payments::helpers::get_authentication_details(
+        connector_response_pm_data.as_ref(),
+        payment_data.authentication.as_ref(),
+    )?;
     let additional_payment_method_data =
         payments::helpers::update_additional_payment_data_with_connector_response_pm_data(
             payment_data.payment_attempt.payment_method_data.clone(),
-            router_data
-                .connector_response
-                .clone()
-                .and_then(|connector_response| connector_response.additional_payment_method_data),
+            connector_response_pm_data,
         )?;
 
     match router_data.response {
@@ -394,6 +407,8 @@ where
                     error_code: None,
                     error_message: None,
                     error_reason: None,
+                    amount: None,
+                    is_split_tender: None,
                     amount_capturable: if router_data.status.is_terminal_status() {
                         Some(0)
                     } else {
@@ -405,6 +420,14 @@ where
                     unified_code: None,
                     unified_message: None,
                     payment_method_data: additional_payment_method_data,
+                    card_verification_results,
+                    authentication_details,
+                    acquirer_reference_number,
+                    authorized_at: payments::authorization_expiry::get_authorized_at(
+                        payment_data.payment_attempt.authorized_at,
+                        router_data.status,
+                        common_utils::date_time::now(),
+                    ),
                 },
                 storage_scheme,
             )
@@ -432,6 +455,8 @@ where
                     unified_message: option_gsm.map(|gsm| gsm.unified_message),
                     connector_transaction_id: error_response.connector_transaction_id.clone(),
                     payment_method_data: additional_payment_method_data,
+                    card_verification_results,
+                    authentication_details,
                 },
                 storage_scheme,
             )
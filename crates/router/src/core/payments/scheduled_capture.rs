@@ -0,0 +1,373 @@
+use common_utils::fp_utils::when;
+use error_stack::{report, ResultExt};
+use router_env::logger;
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    routes::{metrics, AppState},
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+pub const SCHEDULED_CAPTURE_TASK: &str = "SCHEDULED_CAPTURE";
+
+/// Tracking data stored against the scheduled capture process tracker entry
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScheduledCaptureTrackingData {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+}
+
+/// `capture_on` is only honoured for manually captured payments, and has to be in the future
+pub fn validate_capture_on(
+    capture_on: Option<PrimitiveDateTime>,
+    capture_method: Option<storage_enums::CaptureMethod>,
+) -> RouterResult<()> {
+    let Some(capture_on) = capture_on else {
+        return Ok(());
+    };
+
+    when(
+        capture_method != Some(storage_enums::CaptureMethod::Manual),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "capture_on can only be provided when capture_method is manual"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    when(capture_on <= common_utils::date_time::now(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "capture_on should be a time in the future".to_string(),
+        }))
+    })
+}
+
+/// Whether the payment update request only touches the capture schedule of the payment.
+///
+/// An authorized payment is rescheduled by updating it with a new `capture_on`, and its scheduled
+/// capture is cancelled by updating it with `capture_method` set to `manual` and no `capture_on`.
+/// The request is destructured exhaustively so that every new request field has to be considered
+/// here.
+pub fn is_capture_schedule_update_request(request: &api::PaymentsRequest) -> bool {
+    let api::PaymentsRequest {
+        payment_id: _,
+        merchant_id: _,
+        // The client secret only authenticates the request
+        client_secret: _,
+        capture_on,
+        capture_method,
+        amount,
+        currency,
+        amount_to_capture,
+        routing,
+        connector,
+        authentication_type,
+        billing,
+        confirm,
+        customer,
+        customer_id,
+        email,
+        name,
+        phone,
+        phone_country_code,
+        off_session,
+        description,
+        return_url,
+        setup_future_usage,
+        payment_method_data,
+        payment_method,
+        payment_token,
+        card_cvc,
+        shipping,
+        statement_descriptor_name,
+        statement_descriptor_suffix,
+        order_details,
+        mandate_data,
+        customer_acceptance,
+        mandate_id,
+        browser_info,
+        payment_experience,
+        payment_method_type,
+        business_country,
+        business_label,
+        merchant_connector_details,
+        allowed_payment_method_types,
+        business_sub_label,
+        retry_action,
+        tender_amount,
+        metadata,
+        connector_metadata,
+        feature_metadata,
+        payment_link,
+        payment_link_config,
+        profile_id,
+        surcharge_details,
+        payment_type,
+        request_incremental_authorization,
+        session_expiry,
+        frm_metadata,
+        request_external_three_ds_authentication,
+        allow_partial_authorization,
+        l2_l3_data,
+        recurring_details,
+    } = request;
+
+    (capture_on.is_some() || capture_method.is_some())
+        && [
+            amount.is_none(),
+            currency.is_none(),
+            amount_to_capture.is_none(),
+            routing.is_none(),
+            connector.is_none(),
+            authentication_type.is_none(),
+            billing.is_none(),
+            confirm.is_none(),
+            customer.is_none(),
+            customer_id.is_none(),
+            email.is_none(),
+            name.is_none(),
+            phone.is_none(),
+            phone_country_code.is_none(),
+            off_session.is_none(),
+            description.is_none(),
+            return_url.is_none(),
+            setup_future_usage.is_none(),
+            payment_method_data.is_none(),
+            payment_method.is_none(),
+            payment_token.is_none(),
+            card_cvc.is_none(),
+            shipping.is_none(),
+            statement_descriptor_name.is_none(),
+            statement_descriptor_suffix.is_none(),
+            order_details.is_none(),
+            mandate_data.is_none(),
+            customer_acceptance.is_none(),
+            mandate_id.is_none(),
+            browser_info.is_none(),
+            payment_experience.is_none(),
+            payment_method_type.is_none(),
+            business_country.is_none(),
+            business_label.is_none(),
+            merchant_connector_details.is_none(),
+            allowed_payment_method_types.is_none(),
+            business_sub_label.is_none(),
+            retry_action.is_none(),
+            tender_amount.is_none(),
+            metadata.is_none(),
+            connector_metadata.is_none(),
+            feature_metadata.is_none(),
+            payment_link.is_none(),
+            payment_link_config.is_none(),
+            profile_id.is_none(),
+            surcharge_details.is_none(),
+            payment_type.is_none(),
+            request_incremental_authorization.is_none(),
+            session_expiry.is_none(),
+            frm_metadata.is_none(),
+            request_external_three_ds_authentication.is_none(),
+            allow_partial_authorization.is_none(),
+            l2_l3_data.is_none(),
+            recurring_details.is_none(),
+        ]
+        .into_iter()
+        .all(|is_field_absent| is_field_absent)
+}
+
+fn get_process_tracker_id(payment_attempt: &storage::PaymentAttempt) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+        SCHEDULED_CAPTURE_TASK,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    )
+}
+
+fn is_capture_schedulable(payment_attempt: &storage::PaymentAttempt) -> bool {
+    payment_attempt.status == storage_enums::AttemptStatus::Authorized
+        && payment_attempt.capture_method == Some(storage_enums::CaptureMethod::Manual)
+}
+
+/// Schedules an existing scheduled capture task at `capture_on`, reopening it if it had finished
+/// because it was cancelled or ran out of retries
+async fn reschedule_capture_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    capture_on: PrimitiveDateTime,
+) -> RouterResult<()> {
+    db.as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(capture_on),
+                tracking_data: None,
+                business_status: Some(consts::SCHEDULED_CAPTURE_PENDING.to_string()),
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reschedule scheduled capture task")?;
+    metrics::TASKS_RESET_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "ScheduledCapture")],
+    );
+    Ok(())
+}
+
+/// Adds a task which captures the payment at `capture_on`, once the payment is authorized.
+/// An existing task of the payment attempt which has finished is rescheduled instead.
+pub async fn add_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let Some(capture_on) = payment_attempt.capture_on else {
+        return Ok(());
+    };
+    if !is_capture_schedulable(payment_attempt) {
+        return Ok(());
+    }
+
+    let process_tracker_id = get_process_tracker_id(payment_attempt);
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch scheduled capture task")?;
+    if let Some(process) = existing_process {
+        if process.status == storage_enums::ProcessTrackerStatus::Finish {
+            reschedule_capture_task(db, process, capture_on).await?;
+        }
+        return Ok(());
+    }
+
+    let tracking_data = ScheduledCaptureTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SCHEDULED_CAPTURE_TASK,
+        storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+        ["PAYMENTS", "SCHEDULED_CAPTURE"],
+        tracking_data,
+        capture_on,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct scheduled capture process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert scheduled capture task in process tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "ScheduledCapture")],
+    );
+    logger::debug!(payment_id = %payment_attempt.payment_id, ?capture_on, "Scheduled capture");
+
+    Ok(())
+}
+
+/// Moves the scheduled capture of an authorized payment to the new `capture_on`, or cancels it
+/// if `capture_on` has been removed from the payment attempt. A task which had been cancelled or
+/// had run out of retries is scheduled again when a new `capture_on` is provided.
+pub async fn update_scheduled_capture_task(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let existing_process = db
+        .find_process_by_id(&get_process_tracker_id(payment_attempt))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch scheduled capture task")?;
+
+    match (payment_attempt.capture_on, existing_process) {
+        (Some(capture_on), Some(process)) => reschedule_capture_task(db, process, capture_on).await,
+        (Some(_), None) => add_scheduled_capture_task(db, payment_attempt).await,
+        (None, Some(process)) if process.status != storage_enums::ProcessTrackerStatus::Finish => {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    consts::SCHEDULED_CAPTURE_CANCELLED.to_string(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to cancel scheduled capture task")
+        }
+        (None, _) => Ok(()),
+    }
+}
+
+/// Time at which a failed scheduled capture is retried, backing off exponentially
+pub fn get_capture_retry_schedule_time(retry_count: i32) -> Option<PrimitiveDateTime> {
+    (retry_count < consts::SCHEDULED_CAPTURE_MAX_RETRIES).then(|| {
+        let delay_in_minutes = consts::SCHEDULED_CAPTURE_RETRY_BASE_DELAY_IN_MINUTES
+            .saturating_mul(2_i64.saturating_pow(retry_count.unsigned_abs()));
+        common_utils::date_time::now().saturating_add(time::Duration::minutes(delay_in_minutes))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_capture_schedule_update_request() {
+        let reschedule_request = api::PaymentsRequest {
+            capture_on: Some(common_utils::date_time::now()),
+            client_secret: Some("pay_123_secret_456".to_string()),
+            ..Default::default()
+        };
+        assert!(is_capture_schedule_update_request(&reschedule_request));
+
+        let cancel_request = api::PaymentsRequest {
+            capture_method: Some(storage_enums::CaptureMethod::Manual),
+            ..Default::default()
+        };
+        assert!(is_capture_schedule_update_request(&cancel_request));
+
+        let description_update_request = api::PaymentsRequest {
+            capture_on: Some(common_utils::date_time::now()),
+            description: Some("updated".to_string()),
+            ..Default::default()
+        };
+        assert!(!is_capture_schedule_update_request(
+            &description_update_request
+        ));
+
+        assert!(!is_capture_schedule_update_request(
+            &api::PaymentsRequest::default()
+        ));
+    }
+
+    #[test]
+    fn test_capture_retry_schedule_time() {
+        let current_time = common_utils::date_time::now();
+        let first_retry = get_capture_retry_schedule_time(0);
+        let second_retry = get_capture_retry_schedule_time(1);
+
+        assert!(first_retry.is_some_and(|schedule_time| schedule_time > current_time));
+        assert!(second_retry > first_retry);
+        assert_eq!(
+            get_capture_retry_schedule_time(consts::SCHEDULED_CAPTURE_MAX_RETRIES),
+            None
+        );
+    }
+}
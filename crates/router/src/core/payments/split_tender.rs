@@ -0,0 +1,389 @@
+use common_utils::fp_utils::when;
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        refunds,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+pub const SPLIT_TENDER_REVERSAL_REASON: &str = "split_tender_reversal";
+
+/// A payment attempt is a tender of a split tender payment if it was marked as one when it was
+/// confirmed for a part of the payment amount, or when it was partially authorized
+pub fn is_split_tender_attempt(payment_attempt: &storage::PaymentAttempt) -> bool {
+    payment_attempt.is_split_tender == Some(true)
+}
+
+/// Whether the amount charged by a payment attempt pays only a part of the payment
+pub fn is_partial_tender(payment_amount: i64, tender_amount: i64) -> bool {
+    tender_amount < payment_amount
+}
+
+/// Whether the active attempt of a split tender payment which still has an amount remaining to
+/// be paid is done, so that the next tender has to be made on a new payment attempt
+pub fn requires_new_tender_attempt(payment_attempt: &storage::PaymentAttempt) -> bool {
+    is_split_tender_attempt(payment_attempt)
+        && matches!(
+            payment_attempt.status,
+            storage_enums::AttemptStatus::Charged
+                | storage_enums::AttemptStatus::Failure
+                | storage_enums::AttemptStatus::AuthorizationFailed
+                | storage_enums::AttemptStatus::AuthenticationFailed
+        )
+}
+
+/// Successfully charged attempts of the payment, in the order in which they were made
+pub async fn get_successful_tenders(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<Vec<storage::PaymentAttempt>> {
+    let mut tenders = db
+        .find_attempts_by_merchant_id_payment_id(
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?
+        .into_iter()
+        .filter(|attempt| attempt.status == storage_enums::AttemptStatus::Charged)
+        .collect::<Vec<_>>();
+    tenders.sort_by_key(|attempt| attempt.created_at);
+
+    Ok(tenders)
+}
+
+async fn get_amount_paid_by_other_tenders(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<i64> {
+    Ok(get_successful_tenders(db, payment_intent, storage_scheme)
+        .await?
+        .iter()
+        .filter(|tender| tender.attempt_id != payment_attempt.attempt_id)
+        .map(|tender| tender.amount)
+        .sum())
+}
+
+fn is_gift_card_tender(
+    request: &api::PaymentsRequest,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    match request
+        .payment_method_data
+        .as_ref()
+        .and_then(|payment_method_data| payment_method_data.payment_method_data.as_ref())
+    {
+        Some(payment_method_data) => matches!(
+            payment_method_data,
+            api_models::payments::PaymentMethodData::GiftCard(_)
+        ),
+        None => {
+            request.payment_method.or(payment_attempt.payment_method)
+                == Some(storage_enums::PaymentMethod::GiftCard)
+        }
+    }
+}
+
+/// A tender paying only a part of the payment has to be captured automatically
+pub fn validate_tender_capture_method(
+    payment_amount: i64,
+    tender_amount: i64,
+    capture_method: Option<storage_enums::CaptureMethod>,
+) -> RouterResult<()> {
+    when(
+        is_partial_tender(payment_amount, tender_amount)
+            && capture_method.is_some_and(|capture_method| {
+                capture_method != storage_enums::CaptureMethod::Automatic
+            }),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Split tender payments can only be captured automatically".to_string(),
+            }))
+        },
+    )
+}
+
+/// Resolves the amount to be charged by the payment attempt being confirmed. Only gift cards can
+/// pay a part of the remaining amount, which makes sure that the gift cards are charged before
+/// the payment method paying the rest of the amount.
+pub async fn get_tender_amount(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    request: &api::PaymentsRequest,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<i64> {
+    if request.tender_amount.is_none() && !is_split_tender_attempt(payment_attempt) {
+        return Ok(payment_attempt.amount);
+    }
+
+    let amount_remaining = payment_intent.amount.saturating_sub(
+        get_amount_paid_by_other_tenders(db, payment_intent, payment_attempt, storage_scheme)
+            .await?,
+    );
+    let tender_amount = request.tender_amount.unwrap_or(amount_remaining);
+
+    when(tender_amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "tender_amount should be greater than 0".to_string(),
+        }))
+    })?;
+    when(tender_amount > amount_remaining, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "tender_amount should not exceed the remaining amount {amount_remaining} of the payment"
+            ),
+        }))
+    })?;
+
+    validate_tender_capture_method(
+        payment_intent.amount,
+        tender_amount,
+        payment_attempt.capture_method,
+    )?;
+
+    when(
+        tender_amount < amount_remaining && !is_gift_card_tender(request, payment_attempt),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Only gift cards can pay a part of the payment, the remaining amount {amount_remaining} has to be paid in full"
+                ),
+            }))
+        },
+    )?;
+
+    Ok(tender_amount)
+}
+
+/// A split tender payment stays open for further tenders until the amount paid by its tenders
+/// adds up to the payment amount. A failed tender which would not have completed the payment can
+/// be replaced by another tender, whereas a failed final tender fails the payment.
+pub async fn get_intent_status_and_amount_captured(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    intent_status: storage_enums::IntentStatus,
+    amount_captured: Option<i64>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<(storage_enums::IntentStatus, Option<i64>)> {
+    if !is_split_tender_attempt(payment_attempt) {
+        return Ok((intent_status, amount_captured));
+    }
+
+    let amount_paid_by_other_tenders =
+        get_amount_paid_by_other_tenders(db, payment_intent, payment_attempt, storage_scheme)
+            .await?;
+
+    Ok(get_tender_intent_status_and_amount_captured(
+        payment_intent.amount,
+        amount_paid_by_other_tenders,
+        payment_attempt.amount,
+        intent_status,
+        amount_captured,
+    ))
+}
+
+fn get_tender_intent_status_and_amount_captured(
+    payment_amount: i64,
+    amount_paid_by_other_tenders: i64,
+    tender_amount: i64,
+    intent_status: storage_enums::IntentStatus,
+    amount_captured: Option<i64>,
+) -> (storage_enums::IntentStatus, Option<i64>) {
+    let amount_remaining = payment_amount.saturating_sub(amount_paid_by_other_tenders);
+
+    match intent_status {
+        storage_enums::IntentStatus::Succeeded => {
+            let amount_paid = amount_paid_by_other_tenders.saturating_add(tender_amount);
+            let intent_status = if amount_paid < payment_amount {
+                storage_enums::IntentStatus::RequiresPaymentMethod
+            } else {
+                storage_enums::IntentStatus::Succeeded
+            };
+            (intent_status, Some(amount_paid))
+        }
+        storage_enums::IntentStatus::Failed if tender_amount < amount_remaining => (
+            storage_enums::IntentStatus::RequiresPaymentMethod,
+            Some(amount_paid_by_other_tenders),
+        ),
+        _ => (intent_status, amount_captured),
+    }
+}
+
+/// The tenders of a split tender payment are reversed only when the connector response of its
+/// final tender fails the payment, and not when a payment which has already failed is retrieved
+fn should_reverse_tenders(
+    previous_intent_status: storage_enums::IntentStatus,
+    intent_status: storage_enums::IntentStatus,
+    is_split_tender: Option<bool>,
+) -> bool {
+    previous_intent_status != storage_enums::IntentStatus::Failed
+        && intent_status == storage_enums::IntentStatus::Failed
+        && is_split_tender == Some(true)
+}
+
+/// Refunds the tenders of a split tender payment whose final tender has failed. Tenders which
+/// have already been refunded are skipped, so that the reversal can be triggered more than once.
+pub async fn reverse_tenders_of_failed_payment(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    previous_intent_status: storage_enums::IntentStatus,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    if !should_reverse_tenders(
+        previous_intent_status,
+        payment_intent.status,
+        payment_attempt.is_split_tender,
+    ) {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let tenders =
+        get_successful_tenders(db, payment_intent, merchant_account.storage_scheme).await?;
+
+    for tender in tenders {
+        let refundable_amount =
+            refunds::get_refundable_amount(db, merchant_account, &tender).await?;
+        if refundable_amount <= 0 {
+            continue;
+        }
+
+        let refund_request = api_models::refunds::RefundRequest {
+            payment_id: payment_intent.payment_id.clone(),
+            merchant_id: Some(merchant_account.merchant_id.clone()),
+            amount: Some(refundable_amount),
+            reason: Some(SPLIT_TENDER_REVERSAL_REASON.to_string()),
+            refund_type: Some(api_models::refunds::RefundType::Instant),
+            ..Default::default()
+        };
+        let refund = Box::pin(refunds::validate_and_create_refund(
+            state,
+            merchant_account,
+            key_store,
+            &tender,
+            payment_intent,
+            refundable_amount,
+            refund_request,
+            None,
+            None,
+        ))
+        .await
+        .attach_printable("Failed to reverse tender of split tender payment")?;
+
+        logger::info!(
+            payment_id = %payment_intent.payment_id,
+            attempt_id = %tender.attempt_id,
+            refund_id = %refund.refund_id,
+            "Reversed tender of failed split tender payment"
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_partial_tender() {
+        assert!(is_partial_tender(10000, 3000));
+        assert!(!is_partial_tender(10000, 10000));
+    }
+
+    #[test]
+    fn test_tender_intent_status_and_amount_captured() {
+        // A successful tender which does not complete the payment keeps it open for the next one
+        assert_eq!(
+            get_tender_intent_status_and_amount_captured(
+                10000,
+                0,
+                3000,
+                storage_enums::IntentStatus::Succeeded,
+                Some(3000),
+            ),
+            (
+                storage_enums::IntentStatus::RequiresPaymentMethod,
+                Some(3000)
+            )
+        );
+        // The final tender completes the payment
+        assert_eq!(
+            get_tender_intent_status_and_amount_captured(
+                10000,
+                3000,
+                7000,
+                storage_enums::IntentStatus::Succeeded,
+                Some(7000),
+            ),
+            (storage_enums::IntentStatus::Succeeded, Some(10000))
+        );
+        // A failed partial tender can be replaced by another tender
+        assert_eq!(
+            get_tender_intent_status_and_amount_captured(
+                10000,
+                3000,
+                2000,
+                storage_enums::IntentStatus::Failed,
+                None,
+            ),
+            (
+                storage_enums::IntentStatus::RequiresPaymentMethod,
+                Some(3000)
+            )
+        );
+        // A failed final tender fails the payment
+        assert_eq!(
+            get_tender_intent_status_and_amount_captured(
+                10000,
+                3000,
+                7000,
+                storage_enums::IntentStatus::Failed,
+                None,
+            ),
+            (storage_enums::IntentStatus::Failed, None)
+        );
+    }
+
+    #[test]
+    fn test_should_reverse_tenders() {
+        assert!(should_reverse_tenders(
+            storage_enums::IntentStatus::Processing,
+            storage_enums::IntentStatus::Failed,
+            Some(true),
+        ));
+        // Retrieving a payment which has already failed does not reverse its tenders again
+        assert!(!should_reverse_tenders(
+            storage_enums::IntentStatus::Failed,
+            storage_enums::IntentStatus::Failed,
+            Some(true),
+        ));
+        assert!(!should_reverse_tenders(
+            storage_enums::IntentStatus::Processing,
+            storage_enums::IntentStatus::Failed,
+            None,
+        ));
+        assert!(!should_reverse_tenders(
+            storage_enums::IntentStatus::Processing,
+            storage_enums::IntentStatus::RequiresPaymentMethod,
+            Some(true),
+        ));
+    }
+}
@@ -0,0 +1,665 @@
+use api_models::analytics::{
+    api_event::{ApiLogsRequest, QueryType},
+    connector_events::ConnectorEventsRequest,
+};
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::{logger, Flow};
+
+use crate::{
+    analytics::{
+        api_event::{api_events_core, events::ApiLogsResult},
+        connector_events::{connector_events_core, events::ConnectorEventsResult},
+    },
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+    },
+    routes::AppState,
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        domain, storage,
+    },
+};
+
+/// The flow under which the GSM rules of failed payment attempts are configured
+const PAYMENT_ATTEMPT_GSM_FLOW: &str = "Authorize";
+
+/// The routing approach of a payment attempt, in the order of precedence used while deciding the
+/// connector of the payment
+fn get_routing_approach(
+    is_mandate_payment: bool,
+    is_pre_routed: bool,
+    has_straight_through_algorithm: bool,
+) -> api::PaymentTimelineRoutingApproach {
+    if is_mandate_payment {
+        api::PaymentTimelineRoutingApproach::Mandate
+    } else if is_pre_routed {
+        api::PaymentTimelineRoutingApproach::PreRouting
+    } else if has_straight_through_algorithm {
+        api::PaymentTimelineRoutingApproach::StraightThrough
+    } else {
+        api::PaymentTimelineRoutingApproach::BusinessProfile
+    }
+}
+
+/// The routing algorithm of the business profile at the time of the payment attempt. The routing
+/// configuration only records when it was last changed, so the algorithm is known only if it was
+/// not changed since the payment attempt was made.
+fn get_routing_algorithm_id(
+    routing_algorithm: Option<&api_models::routing::RoutingAlgorithmRef>,
+    payment_attempt_created_at: time::PrimitiveDateTime,
+) -> Option<String> {
+    routing_algorithm
+        .filter(|routing_algorithm| {
+            routing_algorithm.timestamp <= payment_attempt_created_at.assume_utc().unix_timestamp()
+        })
+        .and_then(|routing_algorithm| routing_algorithm.algorithm_id.clone())
+}
+
+fn get_routing_details(
+    payment_attempt: &storage::PaymentAttempt,
+    is_recurring_payment: bool,
+    routing_algorithm: Option<&api_models::routing::RoutingAlgorithmRef>,
+) -> Option<api::PaymentTimelineRoutingDetails> {
+    // Payment attempts that never reached a connector were not routed
+    payment_attempt.connector.as_ref()?;
+
+    let routing_info = payment_attempt
+        .straight_through_algorithm
+        .clone()
+        .map(|routing_info| {
+            routing_info.parse_value::<storage::PaymentRoutingInfo>("PaymentRoutingInfo")
+        })
+        .transpose()
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to parse the routing info of the payment attempt"
+            )
+        })
+        .ok()
+        .flatten();
+    let (straight_through_algorithm, pre_routing_results) = routing_info
+        .map(|routing_info| (routing_info.algorithm, routing_info.pre_routing_results))
+        .unwrap_or_default();
+    let is_pre_routed = payment_attempt
+        .payment_method_type
+        .zip(pre_routing_results.as_ref())
+        .is_some_and(|(payment_method_type, pre_routing_results)| {
+            pre_routing_results.contains_key(&payment_method_type)
+        });
+
+    // Recurring payments are routed to the connector of their mandate
+    let approach = get_routing_approach(
+        is_recurring_payment && payment_attempt.mandate_id.is_some(),
+        is_pre_routed,
+        straight_through_algorithm.is_some(),
+    );
+    let routing_algorithm_id = match approach {
+        api::PaymentTimelineRoutingApproach::BusinessProfile => {
+            get_routing_algorithm_id(routing_algorithm, payment_attempt.created_at)
+        }
+        _ => None,
+    };
+
+    Some(api::PaymentTimelineRoutingDetails {
+        approach,
+        straight_through_algorithm: straight_through_algorithm
+            .and_then(|algorithm| serde_json::to_value(algorithm).ok()),
+        pre_routing_results: pre_routing_results
+            .and_then(|pre_routing_results| serde_json::to_value(pre_routing_results).ok()),
+        routing_algorithm_id,
+    })
+}
+
+fn get_payment_attempt_event(
+    payment_attempt: &storage::PaymentAttempt,
+    gsm: Option<storage::gsm::GatewayStatusMap>,
+    is_recurring_payment: bool,
+    routing_algorithm: Option<&api_models::routing::RoutingAlgorithmRef>,
+) -> api::PaymentTimelineEvent {
+    api::PaymentTimelineEvent {
+        event_type: api::PaymentTimelineEventType::PaymentAttempt,
+        created_at: payment_attempt.created_at,
+        reference_id: Some(payment_attempt.attempt_id.clone()),
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        connector: payment_attempt.connector.clone(),
+        status: Some(payment_attempt.status.to_string()),
+        amount: Some(payment_attempt.amount),
+        currency: payment_attempt.currency,
+        error_code: payment_attempt.error_code.clone(),
+        error_message: payment_attempt.error_message.clone(),
+        details: Some(api::PaymentTimelineEventDetails::PaymentAttempt {
+            payment_method: payment_attempt.payment_method,
+            payment_method_type: payment_attempt.payment_method_type,
+            authentication_type: payment_attempt.authentication_type,
+            merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+            routing: get_routing_details(payment_attempt, is_recurring_payment, routing_algorithm),
+            unified_code: payment_attempt.unified_code.clone(),
+            unified_message: payment_attempt.unified_message.clone(),
+            gsm_decision: gsm.as_ref().map(|gsm| gsm.decision.clone()),
+            gsm_step_up_possible: gsm.as_ref().map(|gsm| gsm.step_up_possible),
+            authentication_details: payment_attempt
+                .authentication_details
+                .clone()
+                .map(|authentication_details| {
+                    authentication_details.parse_value("PaymentAuthenticationDetails")
+                })
+                .transpose()
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse the authentication details of the payment attempt"
+                    )
+                })
+                .ok()
+                .flatten(),
+        }),
+    }
+}
+
+fn get_external_authentication_event(
+    authentication: storage::Authentication,
+    attempt_id: &str,
+) -> api::PaymentTimelineEvent {
+    api::PaymentTimelineEvent {
+        event_type: api::PaymentTimelineEventType::ExternalAuthentication,
+        created_at: authentication.created_at,
+        reference_id: Some(authentication.authentication_id),
+        attempt_id: Some(attempt_id.to_string()),
+        connector: Some(authentication.authentication_connector),
+        status: Some(authentication.authentication_status.to_string()),
+        amount: None,
+        currency: None,
+        error_code: authentication.error_code,
+        error_message: authentication.error_message,
+        details: Some(api::PaymentTimelineEventDetails::ExternalAuthentication {
+            authentication_flow: authentication.authentication_type,
+            trans_status: authentication.trans_status,
+            electronic_commerce_indicator: authentication.eci,
+            message_version: authentication
+                .message_version
+                .map(|version| version.to_string()),
+        }),
+    }
+}
+
+fn get_capture_event(capture: storage::Capture) -> api::PaymentTimelineEvent {
+    api::PaymentTimelineEvent {
+        event_type: api::PaymentTimelineEventType::Capture,
+        created_at: capture.created_at,
+        reference_id: Some(capture.capture_id),
+        attempt_id: Some(capture.authorized_attempt_id),
+        connector: Some(capture.connector),
+        status: Some(capture.status.to_string()),
+        amount: Some(capture.amount),
+        currency: capture.currency,
+        error_code: capture.error_code,
+        error_message: capture.error_message,
+        details: Some(api::PaymentTimelineEventDetails::Capture {
+            capture_sequence: capture.capture_sequence,
+            connector_capture_id: capture.connector_capture_id,
+        }),
+    }
+}
+
+fn get_refund_event(refund: &storage::Refund) -> api::PaymentTimelineEvent {
+    api::PaymentTimelineEvent {
+        event_type: api::PaymentTimelineEventType::Refund,
+        created_at: refund.created_at,
+        reference_id: Some(refund.refund_id.clone()),
+        attempt_id: Some(refund.attempt_id.clone()),
+        connector: Some(refund.connector.clone()),
+        status: Some(refund.refund_status.to_string()),
+        amount: Some(refund.refund_amount),
+        currency: Some(refund.currency),
+        error_code: refund.refund_error_code.clone(),
+        error_message: refund.refund_error_message.clone(),
+        details: Some(api::PaymentTimelineEventDetails::Refund {
+            refund_type: refund.refund_type.to_string(),
+            refund_reason: refund.refund_reason.clone(),
+            connector_refund_id: refund.connector_refund_id.clone(),
+        }),
+    }
+}
+
+fn get_dispute_event(dispute: &storage::Dispute) -> api::PaymentTimelineEvent {
+    api::PaymentTimelineEvent {
+        event_type: api::PaymentTimelineEventType::Dispute,
+        created_at: dispute.created_at,
+        reference_id: Some(dispute.dispute_id.clone()),
+        attempt_id: Some(dispute.attempt_id.clone()),
+        connector: Some(dispute.connector.clone()),
+        status: Some(dispute.dispute_status.to_string()),
+        amount: Some(dispute.dispute_amount),
+        currency: dispute.currency.parse::<api_enums::Currency>().ok(),
+        error_code: None,
+        error_message: None,
+        details: Some(api::PaymentTimelineEventDetails::Dispute {
+            dispute_stage: dispute.dispute_stage,
+            connector_status: dispute.connector_status.clone(),
+            connector_reason: dispute.connector_reason.clone(),
+            connector_reason_code: dispute.connector_reason_code.clone(),
+        }),
+    }
+}
+
+fn get_outgoing_webhook_event(event: domain::Event) -> api::PaymentTimelineEvent {
+    api::PaymentTimelineEvent {
+        event_type: api::PaymentTimelineEventType::OutgoingWebhook,
+        created_at: event.created_at,
+        reference_id: Some(event.event_id),
+        attempt_id: None,
+        connector: None,
+        status: None,
+        amount: None,
+        currency: None,
+        error_code: None,
+        error_message: None,
+        details: Some(api::PaymentTimelineEventDetails::OutgoingWebhook {
+            event_type: event.event_type,
+            primary_object_id: event.primary_object_id,
+            primary_object_type: event.primary_object_type,
+            is_webhook_notified: event.is_webhook_notified,
+        }),
+    }
+}
+
+fn get_incoming_webhook_event(api_log: ApiLogsResult) -> api::PaymentTimelineEvent {
+    api::PaymentTimelineEvent {
+        event_type: api::PaymentTimelineEventType::IncomingWebhook,
+        created_at: api_log.created_at,
+        reference_id: api_log.request_id,
+        attempt_id: None,
+        connector: api_log.connector,
+        status: Some(api_log.status_code.to_string()),
+        amount: None,
+        currency: None,
+        error_code: None,
+        error_message: api_log.error,
+        details: None,
+    }
+}
+
+/// The request and response of connector API calls are masked when the calls are logged
+fn get_connector_api_call_event(
+    connector_event: ConnectorEventsResult,
+) -> api::PaymentTimelineEvent {
+    api::PaymentTimelineEvent {
+        event_type: api::PaymentTimelineEventType::ConnectorApiCall,
+        created_at: connector_event.created_at,
+        reference_id: connector_event.request_id,
+        attempt_id: None,
+        connector: connector_event.connector_name,
+        status: Some(connector_event.status_code.to_string()),
+        amount: None,
+        currency: None,
+        error_code: None,
+        error_message: connector_event.error,
+        details: Some(api::PaymentTimelineEventDetails::ConnectorApiCall {
+            flow: connector_event.flow,
+            method: connector_event.method,
+            latency: connector_event.latency,
+            request: connector_event.request,
+            response: connector_event.response,
+        }),
+    }
+}
+
+/// The events logged to the analytics store. These are left out of the timeline if the store is
+/// unavailable, as the events stored with the payment are still of use.
+async fn get_analytics_events(
+    state: &AppState,
+    merchant_id: &str,
+    payment_id: &str,
+) -> Vec<api::PaymentTimelineEvent> {
+    let connector_events = connector_events_core(
+        &state.pool,
+        ConnectorEventsRequest {
+            payment_id: payment_id.to_string(),
+            refund_id: None,
+            dispute_id: None,
+        },
+        merchant_id.to_string(),
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            "Failed to fetch the connector events of the payment"
+        )
+    })
+    .unwrap_or_default();
+
+    let incoming_webhook_flow = Flow::IncomingWebhookReceive.to_string();
+    let incoming_webhooks = api_events_core(
+        &state.pool,
+        ApiLogsRequest {
+            query_param: QueryType::Payment {
+                payment_id: payment_id.to_string(),
+            },
+        },
+        merchant_id.to_string(),
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to fetch the API events of the payment"))
+    .unwrap_or_default()
+    .into_iter()
+    .filter(|api_log| api_log.api_flow == incoming_webhook_flow);
+
+    connector_events
+        .into_iter()
+        .map(get_connector_api_call_event)
+        .chain(incoming_webhooks.map(get_incoming_webhook_event))
+        .collect()
+}
+
+async fn get_payment_attempt_events(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempts: &[storage::PaymentAttempt],
+    routing_algorithm: Option<&api_models::routing::RoutingAlgorithmRef>,
+) -> RouterResult<Vec<api::PaymentTimelineEvent>> {
+    let is_recurring_payment = payment_intent.off_session == Some(true);
+    let db = &*state.store;
+    let mut events = Vec::new();
+
+    for payment_attempt in payment_attempts {
+        let gsm = match (&payment_attempt.connector, &payment_attempt.error_code) {
+            (Some(connector), Some(_)) => {
+                helpers::get_gsm_record(
+                    state,
+                    payment_attempt.error_code.clone(),
+                    payment_attempt.error_message.clone(),
+                    connector.clone(),
+                    PAYMENT_ATTEMPT_GSM_FLOW.to_string(),
+                )
+                .await
+            }
+            _ => None,
+        };
+        events.push(get_payment_attempt_event(
+            payment_attempt,
+            gsm,
+            is_recurring_payment,
+            routing_algorithm,
+        ));
+
+        if let Some(authentication_id) = &payment_attempt.authentication_id {
+            let authentication = db
+                .find_authentication_by_merchant_id_authentication_id(
+                    merchant_account.merchant_id.clone(),
+                    authentication_id.clone(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the authentication of the payment attempt")?;
+            events.push(get_external_authentication_event(
+                authentication,
+                &payment_attempt.attempt_id,
+            ));
+        }
+
+        if payment_attempt.multiple_capture_count.is_some() {
+            let captures = db
+                .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                    &merchant_account.merchant_id,
+                    &payment_attempt.payment_id,
+                    &payment_attempt.attempt_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the captures of the payment attempt")?;
+            events.extend(captures.into_iter().map(get_capture_event));
+        }
+    }
+
+    Ok(events)
+}
+
+/// Orders the events by the time at which they occurred. The sort is stable, so that events that
+/// occurred at the same time keep the order in which they were collected, e.g. a payment attempt
+/// precedes its authentication.
+fn sort_events(events: &mut [api::PaymentTimelineEvent]) {
+    events.sort_by_key(|event| event.created_at);
+}
+
+/// The chronological timeline of a payment, stitched from its attempts, captures, refunds,
+/// disputes, the webhooks sent to the merchant and, if available, the connector API calls and
+/// webhooks logged to the analytics store
+pub async fn get_payment_timeline(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+) -> RouterResponse<api::PaymentTimelineResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(
+            merchant_id,
+            &payment_intent.payment_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment")?;
+
+    let disputes = db
+        .find_disputes_by_merchant_id_payment_id(merchant_id, &payment_intent.payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the disputes of the payment")?;
+
+    let routing_algorithm = match &payment_intent.profile_id {
+        Some(profile_id) => db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.clone(),
+            })?
+            .routing_algorithm
+            .map(|routing_algorithm| {
+                routing_algorithm
+                    .parse_value::<api_models::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef")
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the routing algorithm of the business profile")?,
+        None => None,
+    };
+
+    let mut events = get_payment_attempt_events(
+        &state,
+        &merchant_account,
+        &payment_intent,
+        &payment_attempts,
+        routing_algorithm.as_ref(),
+    )
+    .await?;
+    events.extend(refunds.iter().map(get_refund_event));
+    events.extend(disputes.iter().map(get_dispute_event));
+
+    // Webhooks are sent to the merchant for the payment, as well as for its refunds and disputes
+    let primary_object_ids = std::iter::once(payment_intent.payment_id.as_str())
+        .chain(refunds.iter().map(|refund| refund.refund_id.as_str()))
+        .chain(disputes.iter().map(|dispute| dispute.dispute_id.as_str()));
+    for primary_object_id in primary_object_ids {
+        let outgoing_webhooks = db
+            .list_initial_events_by_merchant_id_primary_object_id(
+                merchant_id,
+                primary_object_id,
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the webhook events of the payment")?;
+        events.extend(
+            outgoing_webhooks
+                .into_iter()
+                .map(get_outgoing_webhook_event),
+        );
+    }
+
+    events.extend(get_analytics_events(&state, merchant_id, &payment_intent.payment_id).await);
+    sort_events(&mut events);
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentTimelineResponse {
+            payment_id: payment_intent.payment_id,
+            events,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_event(
+        event_type: api::PaymentTimelineEventType,
+        created_at: time::PrimitiveDateTime,
+    ) -> api::PaymentTimelineEvent {
+        api::PaymentTimelineEvent {
+            event_type,
+            created_at,
+            reference_id: None,
+            attempt_id: None,
+            connector: None,
+            status: None,
+            amount: None,
+            currency: None,
+            error_code: None,
+            error_message: None,
+            details: None,
+        }
+    }
+
+    #[test]
+    fn test_sort_events() {
+        let now = common_utils::date_time::now();
+        let mut events = vec![
+            get_event(
+                api::PaymentTimelineEventType::Refund,
+                now + time::Duration::minutes(10),
+            ),
+            get_event(api::PaymentTimelineEventType::PaymentAttempt, now),
+            // Collected after the payment attempt, at the same time
+            get_event(api::PaymentTimelineEventType::ExternalAuthentication, now),
+            get_event(
+                api::PaymentTimelineEventType::ConnectorApiCall,
+                now + time::Duration::seconds(1),
+            ),
+            get_event(
+                api::PaymentTimelineEventType::OutgoingWebhook,
+                now + time::Duration::seconds(2),
+            ),
+        ];
+
+        sort_events(&mut events);
+
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| event.event_type)
+                .collect::<Vec<_>>(),
+            vec![
+                api::PaymentTimelineEventType::PaymentAttempt,
+                api::PaymentTimelineEventType::ExternalAuthentication,
+                api::PaymentTimelineEventType::ConnectorApiCall,
+                api::PaymentTimelineEventType::OutgoingWebhook,
+                api::PaymentTimelineEventType::Refund,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_routing_approach() {
+        assert_eq!(
+            get_routing_approach(true, true, true),
+            api::PaymentTimelineRoutingApproach::Mandate
+        );
+        assert_eq!(
+            get_routing_approach(false, true, true),
+            api::PaymentTimelineRoutingApproach::PreRouting
+        );
+        assert_eq!(
+            get_routing_approach(false, false, true),
+            api::PaymentTimelineRoutingApproach::StraightThrough
+        );
+        assert_eq!(
+            get_routing_approach(false, false, false),
+            api::PaymentTimelineRoutingApproach::BusinessProfile
+        );
+    }
+
+    #[test]
+    fn test_get_routing_algorithm_id() {
+        let payment_attempt_created_at = common_utils::date_time::now();
+        let routing_algorithm = |timestamp| api_models::routing::RoutingAlgorithmRef {
+            algorithm_id: Some("routing_123".to_string()),
+            timestamp,
+            config_algo_id: None,
+            surcharge_config_algo_id: None,
+        };
+        let created_at_timestamp = payment_attempt_created_at.assume_utc().unix_timestamp();
+
+        assert_eq!(
+            get_routing_algorithm_id(
+                Some(&routing_algorithm(created_at_timestamp - 60)),
+                payment_attempt_created_at
+            ),
+            Some("routing_123".to_string())
+        );
+        // The routing configuration changed after the payment attempt was made
+        assert_eq!(
+            get_routing_algorithm_id(
+                Some(&routing_algorithm(created_at_timestamp + 60)),
+                payment_attempt_created_at
+            ),
+            None
+        );
+        assert_eq!(
+            get_routing_algorithm_id(None, payment_attempt_created_at),
+            None
+        );
+    }
+
+    #[test]
+    fn test_payment_timeline_event_details_serialization() {
+        let details = api::PaymentTimelineEventDetails::Capture {
+            capture_sequence: 1,
+            connector_capture_id: Some("cap_123".to_string()),
+        };
+
+        assert_eq!(
+            serde_json::to_value(details).unwrap(),
+            serde_json::json!({
+                "type": "capture",
+                "capture_sequence": 1,
+                "connector_capture_id": "cap_123",
+            })
+        );
+    }
+}
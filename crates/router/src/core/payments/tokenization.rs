@@ -435,7 +435,6 @@ where
                                     &card,
                                     customer_id.clone(),
                                     merchant_account,
-                                    api::enums::LockerChoice::HyperswitchCardVault,
                                     Some(
                                         existing_pm
                                             .locker_id
@@ -87,12 +87,24 @@ where
         incremental_authorization_allowed: None,
     });
 
+    // The card network is needed only to validate the level 2 and level 3 data
+    let card_network = if payment_data.payment_intent.l2_l3_data.is_some() {
+        helpers::get_card_network_from_card_bin(
+            &*state.store,
+            payment_data.payment_method_data.as_ref(),
+        )
+        .await?
+    } else {
+        None
+    };
+
     let additional_data = PaymentAdditionalData {
         router_base_url: state.conf.server.base_url.clone(),
         connector_name: connector_id.to_string(),
         payment_data: payment_data.clone(),
         state,
         customer_data: customer,
+        card_network,
     };
 
     let customer_id = customer.to_owned().map(|customer| customer.customer_id);
@@ -450,6 +462,24 @@ where
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "payment_method_data",
             })?;
+    let card_verification_results: Option<api_models::payments::CardVerificationResults> =
+        payment_attempt
+            .card_verification_results
+            .clone()
+            .map(|data| data.parse_value("card_verification_results"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "card_verification_results",
+            })?;
+    let authentication_details: Option<api_models::payments::PaymentAuthenticationDetails> =
+        payment_attempt
+            .authentication_details
+            .clone()
+            .map(|data| data.parse_value("authentication_details"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "authentication_details",
+            })?;
     let surcharge_details =
         payment_attempt
             .surcharge_amount
@@ -624,6 +654,10 @@ where
                 connector_name,
             )
         });
+        let amount_authorized = payments::partial_authorization::get_amount_authorized(
+            &payment_intent,
+            &payment_attempt,
+        );
 
         services::ApplicationResponse::JsonWithHeaders((
             response
@@ -634,6 +668,7 @@ where
                 .set_amount(payment_attempt.amount)
                 .set_amount_capturable(Some(payment_attempt.amount_capturable))
                 .set_amount_received(payment_intent.amount_captured)
+                .set_amount_authorized(amount_authorized)
                 .set_surcharge_details(surcharge_details)
                 .set_connector(routed_through)
                 .set_client_secret(payment_intent.client_secret.map(masking::Secret::new))
@@ -715,6 +750,8 @@ where
                     payment_method_data_response,
                     auth_flow == services::AuthFlow::Merchant,
                 )
+                .set_card_verification_results(card_verification_results)
+                .set_authentication_details(authentication_details)
                 .set_payment_token(payment_attempt.payment_token)
                 .set_error_message(
                     payment_attempt
@@ -732,6 +769,7 @@ where
                 .set_statement_descriptor_suffix(payment_intent.statement_descriptor_suffix)
                 .set_setup_future_usage(payment_intent.setup_future_usage)
                 .set_capture_method(payment_attempt.capture_method)
+                .set_capture_on(payment_attempt.capture_on)
                 .set_payment_experience(payment_attempt.payment_experience)
                 .set_payment_method_type(payment_attempt.payment_method_type)
                 .set_metadata(payment_intent.metadata)
@@ -768,6 +806,7 @@ where
                 .set_authorization_count(payment_intent.authorization_count)
                 .set_incremental_authorizations(incremental_authorizations_response)
                 .set_expires_on(payment_intent.session_expiry)
+                .set_authorization_expires_at(payment_attempt.authorization_expires_at)
                 .set_external_3ds_authentication_attempted(
                     payment_attempt.external_three_ds_authentication_attempted,
                 )
@@ -1062,6 +1101,9 @@ where
     payment_data: PaymentData<F>,
     state: &'a AppState,
     customer_data: &'a Option<domain::Customer>,
+    /// Card network resolved from the card BIN, available only for payments with level 2 and
+    /// level 3 data
+    card_network: Option<common_enums::CardNetwork>,
 }
 impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthorizeData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
@@ -1129,6 +1171,26 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             payment_data.creds_identifier.as_deref(),
         ));
 
+        let l2_l3_data = payment_data
+            .payment_intent
+            .l2_l3_data
+            .map(|l2_l3_data| {
+                l2_l3_data
+                    .parse_value::<api_models::payments::L2L3Data>("L2L3Data")
+                    .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "l2_l3_data",
+                    })
+                    .attach_printable("Unable to parse L2L3Data")
+            })
+            .transpose()?;
+
+        if let Some(l2_l3_data) = &l2_l3_data {
+            helpers::validate_l2_l3_data_for_card_network(
+                l2_l3_data,
+                additional_data.card_network.as_ref(),
+            )?;
+        }
+
         // payment_method_data is not required during recurring mandate payment, in such case keep default PaymentMethodData as MandatePayment
         let payment_method_data = payment_data.payment_method_data.or_else(|| {
             if payment_data.mandate_id.is_some() {
@@ -1189,6 +1251,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 Some(RequestIncrementalAuthorization::True)
                     | Some(RequestIncrementalAuthorization::Default)
             ),
+            allow_partial_authorization: payment_data
+                .payment_intent
+                .allow_partial_authorization
+                .unwrap_or(false),
+            l2_l3_data,
             metadata: additional_data.payment_data.payment_intent.metadata,
             authentication_data: payment_data
                 .authentication
@@ -1514,6 +1581,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::SetupMandateRequ
                     | Some(RequestIncrementalAuthorization::Default)
             ),
             metadata: payment_data.payment_intent.metadata.clone(),
+            is_card_verification: payment_data.is_card_verification,
         })
     }
 }
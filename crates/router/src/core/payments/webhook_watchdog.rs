@@ -0,0 +1,355 @@
+use std::str::FromStr;
+
+use api_models::enums as api_enums;
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        metrics,
+    },
+    events::webhook_reliability_logs::{WebhookArrivalStatus, WebhookReliabilityEvent},
+    routes::{app::AppStateInfo, metrics as route_metrics, AppState},
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+pub const WEBHOOK_WATCHDOG_TASK: &str = "WEBHOOK_WATCHDOG";
+
+/// Tracking data stored against the webhook watchdog process tracker entry
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WebhookWatchdogTrackingData {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub payment_method: storage_enums::PaymentMethod,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub expected_window_minutes: u32,
+}
+
+fn get_process_tracker_id(attempt_id: &str, merchant_id: &str) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::WebhookWatchdogWorkflow,
+        WEBHOOK_WATCHDOG_TASK,
+        attempt_id,
+        merchant_id,
+    )
+}
+
+/// Redirect and bank transfer payments stay in these statuses until the connector notifies us
+/// about the outcome of the payment
+pub fn is_awaiting_webhook(payment_attempt: &storage::PaymentAttempt) -> bool {
+    matches!(
+        payment_attempt.status,
+        storage_enums::AttemptStatus::AuthenticationPending | storage_enums::AttemptStatus::Pending
+    )
+}
+
+/// Window within which the webhook is expected, if the connector and payment method are watched
+fn get_expected_window_minutes(
+    state: &AppState,
+    connector: &str,
+    payment_method: storage_enums::PaymentMethod,
+) -> Option<u32> {
+    let connector = api_enums::Connector::from_str(connector).ok()?;
+
+    state
+        .conf
+        .webhook_watchdog
+        .expected_window_minutes
+        .get(&connector)
+        .and_then(|windows| windows.get(&payment_method))
+        .copied()
+}
+
+/// Adds a task which syncs the payment with the connector if its webhook does not arrive within
+/// the expected window. Does nothing if the task has already been added for the payment attempt.
+pub async fn add_webhook_watchdog_task(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    if !is_awaiting_webhook(payment_attempt) {
+        return Ok(());
+    }
+    let (Some(connector), Some(payment_method)) = (
+        payment_attempt.connector.clone(),
+        payment_attempt.payment_method,
+    ) else {
+        return Ok(());
+    };
+    let Some(expected_window_minutes) =
+        get_expected_window_minutes(state, &connector, payment_method)
+    else {
+        return Ok(());
+    };
+
+    let db = &*state.store;
+    let process_tracker_id =
+        get_process_tracker_id(&payment_attempt.attempt_id, &payment_attempt.merchant_id);
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook watchdog task")?;
+    if existing_process.is_some() {
+        return Ok(());
+    }
+
+    let tracking_data = WebhookWatchdogTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        connector,
+        payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        expected_window_minutes,
+    };
+    let schedule_time = common_utils::date_time::now()
+        .saturating_add(time::Duration::minutes(i64::from(expected_window_minutes)));
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        WEBHOOK_WATCHDOG_TASK,
+        storage::ProcessTrackerRunner::WebhookWatchdogWorkflow,
+        ["PAYMENTS", "WEBHOOK_WATCHDOG"],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct webhook watchdog process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert webhook watchdog task in process tracker")?;
+    route_metrics::TASKS_ADDED_COUNT.add(
+        &route_metrics::CONTEXT,
+        1,
+        &[route_metrics::request::add_attributes(
+            "flow",
+            "WebhookWatchdog",
+        )],
+    );
+
+    Ok(())
+}
+
+/// Records the arrival of a connector webhook for the payment, finishing the webhook watchdog
+/// task of its active attempt. Webhooks arriving after the watchdog has already given up on them
+/// are recorded as late, and only the first webhook of a payment attempt is recorded.
+pub async fn record_webhook_arrival(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+) -> RouterResult<()> {
+    if state
+        .conf
+        .webhook_watchdog
+        .expected_window_minutes
+        .is_empty()
+    {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let process_tracker_id = get_process_tracker_id(
+        &payment_intent.active_attempt.get_id(),
+        &merchant_account.merchant_id,
+    );
+    let Some(process) = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook watchdog task")?
+    else {
+        return Ok(());
+    };
+
+    let Some((arrival_status, business_status)) =
+        get_webhook_arrival_status(process.status, &process.business_status)
+    else {
+        return Ok(());
+    };
+
+    let tracking_data: WebhookWatchdogTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("WebhookWatchdogTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook watchdog tracking data")?;
+    let arrival_latency = common_utils::date_time::now() - process.created_at;
+
+    db.as_scheduler()
+        .finish_process_with_business_status(process, business_status.to_string())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to finish webhook watchdog task")?;
+    raise_webhook_reliability_event(state, &tracking_data, arrival_status, Some(arrival_latency));
+
+    Ok(())
+}
+
+/// Obtain the arrival status of a webhook from the status of the webhook watchdog task, along with
+/// the business status the task is finished with. `None` if the arrival of a webhook has already
+/// been recorded for the task.
+fn get_webhook_arrival_status(
+    process_status: storage_enums::ProcessTrackerStatus,
+    business_status: &str,
+) -> Option<(WebhookArrivalStatus, &'static str)> {
+    if process_status != storage_enums::ProcessTrackerStatus::Finish {
+        Some((
+            WebhookArrivalStatus::Received,
+            consts::WEBHOOK_WATCHDOG_RECEIVED,
+        ))
+    } else if business_status == consts::WEBHOOK_WATCHDOG_MISSED
+        || business_status == consts::WEBHOOK_WATCHDOG_RESOLVED_WITHOUT_WEBHOOK
+    {
+        Some((
+            WebhookArrivalStatus::ReceivedLate,
+            consts::WEBHOOK_WATCHDOG_RECEIVED_LATE,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Publishes the arrival status of the expected webhook to the analytics pipeline
+pub fn raise_webhook_reliability_event(
+    state: &AppState,
+    tracking_data: &WebhookWatchdogTrackingData,
+    arrival_status: WebhookArrivalStatus,
+    arrival_latency: Option<time::Duration>,
+) {
+    metrics::WEBHOOK_WATCHDOG_OUTCOME_METRIC.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::KeyValue::new("connector", tracking_data.connector.clone()),
+            metrics::KeyValue::new("outcome", arrival_status.to_string()),
+        ],
+    );
+    logger::info!(
+        payment_id = %tracking_data.payment_id,
+        connector = %tracking_data.connector,
+        %arrival_status,
+        ?arrival_latency,
+        "Recorded arrival of expected connector webhook"
+    );
+
+    let webhook_reliability_event = WebhookReliabilityEvent::new(
+        tracking_data.merchant_id.clone(),
+        tracking_data.payment_id.clone(),
+        tracking_data.attempt_id.clone(),
+        tracking_data.connector.clone(),
+        tracking_data.payment_method,
+        tracking_data.payment_method_type,
+        arrival_status,
+        i64::from(tracking_data.expected_window_minutes) * 60,
+        arrival_latency.map(|latency| latency.whole_seconds()),
+    );
+    state.event_handler().log_event(&webhook_reliability_event);
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_webhook_arrival_status_within_window() {
+        assert_eq!(
+            get_webhook_arrival_status(storage_enums::ProcessTrackerStatus::New, "Pending"),
+            Some((
+                WebhookArrivalStatus::Received,
+                consts::WEBHOOK_WATCHDOG_RECEIVED
+            ))
+        );
+    }
+
+    #[test]
+    fn test_get_webhook_arrival_status_after_window() {
+        for business_status in [
+            consts::WEBHOOK_WATCHDOG_MISSED,
+            consts::WEBHOOK_WATCHDOG_RESOLVED_WITHOUT_WEBHOOK,
+        ] {
+            assert_eq!(
+                get_webhook_arrival_status(
+                    storage_enums::ProcessTrackerStatus::Finish,
+                    business_status
+                ),
+                Some((
+                    WebhookArrivalStatus::ReceivedLate,
+                    consts::WEBHOOK_WATCHDOG_RECEIVED_LATE
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_webhook_arrival_status_is_recorded_once() {
+        for business_status in [
+            consts::WEBHOOK_WATCHDOG_RECEIVED,
+            consts::WEBHOOK_WATCHDOG_RECEIVED_LATE,
+        ] {
+            assert_eq!(
+                get_webhook_arrival_status(
+                    storage_enums::ProcessTrackerStatus::Finish,
+                    business_status
+                ),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_webhook_arrival_status_serialization() {
+        assert_eq!(
+            serde_json::to_value(WebhookArrivalStatus::ResolvedWithoutWebhook).unwrap(),
+            serde_json::json!("resolved_without_webhook")
+        );
+        assert_eq!(
+            WebhookArrivalStatus::ReceivedLate.to_string(),
+            "received_late"
+        );
+    }
+
+    #[test]
+    fn test_webhook_watchdog_tracking_data_round_trip() {
+        let tracking_data = WebhookWatchdogTrackingData {
+            payment_id: "pay_1".to_string(),
+            attempt_id: "pay_1_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            connector: "adyen".to_string(),
+            payment_method: storage_enums::PaymentMethod::BankRedirect,
+            payment_method_type: Some(storage_enums::PaymentMethodType::Ideal),
+            expected_window_minutes: 30,
+        };
+
+        let parsed_tracking_data: WebhookWatchdogTrackingData =
+            serde_json::to_value(&tracking_data)
+                .unwrap()
+                .parse_value("WebhookWatchdogTrackingData")
+                .unwrap();
+        assert_eq!(parsed_tracking_data.attempt_id, tracking_data.attempt_id);
+        assert_eq!(parsed_tracking_data.expected_window_minutes, 30);
+        assert_eq!(
+            parsed_tracking_data.payment_method_type,
+            Some(storage_enums::PaymentMethodType::Ideal)
+        );
+    }
+}
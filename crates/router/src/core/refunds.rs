@@ -1,6 +1,8 @@
+#[cfg(feature = "payouts")]
+pub mod payout_fallback;
 pub mod validator;
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::ext_traits::{AsyncExt, Encode, ValueExt};
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 use scheduler::{consumer::types::process_data, utils as process_tracker_utils};
@@ -8,12 +10,13 @@ use scheduler::{consumer::types::process_data, utils as process_tracker_utils};
 use crate::{
     consts,
     core::{
+        api_locking,
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, access_token},
         utils as core_utils,
     },
     db, logger,
-    routes::{metrics, AppState},
+    routes::{lock_utils, metrics, AppState},
     services,
     types::{
         self,
@@ -33,6 +36,7 @@ pub async fn refund_create_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    requested_by: Option<String>,
     req: refunds::RefundRequest,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
@@ -88,6 +92,15 @@ pub async fn refund_create_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
 
+    let approval_details = get_refund_approval_details(
+        db,
+        &payment_intent,
+        amount,
+        req.reason.as_deref(),
+        requested_by,
+    )
+    .await?;
+
     let creds_identifier = req
         .merchant_connector_details
         .as_ref()
@@ -105,6 +118,30 @@ pub async fn refund_create_core(
         .await
         .transpose()?;
 
+    if payment_intent.attempt_count > 1 {
+        let tenders = payments::split_tender::get_successful_tenders(
+            db,
+            &payment_intent,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+        if tenders.len() > 1 {
+            return Box::pin(create_split_tender_refunds(
+                &state,
+                &merchant_account,
+                &key_store,
+                &payment_intent,
+                tenders,
+                amount,
+                req,
+                creds_identifier,
+                approval_details,
+            ))
+            .await
+            .map(services::ApplicationResponse::Json);
+        }
+    }
+
     Box::pin(validate_and_create_refund(
         &state,
         &merchant_account,
@@ -114,11 +151,184 @@ pub async fn refund_create_core(
         amount,
         req,
         creds_identifier,
+        approval_details,
     ))
     .await
     .map(services::ApplicationResponse::Json)
 }
 
+/// Approval details of the refund, if the refund approval policy of the business profile requires
+/// the refund to be approved by a dashboard user. The refund is rejected automatically when the
+/// approval expires.
+async fn get_refund_approval_details(
+    db: &dyn db::StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    refund_amount: i64,
+    refund_reason: Option<&str>,
+    requested_by: Option<String>,
+) -> RouterResult<Option<refunds::RefundApprovalDetails>> {
+    let Some(profile_id) = payment_intent.profile_id.as_ref() else {
+        return Ok(None);
+    };
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    let refund_approval_policy = business_profile
+        .refund_approval_policy
+        .map(|refund_approval_policy| {
+            refund_approval_policy
+                .parse_value::<api_models::admin::RefundApprovalPolicy>("RefundApprovalPolicy")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse refund approval policy of the business profile")?;
+
+    Ok(refund_approval_policy
+        .filter(|refund_approval_policy| {
+            validator::is_refund_approval_required(
+                refund_approval_policy,
+                refund_amount,
+                refund_reason,
+            )
+        })
+        .map(|refund_approval_policy| {
+            let approval_expiry_hours = refund_approval_policy
+                .approval_expiry_hours
+                .unwrap_or(consts::DEFAULT_REFUND_APPROVAL_EXPIRY_HOURS);
+            refunds::RefundApprovalDetails {
+                expires_at: common_utils::date_time::now()
+                    .saturating_add(time::Duration::hours(i64::from(approval_expiry_hours))),
+                requested_by,
+                first_approved_by: None,
+                decision: None,
+                decided_by: None,
+                decided_at: None,
+                reason: None,
+            }
+        }))
+}
+
+/// Allocates the refund amount across the tenders of a split tender payment, starting with the
+/// last tender paid. A refund is created for every tender the amount is allocated to. The refund
+/// of the last tender is returned, with the refunds of the other tenders attached to it.
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+async fn create_split_tender_refunds(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    tenders: Vec<storage::PaymentAttempt>,
+    refund_amount: i64,
+    req: refunds::RefundRequest,
+    creds_identifier: Option<String>,
+    approval_details: Option<refunds::RefundApprovalDetails>,
+) -> RouterResult<refunds::RefundResponse> {
+    let db = &*state.store;
+    let mut refundable_tenders = Vec::with_capacity(tenders.len());
+    for tender in tenders {
+        let refundable_amount = get_refundable_amount(db, merchant_account, &tender).await?;
+        refundable_tenders.push((tender, refundable_amount));
+    }
+    let allocations = allocate_split_tender_refund_amount(refundable_tenders, refund_amount)?;
+
+    let mut refunds = Vec::with_capacity(allocations.len());
+    for (index, (tender, tender_refund_amount)) in allocations.into_iter().enumerate() {
+        // The refund id of the request identifies the refund of the last tender, the refunds of
+        // the other tenders are given generated ids so that they cannot collide with other refunds
+        let refund_id = if index == 0 {
+            req.refund_id.clone()
+        } else {
+            None
+        };
+        let refund = Box::pin(validate_and_create_refund(
+            state,
+            merchant_account,
+            key_store,
+            &tender,
+            payment_intent,
+            tender_refund_amount,
+            refunds::RefundRequest {
+                refund_id,
+                amount: Some(tender_refund_amount),
+                ..req.clone()
+            },
+            creds_identifier.clone(),
+            approval_details.clone(),
+        ))
+        .await?;
+        refunds.push(refund);
+    }
+
+    let mut refunds = refunds.into_iter();
+    let mut refund = refunds
+        .next()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("No refund created for split tender payment")?;
+    let split_tender_refunds = refunds.collect::<Vec<_>>();
+    refund.split_tender_refunds =
+        (!split_tender_refunds.is_empty()).then_some(split_tender_refunds);
+
+    Ok(refund)
+}
+
+/// Allocates the refund amount to the tenders in the reverse order in which they were paid, up to
+/// the amount which can still be refunded on every tender
+fn allocate_split_tender_refund_amount<T>(
+    refundable_tenders: Vec<(T, i64)>,
+    refund_amount: i64,
+) -> RouterResult<Vec<(T, i64)>> {
+    let mut amount_to_allocate = refund_amount;
+    let mut allocations = Vec::new();
+    for (tender, refundable_amount) in refundable_tenders.into_iter().rev() {
+        if amount_to_allocate <= 0 {
+            break;
+        }
+        let tender_refund_amount = refundable_amount.min(amount_to_allocate);
+        if tender_refund_amount > 0 {
+            amount_to_allocate -= tender_refund_amount;
+            allocations.push((tender, tender_refund_amount));
+        }
+    }
+
+    utils::when(amount_to_allocate > 0, || {
+        Err(report!(
+            errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount
+        ))
+    })?;
+
+    Ok(allocations)
+}
+
+/// Amount of the payment attempt which has not been refunded yet
+pub async fn get_refundable_amount(
+    db: &dyn db::StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<i64> {
+    let connector_transaction_id = payment_attempt
+        .connector_transaction_id
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing connector transaction id in payment attempt")?;
+
+    let all_refunds = db
+        .find_refund_by_merchant_id_connector_transaction_id(
+            &merchant_account.merchant_id,
+            connector_transaction_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    Ok(payment_attempt.amount - validator::get_total_refunded_amount(&all_refunds))
+}
+
 #[instrument(skip_all)]
 pub async fn trigger_refund_to_gateway(
     state: &AppState,
@@ -158,7 +368,24 @@ pub async fn trigger_refund_to_gateway(
         )
     })?;
 
-    validator::validate_for_valid_refunds(payment_attempt, connector.connector_name)?;
+    if let Err(error) =
+        validator::validate_for_valid_refunds(payment_attempt, connector.connector_name)
+    {
+        #[cfg(feature = "payouts")]
+        if let Some(updated_refund) = payout_fallback::fulfil_refund_through_payout(
+            state,
+            merchant_account,
+            key_store,
+            payment_intent,
+            refund,
+            payout_fallback::RefundFailureReason::NotSupported,
+        )
+        .await
+        {
+            return Ok(updated_refund);
+        }
+        return Err(error);
+    }
 
     let mut router_data = core_utils::construct_refund_router_data(
         state,
@@ -231,6 +458,19 @@ pub async fn trigger_refund_to_gateway(
                 });
         // Update the refund status as failure if connector_error is NotImplemented
         if let Some(refund_error_update) = option_refund_error_update {
+            #[cfg(feature = "payouts")]
+            if let Some(updated_refund) = payout_fallback::fulfil_refund_through_payout(
+                state,
+                merchant_account,
+                key_store,
+                payment_intent,
+                refund,
+                payout_fallback::RefundFailureReason::NotSupported,
+            )
+            .await
+            {
+                return Ok(updated_refund);
+            }
             state
                 .store
                 .update_refund(
@@ -253,12 +493,27 @@ pub async fn trigger_refund_to_gateway(
     };
 
     let refund_update = match router_data_res.response {
-        Err(err) => storage::RefundUpdate::ErrorUpdate {
-            refund_status: Some(enums::RefundStatus::Failure),
-            refund_error_message: err.reason.or(Some(err.message)),
-            refund_error_code: Some(err.code),
-            updated_by: storage_scheme.to_string(),
-        },
+        Err(err) => {
+            #[cfg(feature = "payouts")]
+            if let Some(updated_refund) = payout_fallback::fulfil_refund_through_payout(
+                state,
+                merchant_account,
+                key_store,
+                payment_intent,
+                refund,
+                payout_fallback::RefundFailureReason::ConnectorError(&err.code),
+            )
+            .await
+            {
+                return Ok(updated_refund);
+            }
+            storage::RefundUpdate::ErrorUpdate {
+                refund_status: Some(enums::RefundStatus::Failure),
+                refund_error_message: err.reason.or(Some(err.message)),
+                refund_error_code: Some(err.code),
+                updated_by: storage_scheme.to_string(),
+            }
+        }
         Ok(response) => {
             if response.refund_status == diesel_models::enums::RefundStatus::Success {
                 metrics::SUCCESSFUL_REFUND.add(
@@ -380,19 +635,34 @@ pub async fn refund_retrieve_core(
         .await
         .transpose()?;
 
-    response = if should_call_refund(&refund, request.force_sync.unwrap_or(false)) {
-        sync_refund_with_gateway(
-            &state,
-            &merchant_account,
-            &key_store,
-            &payment_attempt,
-            &payment_intent,
-            &refund,
-            creds_identifier,
-        )
-        .await
-    } else {
-        Ok(refund)
+    response = match refund.fulfilment_payout_id.clone() {
+        // Refunds fulfilled through a payout are synced with the payout instead of the connector
+        #[cfg(feature = "payouts")]
+        Some(payout_id)
+            if should_sync_refund_payout(&refund, request.force_sync.unwrap_or(false)) =>
+        {
+            payout_fallback::sync_refund_with_payout(
+                &state,
+                &merchant_account,
+                &key_store,
+                refund,
+                &payout_id,
+            )
+            .await
+        }
+        _ if should_call_refund(&refund, request.force_sync.unwrap_or(false)) => {
+            sync_refund_with_gateway(
+                &state,
+                &merchant_account,
+                &key_store,
+                &payment_attempt,
+                &payment_intent,
+                &refund,
+                creds_identifier,
+            )
+            .await
+        }
+        _ => Ok(refund),
     }?;
 
     Ok(response)
@@ -415,6 +685,16 @@ fn should_call_refund(refund: &diesel_models::refund::Refund, force_sync: bool)
     predicate1 && predicate2
 }
 
+#[cfg(feature = "payouts")]
+fn should_sync_refund_payout(refund: &diesel_models::refund::Refund, force_sync: bool) -> bool {
+    force_sync
+        || !matches!(
+            refund.refund_status,
+            diesel_models::enums::RefundStatus::Failure
+                | diesel_models::enums::RefundStatus::Success
+        )
+}
+
 #[instrument(skip_all)]
 pub async fn sync_refund_with_gateway(
     state: &AppState,
@@ -556,6 +836,238 @@ pub async fn refund_update_core(
     Ok(services::ApplicationResponse::Json(response.foreign_into()))
 }
 
+// ********************************************** REFUND APPROVAL **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_approve_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    user_id: String,
+    req: refunds::RefundApprovalRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let refund = db
+        .find_refund_by_merchant_id_refund_id(
+            merchant_id,
+            &req.refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+    validator::validate_refund_pending_approval(&refund)?;
+    let approval_details = parse_refund_approval_details(&refund)?;
+    validator::validate_refund_approver(&approval_details, &user_id)?;
+    validator::validate_refund_approval_not_expired(
+        &approval_details,
+        common_utils::date_time::now(),
+    )?;
+
+    if validator::is_first_of_two_refund_approvals(&approval_details) {
+        let refund = record_first_refund_approval(
+            db,
+            refund,
+            approval_details,
+            user_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+        return Ok(services::ApplicationResponse::Json(refund.foreign_into()));
+    }
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &refund.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
+            &refund.connector_transaction_id,
+            &refund.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let refund = record_refund_approval_decision(
+        db,
+        refund,
+        refunds::RefundApprovalDecision::Approved,
+        Some(user_id),
+        req.reason,
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    let refund_type = refund.refund_type.foreign_into();
+    let refund = schedule_refund_execution(
+        &state,
+        refund,
+        refund_type,
+        &merchant_account,
+        &key_store,
+        &payment_attempt,
+        &payment_intent,
+        None,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(refund.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn refund_reject_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    req: refunds::RefundApprovalRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let refund = db
+        .find_refund_by_merchant_id_refund_id(
+            &merchant_account.merchant_id,
+            &req.refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+    validator::validate_refund_pending_approval(&refund)?;
+
+    let refund = record_refund_approval_decision(
+        db,
+        refund,
+        refunds::RefundApprovalDecision::Rejected,
+        Some(user_id),
+        req.reason,
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(refund.foreign_into()))
+}
+
+fn parse_refund_approval_details(
+    refund: &storage::Refund,
+) -> RouterResult<refunds::RefundApprovalDetails> {
+    refund
+        .approval_details
+        .clone()
+        .get_required_value("approval_details")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing approval details in refund pending approval")?
+        .parse_value("RefundApprovalDetails")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse refund approval details")
+}
+
+/// Records the first approval of a refund created with an API key, which stays pending approval
+/// until a different dashboard user approves it as well
+async fn record_first_refund_approval(
+    db: &dyn db::StorageInterface,
+    refund: storage::Refund,
+    approval_details: refunds::RefundApprovalDetails,
+    user_id: String,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<storage::Refund> {
+    let approval_details = refunds::RefundApprovalDetails {
+        first_approved_by: Some(user_id),
+        ..approval_details
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encode refund approval details")?;
+
+    let refund_id = refund.refund_id.clone();
+    db.update_refund(
+        refund,
+        storage::RefundUpdate::ApprovalUpdate {
+            refund_status: enums::RefundStatus::PendingApproval,
+            approval_details,
+            refund_error_message: None,
+            refund_error_code: None,
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to record the first approval of refund: {refund_id}"))
+}
+
+/// Records the decision taken on a refund pending approval. Approved refunds are moved to
+/// `pending` so that they can be sent to the connector, while rejected and expired refunds fail.
+async fn record_refund_approval_decision(
+    db: &dyn db::StorageInterface,
+    refund: storage::Refund,
+    decision: refunds::RefundApprovalDecision,
+    decided_by: Option<String>,
+    reason: Option<String>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<storage::Refund> {
+    let approval_details = parse_refund_approval_details(&refund)?;
+
+    let (refund_status, refund_error_code, refund_error_message) = match decision {
+        refunds::RefundApprovalDecision::Approved => (enums::RefundStatus::Pending, None, None),
+        refunds::RefundApprovalDecision::Rejected => (
+            enums::RefundStatus::Failure,
+            Some(consts::REFUND_APPROVAL_REJECTED_ERROR_CODE.to_string()),
+            Some(consts::REFUND_APPROVAL_REJECTED_ERROR_MESSAGE.to_string()),
+        ),
+        refunds::RefundApprovalDecision::Expired => (
+            enums::RefundStatus::Failure,
+            Some(consts::REFUND_APPROVAL_EXPIRED_ERROR_CODE.to_string()),
+            Some(consts::REFUND_APPROVAL_EXPIRED_ERROR_MESSAGE.to_string()),
+        ),
+    };
+
+    let approval_details = refunds::RefundApprovalDetails {
+        decision: Some(decision),
+        decided_by,
+        decided_at: Some(common_utils::date_time::now()),
+        reason,
+        ..approval_details
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encode refund approval details")?;
+
+    let refund_id = refund.refund_id.clone();
+    let refund = db
+        .update_refund(
+            refund,
+            storage::RefundUpdate::ApprovalUpdate {
+                refund_status,
+                approval_details,
+                refund_error_message,
+                refund_error_code,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed to record the approval decision of refund: {refund_id}")
+        })?;
+
+    metrics::REFUND_APPROVAL_DECISION_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "decision",
+            decision.to_string(),
+        )],
+    );
+
+    Ok(refund)
+}
+
 // ********************************************** VALIDATIONS **********************************************
 
 #[instrument(skip_all)]
@@ -569,6 +1081,7 @@ pub async fn validate_and_create_refund(
     refund_amount: i64,
     req: refunds::RefundRequest,
     creds_identifier: Option<String>,
+    approval_details: Option<refunds::RefundApprovalDetails>,
 ) -> RouterResult<refunds::RefundResponse> {
     let db = &*state.store;
 
@@ -618,9 +1131,15 @@ pub async fn validate_and_create_refund(
             ),
         })?;
 
-    let total_amount_captured = payment_intent
-        .amount_captured
-        .unwrap_or(payment_attempt.amount);
+    // Each tender of a split tender payment is refunded up to the amount paid by the tender
+    let total_amount_captured = if payments::split_tender::is_split_tender_attempt(payment_attempt)
+    {
+        payment_attempt.amount
+    } else {
+        payment_intent
+            .amount_captured
+            .unwrap_or(payment_attempt.amount)
+    };
 
     validator::validate_refund_amount(total_amount_captured, &all_refunds, refund_amount)
         .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
@@ -637,6 +1156,21 @@ pub async fn validate_and_create_refund(
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("No connector populated in payment attempt")?;
 
+    // Refunds which require approval are not sent to the connector until they are approved
+    let (refund_status, encoded_approval_details) = match approval_details.as_ref() {
+        Some(approval_details) => {
+            let encoded_approval_details = approval_details
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode refund approval details")?;
+            (
+                enums::RefundStatus::PendingApproval,
+                Some(encoded_approval_details),
+            )
+        }
+        None => (enums::RefundStatus::Pending, None),
+    };
+
     let refund_create_req = storage::RefundNew::default()
         .set_refund_id(refund_id.to_string())
         .set_internal_reference_id(utils::generate_id(consts::ID_LENGTH, "refid"))
@@ -651,7 +1185,8 @@ pub async fn validate_and_create_refund(
         .set_currency(currency)
         .set_created_at(Some(common_utils::date_time::now()))
         .set_modified_at(Some(common_utils::date_time::now()))
-        .set_refund_status(enums::RefundStatus::Pending)
+        .set_refund_status(refund_status)
+        .set_approval_details(encoded_approval_details)
         .set_metadata(req.metadata)
         .set_description(req.reason.clone())
         .set_attempt_id(payment_attempt.attempt_id.clone())
@@ -664,19 +1199,33 @@ pub async fn validate_and_create_refund(
         .insert_refund(refund_create_req, merchant_account.storage_scheme)
         .await
     {
-        Ok(refund) => {
-            schedule_refund_execution(
-                state,
-                refund.clone(),
-                refund_type,
-                merchant_account,
-                key_store,
-                payment_attempt,
-                payment_intent,
-                creds_identifier,
-            )
-            .await?
-        }
+        Ok(refund) => match approval_details {
+            Some(approval_details) => {
+                add_refund_approval_expiry_task(db, &refund, approval_details.expires_at).await?;
+                metrics::REFUND_APPROVAL_REQUESTED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::request::add_attributes(
+                        "merchant_id",
+                        merchant_account.merchant_id.clone(),
+                    )],
+                );
+                refund
+            }
+            None => {
+                schedule_refund_execution(
+                    state,
+                    refund.clone(),
+                    refund_type,
+                    merchant_account,
+                    key_store,
+                    payment_attempt,
+                    payment_intent,
+                    creds_identifier,
+                )
+                .await?
+            }
+        },
         Err(err) => {
             if err.current_context().is_db_unique_violation() {
                 db.find_refund_by_merchant_id_refund_id(
@@ -785,6 +1334,16 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             updated_at: Some(refund.updated_at),
             connector: refund.connector,
             merchant_connector_id: refund.merchant_connector_id,
+            approval_details: refund.approval_details.and_then(|approval_details| {
+                approval_details
+                    .parse_value("RefundApprovalDetails")
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to parse refund approval details")
+                    })
+                    .ok()
+            }),
+            fulfilment_payout_id: refund.fulfilment_payout_id,
+            split_tender_refunds: None,
         }
     }
 }
@@ -830,7 +1389,7 @@ pub async fn schedule_refund_execution(
                             Ok(refund)
                         }
                         api_models::refunds::RefundType::Instant => {
-                            trigger_refund_to_gateway(
+                            let updated_refund = trigger_refund_to_gateway(
                                 state,
                                 &refund,
                                 merchant_account,
@@ -839,7 +1398,18 @@ pub async fn schedule_refund_execution(
                                 payment_intent,
                                 creds_identifier,
                             )
-                            .await
+                            .await?;
+
+                            // Refunds fulfilled through a payout are tracked till the payout completes
+                            if updated_refund.fulfilment_payout_id.is_some()
+                                && updated_refund.refund_status == enums::RefundStatus::Pending
+                            {
+                                add_refund_sync_task(db, &updated_refund, runner)
+                                    .await
+                                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                                    .attach_printable_lazy(|| format!("Failed while pushing refund sync task in scheduler: refund_id: {}", updated_refund.refund_id))?;
+                            }
+                            Ok(updated_refund)
                         }
                     }
                 }
@@ -950,6 +1520,9 @@ pub async fn start_refund_workflow(
         Some("SYNC_REFUND") => {
             Box::pin(sync_refund_with_gateway_workflow(state, refund_tracker)).await
         }
+        Some("EXPIRE_REFUND_APPROVAL") => {
+            Box::pin(expire_refund_approval_workflow(state, refund_tracker)).await
+        }
         _ => Err(errors::ProcessTrackerError::JobNotFound),
     }
 }
@@ -1057,6 +1630,124 @@ pub async fn trigger_refund_execute_workflow(
     Ok(())
 }
 
+/// Rejects the refund if it is still pending approval when its approval expires
+#[instrument(skip_all)]
+pub async fn expire_refund_approval_workflow(
+    state: &AppState,
+    refund_tracker: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let refund_core =
+        serde_json::from_value::<storage::RefundCoreWorkflow>(refund_tracker.tracking_data.clone())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "unable to convert into refund_core {:?}",
+                    refund_tracker.tracking_data
+                )
+            })?;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &refund_core.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&refund_core.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let refund = db
+        .find_refund_by_internal_reference_id_merchant_id(
+            &refund_core.refund_internal_reference_id,
+            &refund_core.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    // Hold the lock held while approving or rejecting the refund, so that the refund can not be
+    // approved while it is expired
+    let lock_action = get_refund_approval_lock_action(&refund.refund_id);
+    let lock_holder = Some(refund_tracker.id.clone());
+    if let Err(error) = lock_action
+        .clone()
+        .perform_locking_action_for_holder(
+            state,
+            refund_core.merchant_id.clone(),
+            lock_holder.clone(),
+        )
+        .await
+    {
+        logger::info!(
+            ?error,
+            "Refund is locked, retrying refund approval expiry later"
+        );
+        let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+            consts::REFUND_APPROVAL_EXPIRY_LOCK_RETRY_DELAY_IN_SECONDS,
+        ));
+        return Ok(db
+            .as_scheduler()
+            .retry_process(refund_tracker.clone(), schedule_time)
+            .await?);
+    }
+
+    // The refund is fetched again after acquiring the lock, since it may have been approved or
+    // rejected in the meantime
+    let expiry_result = expire_refund_approval(db, &merchant_account, &refund.refund_id).await;
+
+    lock_action
+        .free_lock_action_for_holder(state, refund_core.merchant_id.clone(), lock_holder)
+        .await?;
+    expiry_result?;
+
+    db.as_scheduler()
+        .finish_process_with_business_status(refund_tracker.clone(), "COMPLETED_BY_PT".to_string())
+        .await?;
+    Ok(())
+}
+
+async fn expire_refund_approval(
+    db: &dyn db::StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    refund_id: &str,
+) -> RouterResult<()> {
+    let refund = db
+        .find_refund_by_merchant_id_refund_id(
+            &merchant_account.merchant_id,
+            refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    if refund.refund_status == enums::RefundStatus::PendingApproval {
+        record_refund_approval_decision(
+            db,
+            refund,
+            refunds::RefundApprovalDecision::Expired,
+            None,
+            None,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Lock held while a refund pending approval is approved, rejected or expired
+pub fn get_refund_approval_lock_action(refund_id: &str) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: refund_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Refunds,
+            override_lock_retries: None,
+        },
+    }
+}
+
 #[instrument]
 pub fn refund_to_refund_core_workflow_model(
     refund: &storage::Refund,
@@ -1145,6 +1836,39 @@ pub async fn add_refund_execute_task(
     Ok(response)
 }
 
+#[instrument(skip_all)]
+pub async fn add_refund_approval_expiry_task(
+    db: &dyn db::StorageInterface,
+    refund: &storage::Refund,
+    expires_at: time::PrimitiveDateTime,
+) -> RouterResult<storage::ProcessTracker> {
+    let runner = storage::ProcessTrackerRunner::RefundWorkflowRouter;
+    let task = "EXPIRE_REFUND_APPROVAL";
+    let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
+    let tag = ["REFUND"];
+    let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        refund_workflow_tracking_data,
+        expires_at,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct refund approval expiry process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicateRefundRequest)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: refund_id: {}",
+                refund.refund_id
+            )
+        })
+}
+
 pub async fn get_refund_sync_process_schedule_time(
     db: &dyn db::StorageInterface,
     connector: &str,
@@ -1172,3 +1896,34 @@ pub async fn get_refund_sync_process_schedule_time(
 
     Ok(process_tracker_utils::get_time_from_delta(time_delta))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_split_tender_refund_amount_is_allocated_from_the_last_tender() {
+        let tenders = vec![("gift_card", 3000), ("card", 7000)];
+
+        assert_eq!(
+            allocate_split_tender_refund_amount(tenders.clone(), 5000).unwrap(),
+            vec![("card", 5000)]
+        );
+        assert_eq!(
+            allocate_split_tender_refund_amount(tenders.clone(), 8000).unwrap(),
+            vec![("card", 7000), ("gift_card", 1000)]
+        );
+        assert!(allocate_split_tender_refund_amount(tenders, 10001).is_err());
+    }
+
+    #[test]
+    fn test_fully_refunded_tenders_are_skipped() {
+        let tenders = vec![("gift_card", 3000), ("card", 0)];
+
+        assert_eq!(
+            allocate_split_tender_refund_amount(tenders, 2000).unwrap(),
+            vec![("gift_card", 2000)]
+        );
+    }
+}
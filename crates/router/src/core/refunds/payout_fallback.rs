@@ -0,0 +1,380 @@
+use api_models::admin as admin_types;
+use common_utils::ext_traits::{AsyncExt, StringExt, ValueExt};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        payouts,
+    },
+    db::StorageInterface,
+    logger,
+    routes::{metrics, AppState},
+    services,
+    types::{
+        api::{self, payments as payment_types},
+        domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+/// Reason for which the connector could not process a refund
+#[derive(Debug, Clone, Copy)]
+pub enum RefundFailureReason<'a> {
+    /// The connector does not support refunding the payment
+    NotSupported,
+    /// The connector failed the refund with this error code
+    ConnectorError(&'a str),
+}
+
+/// Refunds which the connector does not support are fulfilled through a payout if the business
+/// profile opted in, while refunds failed by the connector are only fulfilled through a payout
+/// for the error codes listed in the policy
+pub fn should_fallback_to_payout(
+    refund_payout_fallback_policy: &admin_types::RefundPayoutFallbackPolicy,
+    reason: RefundFailureReason<'_>,
+) -> bool {
+    refund_payout_fallback_policy.enabled
+        && match reason {
+            RefundFailureReason::NotSupported => true,
+            RefundFailureReason::ConnectorError(error_code) => refund_payout_fallback_policy
+                .connector_error_codes
+                .as_ref()
+                .is_some_and(|connector_error_codes| {
+                    connector_error_codes
+                        .iter()
+                        .any(|connector_error_code| connector_error_code == error_code)
+                }),
+        }
+}
+
+async fn get_refund_payout_fallback_policy(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<admin_types::RefundPayoutFallbackPolicy>> {
+    let Some(profile_id) = payment_intent.profile_id.as_ref() else {
+        return Ok(None);
+    };
+
+    db.find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?
+        .refund_payout_fallback_policy
+        .map(|refund_payout_fallback_policy| {
+            refund_payout_fallback_policy.parse_value::<admin_types::RefundPayoutFallbackPolicy>(
+                "RefundPayoutFallbackPolicy",
+            )
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse refund payout fallback policy of the business profile")
+}
+
+/// Fulfils a refund which the connector could not process, through a payout to the
+/// customer's saved payout method. Returns `None` when the refund cannot be fulfilled through a
+/// payout, in which case the refund is expected to be marked as failed by the caller.
+#[instrument(skip_all)]
+pub async fn fulfil_refund_through_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    refund: &storage::Refund,
+    reason: RefundFailureReason<'_>,
+) -> Option<storage::Refund> {
+    let refund_payout_fallback_policy =
+        get_refund_payout_fallback_policy(&*state.store, payment_intent)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to fetch refund payout fallback policy")
+            })
+            .ok()
+            .flatten()?;
+    if !should_fallback_to_payout(&refund_payout_fallback_policy, reason) {
+        return None;
+    }
+
+    create_refund_payout(state, merchant_account, key_store, payment_intent, refund)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                refund_id = %refund.refund_id,
+                "Failed to fulfil refund through payout"
+            )
+        })
+        .ok()
+        .flatten()
+}
+
+async fn create_refund_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    refund: &storage::Refund,
+) -> RouterResult<Option<storage::Refund>> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let Some(customer_id) = payment_intent.customer_id.as_deref() else {
+        logger::info!("Refund cannot be fulfilled through payout as the payment has no customer");
+        return Ok(None);
+    };
+
+    let Some(payout_method_data) =
+        get_saved_payout_method_data(state, key_store, customer_id, merchant_account).await?
+    else {
+        logger::info!(
+            "Refund cannot be fulfilled through payout as the customer has no saved payout method"
+        );
+        return Ok(None);
+    };
+
+    let billing = payment_intent
+        .billing_address_id
+        .as_deref()
+        .async_map(|address_id| async move {
+            db.find_address_by_merchant_id_payment_id_address_id(
+                merchant_id,
+                &payment_intent.payment_id,
+                address_id,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+        })
+        .await
+        .transpose()
+        .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?
+        .map(|address| payment_types::Address {
+            address: Some(address.into()),
+            phone: None,
+            email: None,
+        });
+
+    let payout_request = api::PayoutCreateRequest {
+        amount: Some(refund.refund_amount.into()),
+        currency: Some(refund.currency),
+        customer_id: Some(customer_id.to_owned()),
+        payout_type: Some(enums::PayoutType::foreign_from(&payout_method_data)),
+        payout_method_data: Some(payout_method_data),
+        billing,
+        confirm: Some(true),
+        auto_fulfill: Some(true),
+        recurring: Some(false),
+        description: Some(format!(
+            "Refund {} of payment {}",
+            refund.refund_id, refund.payment_id
+        )),
+        profile_id: payment_intent.profile_id.clone(),
+        ..Default::default()
+    };
+
+    let payout_response = match payouts::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payout_request,
+    )
+    .await?
+    {
+        services::ApplicationResponse::Json(payout_response) => payout_response,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payouts create core")?,
+    };
+
+    metrics::REFUND_PAYOUT_FALLBACK_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "connector",
+            refund.connector.clone(),
+        )],
+    );
+
+    update_refund_with_payout_response(db, merchant_account, refund, payout_response)
+        .await
+        .map(Some)
+}
+
+/// Finds the most recently used bank or wallet payout method saved for the customer
+async fn get_saved_payout_method_data(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &str,
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<Option<api::PayoutMethodData>> {
+    let mut payment_methods = state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_status(
+            customer_id,
+            &merchant_account.merchant_id,
+            enums::PaymentMethodStatus::Active,
+            None,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment methods of the customer")?;
+
+    payment_methods.retain(|payment_method| {
+        matches!(
+            payment_method.payment_method,
+            Some(enums::PaymentMethod::BankTransfer | enums::PaymentMethod::Wallet)
+        )
+    });
+    payment_methods.sort_by(|a, b| b.last_used_at.cmp(&a.last_used_at));
+
+    for payment_method in payment_methods {
+        let locker_reference = payment_method
+            .locker_id
+            .as_deref()
+            .unwrap_or(&payment_method.payment_method_id);
+        let payout_method_data = cards::get_payment_method_from_hs_locker(
+            state,
+            key_store,
+            customer_id,
+            &merchant_account.merchant_id,
+            locker_reference,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|data| {
+            data.peek()
+                .to_string()
+                .parse_struct::<api::PayoutMethodData>("PayoutMethodData")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        });
+
+        match payout_method_data {
+            Ok(
+                payout_method_data @ (api::PayoutMethodData::Bank(_)
+                | api::PayoutMethodData::Wallet(_)),
+            ) => return Ok(Some(payout_method_data)),
+            Ok(api::PayoutMethodData::Card(_)) => {}
+            Err(error) => logger::warn!(
+                ?error,
+                payment_method_id = %payment_method.payment_method_id,
+                "Failed to fetch saved payout method from locker"
+            ),
+        }
+    }
+
+    Ok(None)
+}
+
+/// Keeps the status of a refund fulfilled through a payout in sync with the status of the payout
+#[instrument(skip_all)]
+pub async fn sync_refund_with_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    refund: storage::Refund,
+    payout_id: &str,
+) -> RouterResult<storage::Refund> {
+    let payout_response = match payouts::payouts_retrieve_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        api::PayoutRetrieveRequest {
+            payout_id: payout_id.to_owned(),
+            force_sync: Some(true),
+            merchant_id: Some(merchant_account.merchant_id.clone()),
+        },
+    )
+    .await?
+    {
+        services::ApplicationResponse::Json(payout_response) => payout_response,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payouts retrieve core")?,
+    };
+
+    if enums::RefundStatus::foreign_from(payout_response.status) == refund.refund_status {
+        return Ok(refund);
+    }
+
+    update_refund_with_payout_response(&*state.store, merchant_account, &refund, payout_response)
+        .await
+}
+
+async fn update_refund_with_payout_response(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    refund: &storage::Refund,
+    payout_response: api::PayoutCreateResponse,
+) -> RouterResult<storage::Refund> {
+    let refund_update = storage::RefundUpdate::PayoutFulfilmentUpdate {
+        refund_status: enums::RefundStatus::foreign_from(payout_response.status),
+        fulfilment_payout_id: payout_response.payout_id,
+        refund_error_message: payout_response.error_message,
+        refund_error_code: payout_response.error_code,
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+
+    db.update_refund(
+        refund.to_owned(),
+        refund_update,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| {
+        format!(
+            "Failed while updating refund: refund_id: {}",
+            refund.refund_id
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_should_fallback_to_payout() {
+        let refund_payout_fallback_policy = admin_types::RefundPayoutFallbackPolicy {
+            enabled: true,
+            connector_error_codes: Some(vec!["charge_expired_for_refund".to_string()]),
+        };
+        assert!(should_fallback_to_payout(
+            &refund_payout_fallback_policy,
+            RefundFailureReason::NotSupported
+        ));
+        assert!(should_fallback_to_payout(
+            &refund_payout_fallback_policy,
+            RefundFailureReason::ConnectorError("charge_expired_for_refund")
+        ));
+        // Refunds declined for any other reason, such as insufficient funds in the merchant's
+        // balance, are not fulfilled through a payout
+        assert!(!should_fallback_to_payout(
+            &refund_payout_fallback_policy,
+            RefundFailureReason::ConnectorError("insufficient_funds")
+        ));
+
+        let refund_payout_fallback_policy = admin_types::RefundPayoutFallbackPolicy {
+            enabled: true,
+            connector_error_codes: None,
+        };
+        assert!(!should_fallback_to_payout(
+            &refund_payout_fallback_policy,
+            RefundFailureReason::ConnectorError("charge_expired_for_refund")
+        ));
+
+        let refund_payout_fallback_policy = admin_types::RefundPayoutFallbackPolicy {
+            enabled: false,
+            connector_error_codes: Some(vec!["charge_expired_for_refund".to_string()]),
+        };
+        assert!(!should_fallback_to_payout(
+            &refund_payout_fallback_policy,
+            RefundFailureReason::NotSupported
+        ));
+    }
+}
@@ -44,7 +44,21 @@ pub fn validate_refund_amount(
     all_refunds: &[storage::Refund],
     refund_amount: i64,
 ) -> CustomResult<(), RefundValidationError> {
-    let total_refunded_amount: i64 = all_refunds
+    let total_refunded_amount = get_total_refunded_amount(all_refunds);
+
+    utils::when(
+        refund_amount > (amount_captured - total_refunded_amount),
+        || {
+            Err(report!(
+                RefundValidationError::RefundAmountExceedsPaymentAmount
+            ))
+        },
+    )
+}
+
+/// Amount refunded or being refunded, excluding the refunds which have failed
+pub fn get_total_refunded_amount(all_refunds: &[storage::Refund]) -> i64 {
+    all_refunds
         .iter()
         .filter_map(|refund| {
             if refund.refund_status != enums::RefundStatus::Failure
@@ -55,18 +69,94 @@ pub fn validate_refund_amount(
                 None
             }
         })
-        .sum();
+        .sum()
+}
 
+/// Whether the refund approval policy of the business profile requires the refund to be
+/// approved by a dashboard user before it is sent to the connector
+pub fn is_refund_approval_required(
+    refund_approval_policy: &api_models::admin::RefundApprovalPolicy,
+    refund_amount: i64,
+    refund_reason: Option<&str>,
+) -> bool {
+    let exceeds_amount_threshold = refund_approval_policy
+        .amount_threshold
+        .is_some_and(|amount_threshold| refund_amount >= amount_threshold);
+
+    let has_listed_reason = refund_reason
+        .zip(refund_approval_policy.reasons.as_ref())
+        .is_some_and(|(refund_reason, reasons)| {
+            reasons
+                .iter()
+                .any(|reason| reason.trim().eq_ignore_ascii_case(refund_reason.trim()))
+        });
+
+    exceeds_amount_threshold || has_listed_reason
+}
+
+pub fn validate_refund_pending_approval(refund: &storage::Refund) -> RouterResult<()> {
     utils::when(
-        refund_amount > (amount_captured - total_refunded_amount),
+        refund.refund_status != enums::RefundStatus::PendingApproval,
         || {
-            Err(report!(
-                RefundValidationError::RefundAmountExceedsPaymentAmount
-            ))
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The refund is not pending approval, its current status is {}",
+                    refund.refund_status
+                ),
+            }))
         },
     )
 }
 
+/// Refunds which require approval have to be approved by a dashboard user other than the one who
+/// created the refund. Refunds created with an API key have no known creator, so they have to be
+/// approved by two different dashboard users instead
+pub fn validate_refund_approver(
+    approval_details: &api_models::refunds::RefundApprovalDetails,
+    user_id: &str,
+) -> RouterResult<()> {
+    utils::when(
+        approval_details.requested_by.as_deref() == Some(user_id),
+        || {
+            Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: "approving a refund created by the same user".to_string(),
+            }))
+        },
+    )?;
+    utils::when(
+        approval_details.first_approved_by.as_deref() == Some(user_id),
+        || {
+            Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: "approving a refund already approved by the same user".to_string(),
+            }))
+        },
+    )
+}
+
+/// Whether the approval being given is the first of the two approvals required for a refund
+/// created with an API key
+pub fn is_first_of_two_refund_approvals(
+    approval_details: &api_models::refunds::RefundApprovalDetails,
+) -> bool {
+    approval_details.requested_by.is_none() && approval_details.first_approved_by.is_none()
+}
+
+/// A refund can no longer be approved once its approval has expired, even if the expiry task has
+/// not failed it yet
+pub fn validate_refund_approval_not_expired(
+    approval_details: &api_models::refunds::RefundApprovalDetails,
+    current_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    utils::when(approval_details.expires_at <= current_time, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The approval of the refund expired at {}",
+                approval_details.expires_at
+            ),
+        }))
+    })
+}
+
 #[instrument(skip_all)]
 pub fn validate_payment_order_age(
     created_at: &PrimitiveDateTime,
@@ -144,3 +234,92 @@ pub fn validate_for_valid_refunds(
         _ => Ok(()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_is_refund_approval_required() {
+        let refund_approval_policy = api_models::admin::RefundApprovalPolicy {
+            amount_threshold: Some(10000),
+            reasons: Some(vec!["Goodwill ".to_string()]),
+            approval_expiry_hours: None,
+        };
+        assert!(is_refund_approval_required(
+            &refund_approval_policy,
+            10000,
+            None
+        ));
+        assert!(!is_refund_approval_required(
+            &refund_approval_policy,
+            9999,
+            Some("duplicate")
+        ));
+        assert!(is_refund_approval_required(
+            &refund_approval_policy,
+            100,
+            Some("goodwill")
+        ));
+    }
+
+    #[test]
+    fn test_validate_refund_approver() {
+        let approval_details = api_models::refunds::RefundApprovalDetails {
+            expires_at: common_utils::date_time::now(),
+            requested_by: Some("user_1".to_string()),
+            first_approved_by: None,
+            decision: None,
+            decided_by: None,
+            decided_at: None,
+            reason: None,
+        };
+        assert!(validate_refund_approver(&approval_details, "user_1").is_err());
+        assert!(validate_refund_approver(&approval_details, "user_2").is_ok());
+        assert!(!is_first_of_two_refund_approvals(&approval_details));
+
+        // Refunds created with an API key need a second approval from a different user
+        let approval_details = api_models::refunds::RefundApprovalDetails {
+            requested_by: None,
+            ..approval_details
+        };
+        assert!(validate_refund_approver(&approval_details, "user_1").is_ok());
+        assert!(is_first_of_two_refund_approvals(&approval_details));
+
+        let approval_details = api_models::refunds::RefundApprovalDetails {
+            first_approved_by: Some("user_1".to_string()),
+            ..approval_details
+        };
+        assert!(validate_refund_approver(&approval_details, "user_1").is_err());
+        assert!(validate_refund_approver(&approval_details, "user_2").is_ok());
+        assert!(!is_first_of_two_refund_approvals(&approval_details));
+    }
+
+    #[test]
+    fn test_validate_refund_approval_not_expired() {
+        let current_time = common_utils::date_time::now();
+        let approval_details = api_models::refunds::RefundApprovalDetails {
+            expires_at: current_time.saturating_add(time::Duration::hours(1)),
+            requested_by: Some("user_1".to_string()),
+            first_approved_by: None,
+            decision: None,
+            decided_by: None,
+            decided_at: None,
+            reason: None,
+        };
+        assert!(validate_refund_approval_not_expired(&approval_details, current_time).is_ok());
+
+        // The approval can not be granted at or after the expiry
+        let approval_details = api_models::refunds::RefundApprovalDetails {
+            expires_at: current_time,
+            ..approval_details
+        };
+        assert!(validate_refund_approval_not_expired(&approval_details, current_time).is_err());
+        assert!(validate_refund_approval_not_expired(
+            &approval_details,
+            current_time.saturating_add(time::Duration::minutes(1))
+        )
+        .is_err());
+    }
+}
@@ -264,6 +264,14 @@ pub async fn update_business_profile_active_algorithm_ref(
         session_expiry: None,
         authentication_connector_details: None,
         extended_card_info_config: None,
+        authorization_expiry_config: None,
+        intent_expiry: None,
+        max_over_capture_percentage: None,
+        refund_approval_policy: None,
+        dispute_alert_policy: None,
+        webhook_endpoints: None,
+        webhook_secret_versions: None,
+        refund_payout_fallback_policy: None,
     };
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
         .await
@@ -963,6 +963,66 @@ pub async fn construct_retrieve_file_router_data<'a>(
     Ok(router_data)
 }
 
+#[instrument(skip_all)]
+pub fn construct_fetch_disputes_router_data(
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    fetch_disputes_request_data: types::FetchDisputesRequestData,
+) -> RouterResult<types::FetchDisputesRouterData> {
+    let connector_name = merchant_connector_account.connector_name.clone();
+    let merchant_connector_account =
+        helpers::MerchantConnectorAccountType::DbVal(merchant_connector_account.clone());
+    let test_mode: Option<bool> = merchant_connector_account.is_test_mode_on();
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: connector_name,
+        customer_id: None,
+        connector_customer: None,
+        payment_id: IRRELEVANT_PAYMENT_ID_IN_DISPUTE_FLOW.to_string(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_DISPUTE_FLOW.to_string(),
+        status: diesel_models::enums::AttemptStatus::default(),
+        payment_method: diesel_models::enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: diesel_models::enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        amount_captured: None,
+        payment_method_status: None,
+        request: fetch_disputes_request_data,
+        response: Err(ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_request_reference_id: IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_DISPUTE_FLOW
+            .to_string(),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode,
+        connector_api_version: None,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+    };
+    Ok(router_data)
+}
+
 pub fn is_merchant_enabled_for_payment_id_as_connector_request_id(
     conf: &Settings,
     merchant_id: &str,
@@ -1,3 +1,4 @@
+pub mod dead_letters;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
@@ -16,6 +17,7 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use redis_interface::RedisEntryId;
 use router_env::{
     instrument,
     tracing::{self, Instrument},
@@ -25,10 +27,12 @@ use router_env::{
 use super::{errors::StorageErrorExt, metrics};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
+#[cfg(feature = "payouts")]
+use crate::core::payouts;
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse},
         payment_methods::PaymentMethodRetrieve,
         payments, refunds,
@@ -37,6 +41,7 @@ use crate::{
     events::{
         api_logs::ApiEvent,
         outgoing_webhook_logs::{OutgoingWebhookEvent, OutgoingWebhookEventMetric},
+        EventsHandler,
     },
     logger,
     routes::{
@@ -85,7 +90,7 @@ pub async fn payments_incoming_webhook_flow<Ctx: PaymentMethodRetrieve>(
 
             let lock_action = api_locking::LockAction::Hold {
                 input: api_locking::LockingInput {
-                    unique_locking_key: payment_id,
+                    unique_locking_key: payment_id.clone(),
                     api_identifier: lock_utils::ApiIdentifier::Payments,
                     override_lock_retries: None,
                 },
@@ -127,6 +132,19 @@ pub async fn payments_incoming_webhook_flow<Ctx: PaymentMethodRetrieve>(
             ))
             .await;
 
+            // Only a verified webhook which was processed counts as the arrival of the webhook
+            // expected by the watchdog
+            if source_verified && response.is_ok() {
+                payments::webhook_watchdog::record_webhook_arrival(
+                    &state,
+                    &merchant_account,
+                    &payment_id,
+                )
+                .await
+                .map_err(|error| logger::warn!(webhook_watchdog_error=?error))
+                .ok();
+            }
+
             lock_action
                 .free_lock_action(&state, merchant_account.merchant_id.to_owned())
                 .await?;
@@ -349,7 +367,7 @@ pub async fn get_or_update_dispute_object(
     dispute_details: api::disputes::DisputePayload,
     merchant_id: &str,
     payment_attempt: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
-    event_type: webhooks::IncomingWebhookEvent,
+    dispute_status: common_enums::DisputeStatus,
     business_profile: &diesel_models::business_profile::BusinessProfile,
     connector_name: &str,
 ) -> CustomResult<diesel_models::dispute::Dispute, errors::ApiErrorResponse> {
@@ -363,9 +381,7 @@ pub async fn get_or_update_dispute_object(
                 amount: dispute_details.amount.clone(),
                 currency: dispute_details.currency,
                 dispute_stage: dispute_details.dispute_stage,
-                dispute_status: common_enums::DisputeStatus::foreign_try_from(event_type)
-                    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                    .attach_printable("event type to dispute status mapping failed")?,
+                dispute_status,
                 payment_id: payment_attempt.payment_id.to_owned(),
                 connector: connector_name.to_owned(),
                 attempt_id: payment_attempt.attempt_id.to_owned(),
@@ -391,9 +407,6 @@ pub async fn get_or_update_dispute_object(
         Some(dispute) => {
             logger::info!("Dispute Already exists, Updating the dispute details");
             metrics::INCOMING_DISPUTE_WEBHOOK_UPDATE_RECORD_METRIC.add(&metrics::CONTEXT, 1, &[]);
-            let dispute_status = diesel_models::enums::DisputeStatus::foreign_try_from(event_type)
-                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                .attach_printable("event type to dispute state conversion failure")?;
             crate::core::utils::validate_dispute_stage_and_dispute_status(
                 dispute.dispute_stage,
                 dispute.dispute_status,
@@ -680,6 +693,171 @@ pub async fn mandates_incoming_webhook_flow(
     }
 }
 
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn payouts_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    key_store: domain::MerchantKeyStore,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: webhooks::IncomingWebhookEvent,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    metrics::INCOMING_PAYOUT_WEBHOOK_METRIC.add(&metrics::CONTEXT, 1, &[]);
+    if source_verified {
+        let db = &*state.store;
+        let payout_attempt = match webhook_details.object_reference_id {
+            webhooks::ObjectReferenceId::PayoutId(webhooks::PayoutIdType::PayoutAttemptId(
+                payout_attempt_id,
+            )) => db
+                .find_payout_attempt_by_merchant_id_payout_attempt_id(
+                    &merchant_account.merchant_id,
+                    &payout_attempt_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?,
+            webhooks::ObjectReferenceId::PayoutId(webhooks::PayoutIdType::ConnectorPayoutId(
+                connector_payout_id,
+            )) => db
+                .find_payout_attempt_by_merchant_id_connector_payout_id(
+                    &merchant_account.merchant_id,
+                    &connector_payout_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?,
+            _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+                .attach_printable("received a non-payout id for retrieving payout")?,
+        };
+        let payout = db
+            .find_payout_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &payout_attempt.payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+        let current_status = payout_attempt.status;
+        let Some(status) = get_payout_status_from_webhook(current_status, event_type)? else {
+            logger::info!(
+                payout_id = %payout_attempt.payout_id,
+                ?current_status,
+                ?event_type,
+                "Ignoring payout webhook which does not change the payout status"
+            );
+            return Ok(WebhookResponseTracker::Payout {
+                payout_id: payout_attempt.payout_id,
+                status: current_status,
+            });
+        };
+
+        let error_message = payouts::helpers::is_payout_err_state(status)
+            .then(|| format!("Payout marked as {status} by connector webhook"));
+        let updated_payout_attempt = db
+            .update_payout_attempt(
+                &payout_attempt,
+                storage::PayoutAttemptUpdate::StatusUpdate {
+                    connector_payout_id: payout_attempt.connector_payout_id.clone(),
+                    status,
+                    error_message,
+                    error_code: None,
+                    is_eligible: payout_attempt.is_eligible,
+                },
+                &payout,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating payout_attempt in db")?;
+        db.update_payout(
+            &payout,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &updated_payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+
+        let payout_data = payouts::make_payout_data(
+            &state,
+            &merchant_account,
+            &key_store,
+            &api_models::payouts::PayoutRequest::PayoutRetrieveRequest(
+                api_models::payouts::PayoutRetrieveRequest {
+                    payout_id: updated_payout_attempt.payout_id.clone(),
+                    force_sync: None,
+                    merchant_id: Some(merchant_account.merchant_id.clone()),
+                },
+            ),
+        )
+        .await?;
+        let payout_response =
+            match payouts::response_handler(&merchant_account, &payout_data).await? {
+                services::ApplicationResponse::Json(payout_response)
+                | services::ApplicationResponse::JsonWithHeaders((payout_response, _)) => {
+                    payout_response
+                }
+                _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+                    .attach_printable("received non-json response from payouts core")?,
+            };
+
+        let event_type: Option<enums::EventType> = status.foreign_into();
+        if let Some(outgoing_event_type) = event_type {
+            create_event_and_trigger_outgoing_webhook(
+                state,
+                merchant_account,
+                business_profile,
+                &key_store,
+                outgoing_event_type,
+                enums::EventClass::Payouts,
+                updated_payout_attempt.payout_id.clone(),
+                enums::EventObjectType::PayoutDetails,
+                api::OutgoingWebhookContent::PayoutDetails(Box::new(payout_response)),
+                Some(payout_data.payouts.created_at),
+            )
+            .await?;
+        }
+
+        Ok(WebhookResponseTracker::Payout {
+            payout_id: updated_payout_attempt.payout_id,
+            status,
+        })
+    } else {
+        logger::error!("Webhook source verification failed for payouts webhook flow");
+        Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+    }
+}
+
+/// Obtain the status to which the payout is moved on receiving the webhook, `None` if the webhook
+/// does not change the status of the payout. A payout in a terminal state is only moved again when
+/// the connector explicitly reports that a successful payout was reversed, late or duplicate
+/// webhooks are otherwise ignored.
+#[cfg(feature = "payouts")]
+fn get_payout_status_from_webhook(
+    current_status: common_enums::PayoutStatus,
+    event_type: webhooks::IncomingWebhookEvent,
+) -> CustomResult<Option<common_enums::PayoutStatus>, errors::ApiErrorResponse> {
+    let status = common_enums::PayoutStatus::foreign_try_from(event_type)
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("event type to payout status mapping failed")?;
+
+    let is_reversal = current_status == common_enums::PayoutStatus::Success
+        && event_type == webhooks::IncomingWebhookEvent::PayoutReversed;
+    if current_status == status
+        || (payouts::helpers::is_payout_terminal_state(current_status) && !is_reversal)
+    {
+        Ok(None)
+    } else {
+        Ok(Some(status))
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub async fn disputes_incoming_webhook_flow(
@@ -711,32 +889,28 @@ pub async fn disputes_incoming_webhook_flow(
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+        let dispute_status = common_enums::DisputeStatus::foreign_try_from(event_type)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("event type to dispute status mapping failed")?;
         let dispute_object = get_or_update_dispute_object(
             state.clone(),
             option_dispute,
             dispute_details,
             &merchant_account.merchant_id,
             &payment_attempt,
-            event_type,
+            dispute_status,
             &business_profile,
             connector.id(),
         )
         .await?;
-        let disputes_response = Box::new(dispute_object.clone().foreign_into());
-        let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
-
-        create_event_and_trigger_outgoing_webhook(
-            state,
-            merchant_account,
+        Box::pin(handle_dispute_update(
+            &state,
+            &merchant_account,
             business_profile,
             &key_store,
-            event_type,
-            enums::EventClass::Disputes,
-            dispute_object.dispute_id.clone(),
-            enums::EventObjectType::DisputeDetails,
-            api::OutgoingWebhookContent::DisputeDetails(disputes_response),
-            Some(dispute_object.created_at),
-        )
+            &payment_attempt,
+            &dispute_object,
+        ))
         .await?;
         metrics::INCOMING_DISPUTE_WEBHOOK_MERCHANT_NOTIFIED_METRIC.add(&metrics::CONTEXT, 1, &[]);
         Ok(WebhookResponseTracker::Dispute {
@@ -752,6 +926,66 @@ pub async fn disputes_incoming_webhook_flow(
     }
 }
 
+/// Follows up on a dispute which was created or updated with the details received from the
+/// connector: schedules the deadline reminders, notifies the merchant and applies the dispute
+/// policy of the business profile
+pub(crate) async fn handle_dispute_update(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+    payment_attempt: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
+    dispute_object: &diesel_models::dispute::Dispute,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    disputes::deadline_reminders::add_or_update_dispute_deadline_reminder_task(
+        state,
+        dispute_object,
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            dispute_id = %dispute_object.dispute_id,
+            "Failed to schedule dispute deadline reminder task"
+        )
+    })
+    .ok();
+    let disputes_response = Box::new(dispute_object.clone().foreign_into());
+    let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
+
+    create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        enums::EventClass::Disputes,
+        dispute_object.dispute_id.clone(),
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(disputes_response),
+        Some(dispute_object.created_at),
+    )
+    .await?;
+    Box::pin(disputes::policies::apply_dispute_policy(
+        state,
+        merchant_account,
+        key_store,
+        payment_attempt,
+        dispute_object,
+    ))
+    .await
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            dispute_id = %dispute_object.dispute_id,
+            "Failed to apply dispute policy"
+        )
+    })
+    .ok();
+
+    Ok(())
+}
+
 async fn bank_transfer_webhook_flow<Ctx: PaymentMethodRetrieve>(
     state: AppState,
     req_state: ReqState,
@@ -769,14 +1003,28 @@ async fn bank_transfer_webhook_flow<Ctx: PaymentMethodRetrieve>(
         )
         .await?;
         let payment_id = payment_attempt.payment_id;
+
+        let lock_action = api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: payment_id.clone(),
+                api_identifier: lock_utils::ApiIdentifier::Payments,
+                override_lock_retries: None,
+            },
+        };
+
+        lock_action
+            .clone()
+            .perform_locking_action(&state, merchant_account.merchant_id.to_string())
+            .await?;
+
         let request = api::PaymentsRequest {
             payment_id: Some(api_models::payments::PaymentIdType::PaymentIntentId(
-                payment_id,
+                payment_id.clone(),
             )),
             payment_token: payment_attempt.payment_token,
             ..Default::default()
         };
-        Box::pin(payments::payments_core::<
+        let response = Box::pin(payments::payments_core::<
             api::Authorize,
             api::PaymentsResponse,
             _,
@@ -795,7 +1043,24 @@ async fn bank_transfer_webhook_flow<Ctx: PaymentMethodRetrieve>(
             None,
             HeaderPayload::with_source(common_enums::PaymentSource::Webhook),
         ))
-        .await
+        .await;
+
+        if response.is_ok() {
+            payments::webhook_watchdog::record_webhook_arrival(
+                &state,
+                &merchant_account,
+                &payment_id,
+            )
+            .await
+            .map_err(|error| logger::warn!(webhook_watchdog_error=?error))
+            .ok();
+        }
+
+        lock_action
+            .free_lock_action(&state, merchant_account.merchant_id.to_owned())
+            .await?;
+
+        response
     } else {
         Err(report!(
             errors::ApiErrorResponse::WebhookAuthenticationFailed
@@ -854,24 +1119,112 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        enums::WebhookDeliveryAttempt::InitialAttempt,
+        None,
+    );
+    create_idempotent_event_and_trigger_outgoing_webhook(
+        state,
+        merchant_account,
+        business_profile,
+        merchant_key_store,
+        event_type,
+        event_class,
+        primary_object_id,
+        primary_object_type,
+        content,
+        primary_object_created_at,
+        idempotent_event_id,
+    )
+    .await
+}
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+/// Same as `create_event_and_trigger_outgoing_webhook`, for events which can be raised multiple
+/// times for the same object, and are deduplicated using the provided idempotent event ID instead
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(crate) async fn create_idempotent_event_and_trigger_outgoing_webhook(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    idempotent_event_id: String,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if !state.conf.webhooks.outgoing_enabled {
+        logger::debug!(
+            business_profile_id=%business_profile.profile_id,
+            %idempotent_event_id,
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
+        );
+        return Ok(());
+    }
+
+    let delivery_targets =
+        get_webhook_delivery_targets_for_event(&business_profile, event_class, event_type);
+    if delivery_targets.is_empty() {
         logger::debug!(
             business_profile_id=%business_profile.profile_id,
             %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            "Merchant webhook URL could not be obtained and no webhook endpoints are subscribed \
+             to the event; skipping outgoing webhooks for event"
         );
         return Ok(());
     }
 
+    // Each delivery target is tracked as a separate event, so that deliveries to one target are
+    // retried independently of the deliveries to other targets
+    for delivery_target in delivery_targets {
+        let idempotent_event_id = match &delivery_target.webhook_endpoint_id {
+            Some(webhook_endpoint_id) => format!("{idempotent_event_id}_{webhook_endpoint_id}"),
+            None => idempotent_event_id.clone(),
+        };
+
+        create_event_and_trigger_webhook_to_delivery_target(
+            state.clone(),
+            &merchant_account,
+            business_profile.clone(),
+            merchant_key_store,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            idempotent_event_id,
+            delivery_target,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_webhook_to_delivery_target(
+    state: AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    idempotent_event_id: String,
+    delivery_target: types::WebhookDeliveryTarget,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -885,9 +1238,10 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     };
 
     let request_content = get_outgoing_webhook_request(
-        &merchant_account,
+        merchant_account,
         outgoing_webhook,
-        business_profile.payment_response_hash_key.as_deref(),
+        delivery_target.signing_key.as_deref(),
+        delivery_target.api_version,
     )
     .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
     .attach_printable("Failed to construct outgoing webhook request content")?;
@@ -920,6 +1274,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         ),
         response: None,
         delivery_attempt: Some(delivery_attempt),
+        webhook_endpoint_id: delivery_target.webhook_endpoint_id,
     };
 
     let event_insert_result = state
@@ -1023,11 +1378,11 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+    let webhook_destination = match (
+        get_webhook_destination_for_event(&business_profile, &event),
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+        (Ok(webhook_destination), _) => Ok(webhook_destination),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
@@ -1048,13 +1403,46 @@ async fn trigger_webhook_to_merchant(
         (Err(error), None) => Err(error),
     }?;
 
+    // Versioned signatures are computed when the webhook is sent rather than when the event is
+    // created, so that retries carry a fresh timestamp and secrets rotated in the meantime
+    let signing_secrets = get_webhook_signing_secrets_for_event(&business_profile, &event)?;
+    let versioned_signature_headers = utils::get_versioned_webhook_signature_headers(
+        request_content.body.peek(),
+        &signing_secrets,
+        common_utils::date_time::now_unix_timestamp(),
+    )?;
+
     let event_id = event.event_id;
 
     let headers = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
-        .collect();
+        .chain(versioned_signature_headers)
+        .collect::<Vec<_>>();
+
+    let webhook_url = match webhook_destination {
+        types::WebhookDestination::Url(webhook_url) => webhook_url,
+        types::WebhookDestination::MessageQueue(message_queue) => {
+            return trigger_webhook_to_message_queue(
+                state,
+                business_profile.merchant_id,
+                merchant_key_store,
+                event_id,
+                event.primary_object_id,
+                message_queue,
+                headers
+                    .into_iter()
+                    .map(|(name, value)| (name, value.into_inner()))
+                    .collect(),
+                request_content.body.expose(),
+                delivery_attempt,
+                process_tracker,
+            )
+            .await;
+        }
+    };
+
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
@@ -1412,6 +1800,182 @@ async fn trigger_webhook_to_merchant(
     Ok(())
 }
 
+/// Publish the webhook to the message queue configured for the business profile webhook endpoint
+/// the event is associated with, and record the outcome against the event similar to webhooks
+/// delivered to a URL.
+#[allow(clippy::too_many_arguments)]
+async fn trigger_webhook_to_message_queue(
+    state: AppState,
+    merchant_id: String,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_id: String,
+    primary_object_id: String,
+    message_queue: api_models::admin::WebhookMessageQueue,
+    headers: Vec<(String, String)>,
+    body: String,
+    delivery_attempt: enums::WebhookDeliveryAttempt,
+    process_tracker: Option<storage::ProcessTracker>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let queue_name = utils::get_webhook_message_queue_name(&merchant_id, &message_queue);
+    let publish_result = match &message_queue {
+        api_models::admin::WebhookMessageQueue::Kafka { .. } => match &state.event_handler {
+            EventsHandler::Kafka(kafka_producer) => kafka_producer
+                .publish_message(&queue_name, &primary_object_id, body.as_bytes(), &headers)
+                .await
+                .change_context(errors::WebhooksFlowError::PublishToMessageQueueFailed),
+            EventsHandler::Logs(_) => Err(report!(
+                errors::WebhooksFlowError::MessageQueueNotConfigured
+            ))
+            .attach_printable("Kafka is not configured as the events handler"),
+        },
+        api_models::admin::WebhookMessageQueue::RedisStream { .. } => {
+            let headers = headers
+                .into_iter()
+                .collect::<std::collections::HashMap<_, _>>()
+                .encode_to_string_of_json()
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)?;
+            match state.store.get_redis_conn() {
+                Ok(redis_conn) => redis_conn
+                    .stream_append_entry_with_cap(
+                        &queue_name,
+                        &RedisEntryId::AutoGeneratedID,
+                        vec![("headers", headers), ("body", body)],
+                        (
+                            redis_interface::StreamCapKind::MaxLen,
+                            redis_interface::StreamCapTrim::AlmostExact,
+                            consts::WEBHOOK_REDIS_STREAM_MAX_LENGTH,
+                        ),
+                    )
+                    .await
+                    .change_context(errors::WebhooksFlowError::PublishToMessageQueueFailed),
+                Err(error) => {
+                    Err(error).change_context(errors::WebhooksFlowError::MessageQueueNotConfigured)
+                }
+            }
+        }
+    };
+
+    metrics::WEBHOOK_OUTGOING_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new(MERCHANT_ID, merchant_id.clone())],
+    );
+    logger::debug!(?queue_name, ?publish_result, "Published outgoing webhook");
+
+    // Message queues do not return a response, so only the error (if any) is recorded
+    let response_to_store = OutgoingWebhookResponseContent {
+        body: None,
+        headers: None,
+        status_code: None,
+        error_message: publish_result
+            .as_ref()
+            .err()
+            .map(|_| format!("Unable to publish webhook to `{queue_name}`")),
+    };
+    let event_update = domain::EventUpdate::UpdateResponse {
+        is_webhook_notified: publish_result.is_ok(),
+        response: Some(
+            domain_types::encrypt(
+                response_to_store
+                    .encode_to_string_of_json()
+                    .change_context(
+                        errors::WebhooksFlowError::OutgoingWebhookResponseEncodingFailed,
+                    )
+                    .map(Secret::new)?,
+                merchant_key_store.key.get_inner().peek(),
+            )
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
+            .attach_printable("Failed to encrypt outgoing webhook response content")?,
+        ),
+    };
+    state
+        .store
+        .update_event_by_merchant_id_event_id(
+            &merchant_id,
+            &event_id,
+            event_update,
+            merchant_key_store,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+
+    match publish_result {
+        Ok(()) => {
+            metrics::WEBHOOK_OUTGOING_RECEIVED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue::new(MERCHANT_ID, merchant_id)],
+            );
+
+            let business_status = match delivery_attempt {
+                enums::WebhookDeliveryAttempt::InitialAttempt => {
+                    "INITIAL_DELIVERY_ATTEMPT_SUCCESSFUL"
+                }
+                enums::WebhookDeliveryAttempt::AutomaticRetry => "COMPLETED_BY_PT",
+                // Manual retries are not tracked by the process tracker
+                enums::WebhookDeliveryAttempt::ManualRetry => return Ok(()),
+            };
+            if let Some(process_tracker) = process_tracker {
+                state
+                    .store
+                    .as_scheduler()
+                    .finish_process_with_business_status(process_tracker, business_status.into())
+                    .await
+                    .change_context(
+                        errors::WebhooksFlowError::OutgoingWebhookProcessTrackerTaskUpdateFailed,
+                    )?;
+            }
+        }
+        Err(error) => {
+            metrics::WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue::new(MERCHANT_ID, merchant_id.clone())],
+            );
+            logger::error!(
+                ?error,
+                ?delivery_attempt,
+                "An error occurred when publishing webhook to message queue"
+            );
+
+            if delivery_attempt == enums::WebhookDeliveryAttempt::AutomaticRetry {
+                let process_tracker = process_tracker
+                    .get_required_value("process_tracker")
+                    .change_context(errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed)
+                    .attach_printable("`process_tracker` is unavailable in automatic retry flow")?;
+
+                if error
+                    .current_context()
+                    .is_webhook_delivery_retryable_error()
+                {
+                    // Schedule a retry attempt for webhook delivery
+                    outgoing_webhook_retry::retry_webhook_delivery_task(
+                        &*state.store,
+                        &merchant_id,
+                        process_tracker,
+                    )
+                    .await
+                    .change_context(
+                        errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed,
+                    )?;
+                } else {
+                    state
+                        .store
+                        .as_scheduler()
+                        .finish_process_with_business_status(process_tracker, "FAILURE".into())
+                        .await
+                        .change_context(
+                            errors::WebhooksFlowError::OutgoingWebhookProcessTrackerTaskUpdateFailed,
+                        )?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn raise_webhooks_analytics_event(
     state: AppState,
     trigger_webhook_result: CustomResult<(), errors::WebhooksFlowError>,
@@ -1459,17 +2023,57 @@ pub async fn webhooks_wrapper<W: types::OutgoingWebhookType, Ctx: PaymentMethodR
     body: actix_web::web::Bytes,
 ) -> RouterResponse<serde_json::Value> {
     let start_instant = Instant::now();
+    let request_details = api::IncomingWebhookRequestDetails {
+        method: req.method().clone(),
+        uri: req.uri().clone(),
+        headers: req.headers(),
+        query_params: req.query_string().to_string(),
+        body: &body,
+    };
+    let webhooks_core_result = Box::pin(webhooks_core::<W, Ctx>(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        key_store.clone(),
+        connector_name_or_mca_id,
+        request_details.clone(),
+    ))
+    .await;
+
+    // Webhooks which could not be processed are recorded so that they can be replayed later,
+    // failing to record them must not affect the response sent to the connector
     let (application_response, webhooks_response_tracker, serialized_req) =
-        Box::pin(webhooks_core::<W, Ctx>(
-            state.clone(),
-            req_state,
-            req,
-            merchant_account.clone(),
-            key_store,
-            connector_name_or_mca_id,
-            body.clone(),
-        ))
-        .await?;
+        match webhooks_core_result {
+            Ok(webhooks_core_response) => {
+                dead_letters::resolve_incoming_webhook_dead_letter(
+                    &state,
+                    &key_store,
+                    connector_name_or_mca_id,
+                    &body,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to resolve incoming webhook dead letter")
+                })
+                .ok();
+                webhooks_core_response
+            }
+            Err(error) => {
+                dead_letters::record_failed_incoming_webhook::<W>(
+                    &state,
+                    &key_store,
+                    connector_name_or_mca_id,
+                    &request_details,
+                    &error,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to record incoming webhook dead letter")
+                })
+                .ok();
+                return Err(error);
+            }
+        };
 
     logger::info!(incoming_webhook_payload = ?serialized_req);
 
@@ -1516,11 +2120,10 @@ pub async fn webhooks_wrapper<W: types::OutgoingWebhookType, Ctx: PaymentMethodR
 pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetrieve>(
     state: AppState,
     req_state: ReqState,
-    req: &actix_web::HttpRequest,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     connector_name_or_mca_id: &str,
-    body: actix_web::web::Bytes,
+    request_details: api::IncomingWebhookRequestDetails<'_>,
 ) -> errors::RouterResult<(
     services::ApplicationResponse<serde_json::Value>,
     WebhookResponseTracker,
@@ -1534,13 +2137,8 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
             merchant_account.merchant_id.clone(),
         )],
     );
-    let mut request_details = api::IncomingWebhookRequestDetails {
-        method: req.method().clone(),
-        uri: req.uri().clone(),
-        headers: req.headers(),
-        query_params: req.query_string().to_string(),
-        body: &body,
-    };
+    // The body is replaced with the decoded body, which lives shorter than the request details
+    let mut request_details = request_details;
 
     // Fetch the merchant connector account to get the webhooks source secret
     // `webhooks source secret` is a secret shared between the merchant and connector
@@ -1815,6 +2413,19 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
             .await
             .attach_printable("Incoming webhook flow for mandates failed")?,
 
+            #[cfg(feature = "payouts")]
+            api::WebhookFlow::Payout => Box::pin(payouts_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                business_profile,
+                key_store,
+                webhook_details,
+                source_verified,
+                event_type,
+            ))
+            .await
+            .attach_printable("Incoming webhook flow for payouts failed")?,
+
             api::WebhookFlow::ExternalAuthentication => {
                 Box::pin(external_authentication_incoming_webhook_flow::<Ctx>(
                     state.clone(),
@@ -2041,6 +2652,152 @@ pub async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+/// Obtain the destinations to which an event of the specified class and type is delivered: the
+/// webhook URL in the webhook details of the business profile, if configured, followed by the
+/// business profile webhook endpoints subscribed to the event.
+fn get_webhook_delivery_targets_for_event(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event_class: enums::EventClass,
+    event_type: enums::EventType,
+) -> Vec<types::WebhookDeliveryTarget> {
+    let webhook_secret = get_business_profile_webhook_secret(business_profile);
+    let default_target = get_webhook_url_from_business_profile(business_profile)
+        .ok()
+        .filter(|webhook_url| !webhook_url.is_empty())
+        .map(|_| types::WebhookDeliveryTarget {
+            webhook_endpoint_id: None,
+            signing_key: webhook_secret.clone(),
+            api_version: None,
+        });
+
+    let webhook_endpoints = get_webhook_endpoints_from_business_profile(business_profile)
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to read webhook endpoints of business profile, ignoring"
+            );
+            error
+        })
+        .unwrap_or_default();
+
+    default_target
+        .into_iter()
+        .chain(
+            webhook_endpoints
+                .into_iter()
+                .filter(|webhook_endpoint| {
+                    webhook_endpoint.is_subscribed_to(event_class, event_type)
+                })
+                .map(|webhook_endpoint| types::WebhookDeliveryTarget {
+                    webhook_endpoint_id: Some(webhook_endpoint.endpoint_id),
+                    signing_key: webhook_endpoint
+                        .secret
+                        .map(ExposeInterface::expose)
+                        .or_else(|| webhook_secret.clone()),
+                    api_version: webhook_endpoint.api_version,
+                }),
+        )
+        .collect()
+}
+
+fn get_webhook_endpoints_from_business_profile(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+) -> CustomResult<Vec<api_models::admin::WebhookEndpoint>, errors::WebhooksFlowError> {
+    business_profile
+        .webhook_endpoints
+        .clone()
+        .map(|webhook_endpoints| {
+            webhook_endpoints
+                .parse_value("Vec<WebhookEndpoint>")
+                .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Obtain the URL or message queue to which the specified event is delivered, based on the
+/// webhook endpoint the event is associated with.
+fn get_webhook_destination_for_event(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event: &domain::Event,
+) -> CustomResult<types::WebhookDestination, errors::WebhooksFlowError> {
+    match &event.webhook_endpoint_id {
+        Some(webhook_endpoint_id) => get_webhook_endpoints_from_business_profile(business_profile)?
+            .into_iter()
+            .find(|webhook_endpoint| {
+                webhook_endpoint.enabled && &webhook_endpoint.endpoint_id == webhook_endpoint_id
+            })
+            .and_then(|webhook_endpoint| {
+                match (webhook_endpoint.message_queue, webhook_endpoint.url) {
+                    (Some(message_queue), _) => {
+                        Some(types::WebhookDestination::MessageQueue(message_queue))
+                    }
+                    (None, Some(webhook_url)) => {
+                        Some(types::WebhookDestination::Url(webhook_url.expose()))
+                    }
+                    (None, None) => None,
+                }
+            })
+            .ok_or(errors::WebhooksFlowError::WebhookEndpointNotConfigured)
+            .attach_printable_lazy(|| {
+                format!("Webhook endpoint `{webhook_endpoint_id}` is not configured or is disabled")
+            }),
+        None => get_webhook_url_from_business_profile(business_profile)
+            .map(types::WebhookDestination::Url),
+    }
+}
+
+/// Obtain the versions of the secret with which the specified event is signed: the secret of the
+/// webhook endpoint the event is associated with, if the endpoint has its own secret, or else the
+/// current and unexpired previous secrets of the business profile. Secrets of webhook endpoints
+/// are not versioned, and are always reported as version 1.
+fn get_webhook_signing_secrets_for_event(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event: &domain::Event,
+) -> CustomResult<Vec<(i32, Secret<String>)>, errors::WebhooksFlowError> {
+    let webhook_endpoint_secret = match &event.webhook_endpoint_id {
+        Some(webhook_endpoint_id) => get_webhook_endpoints_from_business_profile(business_profile)?
+            .into_iter()
+            .find(|webhook_endpoint| &webhook_endpoint.endpoint_id == webhook_endpoint_id)
+            .and_then(|webhook_endpoint| webhook_endpoint.secret),
+        None => None,
+    };
+    if let Some(webhook_endpoint_secret) = webhook_endpoint_secret {
+        return Ok(vec![(1, webhook_endpoint_secret)]);
+    }
+
+    let webhook_secret_versions = utils::get_webhook_secret_versions(business_profile)
+        .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)
+        .attach_printable("Failed to parse webhook secret versions of business profile")?;
+    let current_secret =
+        utils::get_current_webhook_secret(business_profile, &webhook_secret_versions);
+
+    Ok(utils::get_valid_webhook_secrets(
+        webhook_secret_versions,
+        current_secret,
+        common_utils::date_time::now(),
+    ))
+}
+
+/// Obtain the secret currently used for signing outgoing webhooks of the business profile, with
+/// which the `X-Webhook-Signature-512` header is computed.
+pub(crate) fn get_business_profile_webhook_secret(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+) -> Option<String> {
+    let webhook_secret_versions = utils::get_webhook_secret_versions(business_profile)
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to parse webhook secret versions of business profile, ignoring"
+            );
+            error
+        })
+        .unwrap_or_default();
+
+    utils::get_current_webhook_secret(business_profile, &webhook_secret_versions)
+        .map(ExposeInterface::expose)
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &diesel_models::business_profile::BusinessProfile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -2066,6 +2823,7 @@ pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
     payment_response_hash_key: Option<&str>,
+    api_version: Option<api_models::enums::WebhookApiVersion>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
@@ -2095,16 +2853,290 @@ pub(crate) fn get_outgoing_webhook_request(
         })
     }
 
-    match merchant_account.get_compatible_connector() {
+    // The API version of the webhook endpoint takes precedence over the compatible connector of
+    // the merchant account
+    match (api_version, merchant_account.get_compatible_connector()) {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(
-            outgoing_webhook, payment_response_hash_key
-        ),
+        (Some(api_models::enums::WebhookApiVersion::StripeCompatible), _)
+        | (None, Some(api_models::enums::Connector::Stripe)) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                payment_response_hash_key,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             payment_response_hash_key,
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_business_profile(
+        webhook_url: Option<&str>,
+        webhook_endpoints: serde_json::Value,
+    ) -> diesel_models::business_profile::BusinessProfile {
+        let now = common_utils::date_time::now();
+        diesel_models::business_profile::BusinessProfile {
+            profile_id: "pro_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            profile_name: "default".to_string(),
+            created_at: now,
+            modified_at: now,
+            return_url: None,
+            enable_payment_response_hash: true,
+            payment_response_hash_key: Some("profile_key".to_string()),
+            redirect_to_merchant_with_http_post: false,
+            webhook_details: webhook_url
+                .map(|webhook_url| serde_json::json!({ "webhook_url": webhook_url })),
+            metadata: None,
+            routing_algorithm: None,
+            intent_fulfillment_time: None,
+            frm_routing_algorithm: None,
+            payout_routing_algorithm: None,
+            is_recon_enabled: false,
+            applepay_verified_domains: None,
+            payment_link_config: None,
+            session_expiry: None,
+            authentication_connector_details: None,
+            is_extended_card_info_enabled: None,
+            extended_card_info_config: None,
+            is_connector_agnostic_mit_enabled: None,
+            authorization_expiry_config: None,
+            intent_expiry: None,
+            max_over_capture_percentage: None,
+            refund_approval_policy: None,
+            dispute_alert_policy: None,
+            webhook_endpoints: Some(webhook_endpoints),
+            webhook_secret_versions: None,
+            refund_payout_fallback_policy: None,
+        }
+    }
+
+    fn get_webhook_endpoints() -> serde_json::Value {
+        serde_json::json!([
+            {
+                "endpoint_id": "finance",
+                "url": "https://finance.example.com/webhooks",
+                "enabled_event_classes": ["refunds"],
+                "secret": "finance_key"
+            },
+            {
+                "endpoint_id": "support",
+                "url": "https://support.example.com/webhooks",
+                "enabled_event_types": ["refund_failed"]
+            },
+            {
+                "endpoint_id": "disabled",
+                "url": "https://disabled.example.com/webhooks",
+                "enabled": false
+            }
+        ])
+    }
+
+    #[test]
+    fn test_get_webhook_delivery_targets_for_event() {
+        let business_profile = get_business_profile(
+            Some("https://merchant.example.com/webhooks"),
+            get_webhook_endpoints(),
+        );
+
+        let targets = get_webhook_delivery_targets_for_event(
+            &business_profile,
+            enums::EventClass::Refunds,
+            enums::EventType::RefundFailed,
+        );
+        let endpoint_ids = targets
+            .iter()
+            .map(|target| target.webhook_endpoint_id.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(endpoint_ids, vec![None, Some("finance"), Some("support")]);
+        assert_eq!(targets[1].signing_key.as_deref(), Some("finance_key"));
+        // Endpoints without a secret of their own are signed with the key of the business profile
+        assert_eq!(targets[2].signing_key.as_deref(), Some("profile_key"));
+
+        let targets = get_webhook_delivery_targets_for_event(
+            &business_profile,
+            enums::EventClass::Payments,
+            enums::EventType::PaymentSucceeded,
+        );
+        assert_eq!(targets.len(), 1);
+        assert!(targets[0].webhook_endpoint_id.is_none());
+    }
+
+    #[test]
+    fn test_get_webhook_delivery_targets_without_webhook_url() {
+        let business_profile = get_business_profile(None, get_webhook_endpoints());
+
+        let targets = get_webhook_delivery_targets_for_event(
+            &business_profile,
+            enums::EventClass::Refunds,
+            enums::EventType::RefundSucceeded,
+        );
+        let endpoint_ids = targets
+            .iter()
+            .map(|target| target.webhook_endpoint_id.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(endpoint_ids, vec![Some("finance")]);
+
+        let targets = get_webhook_delivery_targets_for_event(
+            &business_profile,
+            enums::EventClass::Disputes,
+            enums::EventType::DisputeOpened,
+        );
+        assert!(targets.is_empty());
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_get_payout_status_from_webhook() {
+        use common_enums::PayoutStatus;
+        use webhooks::IncomingWebhookEvent;
+
+        assert_eq!(
+            get_payout_status_from_webhook(
+                PayoutStatus::Pending,
+                IncomingWebhookEvent::PayoutSuccess
+            )
+            .unwrap(),
+            Some(PayoutStatus::Success)
+        );
+        assert_eq!(
+            get_payout_status_from_webhook(
+                PayoutStatus::RequiresFulfillment,
+                IncomingWebhookEvent::PayoutFailure
+            )
+            .unwrap(),
+            Some(PayoutStatus::Failed)
+        );
+        // Duplicate webhooks do not update the payout
+        assert_eq!(
+            get_payout_status_from_webhook(
+                PayoutStatus::Pending,
+                IncomingWebhookEvent::PayoutProcessing
+            )
+            .unwrap(),
+            None
+        );
+        assert!(get_payout_status_from_webhook(
+            PayoutStatus::Pending,
+            IncomingWebhookEvent::PaymentIntentSuccess
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_get_payout_status_from_webhook_only_reverses_successful_payouts_on_reversal_events() {
+        use common_enums::PayoutStatus;
+        use webhooks::IncomingWebhookEvent;
+
+        assert_eq!(
+            get_payout_status_from_webhook(
+                PayoutStatus::Success,
+                IncomingWebhookEvent::PayoutReversed
+            )
+            .unwrap(),
+            Some(PayoutStatus::Failed)
+        );
+        // Late failure and expiry webhooks do not move a successful payout to failed
+        assert_eq!(
+            get_payout_status_from_webhook(
+                PayoutStatus::Success,
+                IncomingWebhookEvent::PayoutFailure
+            )
+            .unwrap(),
+            None
+        );
+        assert_eq!(
+            get_payout_status_from_webhook(
+                PayoutStatus::Success,
+                IncomingWebhookEvent::PayoutExpired
+            )
+            .unwrap(),
+            None
+        );
+        // Payouts in other terminal states are not moved, even on reversal events
+        assert_eq!(
+            get_payout_status_from_webhook(
+                PayoutStatus::Cancelled,
+                IncomingWebhookEvent::PayoutReversed
+            )
+            .unwrap(),
+            None
+        );
+        assert_eq!(
+            get_payout_status_from_webhook(
+                PayoutStatus::Failed,
+                IncomingWebhookEvent::PayoutSuccess
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rotated_webhook_secret_does_not_replace_payment_response_hash_key() {
+        let mut business_profile = get_business_profile(
+            Some("https://merchant.example.com/webhooks"),
+            get_webhook_endpoints(),
+        );
+        assert_eq!(
+            get_business_profile_webhook_secret(&business_profile).as_deref(),
+            Some("profile_key")
+        );
+
+        business_profile.webhook_secret_versions = Some(serde_json::json!({
+            "current_version": 2,
+            "current_secret": "rotated_key",
+            "previous_secrets": []
+        }));
+        assert_eq!(
+            get_business_profile_webhook_secret(&business_profile).as_deref(),
+            Some("rotated_key")
+        );
+        assert_eq!(
+            business_profile.payment_response_hash_key.as_deref(),
+            Some("profile_key")
+        );
+
+        let targets = get_webhook_delivery_targets_for_event(
+            &business_profile,
+            enums::EventClass::Refunds,
+            enums::EventType::RefundFailed,
+        );
+        assert_eq!(targets[0].signing_key.as_deref(), Some("rotated_key"));
+        assert_eq!(targets[2].signing_key.as_deref(), Some("rotated_key"));
+    }
+
+    #[test]
+    fn test_get_idempotent_event_id_of_webhook_endpoint() {
+        let event_id = utils::get_idempotent_event_id(
+            "pay_1",
+            enums::EventType::PaymentSucceeded,
+            enums::WebhookDeliveryAttempt::InitialAttempt,
+            Some("finance"),
+        );
+        assert_eq!(event_id, "pay_1_payment_succeeded_finance");
+
+        let event_id = utils::get_idempotent_event_id(
+            "pay_1",
+            enums::EventType::PaymentSucceeded,
+            enums::WebhookDeliveryAttempt::InitialAttempt,
+            None,
+        );
+        assert_eq!(event_id, "pay_1_payment_succeeded");
+
+        let retry_event_id = utils::get_idempotent_event_id(
+            "pay_1",
+            enums::EventType::PaymentSucceeded,
+            enums::WebhookDeliveryAttempt::AutomaticRetry,
+            Some("finance"),
+        );
+        assert!(retry_event_id.starts_with("pay_1_payment_succeeded_finance_"));
+    }
+}
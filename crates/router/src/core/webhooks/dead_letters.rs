@@ -0,0 +1,639 @@
+#[cfg(feature = "olap")]
+use std::str::FromStr;
+
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    ext_traits::Encode,
+};
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use masking::ExposeInterface;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use super::types as webhook_types;
+use crate::{
+    consts,
+    core::errors::{self, StorageErrorExt},
+    routes::AppState,
+    types::{
+        api,
+        domain::{self, types as domain_types},
+        storage::enums,
+    },
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::{errors::RouterResponse, payment_methods::PaymentMethodRetrieve},
+    logger,
+    routes::app::ReqState,
+    services::ApplicationResponse,
+    utils::StringExt,
+};
+
+#[cfg(feature = "olap")]
+const DEAD_LETTERS_LIST_MAX_LIMIT: i64 = 100;
+
+/// Record an incoming webhook which could not be processed, so that it can be inspected and
+/// replayed later. Webhooks delivered again by the connector are recorded against the same entry,
+/// which is identified by the hash of the webhook body.
+#[instrument(skip_all)]
+pub(crate) async fn record_failed_incoming_webhook<W: webhook_types::OutgoingWebhookType>(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    connector_name_or_mca_id: &str,
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+    error: &error_stack::Report<errors::ApiErrorResponse>,
+) -> errors::RouterResult<()> {
+    let store = state.store.as_ref();
+    let body_hash = get_body_hash(request_details.body)?;
+    let request =
+        encrypt_request_content(request_details, W::OUTGOING_WEBHOOK_FORMAT, key_store).await?;
+    let (error_code, error_message, error_reason) = get_error_details(error);
+
+    match store
+        .find_incoming_webhook_dead_letter_by_merchant_id_connector_body_hash(
+            &key_store.merchant_id,
+            connector_name_or_mca_id,
+            &body_hash,
+            key_store,
+        )
+        .await
+    {
+        Ok(dead_letter) => {
+            let dead_letter_update = domain::IncomingWebhookDeadLetterUpdate::FailureUpdate {
+                request,
+                error_code,
+                error_message,
+                error_reason,
+                failure_count: dead_letter.failure_count.saturating_add(1),
+            };
+            store
+                .update_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+                    &key_store.merchant_id,
+                    &dead_letter.dead_letter_id,
+                    dead_letter_update,
+                    key_store,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update incoming webhook dead letter")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let now = common_utils::date_time::now();
+            let dead_letter = domain::IncomingWebhookDeadLetter {
+                dead_letter_id: common_utils::generate_time_ordered_id("whdl"),
+                merchant_id: key_store.merchant_id.clone(),
+                connector: connector_name_or_mca_id.to_owned(),
+                body_hash,
+                request,
+                status: enums::IncomingWebhookDeadLetterStatus::Pending,
+                error_code,
+                error_message,
+                error_reason,
+                failure_count: 1,
+                replay_count: 0,
+                last_replayed_at: None,
+                created_at: now,
+                modified_at: now,
+            };
+            store
+                .insert_incoming_webhook_dead_letter(dead_letter, key_store)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert incoming webhook dead letter")?;
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find incoming webhook dead letter")?,
+    }
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_key_with_expiry(
+            &get_dead_letter_marker_key(&key_store.merchant_id, connector_name_or_mca_id),
+            true,
+            consts::INCOMING_WEBHOOK_DEAD_LETTER_MARKER_TTL,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set incoming webhook dead letter marker in redis")?;
+
+    Ok(())
+}
+
+/// Mark the incoming webhook as resolved if it had previously failed and has now been processed
+/// successfully on being delivered again by the connector, so that it is not replayed. The dead
+/// letters are looked up only if a webhook from the connector has failed recently, so that
+/// webhooks are not hashed and looked up in the database when there is nothing to resolve.
+#[instrument(skip_all)]
+pub(crate) async fn resolve_incoming_webhook_dead_letter(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    connector_name_or_mca_id: &str,
+    body: &[u8],
+) -> errors::RouterResult<()> {
+    let store = state.store.as_ref();
+    let has_recent_failures = store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .exists::<()>(&get_dead_letter_marker_key(
+            &key_store.merchant_id,
+            connector_name_or_mca_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check incoming webhook dead letter marker in redis")?;
+    if !has_recent_failures {
+        return Ok(());
+    }
+
+    let body_hash = get_body_hash(body)?;
+
+    let dead_letter = match store
+        .find_incoming_webhook_dead_letter_by_merchant_id_connector_body_hash(
+            &key_store.merchant_id,
+            connector_name_or_mca_id,
+            &body_hash,
+            key_store,
+        )
+        .await
+    {
+        Ok(dead_letter) => dead_letter,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find incoming webhook dead letter")?,
+    };
+
+    if dead_letter.status == enums::IncomingWebhookDeadLetterStatus::Pending {
+        store
+            .update_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+                &key_store.merchant_id,
+                &dead_letter.dead_letter_id,
+                domain::IncomingWebhookDeadLetterUpdate::ResolvedUpdate,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update incoming webhook dead letter")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn list_dead_letters(
+    state: AppState,
+    merchant_id: String,
+    constraints: api::webhook_dead_letters::DeadLetterListConstraints,
+) -> RouterResponse<Vec<api::webhook_dead_letters::DeadLetterListItemResponse>> {
+    let store = state.store.as_ref();
+    let key_store = get_key_store(&state, &merchant_id).await?;
+
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit <= DEAD_LETTERS_LIST_MAX_LIMIT => Ok(limit),
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`limit` must be a number less than {DEAD_LETTERS_LIST_MAX_LIMIT}"),
+        }),
+        None => Ok(DEAD_LETTERS_LIST_MAX_LIMIT),
+    }?;
+    let offset = constraints
+        .offset
+        .map(i64::from)
+        .filter(|offset| *offset > 0);
+
+    let dead_letters = store
+        .list_incoming_webhook_dead_letters_by_merchant_id_constraints(
+            &merchant_id,
+            constraints.status,
+            constraints.created_after,
+            constraints.created_before,
+            Some(limit),
+            offset,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list incoming webhook dead letters")?;
+
+    Ok(ApplicationResponse::Json(
+        dead_letters
+            .into_iter()
+            .map(api::webhook_dead_letters::DeadLetterListItemResponse::from)
+            .collect(),
+    ))
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn retrieve_dead_letter(
+    state: AppState,
+    merchant_id: String,
+    dead_letter_id: String,
+) -> RouterResponse<api::webhook_dead_letters::DeadLetterRetrieveResponse> {
+    let key_store = get_key_store(&state, &merchant_id).await?;
+    let dead_letter = find_dead_letter(&state, &key_store, &dead_letter_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_dead_letters::DeadLetterRetrieveResponse::try_from(dead_letter)?,
+    ))
+}
+
+/// Process the incoming webhook again through the same flow as webhooks received from the
+/// connector. Webhooks which have been processed successfully cannot be replayed, and concurrent
+/// replays of a webhook are serialized by the API lock held on the dead letter ID.
+#[cfg(feature = "olap")]
+#[instrument(skip(state, req_state))]
+pub async fn replay_dead_letter<Ctx: PaymentMethodRetrieve>(
+    state: AppState,
+    req_state: ReqState,
+    merchant_id: String,
+    dead_letter_id: String,
+) -> RouterResponse<api::webhook_dead_letters::DeadLetterRetrieveResponse> {
+    let store = state.store.as_ref();
+    let key_store = get_key_store(&state, &merchant_id).await?;
+    let merchant_account = store
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let dead_letter = find_dead_letter(&state, &key_store, &dead_letter_id).await?;
+
+    if dead_letter.status == enums::IncomingWebhookDeadLetterStatus::Resolved {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The incoming webhook has already been processed successfully".to_string(),
+        })?
+    }
+
+    let request_content: webhook_types::StoredIncomingWebhookRequest = dead_letter
+        .request
+        .peek()
+        .parse_struct("StoredIncomingWebhookRequest")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse incoming webhook request information")?;
+
+    let method = actix_web::http::Method::from_str(&request_content.method)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse incoming webhook request method")?;
+    let uri = actix_web::http::Uri::from_str(&request_content.uri)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse incoming webhook request URI")?;
+    let mut headers = actix_web::http::header::HeaderMap::new();
+    for (name, value) in request_content.headers {
+        headers.append(
+            actix_web::http::header::HeaderName::from_str(&name)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse incoming webhook request header name")?,
+            actix_web::http::header::HeaderValue::from_str(&value.expose())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse incoming webhook request header value")?,
+        );
+    }
+    let body = request_content.body.expose();
+
+    let request_details = api::IncomingWebhookRequestDetails {
+        method,
+        query_params: uri.query().unwrap_or_default().to_string(),
+        uri,
+        headers: &headers,
+        body: body.as_bytes(),
+    };
+
+    // The webhook is replayed in the format of the route on which it was received, so that
+    // merchants using the Stripe compatible routes continue to receive Stripe compatible webhooks
+    let replay_result = match request_content.outgoing_webhook_format {
+        webhook_types::OutgoingWebhookFormat::Default => {
+            Box::pin(super::webhooks_core::<
+                api_models::webhooks::OutgoingWebhook,
+                Ctx,
+            >(
+                state.clone(),
+                req_state,
+                merchant_account,
+                key_store.clone(),
+                &dead_letter.connector,
+                request_details,
+            ))
+            .await
+        }
+        webhook_types::OutgoingWebhookFormat::StripeCompatible => {
+            Box::pin(super::webhooks_core::<
+                crate::compatibility::stripe::webhooks::StripeOutgoingWebhook,
+                Ctx,
+            >(
+                state.clone(),
+                req_state,
+                merchant_account,
+                key_store.clone(),
+                &dead_letter.connector,
+                request_details,
+            ))
+            .await
+        }
+    };
+
+    let replay_count = dead_letter.replay_count.saturating_add(1);
+    let dead_letter_update = match replay_result {
+        Ok(_) => domain::IncomingWebhookDeadLetterUpdate::ReplaySuccessUpdate { replay_count },
+        Err(error) => {
+            logger::error!(?error, "Failed to process replayed incoming webhook");
+            let (error_code, error_message, error_reason) = get_error_details(&error);
+            domain::IncomingWebhookDeadLetterUpdate::ReplayFailureUpdate {
+                error_code,
+                error_message,
+                error_reason,
+                replay_count,
+            }
+        }
+    };
+
+    let updated_dead_letter = store
+        .update_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+            &merchant_id,
+            &dead_letter_id,
+            dead_letter_update,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update incoming webhook dead letter")?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_dead_letters::DeadLetterRetrieveResponse::try_from(updated_dead_letter)?,
+    ))
+}
+
+#[cfg(feature = "olap")]
+async fn get_key_store(
+    state: &AppState,
+    merchant_id: &str,
+) -> errors::RouterResult<domain::MerchantKeyStore> {
+    let store = state.store.as_ref();
+    store
+        .get_merchant_key_store_by_merchant_id(merchant_id, &store.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
+
+#[cfg(feature = "olap")]
+async fn find_dead_letter(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    dead_letter_id: &str,
+) -> errors::RouterResult<domain::IncomingWebhookDeadLetter> {
+    state
+        .store
+        .find_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+            &key_store.merchant_id,
+            dead_letter_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Incoming webhook does not exist in our records".to_string(),
+        })
+}
+
+fn get_body_hash(body: &[u8]) -> errors::RouterResult<String> {
+    Sha256
+        .generate_digest(body)
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash incoming webhook body")
+}
+
+fn get_dead_letter_marker_key(merchant_id: &str, connector_name_or_mca_id: &str) -> String {
+    format!("incoming_webhook_dead_letter_{merchant_id}_{connector_name_or_mca_id}")
+}
+
+async fn encrypt_request_content(
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+    outgoing_webhook_format: webhook_types::OutgoingWebhookFormat,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<common_utils::crypto::Encryptable<Secret<String>>> {
+    // Webhooks are replayed with the exact body received, which is required for verifying the
+    // source of the webhook
+    let body = String::from_utf8(request_details.body.to_vec())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Incoming webhook body is not valid UTF-8")?;
+    let headers = request_details
+        .headers
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.to_string(), Secret::new(value.to_string())))
+        })
+        .collect();
+
+    let request_content = webhook_types::StoredIncomingWebhookRequest {
+        method: request_details.method.to_string(),
+        uri: request_details.uri.to_string(),
+        headers,
+        body: Secret::new(body),
+        outgoing_webhook_format,
+    };
+
+    domain_types::encrypt(
+        request_content
+            .encode_to_string_of_json()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode incoming webhook request content")
+            .map(Secret::new)?,
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt incoming webhook request content")
+}
+
+/// Obtain the request information of the webhook which can be returned to the merchant: the path
+/// without the query, the allowed headers and the body with all of its values masked
+#[cfg(feature = "olap")]
+pub(crate) fn get_masked_request_content(
+    request: webhook_types::StoredIncomingWebhookRequest,
+) -> api::webhook_dead_letters::IncomingWebhookRequestContent {
+    let path = request
+        .uri
+        .split_once('?')
+        .map_or(request.uri.as_str(), |(path, _query)| path)
+        .to_string();
+    let headers = request
+        .headers
+        .into_iter()
+        .filter(|(name, _value)| {
+            consts::INCOMING_WEBHOOK_DEAD_LETTER_ALLOWED_HEADERS
+                .contains(&name.to_ascii_lowercase().as_str())
+        })
+        .map(|(name, value)| (name, value.expose()))
+        .collect();
+    let body = request
+        .body
+        .peek()
+        .parse_struct::<serde_json::Value>("IncomingWebhookBody")
+        .map(mask_json_value)
+        .unwrap_or_else(|_| serde_json::Value::String(format!("{:?}", request.body)));
+
+    api::webhook_dead_letters::IncomingWebhookRequestContent {
+        method: request.method,
+        path,
+        headers,
+        body,
+    }
+}
+
+/// Mask the values in the JSON value, retaining its structure and keys
+#[cfg(feature = "olap")]
+fn mask_json_value(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Null => serde_json::Value::Null,
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(mask_json_value).collect())
+        }
+        serde_json::Value::Object(values) => serde_json::Value::Object(
+            values
+                .into_iter()
+                .map(|(key, value)| (key, mask_json_value(value)))
+                .collect(),
+        ),
+        serde_json::Value::Bool(value) => {
+            serde_json::Value::String(format!("{:?}", Secret::new(value)))
+        }
+        serde_json::Value::Number(value) => {
+            serde_json::Value::String(format!("{:?}", Secret::new(value)))
+        }
+        serde_json::Value::String(value) => {
+            serde_json::Value::String(format!("{:?}", Secret::new(value)))
+        }
+    }
+}
+
+/// Obtain the error code, error message and the most recent description attached to the error
+fn get_error_details(
+    error: &error_stack::Report<errors::ApiErrorResponse>,
+) -> (String, String, Option<String>) {
+    let api_error = error.current_context();
+    let error_reason = error.frames().find_map(|frame| {
+        frame
+            .downcast_ref::<&'static str>()
+            .map(|reason| reason.to_string())
+            .or_else(|| frame.downcast_ref::<String>().cloned())
+    });
+
+    (
+        api_error.error_code(),
+        api_error.error_message(),
+        error_reason,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_stored_request(body: &str) -> webhook_types::StoredIncomingWebhookRequest {
+        serde_json::from_value(serde_json::json!({
+            "method": "POST",
+            "uri": "/webhooks/merchant_1/stripe?token=secret_token",
+            "headers": [
+                ["content-type", "application/json"],
+                ["Stripe-Signature", "t=1717000000,v1=signature"],
+                ["authorization", "Bearer secret_token"],
+                ["user-agent", "Stripe/1.0"]
+            ],
+            "body": body
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_stored_request_defaults_to_default_outgoing_webhook_format() {
+        let request = get_stored_request("{}");
+
+        assert_eq!(
+            request.outgoing_webhook_format,
+            webhook_types::OutgoingWebhookFormat::Default
+        );
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_get_masked_request_content() {
+        let request = get_stored_request(
+            r#"{"id":"evt_1","data":{"amount":6540,"livemode":false,"card":null,"tags":["a"]}}"#,
+        );
+
+        let request_content = get_masked_request_content(request);
+
+        assert_eq!(request_content.method, "POST");
+        assert_eq!(request_content.path, "/webhooks/merchant_1/stripe");
+        assert_eq!(
+            request_content.headers,
+            vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("user-agent".to_string(), "Stripe/1.0".to_string()),
+            ]
+        );
+
+        let body = request_content.body.to_string();
+        assert!(!body.contains("evt_1"));
+        assert!(!body.contains("6540"));
+        assert_eq!(
+            request_content.body["data"]["card"],
+            serde_json::Value::Null
+        );
+        assert_eq!(
+            request_content.body["id"],
+            serde_json::Value::String(format!("{:?}", Secret::new("evt_1".to_string())))
+        );
+        assert!(request_content.body["data"]["tags"].is_array());
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_get_masked_request_content_of_non_json_body() {
+        let request = get_stored_request("payment_id=pay_1&status=succeeded");
+
+        let request_content = get_masked_request_content(request);
+
+        assert_eq!(
+            request_content.body,
+            serde_json::Value::String(format!(
+                "{:?}",
+                Secret::new("payment_id=pay_1&status=succeeded".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_get_dead_letter_marker_key() {
+        assert_eq!(
+            get_dead_letter_marker_key("merchant_1", "stripe"),
+            "incoming_webhook_dead_letter_merchant_1_stripe"
+        );
+    }
+
+    #[test]
+    fn test_get_error_details() {
+        let error = error_stack::report!(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("Incoming webhook flow for payments failed");
+
+        let (error_code, _error_message, error_reason) = get_error_details(&error);
+
+        assert_eq!(
+            error_code,
+            errors::ApiErrorResponse::WebhookProcessingFailure.error_code()
+        );
+        assert_eq!(
+            error_reason.as_deref(),
+            Some("Incoming webhook flow for payments failed")
+        );
+    }
+}
@@ -6,6 +6,16 @@ use serde::Serialize;
 
 use crate::{core::errors, headers, services::request::Maskable, types::storage::enums};
 
+/// The format of outgoing webhooks, which depends on the route on which the incoming webhook was
+/// received
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutgoingWebhookFormat {
+    #[default]
+    Default,
+    StripeCompatible,
+}
+
 pub struct OutgoingWebhookPayloadWithSignature {
     pub payload: Secret<String>,
     pub signature: Option<String>,
@@ -14,6 +24,10 @@ pub struct OutgoingWebhookPayloadWithSignature {
 pub trait OutgoingWebhookType:
     Serialize + From<webhooks::OutgoingWebhook> + Sync + Send + std::fmt::Debug + 'static
 {
+    /// The format of the outgoing webhooks sent for the incoming webhooks received on the route
+    /// which uses this type
+    const OUTGOING_WEBHOOK_FORMAT: OutgoingWebhookFormat;
+
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
@@ -23,6 +37,8 @@ pub trait OutgoingWebhookType:
 }
 
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
+    const OUTGOING_WEBHOOK_FORMAT: OutgoingWebhookFormat = OutgoingWebhookFormat::Default;
+
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
@@ -56,6 +72,23 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     }
 }
 
+/// A destination to which outgoing webhooks of a business profile are delivered
+#[derive(Clone, Debug)]
+pub(crate) struct WebhookDeliveryTarget {
+    /// The identifier of the business profile webhook endpoint, `None` for the webhook URL in the
+    /// webhook details of the business profile
+    pub(crate) webhook_endpoint_id: Option<String>,
+    pub(crate) signing_key: Option<String>,
+    pub(crate) api_version: Option<api_models::enums::WebhookApiVersion>,
+}
+
+/// The destination to which an outgoing webhook is delivered
+#[derive(Clone, Debug)]
+pub(crate) enum WebhookDestination {
+    Url(String),
+    MessageQueue(api_models::admin::WebhookMessageQueue),
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) merchant_id: String,
@@ -66,3 +99,16 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) primary_object_type: enums::EventObjectType,
     pub(crate) initial_attempt_id: Option<String>,
 }
+
+/// The request information of an incoming webhook which could not be processed. It is stored
+/// encrypted, since the exact request received from the connector is required for verifying the
+/// source of the webhook when it is replayed.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct StoredIncomingWebhookRequest {
+    pub(crate) method: String,
+    pub(crate) uri: String,
+    pub(crate) headers: Vec<(String, Secret<String>)>,
+    pub(crate) body: Secret<String>,
+    #[serde(default)]
+    pub(crate) outgoing_webhook_format: OutgoingWebhookFormat,
+}
@@ -1,15 +1,22 @@
 use std::marker::PhantomData;
 
-use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use common_utils::{
+    crypto::{self, SignMessage},
+    errors::CustomResult,
+    ext_traits::ValueExt,
+};
 use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
 
 use crate::{
+    consts,
     core::{
         errors::{self},
         payments::helpers,
     },
     db::{get_and_deserialize_key, StorageInterface},
-    services::logger,
+    headers,
+    services::{logger, request::Maskable},
     types::{self, api, domain, PaymentAddress},
 };
 
@@ -126,12 +133,18 @@ pub(crate) fn get_idempotent_event_id(
     primary_object_id: &str,
     event_type: types::storage::enums::EventType,
     delivery_attempt: types::storage::enums::WebhookDeliveryAttempt,
+    webhook_endpoint_id: Option<&str>,
 ) -> String {
     use crate::types::storage::enums::WebhookDeliveryAttempt;
 
     const EVENT_ID_SUFFIX_LENGTH: usize = 8;
 
-    let common_prefix = format!("{primary_object_id}_{event_type}");
+    let common_prefix = match webhook_endpoint_id {
+        Some(webhook_endpoint_id) => {
+            format!("{primary_object_id}_{event_type}_{webhook_endpoint_id}")
+        }
+        None => format!("{primary_object_id}_{event_type}"),
+    };
     match delivery_attempt {
         WebhookDeliveryAttempt::InitialAttempt => common_prefix,
         WebhookDeliveryAttempt::AutomaticRetry | WebhookDeliveryAttempt::ManualRetry => {
@@ -144,3 +157,243 @@ pub(crate) fn get_idempotent_event_id(
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
 }
+
+/// Obtain the name of the topic or stream to which webhooks of the merchant are published, which
+/// is namespaced by the merchant ID.
+#[inline]
+pub(crate) fn get_webhook_message_queue_name(
+    merchant_id: &str,
+    message_queue: &api_models::admin::WebhookMessageQueue,
+) -> String {
+    format!(
+        "{}.{merchant_id}.{}",
+        consts::WEBHOOK_MESSAGE_QUEUE_NAME_PREFIX,
+        message_queue.get_name()
+    )
+}
+
+/// Obtain the versions of the webhook signing secret of the business profile. Business profiles
+/// whose secret has never been rotated use version 1 of the secret.
+pub(crate) fn get_webhook_secret_versions(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+) -> CustomResult<
+    diesel_models::business_profile::WebhookSecretVersions,
+    common_utils::errors::ParsingError,
+> {
+    business_profile
+        .webhook_secret_versions
+        .clone()
+        .map(|webhook_secret_versions| webhook_secret_versions.parse_value("WebhookSecretVersions"))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Obtain the secret currently used for signing outgoing webhooks of the business profile, which
+/// is the `payment_response_hash_key` of the business profile until the secret is first rotated.
+pub(crate) fn get_current_webhook_secret(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    webhook_secret_versions: &diesel_models::business_profile::WebhookSecretVersions,
+) -> Option<Secret<String>> {
+    webhook_secret_versions.current_secret.clone().or_else(|| {
+        business_profile
+            .payment_response_hash_key
+            .clone()
+            .map(Secret::new)
+    })
+}
+
+/// Obtain the versions of the webhook signing secret which are valid at the specified time: the
+/// current secret, followed by the previous secrets which have not expired yet.
+pub(crate) fn get_valid_webhook_secrets(
+    webhook_secret_versions: diesel_models::business_profile::WebhookSecretVersions,
+    current_secret: Option<Secret<String>>,
+    now: time::PrimitiveDateTime,
+) -> Vec<(i32, Secret<String>)> {
+    let Some(current_secret) = current_secret else {
+        return Vec::new();
+    };
+
+    std::iter::once((webhook_secret_versions.current_version, current_secret))
+        .chain(
+            webhook_secret_versions
+                .previous_secrets
+                .into_iter()
+                .filter(|previous_secret| previous_secret.expires_at > now)
+                .map(|previous_secret| (previous_secret.version, previous_secret.secret)),
+        )
+        .collect()
+}
+
+/// Sign the outgoing webhook payload with each of the specified versions of the signing secret.
+/// The signatures are computed over `{timestamp}.{payload}`, so that merchants can reject replayed
+/// webhooks, and are sent along with the timestamp as
+/// `X-Webhook-Signatures: v2=<signature>,v1=<signature>`.
+pub(crate) fn get_versioned_webhook_signature_headers(
+    payload: &str,
+    signing_secrets: &[(i32, Secret<String>)],
+    timestamp: i64,
+) -> CustomResult<Vec<(String, Maskable<String>)>, errors::WebhooksFlowError> {
+    if signing_secrets.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let signed_payload = format!("{timestamp}.{payload}");
+    let signatures = signing_secrets
+        .iter()
+        .map(|(version, secret)| {
+            crypto::HmacSha512
+                .sign_message(secret.peek().as_bytes(), signed_payload.as_bytes())
+                .map(|signature| format!("v{version}={}", hex::encode(signature)))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?;
+
+    Ok(vec![
+        (
+            headers::X_WEBHOOK_TIMESTAMP.to_string(),
+            timestamp.to_string().into(),
+        ),
+        (
+            headers::X_WEBHOOK_SIGNATURES.to_string(),
+            signatures.join(",").into(),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::crypto::VerifySignature;
+    use masking::ExposeInterface;
+
+    use super::*;
+
+    fn get_webhook_secret_versions(
+        now: time::PrimitiveDateTime,
+    ) -> diesel_models::business_profile::WebhookSecretVersions {
+        diesel_models::business_profile::WebhookSecretVersions {
+            current_version: 3,
+            current_secret: Some(Secret::new("secret_v3".to_string())),
+            previous_secrets: vec![
+                diesel_models::business_profile::PreviousWebhookSecret {
+                    version: 2,
+                    secret: Secret::new("secret_v2".to_string()),
+                    expires_at: now.saturating_add(time::Duration::hours(1)),
+                },
+                diesel_models::business_profile::PreviousWebhookSecret {
+                    version: 1,
+                    secret: Secret::new("secret_v1".to_string()),
+                    expires_at: now.saturating_sub(time::Duration::hours(1)),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_get_webhook_message_queue_name() {
+        let kafka = api_models::admin::WebhookMessageQueue::Kafka {
+            topic: "payments".to_string(),
+        };
+        let redis_stream = api_models::admin::WebhookMessageQueue::RedisStream {
+            stream: "payments.v1".to_string(),
+        };
+
+        assert_eq!(
+            get_webhook_message_queue_name("merchant_1", &kafka),
+            "merchant_webhooks.merchant_1.payments"
+        );
+        assert_eq!(
+            get_webhook_message_queue_name("merchant_1", &redis_stream),
+            "merchant_webhooks.merchant_1.payments.v1"
+        );
+    }
+
+    #[test]
+    fn test_message_queue_publish_failures_are_retried() {
+        assert!(errors::WebhooksFlowError::PublishToMessageQueueFailed
+            .is_webhook_delivery_retryable_error());
+        // Webhooks are not retried if the message queue is not configured, since retrying would
+        // fail in the same way
+        assert!(!errors::WebhooksFlowError::MessageQueueNotConfigured
+            .is_webhook_delivery_retryable_error());
+    }
+
+    #[test]
+    fn test_get_valid_webhook_secrets_excludes_expired_secrets() {
+        let now = common_utils::date_time::now();
+        let webhook_secret_versions = get_webhook_secret_versions(now);
+        let current_secret = webhook_secret_versions.current_secret.clone();
+
+        let valid_secrets = get_valid_webhook_secrets(webhook_secret_versions, current_secret, now)
+            .into_iter()
+            .map(|(version, secret)| (version, secret.expose()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            valid_secrets,
+            vec![(3, "secret_v3".to_string()), (2, "secret_v2".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_get_valid_webhook_secrets_without_current_secret() {
+        let now = common_utils::date_time::now();
+
+        assert!(get_valid_webhook_secrets(get_webhook_secret_versions(now), None, now).is_empty());
+    }
+
+    #[test]
+    fn test_get_versioned_webhook_signature_headers() {
+        let payload = r#"{"event_type":"payment_succeeded"}"#;
+        let signing_secrets = vec![
+            (2, Secret::new("secret_v2".to_string())),
+            (1, Secret::new("secret_v1".to_string())),
+        ];
+        let timestamp = 1_717_000_000;
+
+        let headers =
+            get_versioned_webhook_signature_headers(payload, &signing_secrets, timestamp).unwrap();
+        let headers = headers
+            .into_iter()
+            .map(|(name, value)| (name, value.into_inner()))
+            .collect::<std::collections::HashMap<_, _>>();
+        assert_eq!(
+            headers.get(crate::headers::X_WEBHOOK_TIMESTAMP),
+            Some(&timestamp.to_string())
+        );
+
+        let signatures = headers
+            .get(crate::headers::X_WEBHOOK_SIGNATURES)
+            .unwrap()
+            .split(',')
+            .map(|signature| signature.split_once('=').unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            signatures
+                .iter()
+                .map(|(version, _)| *version)
+                .collect::<Vec<_>>(),
+            vec!["v2", "v1"]
+        );
+
+        let signed_payload = format!("{timestamp}.{payload}");
+        for ((_, signature), (_, secret)) in signatures.iter().zip(&signing_secrets) {
+            assert!(crypto::HmacSha512
+                .verify_signature(
+                    secret.peek().as_bytes(),
+                    &hex::decode(signature).unwrap(),
+                    signed_payload.as_bytes(),
+                )
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn test_get_versioned_webhook_signature_headers_without_secrets() {
+        assert!(
+            get_versioned_webhook_signature_headers("{}", &[], 1_717_000_000)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -193,6 +193,7 @@ pub async fn retry_delivery_attempt(
         &event_to_retry.primary_object_id,
         event_to_retry.event_type,
         delivery_attempt,
+        event_to_retry.webhook_endpoint_id.as_deref(),
     );
 
     let now = common_utils::date_time::now();
@@ -212,6 +213,7 @@ pub async fn retry_delivery_attempt(
         request: event_to_retry.request,
         response: None,
         delivery_attempt: Some(delivery_attempt),
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
     };
 
     let event = store
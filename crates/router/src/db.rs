@@ -13,12 +13,14 @@ pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
+pub mod dispute_alert;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod incoming_webhook_dead_letter;
 mod kafka_store;
 pub mod locker_mock_up;
 pub mod mandate;
@@ -34,6 +36,7 @@ pub mod role;
 pub mod routing_algorithm;
 pub mod user;
 pub mod user_role;
+pub mod vault_entry;
 
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate},
@@ -83,6 +86,7 @@ pub trait StorageInterface:
     + customers::CustomerInterface
     + dashboard_metadata::DashboardMetadataInterface
     + dispute::DisputeInterface
+    + dispute_alert::DisputeAlertInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
     + file::FileMetadataInterface
@@ -112,6 +116,7 @@ pub trait StorageInterface:
     + OrganizationInterface
     + routing_algorithm::RoutingAlgorithmInterface
     + gsm::GsmInterface
+    + incoming_webhook_dead_letter::IncomingWebhookDeadLetterInterface
     + user::UserInterface
     + user_role::UserRoleInterface
     + authorization::AuthorizationInterface
@@ -119,6 +124,7 @@ pub trait StorageInterface:
     + health_check::HealthCheckDbInterface
     + role::RoleInterface
     + authentication::AuthenticationInterface
+    + vault_entry::VaultEntryInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -172,6 +172,7 @@ impl DisputeInterface for MockDb {
             evidence,
             merchant_connector_id: dispute.merchant_connector_id,
             dispute_amount: dispute.dispute_amount,
+            policy_decision: None,
         };
 
         locked_disputes.push(new_dispute.clone());
@@ -353,6 +354,9 @@ impl DisputeInterface for MockDb {
             storage::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
+            storage::DisputeUpdate::PolicyDecisionUpdate { policy_decision } => {
+                dispute_to_update.policy_decision = Some(policy_decision);
+            }
         }
 
         dispute_to_update.modified_at = now;
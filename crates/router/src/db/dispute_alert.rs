@@ -0,0 +1,181 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait DisputeAlertInterface {
+    async fn insert_dispute_alert(
+        &self,
+        dispute_alert_new: storage::DisputeAlertNew,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError>;
+
+    async fn find_dispute_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError>;
+
+    async fn find_dispute_alert_by_merchant_id_provider_alert_id(
+        &self,
+        merchant_id: &str,
+        provider: enums::DisputeAlertProvider,
+        provider_alert_id: &str,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError>;
+
+    async fn update_dispute_alert(
+        &self,
+        this: storage::DisputeAlert,
+        dispute_alert: storage::DisputeAlertUpdate,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DisputeAlertInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_dispute_alert(
+        &self,
+        dispute_alert_new: storage::DisputeAlertNew,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        dispute_alert_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_dispute_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DisputeAlert::find_by_merchant_id_alert_id(&conn, merchant_id, alert_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_dispute_alert_by_merchant_id_provider_alert_id(
+        &self,
+        merchant_id: &str,
+        provider: enums::DisputeAlertProvider,
+        provider_alert_id: &str,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DisputeAlert::find_by_merchant_id_provider_provider_alert_id(
+            &conn,
+            merchant_id,
+            provider,
+            provider_alert_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_dispute_alert(
+        &self,
+        this: storage::DisputeAlert,
+        dispute_alert: storage::DisputeAlertUpdate,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, dispute_alert)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DisputeAlertInterface for MockDb {
+    async fn insert_dispute_alert(
+        &self,
+        _dispute_alert_new: storage::DisputeAlertNew,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_dispute_alert_by_merchant_id_alert_id(
+        &self,
+        _merchant_id: &str,
+        _alert_id: &str,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_dispute_alert_by_merchant_id_provider_alert_id(
+        &self,
+        _merchant_id: &str,
+        _provider: enums::DisputeAlertProvider,
+        _provider_alert_id: &str,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_dispute_alert(
+        &self,
+        _this: storage::DisputeAlert,
+        _dispute_alert: storage::DisputeAlertUpdate,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl DisputeAlertInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_dispute_alert(
+        &self,
+        dispute_alert_new: storage::DisputeAlertNew,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        self.diesel_store
+            .insert_dispute_alert(dispute_alert_new)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_dispute_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        self.diesel_store
+            .find_dispute_alert_by_merchant_id_alert_id(merchant_id, alert_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_dispute_alert_by_merchant_id_provider_alert_id(
+        &self,
+        merchant_id: &str,
+        provider: enums::DisputeAlertProvider,
+        provider_alert_id: &str,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        self.diesel_store
+            .find_dispute_alert_by_merchant_id_provider_alert_id(
+                merchant_id,
+                provider,
+                provider_alert_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_dispute_alert(
+        &self,
+        this: storage::DisputeAlert,
+        dispute_alert: storage::DisputeAlertUpdate,
+    ) -> CustomResult<storage::DisputeAlert, errors::StorageError> {
+        self.diesel_store
+            .update_dispute_alert(this, dispute_alert)
+            .await
+    }
+}
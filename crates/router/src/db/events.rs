@@ -728,6 +728,7 @@ mod tests {
                     request: None,
                     response: None,
                     delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
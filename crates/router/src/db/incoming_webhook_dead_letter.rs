@@ -0,0 +1,330 @@
+use common_utils::ext_traits::AsyncExt;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage::{self, enums},
+    },
+};
+
+#[async_trait::async_trait]
+pub trait IncomingWebhookDeadLetterInterface
+where
+    domain::IncomingWebhookDeadLetter: Conversion<
+        DstType = storage::IncomingWebhookDeadLetter,
+        NewDstType = storage::IncomingWebhookDeadLetterNew,
+    >,
+{
+    async fn insert_incoming_webhook_dead_letter(
+        &self,
+        dead_letter: domain::IncomingWebhookDeadLetter,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError>;
+
+    async fn find_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+        &self,
+        merchant_id: &str,
+        dead_letter_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError>;
+
+    async fn find_incoming_webhook_dead_letter_by_merchant_id_connector_body_hash(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        body_hash: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_incoming_webhook_dead_letters_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        status: Option<enums::IncomingWebhookDeadLetterStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookDeadLetter>, errors::StorageError>;
+
+    async fn update_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+        &self,
+        merchant_id: &str,
+        dead_letter_id: &str,
+        dead_letter: domain::IncomingWebhookDeadLetterUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookDeadLetterInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_incoming_webhook_dead_letter(
+        &self,
+        dead_letter: domain::IncomingWebhookDeadLetter,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        dead_letter
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+        &self,
+        merchant_id: &str,
+        dead_letter_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookDeadLetter::find_by_merchant_id_dead_letter_id(
+            &conn,
+            merchant_id,
+            dead_letter_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(merchant_key_store.key.get_inner())
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_incoming_webhook_dead_letter_by_merchant_id_connector_body_hash(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        body_hash: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookDeadLetter::find_by_merchant_id_connector_body_hash(
+            &conn,
+            merchant_id,
+            connector,
+            body_hash,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(merchant_key_store.key.get_inner())
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_dead_letters_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        status: Option<enums::IncomingWebhookDeadLetterStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookDeadLetter>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookDeadLetter::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            status,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|dead_letters| async {
+            let mut domain_dead_letters = Vec::with_capacity(dead_letters.len());
+            for dead_letter in dead_letters.into_iter() {
+                domain_dead_letters.push(
+                    dead_letter
+                        .convert(merchant_key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_dead_letters)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+        &self,
+        merchant_id: &str,
+        dead_letter_id: &str,
+        dead_letter: domain::IncomingWebhookDeadLetterUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::IncomingWebhookDeadLetter::update_by_merchant_id_dead_letter_id(
+            &conn,
+            merchant_id,
+            dead_letter_id,
+            dead_letter.into(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(merchant_key_store.key.get_inner())
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookDeadLetterInterface for MockDb {
+    async fn insert_incoming_webhook_dead_letter(
+        &self,
+        _dead_letter: domain::IncomingWebhookDeadLetter,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+        &self,
+        _merchant_id: &str,
+        _dead_letter_id: &str,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_incoming_webhook_dead_letter_by_merchant_id_connector_body_hash(
+        &self,
+        _merchant_id: &str,
+        _connector: &str,
+        _body_hash: &str,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_incoming_webhook_dead_letters_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &str,
+        _status: Option<enums::IncomingWebhookDeadLetterStatus>,
+        _created_after: Option<time::PrimitiveDateTime>,
+        _created_before: Option<time::PrimitiveDateTime>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookDeadLetter>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+        &self,
+        _merchant_id: &str,
+        _dead_letter_id: &str,
+        _dead_letter: domain::IncomingWebhookDeadLetterUpdate,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookDeadLetterInterface for KafkaStore {
+    async fn insert_incoming_webhook_dead_letter(
+        &self,
+        dead_letter: domain::IncomingWebhookDeadLetter,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        self.diesel_store
+            .insert_incoming_webhook_dead_letter(dead_letter, merchant_key_store)
+            .await
+    }
+
+    async fn find_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+        &self,
+        merchant_id: &str,
+        dead_letter_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        self.diesel_store
+            .find_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+                merchant_id,
+                dead_letter_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn find_incoming_webhook_dead_letter_by_merchant_id_connector_body_hash(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        body_hash: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        self.diesel_store
+            .find_incoming_webhook_dead_letter_by_merchant_id_connector_body_hash(
+                merchant_id,
+                connector,
+                body_hash,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_incoming_webhook_dead_letters_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        status: Option<enums::IncomingWebhookDeadLetterStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::IncomingWebhookDeadLetter>, errors::StorageError> {
+        self.diesel_store
+            .list_incoming_webhook_dead_letters_by_merchant_id_constraints(
+                merchant_id,
+                status,
+                created_after,
+                created_before,
+                limit,
+                offset,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn update_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+        &self,
+        merchant_id: &str,
+        dead_letter_id: &str,
+        dead_letter: domain::IncomingWebhookDeadLetterUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::IncomingWebhookDeadLetter, errors::StorageError> {
+        self.diesel_store
+            .update_incoming_webhook_dead_letter_by_merchant_id_dead_letter_id(
+                merchant_id,
+                dead_letter_id,
+                dead_letter,
+                merchant_key_store,
+            )
+            .await
+    }
+}
@@ -1165,6 +1165,21 @@ impl PaymentAttemptInterface for KafkaStore {
             .await
     }
 
+    async fn find_payment_attempt_by_merchant_id_acquirer_reference_number(
+        &self,
+        merchant_id: &str,
+        acquirer_reference_number: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::PaymentAttempt, errors::DataStorageError> {
+        self.diesel_store
+            .find_payment_attempt_by_merchant_id_acquirer_reference_number(
+                merchant_id,
+                acquirer_reference_number,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
         &self,
         payment_id: &str,
@@ -1287,6 +1302,27 @@ impl PaymentAttemptInterface for KafkaStore {
             .find_attempts_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
             .await
     }
+
+    async fn find_attempts_by_merchant_id_amount_currency_time_range(
+        &self,
+        merchant_id: &str,
+        amount: i64,
+        currency: common_enums::Currency,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .find_attempts_by_merchant_id_amount_currency_time_range(
+                merchant_id,
+                amount,
+                currency,
+                start_time,
+                end_time,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1342,6 +1378,27 @@ impl PaymentIntentInterface for KafkaStore {
             .await
     }
 
+    async fn filter_payment_intents_by_profile_id_status_created_before(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        statuses: &[enums::IntentStatus],
+        created_before: PrimitiveDateTime,
+        limit: i64,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PaymentIntent>, errors::DataStorageError> {
+        self.diesel_store
+            .filter_payment_intents_by_profile_id_status_created_before(
+                merchant_id,
+                profile_id,
+                statuses,
+                created_before,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payment_intent_by_constraints(
         &self,
@@ -1438,6 +1495,17 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    async fn list_payment_methods_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        after_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_methods_by_merchant_id_after_id(merchant_id, after_id, limit)
+            .await
+    }
+
     async fn find_payment_method_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -1535,6 +1603,21 @@ impl PayoutAttemptInterface for KafkaStore {
             .await
     }
 
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::PayoutAttempt, errors::DataStorageError> {
+        self.diesel_store
+            .find_payout_attempt_by_merchant_id_connector_payout_id(
+                merchant_id,
+                connector_payout_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn update_payout_attempt(
         &self,
         this: &storage::PayoutAttempt,
@@ -28,6 +28,15 @@ pub trait PaymentMethodInterface {
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
 
+    /// Lists the payment methods of the merchant in the order they were created, starting after
+    /// the payment method with the given row ID when one is given
+    async fn list_payment_methods_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        after_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
+
     async fn find_payment_method_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -350,6 +359,24 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn list_payment_methods_by_merchant_id_after_id(
+            &self,
+            merchant_id: &str,
+            after_id: Option<i32>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_merchant_id_after_id(
+                &conn,
+                merchant_id,
+                after_id,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn find_payment_method_by_customer_id_merchant_id_status(
             &self,
@@ -526,6 +553,24 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn list_payment_methods_by_merchant_id_after_id(
+            &self,
+            merchant_id: &str,
+            after_id: Option<i32>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_merchant_id_after_id(
+                &conn,
+                merchant_id,
+                after_id,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn find_payment_method_by_customer_id_merchant_id_status(
             &self,
@@ -690,6 +735,23 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn list_payment_methods_by_merchant_id_after_id(
+        &self,
+        merchant_id: &str,
+        after_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let mut payment_methods_found: Vec<storage_types::PaymentMethod> = payment_methods
+            .iter()
+            .filter(|pm| pm.merchant_id == merchant_id && after_id.map_or(true, |id| pm.id > id))
+            .cloned()
+            .collect();
+        payment_methods_found.sort_by_key(|pm| pm.id);
+        payment_methods_found.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(payment_methods_found)
+    }
+
     async fn find_payment_method_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -768,3 +830,69 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mockdb_list_payment_methods_by_merchant_id_after_id() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        for (merchant_id, payment_method_id) in [
+            ("merchant_1", "pm_1"),
+            ("merchant_2", "pm_2"),
+            ("merchant_1", "pm_3"),
+            ("merchant_1", "pm_4"),
+        ] {
+            mockdb
+                .insert_payment_method(
+                    storage_types::PaymentMethodNew {
+                        customer_id: "cus_1".to_string(),
+                        merchant_id: merchant_id.to_string(),
+                        payment_method_id: payment_method_id.to_string(),
+                        ..Default::default()
+                    },
+                    MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap();
+        }
+
+        let first_chunk = mockdb
+            .list_payment_methods_by_merchant_id_after_id("merchant_1", None, 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            first_chunk
+                .iter()
+                .map(|pm| pm.payment_method_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["pm_1", "pm_3"]
+        );
+
+        let cursor = first_chunk.last().map(|pm| pm.id);
+        let second_chunk = mockdb
+            .list_payment_methods_by_merchant_id_after_id("merchant_1", cursor, 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            second_chunk
+                .iter()
+                .map(|pm| pm.payment_method_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["pm_4"]
+        );
+
+        let cursor = second_chunk.last().map(|pm| pm.id);
+        assert!(mockdb
+            .list_payment_methods_by_merchant_id_after_id("merchant_1", cursor, 2)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -386,6 +386,8 @@ mod storage {
                         profile_id: new.profile_id.clone(),
                         updated_by: new.updated_by.clone(),
                         merchant_connector_id: new.merchant_connector_id.clone(),
+                        approval_details: new.approval_details.clone(),
+                        fulfilment_payout_id: new.fulfilment_payout_id.clone(),
                     };
 
                     let field = format!(
@@ -822,6 +824,8 @@ impl RefundInterface for MockDb {
             profile_id: new.profile_id,
             updated_by: new.updated_by,
             merchant_connector_id: new.merchant_connector_id,
+            approval_details: new.approval_details,
+            fulfilment_payout_id: new.fulfilment_payout_id,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -0,0 +1,200 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait VaultEntryInterface {
+    async fn insert_vault_entry(
+        &self,
+        new: storage::VaultEntryNew,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError>;
+
+    async fn find_vault_entry_by_merchant_id_customer_id_vault_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        vault_reference: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError>;
+
+    async fn find_vault_entry_by_merchant_id_customer_id_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultEntry>, errors::StorageError>;
+
+    async fn delete_vault_entry_by_merchant_id_customer_id_vault_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        vault_reference: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VaultEntryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_vault_entry(
+        &self,
+        new: storage::VaultEntryNew,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        new.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_vault_entry_by_merchant_id_customer_id_vault_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        vault_reference: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultEntry::find_by_merchant_id_customer_id_vault_reference(
+            &conn,
+            merchant_id,
+            customer_id,
+            vault_reference,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_vault_entry_by_merchant_id_customer_id_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultEntry::find_optional_by_merchant_id_customer_id_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            fingerprint,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_vault_entry_by_merchant_id_customer_id_vault_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        vault_reference: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultEntry::delete_by_merchant_id_customer_id_vault_reference(
+            &conn,
+            merchant_id,
+            customer_id,
+            vault_reference,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultEntryInterface for MockDb {
+    async fn insert_vault_entry(
+        &self,
+        _new: storage::VaultEntryNew,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_vault_entry_by_merchant_id_customer_id_vault_reference(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &str,
+        _vault_reference: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_vault_entry_by_merchant_id_customer_id_fingerprint(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &str,
+        _fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_vault_entry_by_merchant_id_customer_id_vault_reference(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &str,
+        _vault_reference: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultEntryInterface for KafkaStore {
+    async fn insert_vault_entry(
+        &self,
+        new: storage::VaultEntryNew,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        self.diesel_store.insert_vault_entry(new).await
+    }
+
+    async fn find_vault_entry_by_merchant_id_customer_id_vault_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        vault_reference: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        self.diesel_store
+            .find_vault_entry_by_merchant_id_customer_id_vault_reference(
+                merchant_id,
+                customer_id,
+                vault_reference,
+            )
+            .await
+    }
+
+    async fn find_vault_entry_by_merchant_id_customer_id_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_vault_entry_by_merchant_id_customer_id_fingerprint(
+                merchant_id,
+                customer_id,
+                fingerprint,
+            )
+            .await
+    }
+
+    async fn delete_vault_entry_by_merchant_id_customer_id_vault_reference(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        vault_reference: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        self.diesel_store
+            .delete_vault_entry_by_merchant_id_customer_id_vault_reference(
+                merchant_id,
+                customer_id,
+                vault_reference,
+            )
+            .await
+    }
+}
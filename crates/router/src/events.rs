@@ -17,6 +17,7 @@ pub mod audit_events;
 pub mod connector_api_logs;
 pub mod event_logger;
 pub mod outgoing_webhook_logs;
+pub mod webhook_reliability_logs;
 #[derive(Debug, Serialize, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum EventType {
@@ -30,6 +31,7 @@ pub enum EventType {
     AuditEvent,
     #[cfg(feature = "payouts")]
     Payout,
+    WebhookReliabilityLogs,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
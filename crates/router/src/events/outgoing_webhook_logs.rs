@@ -43,6 +43,8 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    #[cfg(feature = "payouts")]
+    Payout { payout_id: String, content: Value },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -74,6 +76,12 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&mandate_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            #[cfg(feature = "payouts")]
+            Self::PayoutDetails(payout_payload) => Some(OutgoingWebhookEventContent::Payout {
+                payout_id: payout_payload.payout_id.clone(),
+                content: masking::masked_serialize(&payout_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
         }
     }
 }
@@ -0,0 +1,72 @@
+use api_models::enums::{PaymentMethod, PaymentMethodType};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use super::EventType;
+use crate::services::kafka::KafkaMessage;
+
+/// Whether the webhook expected for a payment arrived within its expected window
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookArrivalStatus {
+    Received,
+    ReceivedLate,
+    Missed,
+    /// The webhook did not arrive within its expected window, but the payment had already been
+    /// resolved by a sync with the connector
+    ResolvedWithoutWebhook,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct WebhookReliabilityEvent {
+    merchant_id: String,
+    payment_id: String,
+    attempt_id: String,
+    connector: String,
+    payment_method: PaymentMethod,
+    payment_method_type: Option<PaymentMethodType>,
+    arrival_status: WebhookArrivalStatus,
+    expected_window_secs: i64,
+    arrival_latency_secs: Option<i64>,
+    created_at_timestamp: i128,
+}
+
+impl WebhookReliabilityEvent {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        merchant_id: String,
+        payment_id: String,
+        attempt_id: String,
+        connector: String,
+        payment_method: PaymentMethod,
+        payment_method_type: Option<PaymentMethodType>,
+        arrival_status: WebhookArrivalStatus,
+        expected_window_secs: i64,
+        arrival_latency_secs: Option<i64>,
+    ) -> Self {
+        Self {
+            merchant_id,
+            payment_id,
+            attempt_id,
+            connector,
+            payment_method,
+            payment_method_type,
+            arrival_status,
+            expected_window_secs,
+            arrival_latency_secs,
+            created_at_timestamp: OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000,
+        }
+    }
+}
+
+impl KafkaMessage for WebhookReliabilityEvent {
+    fn event_type(&self) -> EventType {
+        EventType::WebhookReliabilityLogs
+    }
+
+    fn key(&self) -> String {
+        format!("{}_{}", self.merchant_id, self.attempt_id)
+    }
+}
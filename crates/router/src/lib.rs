@@ -69,6 +69,8 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURES: &str = "X-Webhook-Signatures";
+    pub const X_WEBHOOK_TIMESTAMP: &str = "X-Webhook-Timestamp";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -135,6 +137,7 @@ pub fn mk_app(
             .service(routes::MerchantAccount::server(state.clone()))
             .service(routes::ApiKeys::server(state.clone()))
             .service(routes::Files::server(state.clone()))
+            .service(routes::BulkOperations::server(state.clone()))
             .service(routes::Disputes::server(state.clone()))
             .service(routes::Analytics::server(state.clone()))
             .service(routes::Routing::server(state.clone()))
@@ -144,7 +147,8 @@ pub fn mk_app(
             .service(routes::User::server(state.clone()))
             .service(routes::ConnectorOnboarding::server(state.clone()))
             .service(routes::Verify::server(state.clone()))
-            .service(routes::WebhookEvents::server(state.clone()));
+            .service(routes::WebhookEvents::server(state.clone()))
+            .service(routes::IncomingWebhookDeadLetters::server(state.clone()));
     }
 
     #[cfg(feature = "payouts")]
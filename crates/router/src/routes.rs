@@ -3,6 +3,8 @@ pub mod api_keys;
 pub mod app;
 #[cfg(feature = "olap")]
 pub mod blocklist;
+#[cfg(feature = "olap")]
+pub mod bulk_operations;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
@@ -63,7 +65,9 @@ pub use self::app::{
     MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll, Refunds, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    Blocklist, BulkOperations, IncomingWebhookDeadLetters, Routing, Verify, WebhookEvents,
+};
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
 #[cfg(feature = "olap")]
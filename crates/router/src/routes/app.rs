@@ -19,8 +19,12 @@ use tokio::sync::oneshot;
 
 #[cfg(feature = "olap")]
 use super::blocklist;
+#[cfg(feature = "olap")]
+use super::bulk_operations::{bulk_operations_create, bulk_operations_retrieve};
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
+#[cfg(feature = "olap")]
+use super::locker_migration;
 #[cfg(feature = "payouts")]
 use super::payouts::*;
 #[cfg(feature = "olap")]
@@ -333,6 +337,9 @@ impl Payments {
                 )
                 .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)))
                 .service(web::resource("/filter_v2").route(web::get().to(get_payment_filters)))
+                .service(
+                    web::resource("/{payment_id}/timeline").route(web::get().to(payments_timeline)),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -346,6 +353,10 @@ impl Payments {
                     web::resource("/sync")
                         .route(web::post().to(payments_retrieve_with_gateway_creds)),
                 )
+                .service(
+                    web::resource("/card_verification")
+                        .route(web::post().to(payments_card_verification)),
+                )
                 .service(
                     web::resource("/{payment_id}")
                         .route(web::get().to(payments_retrieve))
@@ -715,7 +726,9 @@ impl Refunds {
         {
             route = route
                 .service(web::resource("/list").route(web::post().to(refunds_list)))
-                .service(web::resource("/filter").route(web::post().to(refunds_filter_list)));
+                .service(web::resource("/filter").route(web::post().to(refunds_filter_list)))
+                .service(web::resource("/{id}/approve").route(web::post().to(refunds_approve)))
+                .service(web::resource("/{id}/reject").route(web::post().to(refunds_reject)));
         }
         #[cfg(feature = "oltp")]
         {
@@ -864,6 +877,11 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/vault")
+                    .route(web::get().to(locker_migration::retrieve_vault_provider))
+                    .route(web::post().to(locker_migration::migrate_vault)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -1043,6 +1061,20 @@ impl Disputes {
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(retrieve_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/{dispute_id}/assemble")
+                    .route(web::post().to(assemble_dispute_evidence)),
+            )
+            .service(
+                web::resource("/policy/{profile_id}")
+                    .route(web::put().to(upsert_dispute_policy))
+                    .route(web::get().to(retrieve_dispute_policy))
+                    .route(web::delete().to(delete_dispute_policy)),
+            )
+            .service(web::resource("/alerts").route(web::post().to(receive_dispute_alert)))
+            .service(
+                web::resource("/alerts/{alert_id}").route(web::get().to(retrieve_dispute_alert)),
+            )
             .service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)))
     }
 }
@@ -1073,6 +1105,19 @@ impl Files {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct BulkOperations;
+
+#[cfg(feature = "olap")]
+impl BulkOperations {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/bulk_operations")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(bulk_operations_create)))
+            .service(web::resource("/{job_id}").route(web::get().to(bulk_operations_retrieve)))
+    }
+}
+
 pub struct Cache;
 
 impl Cache {
@@ -1131,6 +1176,14 @@ impl BusinessProfile {
                     .service(
                         web::resource("/toggle_connector_agnostic_mit")
                             .route(web::post().to(toggle_connector_agnostic_mit)),
+                    )
+                    .service(
+                        web::resource("/webhook_secret")
+                            .route(web::get().to(webhook_secret_retrieve)),
+                    )
+                    .service(
+                        web::resource("/webhook_secret/rotate")
+                            .route(web::post().to(webhook_secret_rotate)),
                     ),
             )
     }
@@ -1310,3 +1363,26 @@ impl WebhookEvents {
             )
     }
 }
+
+#[cfg(feature = "olap")]
+pub struct IncomingWebhookDeadLetters;
+
+#[cfg(feature = "olap")]
+impl IncomingWebhookDeadLetters {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/incoming_webhooks/{merchant_id}/dead_letters")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(list_incoming_webhook_dead_letters)))
+            .service(
+                web::scope("/{dead_letter_id}")
+                    .service(
+                        web::resource("")
+                            .route(web::get().to(retrieve_incoming_webhook_dead_letter)),
+                    )
+                    .service(
+                        web::resource("replay")
+                            .route(web::post().to(replay_incoming_webhook_dead_letter)),
+                    ),
+            )
+    }
+}
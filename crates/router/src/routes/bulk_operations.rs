@@ -0,0 +1,96 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::bulk_operations as bulk_operations_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, bulk_operations},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Bulk Operations - Create
+///
+/// Starts processing the payment creates, captures, voids and refunds described by the rows of a file uploaded with the purpose `bulk_operations`. The rows are processed in the background, and the status of the job can be polled using the bulk operations retrieve API
+#[utoipa::path(
+    post,
+    path = "/bulk_operations",
+    request_body = BulkOperationCreateRequest,
+    responses(
+        (status = 200, description = "Bulk operation job created", body = BulkOperationResponse),
+        (status = 400, description = "Invalid bulk operations file"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Bulk Operations",
+    operation_id = "Create a Bulk Operation",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BulkOperationsCreate))]
+pub async fn bulk_operations_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<bulk_operations_api::BulkOperationCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::BulkOperationsCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            bulk_operations::create_bulk_operation(state, auth.merchant_account, req)
+        },
+        // The rows of the file can refund payments as well as create, capture and void them
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuthWithPermissions(&[Permission::PaymentWrite, Permission::RefundWrite]),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Bulk Operations - Retrieve
+///
+/// Retrieves the status and the progress of a bulk operation job, along with the identifier of its results file
+#[utoipa::path(
+    get,
+    path = "/bulk_operations/{job_id}",
+    params(
+        ("job_id" = String, Path, description = "The identifier for the bulk operation job")
+    ),
+    responses(
+        (status = 200, description = "Bulk operation job retrieved", body = BulkOperationResponse),
+        (status = 404, description = "Bulk operation job not found")
+    ),
+    tag = "Bulk Operations",
+    operation_id = "Retrieve a Bulk Operation",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BulkOperationsRetrieve))]
+pub async fn bulk_operations_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::BulkOperationsRetrieve;
+    let job_id = bulk_operations_api::BulkOperationJobId {
+        job_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        job_id,
+        |state, auth, req, _| {
+            bulk_operations::retrieve_bulk_operation(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
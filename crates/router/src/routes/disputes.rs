@@ -268,6 +268,141 @@ pub async fn retrieve_dispute_evidence(
     .await
 }
 
+/// Disputes - Assemble Dispute Evidence
+///
+/// To pre-fill the evidence for a dispute from the details of the disputed payment. The assembled
+/// evidence is not submitted, and can be reviewed and completed before submitting it.
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence/{dispute_id}/assemble",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute evidence was assembled successfully", body = SubmitEvidenceRequest),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Assemble Dispute Evidence",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AssembleDisputeEvidence))]
+pub async fn assemble_dispute_evidence(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::AssembleDisputeEvidence;
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        dispute_id,
+        |state, auth, req, _| {
+            disputes::evidence_assembly::assemble_dispute_evidence(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputePolicyUpsert))]
+pub async fn upsert_dispute_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<dispute_models::DisputePolicyRequest>,
+) -> HttpResponse {
+    let flow = Flow::DisputePolicyUpsert;
+    let profile_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            disputes::policies::upsert_dispute_policy(
+                state,
+                auth.merchant_account,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputePolicyRetrieve))]
+pub async fn retrieve_dispute_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DisputePolicyRetrieve;
+    let profile_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, auth, profile_id, _| {
+            disputes::policies::retrieve_dispute_policy(state, auth.merchant_account, profile_id)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputePolicyDelete))]
+pub async fn delete_dispute_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DisputePolicyDelete;
+    let profile_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, auth, profile_id, _| {
+            disputes::policies::delete_dispute_policy(state, auth.merchant_account, profile_id)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Disputes - Delete Evidence attached to a Dispute
 ///
 /// To delete an evidence file attached to a dispute
@@ -305,3 +440,89 @@ pub async fn delete_dispute_evidence(
     ))
     .await
 }
+
+/// Disputes - Receive Dispute Alert
+#[utoipa::path(
+    post,
+    path = "/disputes/alerts",
+    request_body = DisputeAlertRequest,
+    responses(
+        (status = 200, description = "The dispute alert was received and processed", body = DisputeAlertResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Disputes",
+    operation_id = "Receive a Dispute Alert",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeAlertReceive))]
+pub async fn receive_dispute_alert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::DisputeAlertRequest>,
+) -> HttpResponse {
+    let flow = Flow::DisputeAlertReceive;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            disputes::alerts::receive_dispute_alert(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Disputes - Retrieve Dispute Alert
+#[utoipa::path(
+    get,
+    path = "/disputes/alerts/{alert_id}",
+    params(
+        ("alert_id" = String, Path, description = "The identifier for the dispute alert")
+    ),
+    responses(
+        (status = 200, description = "The dispute alert was retrieved successfully", body = DisputeAlertResponse),
+        (status = 404, description = "Dispute alert does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve a Dispute Alert",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeAlertRetrieve))]
+pub async fn retrieve_dispute_alert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DisputeAlertRetrieve;
+    let alert_id = dispute_models::DisputeAlertId {
+        alert_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        alert_id,
+        |state, auth, req, _| {
+            disputes::alerts::retrieve_dispute_alert(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -64,13 +64,20 @@ pub async fn get_create_file_request(
         Err(errors::ApiErrorResponse::MissingFile)
             .attach_printable("Missing / Invalid file in the request")?
     }
-    // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
-        .parse::<mime::Mime>()
-        .change_context(errors::ApiErrorResponse::MissingFileContentType)
-        .attach_printable("File content type error")?;
+    let file_type = match purpose {
+        files::FilePurpose::BulkOperations => {
+            helpers::get_bulk_operation_file_type(file_name.as_deref())
+                .ok_or(errors::ApiErrorResponse::MissingFileContentType)?
+        }
+        files::FilePurpose::DisputeEvidence => {
+            // Get file mime type using 'infer'
+            let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
+            kind.mime_type()
+                .parse::<mime::Mime>()
+                .change_context(errors::ApiErrorResponse::MissingFileContentType)
+                .attach_printable("File content type error")?
+        }
+    };
     Ok(CreateFileRequest {
         file,
         file_name,
@@ -35,6 +35,7 @@ pub enum ApiIdentifier {
     ConnectorOnboarding,
     Recon,
     Poll,
+    BulkOperations,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -121,7 +122,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
             | Flow::PaymentsAuthorize
-            | Flow::GetExtendedCardInfo => Self::Payments,
+            | Flow::GetExtendedCardInfo
+            | Flow::PaymentsCardVerification
+            | Flow::PaymentsTimeline => Self::Payments,
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
@@ -136,13 +139,18 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsRetrieve
             | Flow::RefundsRetrieveForceSync
             | Flow::RefundsUpdate
-            | Flow::RefundsList => Self::Refunds,
+            | Flow::RefundsList
+            | Flow::RefundsApprove
+            | Flow::RefundsReject => Self::Refunds,
 
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::IncomingWebhookDeadLetterList
+            | Flow::IncomingWebhookDeadLetterRetrieve
+            | Flow::IncomingWebhookDeadLetterReplay => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
@@ -155,6 +163,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
+            | Flow::AssembleDisputeEvidence
+            | Flow::DisputePolicyUpsert
+            | Flow::DisputePolicyRetrieve
+            | Flow::DisputePolicyDelete
+            | Flow::DisputeAlertReceive
+            | Flow::DisputeAlertRetrieve
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
             Flow::CardsInfo => Self::CardsInfo,
@@ -169,7 +183,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::BusinessProfileDelete
             | Flow::BusinessProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Business,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::WebhookSecretRetrieve
+            | Flow::WebhookSecretRotate => Self::Business,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
@@ -178,7 +194,9 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::Verification => Self::Verification,
 
-            Flow::RustLockerMigration => Self::RustLockerMigration,
+            Flow::RustLockerMigration | Flow::VaultProviderRetrieve | Flow::VaultMigration => {
+                Self::RustLockerMigration
+            }
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
@@ -234,6 +252,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ReconVerifyToken => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
+
+            Flow::BulkOperationsCreate | Flow::BulkOperationsRetrieve => Self::BulkOperations,
         }
     }
 }
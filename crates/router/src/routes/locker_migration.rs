@@ -1,9 +1,13 @@
 use actix_web::{web, HttpRequest, HttpResponse};
-use router_env::Flow;
+use api_models::locker_migration::{VaultMigrationRequest, VaultMigrationRequestInternal};
+use router_env::{instrument, tracing, types, Flow};
 
-use super::AppState;
+use super::{lock_utils, AppState};
 use crate::{
-    core::{api_locking, locker_migration},
+    core::{
+        api_locking::{self, GetLockingInput},
+        locker_migration,
+    },
     services::{api, authentication as auth},
 };
 
@@ -25,3 +29,72 @@ pub async fn rust_locker_migration(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::VaultProviderRetrieve))]
+pub async fn retrieve_vault_provider(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::VaultProviderRetrieve;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| locker_migration::retrieve_vault_provider(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::VaultMigration))]
+pub async fn migrate_vault(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<VaultMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::VaultMigration;
+    let request_internal = VaultMigrationRequestInternal {
+        merchant_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+    let locking_action = request_internal.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            locker_migration::migrate_vault(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        &auth::AdminApiAuth,
+        locking_action,
+    ))
+    .await
+}
+
+impl GetLockingInput for VaultMigrationRequestInternal {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        // Two migrations must not be started at once for a merchant, since only one of them can
+        // be in progress at a time
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.merchant_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
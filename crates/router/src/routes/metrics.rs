@@ -20,6 +20,9 @@ counter_metric!(SUCCESSFUL_PAYMENT, GLOBAL_METER);
 
 counter_metric!(REFUND_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_REFUND, GLOBAL_METER);
+counter_metric!(REFUND_APPROVAL_REQUESTED_COUNT, GLOBAL_METER);
+counter_metric!(REFUND_APPROVAL_DECISION_COUNT, GLOBAL_METER);
+counter_metric!(REFUND_PAYOUT_FALLBACK_COUNT, GLOBAL_METER);
 
 counter_metric!(PAYMENT_CANCEL_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_CANCEL, GLOBAL_METER);
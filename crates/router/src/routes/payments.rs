@@ -126,7 +126,7 @@ pub async fn payments_create(
         payload,
         |state, auth, req, req_state| {
             authorize_verify_select::<_, Oss>(
-                payments::PaymentCreate,
+                payments::PaymentCreate::default(),
                 state,
                 req_state,
                 auth.merchant_account,
@@ -1381,6 +1381,99 @@ pub async fn retrieve_extended_card_info(
     .await
 }
 
+/// Payments - Card Verification
+///
+/// Verifies a card through a zero-amount authorization, without creating a payment for the customer. Connectors that do not support zero-amount authorizations verify the card through an authorization of a minimal amount, which is voided right after
+#[utoipa::path(
+    post,
+    path = "/payments/card_verification",
+    request_body=CardVerificationRequest,
+    responses(
+        (status = 200, description = "Card verified", body = CardVerificationResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Verify a Card",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCardVerification))]
+pub async fn payments_card_verification(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::CardVerificationRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsCardVerification;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, req_state| {
+            payments::card_verification::payments_verify_card::<Oss>(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payments - Timeline
+///
+/// Retrieves the chronological timeline of a payment, with its attempts, captures, refunds, disputes, the webhooks received and sent, and the API calls made to the connector
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/timeline",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment timeline retrieved", body = PaymentTimelineResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the Timeline of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsTimeline, payment_id))]
+#[cfg(feature = "olap")]
+pub async fn payments_timeline(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsTimeline;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth, payment_id, _| {
+            payments::timeline::get_payment_timeline(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub fn get_or_generate_payment_id(
     payload: &mut payment_types::PaymentsRequest,
 ) -> errors::RouterResult<()> {
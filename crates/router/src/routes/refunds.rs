@@ -1,9 +1,13 @@
 use actix_web::{web, HttpRequest, HttpResponse};
-use router_env::{instrument, tracing, Flow};
+use router_env::{instrument, tracing, types, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, refunds::*},
+    core::{
+        api_locking::{self, GetLockingInput},
+        refunds::*,
+    },
+    routes::lock_utils,
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
@@ -31,17 +35,37 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    // Refunds created from the dashboard record the user who created them, so that the same user
+    // can not approve them
+    if auth::is_jwt_auth(req.headers()) {
+        return Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, _| {
+                refund_create_core(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    Some(user_id),
+                    req,
+                )
+            },
+            &auth::JWTAuth(Permission::RefundWrite),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await;
+    }
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth, req, _| refund_create_core(state, auth.merchant_account, auth.key_store, req),
-        auth::auth_type(
-            &auth::ApiKeyAuth,
-            &auth::JWTAuth(Permission::RefundWrite),
-            req.headers(),
-        ),
+        |state, auth, req, _| {
+            refund_create_core(state, auth.merchant_account, auth.key_store, None, req)
+        },
+        &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -267,3 +291,72 @@ pub async fn refunds_filter_list(
     )
     .await
 }
+
+/// Refunds - Approve
+///
+/// To approve a refund which is pending approval according to the refund approval policy of the business profile, after which the refund is sent to the connector. Refunds created with an API key have to be approved by two different dashboard users
+#[instrument(skip_all, fields(flow = ?Flow::RefundsApprove))]
+#[cfg(feature = "olap")]
+pub async fn refunds_approve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundApprovalRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsApprove;
+    let mut refund_approval_req = json_payload.into_inner();
+    refund_approval_req.refund_id = path.into_inner();
+    let locking_action = refund_approval_req.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_approval_req,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, _| {
+            refund_approve_core(state, auth.merchant_account, auth.key_store, user_id, req)
+        },
+        &auth::JWTAuth(Permission::RefundApprove),
+        locking_action,
+    ))
+    .await
+}
+
+/// Refunds - Reject
+///
+/// To reject a refund which is pending approval according to the refund approval policy of the business profile, after which the refund fails without being sent to the connector
+#[instrument(skip_all, fields(flow = ?Flow::RefundsReject))]
+#[cfg(feature = "olap")]
+pub async fn refunds_reject(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundApprovalRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsReject;
+    let mut refund_approval_req = json_payload.into_inner();
+    refund_approval_req.refund_id = path.into_inner();
+    let locking_action = refund_approval_req.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_approval_req,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, _| {
+            refund_reject_core(state, auth.merchant_account, user_id, req)
+        },
+        &auth::JWTAuth(Permission::RefundApprove),
+        locking_action,
+    ))
+    .await
+}
+
+impl GetLockingInput for refunds::RefundApprovalRequest {
+    /// The same lock is held while the approval of the refund expires
+    fn get_locking_input<F>(&self, _flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        get_refund_approval_lock_action(&self.refund_id)
+    }
+}
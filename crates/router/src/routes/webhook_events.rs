@@ -1,13 +1,22 @@
 use actix_web::{web, HttpRequest, Responder};
-use router_env::{instrument, tracing, Flow};
+use router_env::{instrument, tracing, types, Flow};
 
 use crate::{
-    core::{api_locking, webhooks::webhook_events},
-    routes::AppState,
+    core::{
+        api_locking::{self, GetLockingInput},
+        payment_methods::Oss,
+        webhooks::{dead_letters, webhook_events},
+    },
+    routes::{lock_utils, AppState},
     services::{api, authentication as auth, authorization::permissions::Permission},
-    types::api::webhook_events::{
-        EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+    types::api::{
+        webhook_dead_letters::{
+            DeadLetterListConstraints, DeadLetterListRequestInternal, DeadLetterRequestInternal,
+        },
+        webhook_events::{
+            EventListConstraints, EventListRequestInternal,
+            WebhookDeliveryAttemptListRequestInternal, WebhookDeliveryRetryRequestInternal,
+        },
     },
 };
 
@@ -129,3 +138,140 @@ pub async fn retry_webhook_delivery_attempt(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookDeadLetterList))]
+pub async fn list_incoming_webhook_dead_letters(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<DeadLetterListConstraints>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookDeadLetterList;
+    let merchant_id = path.into_inner();
+    let constraints = query.into_inner();
+
+    let request_internal = DeadLetterListRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints,
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            dead_letters::list_dead_letters(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::WebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookDeadLetterRetrieve))]
+pub async fn retrieve_incoming_webhook_dead_letter(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookDeadLetterRetrieve;
+    let (merchant_id, dead_letter_id) = path.into_inner();
+
+    let request_internal = DeadLetterRequestInternal {
+        merchant_id: merchant_id.clone(),
+        dead_letter_id,
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            dead_letters::retrieve_dead_letter(
+                state,
+                request_internal.merchant_id,
+                request_internal.dead_letter_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::WebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookDeadLetterReplay))]
+pub async fn replay_incoming_webhook_dead_letter(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookDeadLetterReplay;
+    let (merchant_id, dead_letter_id) = path.into_inner();
+
+    let request_internal = DeadLetterRequestInternal {
+        merchant_id: merchant_id.clone(),
+        dead_letter_id,
+    };
+    let locking_action = request_internal.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, req_state| {
+            dead_letters::replay_dead_letter::<Oss>(
+                state,
+                req_state,
+                request_internal.merchant_id,
+                request_internal.dead_letter_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::WebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+impl GetLockingInput for DeadLetterRequestInternal {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.dead_letter_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
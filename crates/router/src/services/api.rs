@@ -105,6 +105,17 @@ pub trait ConnectorValidation: ConnectorCommon {
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         false
     }
+
+    fn validate_over_capture(
+        &self,
+        _card_network: Option<common_enums::CardNetwork>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        Err(errors::ConnectorError::NotSupported {
+            message: "Over-capture".to_string(),
+            connector: self.id(),
+        }
+        .into())
+    }
 }
 
 #[async_trait::async_trait]
@@ -91,6 +91,7 @@ impl ModuleInfo {
                 permissions: get_permission_info_from_permissions(&[
                     Permission::RefundRead,
                     Permission::RefundWrite,
+                    Permission::RefundApprove,
                 ]),
             },
             PermissionModule::MerchantAccount => Self {
@@ -99,6 +100,7 @@ impl ModuleInfo {
                 permissions: get_permission_info_from_permissions(&[
                     Permission::MerchantAccountRead,
                     Permission::MerchantAccountWrite,
+                    Permission::WebhookSecretManage,
                 ]),
             },
             PermissionModule::Connectors => Self {
@@ -215,5 +217,6 @@ fn get_group_description(group: PermissionGroup) -> &'static str {
         PermissionGroup::MerchantDetailsView => "View Merchant Details",
         PermissionGroup::MerchantDetailsManage => "Create, modify and delete Merchant Details like api keys, webhooks, etc",
         PermissionGroup::OrganizationManage => "Manage organization level tasks like create new Merchant accounts, Organization level roles, etc",
+        PermissionGroup::RefundApprovalsManage => "Approve and reject Refunds which require approval",
     }
 }
@@ -16,6 +16,7 @@ pub fn get_permissions_vec(permission_group: &PermissionGroup) -> &[Permission]
         PermissionGroup::MerchantDetailsView => &MERCHANT_DETAILS_VIEW,
         PermissionGroup::MerchantDetailsManage => &MERCHANT_DETAILS_MANAGE,
         PermissionGroup::OrganizationManage => &ORGANIZATION_MANAGE,
+        PermissionGroup::RefundApprovalsManage => &REFUND_APPROVALS_MANAGE,
     }
 }
 
@@ -75,8 +76,9 @@ pub static USERS_MANAGE: [Permission; 2] =
 
 pub static MERCHANT_DETAILS_VIEW: [Permission; 1] = [Permission::MerchantAccountRead];
 
-pub static MERCHANT_DETAILS_MANAGE: [Permission; 6] = [
+pub static MERCHANT_DETAILS_MANAGE: [Permission; 7] = [
     Permission::MerchantAccountWrite,
+    Permission::WebhookSecretManage,
     Permission::ApiKeyRead,
     Permission::ApiKeyWrite,
     Permission::MerchantAccountRead,
@@ -88,3 +90,9 @@ pub static ORGANIZATION_MANAGE: [Permission; 2] = [
     Permission::MerchantAccountCreate,
     Permission::MerchantAccountRead,
 ];
+
+pub static REFUND_APPROVALS_MANAGE: [Permission; 3] = [
+    Permission::RefundApprove,
+    Permission::RefundRead,
+    Permission::MerchantAccountRead,
+];
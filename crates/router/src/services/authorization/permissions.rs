@@ -8,10 +8,12 @@ pub enum Permission {
     PaymentWrite,
     RefundRead,
     RefundWrite,
+    RefundApprove,
     ApiKeyRead,
     ApiKeyWrite,
     MerchantAccountRead,
     MerchantAccountWrite,
+    WebhookSecretManage,
     MerchantConnectorAccountRead,
     MerchantConnectorAccountWrite,
     RoutingRead,
@@ -43,12 +45,14 @@ impl Permission {
             Self::PaymentWrite => "Create payment, download payments data",
             Self::RefundRead => "View all refunds",
             Self::RefundWrite => "Create refund, download refunds data",
+            Self::RefundApprove => "Approve and reject refunds which are pending approval",
             Self::ApiKeyRead => "View API keys",
             Self::ApiKeyWrite => "Create and update API keys",
             Self::MerchantAccountRead => "View merchant account details",
             Self::MerchantAccountWrite => {
                 "Update merchant account details, configure webhooks, manage api keys"
             }
+            Self::WebhookSecretManage => "Reveal and rotate webhook signing secrets",
             Self::MerchantConnectorAccountRead => "View connectors configured",
             Self::MerchantConnectorAccountWrite => {
                 "Create, update, verify and delete connector configurations"
@@ -42,10 +42,12 @@ pub static PREDEFINED_PERMISSIONS: Lazy<HashMap<&'static str, RoleInfo>> = Lazy:
                 Permission::PaymentWrite,
                 Permission::RefundRead,
                 Permission::RefundWrite,
+                Permission::RefundApprove,
                 Permission::ApiKeyRead,
                 Permission::ApiKeyWrite,
                 Permission::MerchantAccountRead,
                 Permission::MerchantAccountWrite,
+                Permission::WebhookSecretManage,
                 Permission::MerchantConnectorAccountRead,
                 Permission::MerchantConnectorAccountWrite,
                 Permission::RoutingRead,
@@ -107,10 +109,12 @@ pub static PREDEFINED_PERMISSIONS: Lazy<HashMap<&'static str, RoleInfo>> = Lazy:
                 Permission::PaymentWrite,
                 Permission::RefundRead,
                 Permission::RefundWrite,
+                Permission::RefundApprove,
                 Permission::ApiKeyRead,
                 Permission::ApiKeyWrite,
                 Permission::MerchantAccountRead,
                 Permission::MerchantAccountWrite,
+                Permission::WebhookSecretManage,
                 Permission::MerchantConnectorAccountRead,
                 Permission::MerchantConnectorAccountWrite,
                 Permission::RoutingRead,
@@ -148,10 +152,12 @@ pub static PREDEFINED_PERMISSIONS: Lazy<HashMap<&'static str, RoleInfo>> = Lazy:
                 Permission::PaymentWrite,
                 Permission::RefundRead,
                 Permission::RefundWrite,
+                Permission::RefundApprove,
                 Permission::ApiKeyRead,
                 Permission::ApiKeyWrite,
                 Permission::MerchantAccountRead,
                 Permission::MerchantAccountWrite,
+                Permission::WebhookSecretManage,
                 Permission::MerchantConnectorAccountRead,
                 Permission::MerchantConnectorAccountWrite,
                 Permission::RoutingRead,
@@ -56,6 +56,14 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    DisputeDeadlineReminder {
+        dispute_id: String,
+        payment_id: String,
+        amount: String,
+        currency: String,
+        challenge_required_by: String,
+        hours_left: u16,
+    },
 }
 
 pub mod html {
@@ -143,6 +151,22 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::DisputeDeadlineReminder {
+                dispute_id,
+                payment_id,
+                amount,
+                currency,
+                challenge_required_by,
+                hours_left,
+            } => format!(
+                include_str!("assets/dispute_deadline_reminder.html"),
+                dispute_id = dispute_id,
+                payment_id = payment_id,
+                amount = amount,
+                currency = currency,
+                challenge_required_by = challenge_required_by,
+                hours_left = hours_left,
+            ),
         }
     }
 }
@@ -500,3 +524,36 @@ impl EmailData for ApiKeyExpiryReminder {
         })
     }
 }
+
+pub struct DisputeDeadlineReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub dispute_id: String,
+    pub payment_id: String,
+    pub amount: String,
+    pub currency: String,
+    pub challenge_required_by: time::PrimitiveDateTime,
+    pub hours_left: u16,
+}
+
+#[async_trait::async_trait]
+impl EmailData for DisputeDeadlineReminder {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::DisputeDeadlineReminder {
+            dispute_id: self.dispute_id.clone(),
+            payment_id: self.payment_id.clone(),
+            amount: self.amount.clone(),
+            currency: self.currency.clone(),
+            challenge_required_by: format!("{} UTC", self.challenge_required_by),
+            hours_left: self.hours_left,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
@@ -6,7 +6,11 @@ use error_stack::{report, ResultExt};
 use events::{EventsError, Message, MessagingInterface};
 use rdkafka::{
     config::FromClientConfig,
-    producer::{BaseRecord, DefaultProducerContext, Producer, ThreadedProducer},
+    message::{Header, OwnedHeaders},
+    producer::{
+        BaseRecord, DefaultProducerContext, FutureProducer, FutureRecord, Producer,
+        ThreadedProducer,
+    },
 };
 #[cfg(feature = "payouts")]
 pub mod payout;
@@ -99,6 +103,7 @@ pub struct KafkaSettings {
     audit_events_topic: String,
     #[cfg(feature = "payouts")]
     payout_analytics_topic: String,
+    webhook_reliability_logs_topic: String,
 }
 
 impl KafkaSettings {
@@ -171,6 +176,15 @@ impl KafkaSettings {
             ))
         })?;
 
+        common_utils::fp_utils::when(
+            self.webhook_reliability_logs_topic.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka Webhook Reliability Logs topic must not be empty".into(),
+                ))
+            },
+        )?;
+
         Ok(())
     }
 }
@@ -178,6 +192,7 @@ impl KafkaSettings {
 #[derive(Clone, Debug)]
 pub struct KafkaProducer {
     producer: Arc<RdKafkaProducer>,
+    webhook_producer: Arc<RdKafkaFutureProducer>,
     intent_analytics_topic: String,
     attempt_analytics_topic: String,
     refund_analytics_topic: String,
@@ -188,6 +203,7 @@ pub struct KafkaProducer {
     audit_events_topic: String,
     #[cfg(feature = "payouts")]
     payout_analytics_topic: String,
+    webhook_reliability_logs_topic: String,
 }
 
 struct RdKafkaProducer(ThreadedProducer<DefaultProducerContext>);
@@ -198,6 +214,20 @@ impl std::fmt::Debug for RdKafkaProducer {
     }
 }
 
+/// Producer used for publishing outgoing webhooks, which waits for the delivery of each message
+/// to be acknowledged by the broker
+struct RdKafkaFutureProducer(FutureProducer);
+
+impl std::fmt::Debug for RdKafkaFutureProducer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RdKafkaFutureProducer")
+    }
+}
+
+/// Time within which the delivery of an outgoing webhook published to Kafka must be acknowledged
+/// by the broker, failing which the webhook is retried
+const WEBHOOK_MESSAGE_TIMEOUT_MS: &str = "5000";
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum KafkaError {
     #[error("Generic Kafka Error")]
@@ -218,6 +248,13 @@ impl KafkaProducer {
                 )
                 .change_context(KafkaError::InitializationError)?,
             )),
+            webhook_producer: Arc::new(RdKafkaFutureProducer(
+                rdkafka::ClientConfig::new()
+                    .set("bootstrap.servers", conf.brokers.join(","))
+                    .set("message.timeout.ms", WEBHOOK_MESSAGE_TIMEOUT_MS)
+                    .create()
+                    .change_context(KafkaError::InitializationError)?,
+            )),
 
             intent_analytics_topic: conf.intent_analytics_topic.clone(),
             attempt_analytics_topic: conf.attempt_analytics_topic.clone(),
@@ -229,6 +266,7 @@ impl KafkaProducer {
             audit_events_topic: conf.audit_events_topic.clone(),
             #[cfg(feature = "payouts")]
             payout_analytics_topic: conf.payout_analytics_topic.clone(),
+            webhook_reliability_logs_topic: conf.webhook_reliability_logs_topic.clone(),
         })
     }
 
@@ -245,6 +283,7 @@ impl KafkaProducer {
             EventType::AuditEvent => &self.audit_events_topic,
             #[cfg(feature = "payouts")]
             EventType::Payout => &self.payout_analytics_topic,
+            EventType::WebhookReliabilityLogs => &self.webhook_reliability_logs_topic,
         };
         self.producer
             .0
@@ -262,6 +301,42 @@ impl KafkaProducer {
             .change_context(KafkaError::GenericError)
     }
 
+    /// Publish a message to the specified topic, rather than a topic determined by the event type.
+    /// Used for delivering outgoing webhooks to topics configured by merchants, and hence waits for
+    /// the broker to acknowledge the delivery of the message instead of only enqueuing it.
+    pub async fn publish_message(
+        &self,
+        topic: &str,
+        key: &str,
+        payload: &[u8],
+        headers: &[(String, String)],
+    ) -> MQResult<()> {
+        let message_headers =
+            headers
+                .iter()
+                .fold(OwnedHeaders::new(), |message_headers, (name, value)| {
+                    message_headers.insert(Header {
+                        key: name,
+                        value: Some(value.as_str()),
+                    })
+                });
+
+        self.webhook_producer
+            .0
+            .send(
+                FutureRecord::to(topic)
+                    .key(key)
+                    .payload(payload)
+                    .headers(message_headers),
+                // Fail immediately if the producer queue is full, the webhook is retried later
+                std::time::Duration::ZERO,
+            )
+            .await
+            .map(|_partition_and_offset| ())
+            .map_err(|(error, message)| report!(error).attach_printable(format!("{message:?}")))
+            .change_context(KafkaError::GenericError)
+    }
+
     pub async fn log_payment_attempt(
         &self,
         attempt: &PaymentAttempt,
@@ -397,6 +472,7 @@ impl KafkaProducer {
             EventType::AuditEvent => &self.audit_events_topic,
             #[cfg(feature = "payouts")]
             EventType::Payout => &self.payout_analytics_topic,
+            EventType::WebhookReliabilityLogs => &self.webhook_reliability_logs_topic,
         }
     }
 }
@@ -51,6 +51,8 @@ pub struct KafkaPaymentAttempt<'a> {
     pub unified_code: Option<&'a String>,
     pub unified_message: Option<&'a String>,
     pub mandate_data: Option<&'a MandateDetails>,
+    pub card_verification_results: Option<String>,
+    pub authentication_details: Option<String>,
 }
 
 impl<'a> KafkaPaymentAttempt<'a> {
@@ -95,6 +97,14 @@ impl<'a> KafkaPaymentAttempt<'a> {
             unified_code: attempt.unified_code.as_ref(),
             unified_message: attempt.unified_message.as_ref(),
             mandate_data: attempt.mandate_data.as_ref(),
+            card_verification_results: attempt
+                .card_verification_results
+                .as_ref()
+                .map(|v| v.to_string()),
+            authentication_details: attempt
+                .authentication_details
+                .as_ref()
+                .map(|v| v.to_string()),
         }
     }
 }
@@ -234,6 +234,9 @@ pub type DefendDisputeType = dyn services::ConnectorIntegration<
     DefendDisputeResponse,
 >;
 
+pub type FetchDisputesType =
+    dyn services::ConnectorIntegration<api::Fetch, FetchDisputesRequestData, FetchDisputesResponse>;
+
 pub type SetupMandateRouterData =
     RouterData<api::SetupMandate, SetupMandateRequestData, PaymentsResponseData>;
 
@@ -257,6 +260,9 @@ pub type RetrieveFileRouterData =
 pub type DefendDisputeRouterData =
     RouterData<api::Defend, DefendDisputeRequestData, DefendDisputeResponse>;
 
+pub type FetchDisputesRouterData =
+    RouterData<api::Fetch, FetchDisputesRequestData, FetchDisputesResponse>;
+
 pub type MandateRevokeRouterData =
     RouterData<api::MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>;
 
@@ -337,22 +343,55 @@ pub enum AdditionalPaymentMethodConnectorResponse {
         authentication_data: Option<serde_json::Value>,
         /// Various payment checks that are done for a payment
         payment_checks: Option<serde_json::Value>,
+        /// Address verification and card security code results, normalized across connectors
+        card_verification_results: Option<api_models::payments::CardVerificationResults>,
+        /// Result of the 3DS authentication done by the connector, normalized across connectors
+        authentication_details: Option<api_models::payments::PaymentAuthenticationDetails>,
     },
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ConnectorResponseData {
     pub additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
+    /// The amount approved by the connector, which can be lower than the requested amount if the
+    /// payment was partially authorized
+    pub authorized_amount: Option<i64>,
+    /// The acquirer reference number of the payment, when the connector returns it
+    pub acquirer_reference_number: Option<String>,
 }
 
 impl ConnectorResponseData {
+    pub fn new(
+        additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
+        authorized_amount: Option<i64>,
+    ) -> Option<Self> {
+        (additional_payment_method_data.is_some() || authorized_amount.is_some()).then_some(Self {
+            additional_payment_method_data,
+            authorized_amount,
+            acquirer_reference_number: None,
+        })
+    }
+
     pub fn with_additional_payment_method_data(
         additional_payment_method_data: AdditionalPaymentMethodConnectorResponse,
     ) -> Self {
         Self {
             additional_payment_method_data: Some(additional_payment_method_data),
+            authorized_amount: None,
+            acquirer_reference_number: None,
         }
     }
+
+    pub fn with_card_verification_results(
+        card_verification_results: api_models::payments::CardVerificationResults,
+    ) -> Self {
+        Self::with_additional_payment_method_data(AdditionalPaymentMethodConnectorResponse::Card {
+            authentication_data: None,
+            payment_checks: None,
+            card_verification_results: Some(card_verification_results),
+            authentication_details: None,
+        })
+    }
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -464,6 +503,8 @@ pub struct PaymentsAuthorizeData {
     pub surcharge_details: Option<types::SurchargeDetails>,
     pub customer_id: Option<String>,
     pub request_incremental_authorization: bool,
+    pub allow_partial_authorization: bool,
+    pub l2_l3_data: Option<api_models::payments::L2L3Data>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub authentication_data: Option<AuthenticationData>,
 }
@@ -643,6 +684,9 @@ pub struct SetupMandateRequestData {
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub request_incremental_authorization: bool,
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// Whether the card is verified through the card verification API, which allows falling back
+    /// to an authorization that is voided
+    pub is_card_verification: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -703,13 +747,15 @@ impl Capturable for PaymentsAuthorizeData {
                     | common_enums::IntentStatus::Failed
                     | common_enums::IntentStatus::Processing => Some(0),
                     common_enums::IntentStatus::Cancelled
+                    | common_enums::IntentStatus::Expired
                     | common_enums::IntentStatus::PartiallyCaptured
                     | common_enums::IntentStatus::RequiresCustomerAction
                     | common_enums::IntentStatus::RequiresMerchantAction
                     | common_enums::IntentStatus::RequiresPaymentMethod
                     | common_enums::IntentStatus::RequiresConfirmation
                     | common_enums::IntentStatus::RequiresCapture
-                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+                    | common_enums::IntentStatus::PartiallyAuthorized => None,
                 }
             },
             common_enums::CaptureMethod::Manual => Some(payment_data.payment_attempt.get_total_amount()),
@@ -742,13 +788,15 @@ impl Capturable for PaymentsCaptureData {
             | common_enums::IntentStatus::PartiallyCaptured => Some(0),
             common_enums::IntentStatus::Processing
             | common_enums::IntentStatus::Cancelled
+            | common_enums::IntentStatus::Expired
             | common_enums::IntentStatus::Failed
             | common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation
             | common_enums::IntentStatus::RequiresCapture
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorized => None,
         }
     }
 }
@@ -780,13 +828,15 @@ impl Capturable for CompleteAuthorizeData {
                     common_enums::IntentStatus::Failed|
                     common_enums::IntentStatus::Processing => Some(0),
                     common_enums::IntentStatus::Cancelled
+                    | common_enums::IntentStatus::Expired
                     | common_enums::IntentStatus::PartiallyCaptured
                     | common_enums::IntentStatus::RequiresCustomerAction
                     | common_enums::IntentStatus::RequiresMerchantAction
                     | common_enums::IntentStatus::RequiresPaymentMethod
                     | common_enums::IntentStatus::RequiresConfirmation
                     | common_enums::IntentStatus::RequiresCapture
-                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+                    | common_enums::IntentStatus::PartiallyAuthorized => None,
                 }
             },
             common_enums::CaptureMethod::Manual => Some(payment_data.payment_attempt.get_total_amount()),
@@ -817,6 +867,7 @@ impl Capturable for PaymentsCancelData {
         let intent_status = common_enums::IntentStatus::foreign_from(attempt_status);
         match intent_status {
             common_enums::IntentStatus::Cancelled
+            | common_enums::IntentStatus::Expired
             | common_enums::IntentStatus::Processing
             | common_enums::IntentStatus::PartiallyCaptured => Some(0),
             common_enums::IntentStatus::Succeeded
@@ -826,7 +877,8 @@ impl Capturable for PaymentsCancelData {
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation
             | common_enums::IntentStatus::RequiresCapture
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorized => None,
         }
     }
 }
@@ -1138,6 +1190,28 @@ pub struct DefendDisputeResponse {
     pub connector_status: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct FetchDisputesRequestData {
+    /// Start of the time range for which disputes are fetched. Connectors filter on the time of
+    /// the last update of the dispute where supported, and on the creation time otherwise.
+    pub start_time: time::PrimitiveDateTime,
+    pub end_time: time::PrimitiveDateTime,
+    /// Connector specific cursor to fetch the next page of disputes
+    pub cursor: Option<String>,
+    /// ID of a single dispute to be fetched, instead of listing the disputes in the time range
+    pub connector_dispute_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct FetchDisputesResponse {
+    pub disputes: Vec<api::disputes::FetchedDispute>,
+    /// Cursor to fetch the next page of disputes, if there are more disputes to be fetched
+    pub next_cursor: Option<String>,
+    /// Disputes which were listed without the details needed to reconcile them, and have to be
+    /// fetched individually
+    pub connector_dispute_ids_to_retrieve: Vec<String>,
+}
+
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct UploadFileRequestData {
     pub file_key: String,
@@ -1508,6 +1582,8 @@ impl From<&SetupMandateRouterData> for PaymentsAuthorizeData {
             customer_id: None,
             surcharge_details: None,
             request_incremental_authorization: data.request.request_incremental_authorization,
+            allow_partial_authorization: false,
+            l2_l3_data: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: data.request.customer_acceptance.clone(),
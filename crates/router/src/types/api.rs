@@ -23,6 +23,8 @@ pub mod routing;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(feature = "olap")]
+pub mod webhook_dead_letters;
+#[cfg(feature = "olap")]
 pub mod webhook_events;
 pub mod webhooks;
 
@@ -79,6 +79,51 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
                     authentication_connector_details.parse_value("AuthenticationDetails")
                 })
                 .transpose()?,
+            authorization_expiry_config: item
+                .authorization_expiry_config
+                .map(|authorization_expiry_config| {
+                    authorization_expiry_config.parse_value("AuthorizationExpiryConfig")
+                })
+                .transpose()?,
+            intent_expiry: item.intent_expiry,
+            max_over_capture_percentage: item
+                .max_over_capture_percentage
+                .map(u8::try_from)
+                .transpose()
+                .change_context(errors::ParsingError::StructParseFailure(
+                    "max_over_capture_percentage",
+                ))?,
+            refund_approval_policy: item
+                .refund_approval_policy
+                .map(|refund_approval_policy| {
+                    refund_approval_policy.parse_value("RefundApprovalPolicy")
+                })
+                .transpose()?,
+            dispute_alert_policy: item
+                .dispute_alert_policy
+                .map(|dispute_alert_policy| dispute_alert_policy.parse_value("DisputeAlertPolicy"))
+                .transpose()?,
+            // The signing secrets of the endpoints are only returned by the webhook secret endpoint
+            webhook_endpoints: item
+                .webhook_endpoints
+                .map(|webhook_endpoints| {
+                    webhook_endpoints.parse_value::<Vec<api_models::admin::WebhookEndpoint>>(
+                        "Vec<WebhookEndpoint>",
+                    )
+                })
+                .transpose()?
+                .map(|webhook_endpoints| {
+                    webhook_endpoints
+                        .into_iter()
+                        .map(api_models::admin::WebhookEndpointResponse::from)
+                        .collect()
+                }),
+            refund_payout_fallback_policy: item
+                .refund_payout_fallback_policy
+                .map(|refund_payout_fallback_policy| {
+                    refund_payout_fallback_policy.parse_value("RefundPayoutFallbackPolicy")
+                })
+                .transpose()?,
         })
     }
 }
@@ -178,6 +223,49 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
             is_connector_agnostic_mit_enabled: None,
             is_extended_card_info_enabled: None,
             extended_card_info_config: None,
+            authorization_expiry_config: request
+                .authorization_expiry_config
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "authorization_expiry_config",
+                })?,
+            intent_expiry: request.intent_expiry.map(i64::from),
+            max_over_capture_percentage: request.max_over_capture_percentage.map(i16::from),
+            refund_approval_policy: request
+                .refund_approval_policy
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "refund_approval_policy",
+                })?,
+            dispute_alert_policy: request
+                .dispute_alert_policy
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "dispute_alert_policy",
+                })?,
+            webhook_endpoints: request
+                .webhook_endpoints
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "webhook_endpoints",
+                })?,
+            webhook_secret_versions: None,
+            refund_payout_fallback_policy: request
+                .refund_payout_fallback_policy
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "refund_payout_fallback_policy",
+                })?,
         })
     }
 }
@@ -8,7 +8,7 @@ pub struct DisputeId {
     pub dispute_id: String,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct DisputePayload {
     pub amount: String,
     pub currency: String,
@@ -22,6 +22,15 @@ pub struct DisputePayload {
     pub updated_at: Option<PrimitiveDateTime>,
 }
 
+/// A dispute pulled from the dispute list API of a connector
+#[derive(Debug, Clone)]
+pub struct FetchedDispute {
+    /// Reference to the disputed payment
+    pub payment_reference: api_models::payments::PaymentIdType,
+    pub dispute_status: api_models::enums::DisputeStatus,
+    pub details: DisputePayload,
+}
+
 #[derive(Default, Debug, Deserialize, Serialize)]
 pub struct DisputeEvidence {
     pub cancellation_policy: Option<String>,
@@ -94,4 +103,15 @@ pub trait DefendDispute:
 {
 }
 
-pub trait Dispute: super::ConnectorCommon + AcceptDispute + SubmitEvidence + DefendDispute {}
+#[derive(Debug, Clone)]
+pub struct Fetch;
+
+pub trait FetchDisputes:
+    services::ConnectorIntegration<Fetch, types::FetchDisputesRequestData, types::FetchDisputesResponse>
+{
+}
+
+pub trait Dispute:
+    super::ConnectorCommon + AcceptDispute + SubmitEvidence + DefendDispute + FetchDisputes
+{
+}
@@ -1,12 +1,15 @@
 pub use api_models::payments::{
     AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CryptoData, CustomerAcceptance, HeaderPayload, MandateAmountData, MandateData,
-    MandateTransactionType, MandateType, MandateValidationFields, NextActionType, OnlineMandate,
-    PayLaterData, PaymentIdType, PaymentListConstraints, PaymentListFilterConstraints,
-    PaymentListFilters, PaymentListFiltersV2, PaymentListResponse, PaymentListResponseV2,
-    PaymentMethodData, PaymentMethodDataRequest, PaymentMethodDataResponse, PaymentOp,
-    PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials, PaymentsApproveRequest,
-    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsExternalAuthenticationRequest,
+    CardVerificationRequest, CardVerificationResponse, CardVerificationStatus, CryptoData,
+    CustomerAcceptance, HeaderPayload, MandateAmountData, MandateData, MandateTransactionType,
+    MandateType, MandateValidationFields, NextActionType, OnlineMandate, PayLaterData,
+    PaymentIdType, PaymentListConstraints, PaymentListFilterConstraints, PaymentListFilters,
+    PaymentListFiltersV2, PaymentListResponse, PaymentListResponseV2, PaymentMethodData,
+    PaymentMethodDataRequest, PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody,
+    PaymentRetrieveBodyWithCredentials, PaymentTimelineEvent, PaymentTimelineEventDetails,
+    PaymentTimelineEventType, PaymentTimelineResponse, PaymentTimelineRoutingApproach,
+    PaymentTimelineRoutingDetails, PaymentsApproveRequest, PaymentsCancelRequest,
+    PaymentsCaptureRequest, PaymentsExternalAuthenticationRequest,
     PaymentsIncrementalAuthorizationRequest, PaymentsRedirectRequest, PaymentsRedirectionResponse,
     PaymentsRejectRequest, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
     PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
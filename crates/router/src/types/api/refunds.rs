@@ -1,6 +1,6 @@
 pub use api_models::refunds::{
-    RefundRequest, RefundResponse, RefundStatus, RefundType, RefundUpdateRequest,
-    RefundsRetrieveRequest,
+    RefundApprovalDecision, RefundApprovalDetails, RefundApprovalRequest, RefundRequest,
+    RefundResponse, RefundStatus, RefundType, RefundUpdateRequest, RefundsRetrieveRequest,
 };
 
 use super::ConnectorCommon;
@@ -17,6 +17,7 @@ impl ForeignFrom<storage_enums::RefundStatus> for RefundStatus {
             storage_enums::RefundStatus::ManualReview => Self::Review,
             storage_enums::RefundStatus::Pending => Self::Pending,
             storage_enums::RefundStatus::Success => Self::Succeeded,
+            storage_enums::RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
@@ -50,6 +50,8 @@ impl VerifyConnectorData {
             related_transaction_id: None,
             statement_descriptor_suffix: None,
             request_incremental_authorization: false,
+            allow_partial_authorization: false,
+            l2_l3_data: None,
             authentication_data: None,
             customer_acceptance: None,
         }
@@ -0,0 +1,4 @@
+pub use api_models::webhook_dead_letters::{
+    DeadLetterListConstraints, DeadLetterListItemResponse, DeadLetterListRequestInternal,
+    DeadLetterRequestInternal, DeadLetterRetrieveResponse, IncomingWebhookRequestContent,
+};
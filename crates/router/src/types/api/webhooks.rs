@@ -20,6 +20,7 @@ use crate::{
     utils::crypto,
 };
 
+#[derive(Clone)]
 pub struct IncomingWebhookRequestDetails<'a> {
     pub method: actix_web::http::Method,
     pub uri: actix_web::http::Uri,
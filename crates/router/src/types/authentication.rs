@@ -23,6 +23,8 @@ pub enum AuthenticationResponseData {
         authn_flow_type: AuthNFlowType,
         authentication_value: Option<String>,
         trans_status: common_enums::TransactionStatus,
+        /// The transaction id of the authentication at the directory server
+        ds_trans_id: Option<String>,
     },
     PostAuthNResponse {
         trans_status: common_enums::TransactionStatus,
@@ -2,6 +2,7 @@ mod address;
 pub mod behaviour;
 mod customer;
 mod event;
+mod incoming_webhook_dead_letter;
 mod merchant_account;
 mod merchant_connector_account;
 mod merchant_key_store;
@@ -13,6 +14,7 @@ pub mod user;
 pub use address::*;
 pub use customer::*;
 pub use event::*;
+pub use incoming_webhook_dead_letter::*;
 pub use merchant_account::*;
 pub use merchant_connector_account::*;
 pub use merchant_key_store::*;
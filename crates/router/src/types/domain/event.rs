@@ -28,6 +28,7 @@ pub struct Event {
     pub request: OptionalEncryptableSecretString,
     pub response: OptionalEncryptableSecretString,
     pub delivery_attempt: Option<WebhookDeliveryAttempt>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -74,6 +75,7 @@ impl super::behaviour::Conversion for Event {
             request: self.request.map(Into::into),
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 
@@ -107,6 +109,7 @@ impl super::behaviour::Conversion for Event {
                     .async_lift(|inner| types::decrypt(inner, key.peek()))
                     .await?,
                 delivery_attempt: item.delivery_attempt,
+                webhook_endpoint_id: item.webhook_endpoint_id,
             })
         }
         .await
@@ -132,6 +135,7 @@ impl super::behaviour::Conversion for Event {
             request: self.request.map(Into::into),
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 }
@@ -0,0 +1,178 @@
+use common_utils::crypto::{Encryptable, GcmAes256};
+use diesel_models::{
+    enums::IncomingWebhookDeadLetterStatus,
+    incoming_webhook_dead_letter::IncomingWebhookDeadLetterUpdateInternal,
+};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use super::types::TypeEncryption;
+use crate::errors::{CustomResult, ValidationError};
+
+#[derive(Clone, Debug)]
+pub struct IncomingWebhookDeadLetter {
+    pub dead_letter_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub body_hash: String,
+    pub request: Encryptable<Secret<String>>,
+    pub status: IncomingWebhookDeadLetterStatus,
+    pub error_code: String,
+    pub error_message: String,
+    pub error_reason: Option<String>,
+    pub failure_count: i32,
+    pub replay_count: i32,
+    pub last_replayed_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum IncomingWebhookDeadLetterUpdate {
+    /// The webhook was delivered again by the connector, and failed again
+    FailureUpdate {
+        request: Encryptable<Secret<String>>,
+        error_code: String,
+        error_message: String,
+        error_reason: Option<String>,
+        failure_count: i32,
+    },
+    /// The webhook was replayed, and failed again
+    ReplayFailureUpdate {
+        error_code: String,
+        error_message: String,
+        error_reason: Option<String>,
+        replay_count: i32,
+    },
+    /// The webhook was replayed, and was processed successfully
+    ReplaySuccessUpdate { replay_count: i32 },
+    /// The webhook was delivered again by the connector, and was processed successfully
+    ResolvedUpdate,
+}
+
+impl From<IncomingWebhookDeadLetterUpdate> for IncomingWebhookDeadLetterUpdateInternal {
+    fn from(dead_letter_update: IncomingWebhookDeadLetterUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match dead_letter_update {
+            IncomingWebhookDeadLetterUpdate::FailureUpdate {
+                request,
+                error_code,
+                error_message,
+                error_reason,
+                failure_count,
+            } => Self {
+                request: Some(request.into()),
+                status: Some(IncomingWebhookDeadLetterStatus::Pending),
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                error_reason,
+                failure_count: Some(failure_count),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            IncomingWebhookDeadLetterUpdate::ReplayFailureUpdate {
+                error_code,
+                error_message,
+                error_reason,
+                replay_count,
+            } => Self {
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                error_reason,
+                replay_count: Some(replay_count),
+                last_replayed_at: Some(now),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            IncomingWebhookDeadLetterUpdate::ReplaySuccessUpdate { replay_count } => Self {
+                status: Some(IncomingWebhookDeadLetterStatus::Resolved),
+                replay_count: Some(replay_count),
+                last_replayed_at: Some(now),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            IncomingWebhookDeadLetterUpdate::ResolvedUpdate => Self {
+                status: Some(IncomingWebhookDeadLetterStatus::Resolved),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl super::behaviour::Conversion for IncomingWebhookDeadLetter {
+    type DstType = diesel_models::incoming_webhook_dead_letter::IncomingWebhookDeadLetter;
+    type NewDstType = diesel_models::incoming_webhook_dead_letter::IncomingWebhookDeadLetterNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(
+            diesel_models::incoming_webhook_dead_letter::IncomingWebhookDeadLetter {
+                dead_letter_id: self.dead_letter_id,
+                merchant_id: self.merchant_id,
+                connector: self.connector,
+                body_hash: self.body_hash,
+                request: self.request.into(),
+                status: self.status,
+                error_code: self.error_code,
+                error_message: self.error_message,
+                error_reason: self.error_reason,
+                failure_count: self.failure_count,
+                replay_count: self.replay_count,
+                last_replayed_at: self.last_replayed_at,
+                created_at: self.created_at,
+                modified_at: self.modified_at,
+            },
+        )
+    }
+
+    async fn convert_back(
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            dead_letter_id: item.dead_letter_id,
+            merchant_id: item.merchant_id,
+            connector: item.connector,
+            body_hash: item.body_hash,
+            request: Encryptable::decrypt(item.request, key.peek(), GcmAes256)
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting incoming webhook request".to_string(),
+                })?,
+            status: item.status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+            error_reason: item.error_reason,
+            failure_count: item.failure_count,
+            replay_count: item.replay_count,
+            last_replayed_at: item.last_replayed_at,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(
+            diesel_models::incoming_webhook_dead_letter::IncomingWebhookDeadLetterNew {
+                dead_letter_id: self.dead_letter_id,
+                merchant_id: self.merchant_id,
+                connector: self.connector,
+                body_hash: self.body_hash,
+                request: self.request.into(),
+                status: self.status,
+                error_code: self.error_code,
+                error_message: self.error_message,
+                error_reason: self.error_reason,
+                failure_count: self.failure_count,
+                replay_count: self.replay_count,
+                last_replayed_at: self.last_replayed_at,
+                created_at: self.created_at,
+                modified_at: self.modified_at,
+            },
+        )
+    }
+}
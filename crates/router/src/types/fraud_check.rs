@@ -107,6 +107,9 @@ pub struct FraudCheckTransactionData {
     pub connector_transaction_id: Option<String>,
     //The name of the payment gateway or financial institution that processed the transaction.
     pub connector: Option<String>,
+    //The AVS and CVV results of the authorization. These are checked only by FRM post-authorization
+    //checks and are not routing inputs, as they are known only once the connector authorized the payment.
+    pub card_verification_results: Option<api_models::payments::CardVerificationResults>,
 }
 
 pub type FrmFulfillmentRouterData =
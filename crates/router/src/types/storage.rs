@@ -12,12 +12,14 @@ pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
+pub mod dispute_alert;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
 pub mod gsm;
+pub mod incoming_webhook_dead_letter;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -36,6 +38,7 @@ pub mod role;
 pub mod routing_algorithm;
 pub mod user;
 pub mod user_role;
+pub mod vault_entry;
 
 use std::collections::HashMap;
 
@@ -57,11 +60,11 @@ pub use scheduler::db::process_tracker;
 pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*, user::*,
-    user_role::*,
+    configs::*, customers::*, dashboard_metadata::*, dispute::*, dispute_alert::*,
+    ephemeral_key::*, events::*, file::*, fraud_check::*, gsm::*, incoming_webhook_dead_letter::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_link::*, payment_method::*, process_tracker::*, refund::*,
+    reverse_lookup::*, role::*, routing_algorithm::*, user::*, user_role::*, vault_entry::*,
 };
 use crate::types::api::routing;
 
@@ -0,0 +1 @@
+pub use diesel_models::dispute_alert::{DisputeAlert, DisputeAlertNew, DisputeAlertUpdate};
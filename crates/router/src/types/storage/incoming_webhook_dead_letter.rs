@@ -0,0 +1,3 @@
+pub use diesel_models::incoming_webhook_dead_letter::{
+    IncomingWebhookDeadLetter, IncomingWebhookDeadLetterNew,
+};
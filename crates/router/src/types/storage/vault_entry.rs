@@ -0,0 +1 @@
+pub use diesel_models::vault_entry::{VaultEntry, VaultEntryNew};
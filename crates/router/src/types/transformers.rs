@@ -17,7 +17,9 @@ use masking::{ExposeInterface, PeekInterface};
 
 use super::domain;
 use crate::{
+    connector::utils as connector_utils,
     core::errors,
+    logger,
     services::authentication::get_header_value_by_key,
     types::{
         api::{self as api_types, routing as routing_types},
@@ -74,6 +76,17 @@ impl ForeignFrom<api_models::refunds::RefundType> for storage_enums::RefundType
     }
 }
 
+impl ForeignFrom<storage_enums::RefundType> for api_models::refunds::RefundType {
+    fn foreign_from(item: storage_enums::RefundType) -> Self {
+        match item {
+            storage_enums::RefundType::InstantRefund => Self::Instant,
+            storage_enums::RefundType::RegularRefund | storage_enums::RefundType::RetryRefund => {
+                Self::Scheduled
+            }
+        }
+    }
+}
+
 impl ForeignFrom<diesel_models::PaymentMethod> for payment_methods::PaymentMethodResponse {
     fn foreign_from(item: diesel_models::PaymentMethod) -> Self {
         Self {
@@ -393,11 +406,13 @@ impl ForeignFrom<api_enums::IntentStatus> for Option<storage_enums::EventType> {
                 Some(storage_enums::EventType::ActionRequired)
             }
             api_enums::IntentStatus::Cancelled => Some(storage_enums::EventType::PaymentCancelled),
+            api_enums::IntentStatus::Expired => Some(storage_enums::EventType::PaymentExpired),
             api_enums::IntentStatus::PartiallyCaptured
             | api_enums::IntentStatus::PartiallyCapturedAndCapturable => {
                 Some(storage_enums::EventType::PaymentCaptured)
             }
-            api_enums::IntentStatus::RequiresCapture => {
+            api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::PartiallyAuthorized => {
                 Some(storage_enums::EventType::PaymentAuthorized)
             }
             api_enums::IntentStatus::RequiresPaymentMethod
@@ -536,7 +551,8 @@ impl ForeignFrom<storage_enums::RefundStatus> for Option<storage_enums::EventTyp
             storage_enums::RefundStatus::Failure => Some(storage_enums::EventType::RefundFailed),
             api_enums::RefundStatus::ManualReview
             | api_enums::RefundStatus::Pending
-            | api_enums::RefundStatus::TransactionFailure => None,
+            | api_enums::RefundStatus::TransactionFailure
+            | api_enums::RefundStatus::PendingApproval => None,
         }
     }
 }
@@ -597,6 +613,50 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+#[cfg(feature = "payouts")]
+impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventType> {
+    fn foreign_from(value: storage_enums::PayoutStatus) -> Self {
+        match value {
+            storage_enums::PayoutStatus::Success => Some(storage_enums::EventType::PayoutSuccess),
+            storage_enums::PayoutStatus::Failed | storage_enums::PayoutStatus::Ineligible => {
+                Some(storage_enums::EventType::PayoutFailed)
+            }
+            storage_enums::PayoutStatus::Cancelled => {
+                Some(storage_enums::EventType::PayoutCancelled)
+            }
+            storage_enums::PayoutStatus::Pending => {
+                Some(storage_enums::EventType::PayoutProcessing)
+            }
+            storage_enums::PayoutStatus::RequiresCreation
+            | storage_enums::PayoutStatus::RequiresPayoutMethodData
+            | storage_enums::PayoutStatus::RequiresFulfillment
+            | storage_enums::PayoutStatus::RequiresVendorAccountCreation => None,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::PayoutStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess => Ok(Self::Success),
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure
+            | api_models::webhooks::IncomingWebhookEvent::PayoutExpired
+            | api_models::webhooks::IncomingWebhookEvent::PayoutReversed => Ok(Self::Failed),
+            api_models::webhooks::IncomingWebhookEvent::PayoutCancelled => Ok(Self::Cancelled),
+            api_models::webhooks::IncomingWebhookEvent::PayoutProcessing
+            | api_models::webhooks::IncomingWebhookEvent::PayoutCreated => Ok(Self::Pending),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
 impl ForeignFrom<storage::Config> for api_types::Config {
     fn foreign_from(config: storage::Config) -> Self {
         Self {
@@ -762,6 +822,33 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
             created_at: dispute.created_at,
             profile_id: dispute.profile_id,
             merchant_connector_id: dispute.merchant_connector_id,
+            policy_decision: dispute.policy_decision.and_then(|policy_decision| {
+                policy_decision
+                    .parse_value("DisputePolicyDecision")
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to parse dispute policy decision")
+                    })
+                    .ok()
+            }),
+        }
+    }
+}
+
+impl ForeignFrom<storage::DisputeAlert> for api_models::disputes::DisputeAlertResponse {
+    fn foreign_from(dispute_alert: storage::DisputeAlert) -> Self {
+        Self {
+            alert_id: dispute_alert.alert_id,
+            provider: dispute_alert.provider,
+            provider_alert_id: dispute_alert.provider_alert_id,
+            amount: dispute_alert.amount,
+            currency: dispute_alert.currency,
+            transaction_date: dispute_alert.transaction_date,
+            payment_id: dispute_alert.payment_id,
+            attempt_id: dispute_alert.attempt_id,
+            refund_id: dispute_alert.refund_id,
+            outcome: dispute_alert.outcome,
+            error_message: dispute_alert.error_message,
+            created_at: dispute_alert.created_at,
         }
     }
 }
@@ -789,7 +876,7 @@ impl ForeignFrom<&storage::Authentication> for payments::ExternalAuthenticationD
             authentication_flow: authn_data.authentication_type,
             electronic_commerce_indicator: authn_data.eci.clone(),
             status: authn_data.authentication_status,
-            ds_transaction_id: authn_data.threeds_server_transaction_id.clone(),
+            ds_transaction_id: authn_data.ds_trans_id.clone(),
             version,
             error_code: authn_data.error_code.clone(),
             error_message: authn_data.error_message.clone(),
@@ -797,6 +884,28 @@ impl ForeignFrom<&storage::Authentication> for payments::ExternalAuthenticationD
     }
 }
 
+impl ForeignFrom<&storage::Authentication> for payments::PaymentAuthenticationDetails {
+    fn foreign_from(authn_data: &storage::Authentication) -> Self {
+        let version = authn_data
+            .message_version
+            .as_ref()
+            .or(authn_data.maximum_supported_version.as_ref())
+            .map(|version| version.to_string());
+        Self {
+            authentication_source: payments::AuthenticationSource::External,
+            trans_status: authn_data.trans_status.clone(),
+            electronic_commerce_indicator: authn_data.eci.clone(),
+            version,
+            liability_shift: connector_utils::get_liability_shift(
+                authn_data.eci.as_deref(),
+                authn_data.trans_status.as_ref(),
+            ),
+            ds_transaction_id: authn_data.ds_trans_id.clone(),
+            authentication_flow: authn_data.authentication_type,
+        }
+    }
+}
+
 impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponsePaymentsRetrieve {
     fn foreign_from(dispute: storage::Dispute) -> Self {
         Self {
@@ -990,6 +1099,34 @@ impl ForeignFrom<api_models::payouts::PayoutMethodData> for api_enums::PaymentMe
     }
 }
 
+#[cfg(feature = "payouts")]
+impl ForeignFrom<&api_models::payouts::PayoutMethodData> for api_enums::PayoutType {
+    fn foreign_from(value: &api_models::payouts::PayoutMethodData) -> Self {
+        match value {
+            api_models::payouts::PayoutMethodData::Bank(_) => Self::Bank,
+            api_models::payouts::PayoutMethodData::Card(_) => Self::Card,
+            api_models::payouts::PayoutMethodData::Wallet(_) => Self::Wallet,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl ForeignFrom<api_enums::PayoutStatus> for storage_enums::RefundStatus {
+    fn foreign_from(status: api_enums::PayoutStatus) -> Self {
+        match status {
+            api_enums::PayoutStatus::Success => Self::Success,
+            api_enums::PayoutStatus::Failed
+            | api_enums::PayoutStatus::Cancelled
+            | api_enums::PayoutStatus::Ineligible => Self::Failure,
+            api_enums::PayoutStatus::Pending
+            | api_enums::PayoutStatus::RequiresCreation
+            | api_enums::PayoutStatus::RequiresPayoutMethodData
+            | api_enums::PayoutStatus::RequiresFulfillment
+            | api_enums::PayoutStatus::RequiresVendorAccountCreation => Self::Pending,
+        }
+    }
+}
+
 #[cfg(feature = "payouts")]
 impl ForeignFrom<api_models::enums::PayoutType> for api_enums::PaymentMethod {
     fn foreign_from(value: api_models::enums::PayoutType) -> Self {
@@ -1248,6 +1385,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_class: item.event_class,
             is_delivery_successful: item.is_webhook_notified,
             initial_attempt_id,
+            webhook_endpoint_id: item.webhook_endpoint_id,
             created: item.created_at,
         })
     }
@@ -1291,3 +1429,48 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveRespons
         })
     }
 }
+
+#[cfg(feature = "olap")]
+impl From<domain::IncomingWebhookDeadLetter>
+    for api_models::webhook_dead_letters::DeadLetterListItemResponse
+{
+    fn from(item: domain::IncomingWebhookDeadLetter) -> Self {
+        Self {
+            dead_letter_id: item.dead_letter_id,
+            merchant_id: item.merchant_id,
+            connector: item.connector,
+            status: item.status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+            failure_count: item.failure_count,
+            replay_count: item.replay_count,
+            created: item.created_at,
+            last_replayed_at: item.last_replayed_at,
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+impl TryFrom<domain::IncomingWebhookDeadLetter>
+    for api_models::webhook_dead_letters::DeadLetterRetrieveResponse
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(item: domain::IncomingWebhookDeadLetter) -> Result<Self, Self::Error> {
+        let request = item
+            .request
+            .peek()
+            .parse_struct("StoredIncomingWebhookRequest")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse incoming webhook request information")
+            .map(crate::core::webhooks::dead_letters::get_masked_request_content)?;
+        let error_reason = item.error_reason.clone();
+
+        Ok(Self {
+            dead_letter_information:
+                api_models::webhook_dead_letters::DeadLetterListItemResponse::from(item),
+            error_reason,
+            request,
+        })
+    }
+}
@@ -21,10 +21,12 @@ impl From<Permission> for user_role_api::Permission {
             Permission::PaymentWrite => Self::PaymentWrite,
             Permission::RefundRead => Self::RefundRead,
             Permission::RefundWrite => Self::RefundWrite,
+            Permission::RefundApprove => Self::RefundApprove,
             Permission::ApiKeyRead => Self::ApiKeyRead,
             Permission::ApiKeyWrite => Self::ApiKeyWrite,
             Permission::MerchantAccountRead => Self::MerchantAccountRead,
             Permission::MerchantAccountWrite => Self::MerchantAccountWrite,
+            Permission::WebhookSecretManage => Self::WebhookSecretManage,
             Permission::MerchantConnectorAccountRead => Self::MerchantConnectorAccountRead,
             Permission::MerchantConnectorAccountWrite => Self::MerchantConnectorAccountWrite,
             Permission::RoutingRead => Self::RoutingRead,
@@ -2,7 +2,15 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod authorization_expiry;
+pub mod bulk_operation;
+pub mod dispute_deadline_reminder;
+pub mod dispute_sync;
+pub mod intent_expiry;
 pub mod outgoing_webhook_retry;
 pub mod payment_sync;
 pub mod refund_router;
+pub mod scheduled_capture;
 pub mod tokenized_data;
+pub mod vault_migration;
+pub mod webhook_watchdog;
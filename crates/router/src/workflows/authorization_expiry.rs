@@ -0,0 +1,299 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums as storage_enums;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::{
+        payment_methods::Oss,
+        payments::{self as payment_flows, authorization_expiry::AuthorizationExpiryTrackingData},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    errors,
+    routes::{metrics, AppState},
+    services,
+    types::{api, domain, storage},
+};
+
+pub struct AuthorizationExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for AuthorizationExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: AuthorizationExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthorizationExpiryTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payments_response =
+            retrieve_payment(state, &merchant_account, &key_store, &tracking_data).await?;
+
+        // Nothing to be done if the payment was captured or voided in the meantime
+        if !matches!(
+            payments_response.status,
+            api_models::enums::IntentStatus::RequiresCapture
+                | api_models::enums::IntentStatus::PartiallyAuthorized
+        ) {
+            return Ok(state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?);
+        }
+
+        if process.retry_count == 0 {
+            let business_profile = db
+                .find_business_profile_by_profile_id(&tracking_data.profile_id)
+                .await?;
+            let primary_object_created_at = payments_response.created;
+
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                state.clone(),
+                merchant_account,
+                business_profile,
+                &key_store,
+                storage_enums::EventType::PaymentAuthorizationExpiring,
+                storage_enums::EventClass::Payments,
+                tracking_data.payment_id.clone(),
+                storage_enums::EventObjectType::PaymentDetails,
+                api::OutgoingWebhookContent::PaymentDetails(payments_response),
+                primary_object_created_at,
+            ))
+            .await?;
+
+            if tracking_data.expiry_action == api_models::enums::AuthorizationExpiryAction::NoAction
+            {
+                return Ok(state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?);
+            }
+
+            // Re-schedule the task to perform the expiry action closer to the expiry
+            let schedule_time = std::cmp::max(
+                tracking_data
+                    .authorization_expires_at
+                    .saturating_sub(time::Duration::hours(i64::from(
+                        tracking_data.action_offset_hours,
+                    ))),
+                common_utils::date_time::now(),
+            );
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(process.retry_count + 1),
+                schedule_time: Some(schedule_time),
+                tracking_data: None,
+                business_status: None,
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(common_utils::date_time::now()),
+            };
+            db.process_tracker_update_process_status_by_ids(
+                vec![process.id.clone()],
+                updated_process_tracker_data,
+            )
+            .await?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "flow",
+                    "AuthorizationExpiry",
+                )],
+            );
+
+            return Ok(());
+        }
+
+        let action_result = match tracking_data.expiry_action {
+            api_models::enums::AuthorizationExpiryAction::AutoCapture => {
+                Box::pin(payment_flows::payments_core::<
+                    api::Capture,
+                    api::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    Oss,
+                >(
+                    state.clone(),
+                    state.get_req_state(),
+                    merchant_account,
+                    key_store,
+                    payment_flows::PaymentCapture,
+                    api::PaymentsCaptureRequest {
+                        payment_id: tracking_data.payment_id.clone(),
+                        merchant_id: Some(tracking_data.merchant_id.clone()),
+                        ..Default::default()
+                    },
+                    services::AuthFlow::Merchant,
+                    payment_flows::CallConnectorAction::Trigger,
+                    None,
+                    api::HeaderPayload::default(),
+                ))
+                .await
+            }
+            api_models::enums::AuthorizationExpiryAction::AutoVoid => {
+                Box::pin(payment_flows::payments_core::<
+                    api::Void,
+                    api::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    Oss,
+                >(
+                    state.clone(),
+                    state.get_req_state(),
+                    merchant_account,
+                    key_store,
+                    payment_flows::PaymentCancel,
+                    api::PaymentsCancelRequest {
+                        payment_id: tracking_data.payment_id.clone(),
+                        cancellation_reason: Some("authorization_expired".to_string()),
+                        merchant_connector_details: None,
+                    },
+                    services::AuthFlow::Merchant,
+                    payment_flows::CallConnectorAction::Trigger,
+                    None,
+                    api::HeaderPayload::default(),
+                ))
+                .await
+            }
+            api_models::enums::AuthorizationExpiryAction::NoAction => {
+                return Ok(state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?);
+            }
+        };
+
+        let is_action_failed = match action_result {
+            Ok(services::ApplicationResponse::JsonWithHeaders((payments_response, _))) => {
+                matches!(
+                    payments_response.status,
+                    api_models::enums::IntentStatus::RequiresCapture
+                        | api_models::enums::IntentStatus::PartiallyAuthorized
+                )
+            }
+            Ok(_) => false,
+            Err(error) => {
+                logger::error!(?error, "Authorization expiry action failed");
+                true
+            }
+        };
+
+        if !is_action_failed {
+            return Ok(state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?);
+        }
+
+        retry_expiry_action(state, process, &tracking_data).await
+    }
+
+    /// A failed expiry action is retried until the authorization expires, whereas a task which
+    /// failed before notifying the merchant is handled as usual
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        logger::error!(%process.id, ?error, "Failed while executing authorization expiry workflow");
+        if process.retry_count > 0 {
+            if let Ok(tracking_data) = process
+                .tracking_data
+                .clone()
+                .parse_value::<AuthorizationExpiryTrackingData>("AuthorizationExpiryTrackingData")
+            {
+                return retry_expiry_action(state, process, &tracking_data)
+                    .await
+                    .map_err(error_stack::Report::from);
+            }
+        }
+
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+async fn retry_expiry_action(
+    state: &AppState,
+    process: storage::ProcessTracker,
+    tracking_data: &AuthorizationExpiryTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db = state.get_db();
+    match payment_flows::authorization_expiry::get_action_retry_schedule_time(
+        process.retry_count,
+        tracking_data.authorization_expires_at,
+        common_utils::date_time::now(),
+    ) {
+        Some(schedule_time) => {
+            db.as_scheduler()
+                .retry_process(process, schedule_time)
+                .await?
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
+                .await?
+        }
+    }
+
+    Ok(())
+}
+
+async fn retrieve_payment(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &AuthorizationExpiryTrackingData,
+) -> Result<api::PaymentsResponse, sch_errors::ProcessTrackerError> {
+    let payments_response = Box::pin(payment_flows::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payment_flows::PaymentStatus,
+        payment_flows::authorization_expiry::get_payments_retrieve_request(tracking_data),
+        services::AuthFlow::Merchant,
+        payment_flows::CallConnectorAction::Avoid,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    match payments_response {
+        services::ApplicationResponse::JsonWithHeaders((payments_response, _))
+        | services::ApplicationResponse::Json(payments_response) => Ok(payments_response),
+        _ => Err(sch_errors::ProcessTrackerError::UnexpectedFlow),
+    }
+}
@@ -0,0 +1,143 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::workflows::ProcessTrackerWorkflow, errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    consts,
+    core::bulk_operations,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums as storage_enums},
+};
+
+pub struct BulkOperationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for BulkOperationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: bulk_operations::BulkOperationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("BulkOperationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        bulk_operations::process_bulk_operation_chunk(
+            state,
+            &merchant_account,
+            &key_store,
+            &mut tracking_data,
+        )
+        .await?;
+
+        // Every execution processes a single chunk of rows, and the task is scheduled again
+        // right away until all the rows have been processed. The retry count is reset after every
+        // successful chunk, so that the retries are counted per chunk rather than across the job.
+        let process_update = if tracking_data.is_completed() {
+            bulk_operations::store_bulk_operation_results(state, &mut tracking_data).await?;
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data.encode_to_value()?),
+                business_status: Some("COMPLETED_BY_PT".to_string()),
+                status: Some(storage_enums::ProcessTrackerStatus::Finish),
+                updated_at: Some(common_utils::date_time::now()),
+            }
+        } else {
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(common_utils::date_time::now()),
+                tracking_data: Some(tracking_data.encode_to_value()?),
+                business_status: None,
+                status: Some(storage_enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(common_utils::date_time::now()),
+            }
+        };
+        db.as_scheduler()
+            .update_process(process, process_update)
+            .await?;
+
+        Ok(())
+    }
+
+    /// The chunk being processed is retried, since the rows which already created a payment or a
+    /// refund return it instead of creating it again. Once the retries are exhausted, the results
+    /// of the rows processed so far are stored.
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        logger::error!(%process.id, ?error, "Failed while executing bulk operation workflow");
+        if process.retry_count < consts::BULK_OPERATION_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now()
+                .saturating_add(time::Duration::seconds(consts::BULK_OPERATION_RETRY_DELAY));
+            return state
+                .get_db()
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        let mut tracking_data: bulk_operations::BulkOperationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("BulkOperationTrackingData")
+            .change_context(sch_errors::ProcessTrackerError::DeserializationFailed)?;
+
+        tracking_data.error_message =
+            Some("The job was stopped because of an internal error".to_string());
+        if tracking_data.result_parts > 0 {
+            bulk_operations::store_bulk_operation_results(state, &mut tracking_data)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to store partial bulk operation results")
+                })
+                .ok();
+        }
+
+        let tracking_data = tracking_data
+            .encode_to_value()
+            .change_context(sch_errors::ProcessTrackerError::SerializationFailed)?;
+        state
+            .get_db()
+            .as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: None,
+                    tracking_data: Some(tracking_data),
+                    business_status: Some("FAILED".to_string()),
+                    status: Some(storage_enums::ProcessTrackerStatus::Finish),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await
+            .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed)?;
+        Ok(())
+    }
+}
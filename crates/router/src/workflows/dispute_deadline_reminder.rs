@@ -0,0 +1,224 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    consts,
+    core::{disputes::deadline_reminders::DisputeDeadlineReminderTrackingData, webhooks},
+    db::StorageInterface,
+    errors,
+    routes::{metrics, AppState},
+    types::{api, storage, transformers::ForeignInto},
+};
+#[cfg(feature = "email")]
+use crate::{
+    services::email::types::DisputeDeadlineReminder,
+    types::domain::{self, UserEmail},
+};
+
+pub struct DisputeDeadlineReminderWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for DisputeDeadlineReminderWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: DisputeDeadlineReminderTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineReminderTrackingData")?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // Nothing to be done if the dispute was challenged, accepted or closed in the meantime.
+        // If the deadline was moved, the task would have been reset with the new deadline.
+        if dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+            || dispute.challenge_required_by != Some(tracking_data.challenge_required_by)
+        {
+            return Ok(state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?);
+        }
+
+        let offset_hours = tracking_data
+            .get_next_reminder_offset_hours()
+            .ok_or(sch_errors::ProcessTrackerError::UnexpectedFlow)?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(&tracking_data.profile_id)
+            .await?;
+
+        #[cfg(feature = "email")]
+        send_reminder_email(state, &merchant_account, &dispute, &tracking_data).await?;
+
+        // Each reminder is delivered as a separate event, hence the offset is part of the
+        // idempotent event ID
+        let event_type = storage_enums::EventType::DisputeDeadlineApproaching;
+        let idempotent_event_id = webhooks::utils::get_idempotent_event_id(
+            &format!("{}_{offset_hours}h", dispute.dispute_id),
+            event_type,
+            storage_enums::WebhookDeliveryAttempt::InitialAttempt,
+            None,
+        );
+        let primary_object_created_at = Some(dispute.created_at);
+        Box::pin(
+            webhooks::create_idempotent_event_and_trigger_outgoing_webhook(
+                state.clone(),
+                merchant_account,
+                business_profile,
+                &key_store,
+                event_type,
+                storage_enums::EventClass::Disputes,
+                dispute.dispute_id.clone(),
+                storage_enums::EventObjectType::DisputeDetails,
+                api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute.foreign_into())),
+                primary_object_created_at,
+                idempotent_event_id,
+            ),
+        )
+        .await?;
+
+        tracking_data.next_reminder_index += 1;
+        let Some(next_offset_hours) = tracking_data.get_next_reminder_offset_hours() else {
+            return Ok(state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?);
+        };
+
+        let schedule_time = std::cmp::max(
+            tracking_data.get_reminder_time(next_offset_hours),
+            common_utils::date_time::now(),
+        );
+        let tracking_data = tracking_data
+            .encode_to_value()
+            .change_context(sch_errors::ProcessTrackerError::SerializationFailed)?;
+        // The retry count is reset, as it only counts the retries of the current reminder
+        let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(schedule_time),
+            tracking_data: Some(tracking_data),
+            business_status: None,
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(common_utils::date_time::now()),
+        };
+        db.process_tracker_update_process_status_by_ids(
+            vec![process.id.clone()],
+            updated_process_tracker_data,
+        )
+        .await?;
+        metrics::TASKS_RESET_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes(
+                "flow",
+                "DisputeDeadlineReminder",
+            )],
+        );
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        logger::error!(%process.id, ?error, "Failed while executing dispute deadline reminder workflow");
+        if process.retry_count < consts::DISPUTE_DEADLINE_REMINDER_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(consts::DISPUTE_DEADLINE_REMINDER_RETRY_DELAY),
+            );
+            return state
+                .get_db()
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Emails the merchant about the approaching deadline. The reminder is skipped if the merchant
+/// has not configured a primary email, since the webhook is sent regardless.
+#[cfg(feature = "email")]
+async fn send_reminder_email(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &storage::Dispute,
+    tracking_data: &DisputeDeadlineReminderTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let Some(email_id) = merchant_account
+        .merchant_details
+        .parse_value::<api::MerchantDetails>("MerchantDetails")?
+        .primary_email
+    else {
+        logger::info!(
+            dispute_id = %dispute.dispute_id,
+            "Skipping dispute deadline reminder email as the merchant has no primary email"
+        );
+        return Ok(());
+    };
+
+    let current_time = common_utils::date_time::now();
+    let hours_left = u16::try_from(
+        (tracking_data.challenge_required_by - current_time)
+            .whole_hours()
+            .max(0),
+    )
+    .unwrap_or(u16::MAX);
+
+    let email_contents = DisputeDeadlineReminder {
+        recipient_email: UserEmail::from_pii_email(email_id).map_err(|err| {
+            logger::error!(%err, "Failed to convert recipient's email to UserEmail from pii::Email");
+            sch_errors::ProcessTrackerError::EApiErrorResponse
+        })?,
+        subject: "Dispute Deadline Approaching",
+        dispute_id: dispute.dispute_id.clone(),
+        payment_id: dispute.payment_id.clone(),
+        amount: dispute.amount.clone(),
+        currency: dispute.currency.clone(),
+        challenge_required_by: tracking_data.challenge_required_by,
+        hours_left,
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .map_err(sch_errors::ProcessTrackerError::EEmailError)?;
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, SchedulerAppState,
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::disputes::sync::{self, DisputeSyncTrackingData},
+    db::StorageInterface,
+    errors,
+    routes::{metrics, AppState},
+    types::storage,
+};
+
+pub struct DisputeSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for DisputeSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: DisputeSyncTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeSyncTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        // The task is finished once the connector account is deleted or disabled, and is resumed
+        // if the connector account is enabled again
+        let merchant_connector_account = match db
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &tracking_data.merchant_id,
+                &tracking_data.merchant_connector_id,
+                &key_store,
+            )
+            .await
+        {
+            Ok(merchant_connector_account)
+                if !merchant_connector_account.disabled.unwrap_or(false) =>
+            {
+                merchant_connector_account
+            }
+            Ok(_) => {
+                return Ok(state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?)
+            }
+            Err(error) if error.current_context().is_db_not_found() => {
+                return Ok(state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?)
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        // If the sync fails, the error handler retries the task without moving `last_synced_at`,
+        // so that the disputes are fetched again from the same point
+        let sync_till = common_utils::date_time::now();
+        sync::sync_disputes(
+            state,
+            &merchant_account,
+            &key_store,
+            &merchant_connector_account,
+            tracking_data.last_synced_at,
+            sync_till,
+        )
+        .await?;
+        let updated_tracking_data = DisputeSyncTrackingData {
+            last_synced_at: sync_till,
+            ..tracking_data
+        }
+        .encode_to_value()?;
+
+        let schedule_time = sync_till.saturating_add(time::Duration::minutes(i64::from(
+            state.conf.dispute_sync.sync_interval_minutes,
+        )));
+        let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(schedule_time),
+            tracking_data: Some(updated_tracking_data),
+            business_status: None,
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(common_utils::date_time::now()),
+        };
+        db.process_tracker_update_process_status_by_ids(
+            vec![process.id.clone()],
+            updated_process_tracker_data,
+        )
+        .await?;
+        metrics::TASKS_RESET_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes("flow", "DisputeSync")],
+        );
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        logger::error!(%process.id, ?error, "Failed while executing dispute sync workflow");
+        match get_retry_schedule_time(
+            process.retry_count,
+            state.conf.dispute_sync.sync_interval_minutes,
+            common_utils::date_time::now(),
+        ) {
+            Some(schedule_time) => state
+                .get_db()
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed),
+            None => {
+                consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+            }
+        }
+    }
+}
+
+/// A failed sync is retried after the sync interval, until the retries are exhausted
+fn get_retry_schedule_time(
+    retry_count: i32,
+    sync_interval_minutes: u32,
+    current_time: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    (retry_count < consts::DISPUTE_SYNC_MAX_RETRIES).then(|| {
+        current_time.saturating_add(time::Duration::minutes(i64::from(sync_interval_minutes)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_retry_schedule_time() {
+        let current_time = common_utils::date_time::now();
+        assert_eq!(
+            get_retry_schedule_time(0, 60, current_time),
+            Some(current_time.saturating_add(time::Duration::hours(1)))
+        );
+        assert_eq!(
+            get_retry_schedule_time(consts::DISPUTE_SYNC_MAX_RETRIES, 60, current_time),
+            None
+        );
+    }
+}
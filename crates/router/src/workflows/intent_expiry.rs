@@ -0,0 +1,279 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums as storage_enums;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    consts,
+    core::{
+        errors::StorageErrorExt,
+        payment_methods::Oss,
+        payments::{self as payment_flows, intent_expiry},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    errors,
+    routes::{metrics, AppState},
+    services,
+    types::{api, domain, storage},
+};
+
+pub struct IntentExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for IntentExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: intent_expiry::IntentExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("IntentExpiryTrackingData")?;
+
+        // The sweep is finished once the business profile is deleted or its intent expiry is
+        // removed, and is resumed if the intent expiry is configured again
+        let business_profile = match db
+            .find_business_profile_by_profile_id(&tracking_data.profile_id)
+            .await
+        {
+            Ok(business_profile) => business_profile,
+            Err(error) if error.current_context().is_db_not_found() => {
+                return Ok(state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?)
+            }
+            Err(error) => return Err(error.into()),
+        };
+        let Some(intent_expiry) = business_profile.intent_expiry else {
+            return Ok(state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?);
+        };
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let current_time = common_utils::date_time::now();
+        let stale_payment_intents = db
+            .filter_payment_intents_by_profile_id_status_created_before(
+                &tracking_data.merchant_id,
+                &tracking_data.profile_id,
+                &intent_expiry::EXPIRABLE_INTENT_STATUSES,
+                intent_expiry::get_stale_intents_created_before(intent_expiry, current_time),
+                consts::INTENT_EXPIRY_SWEEP_BATCH_SIZE,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+        let is_batch_full = i64::try_from(stale_payment_intents.len())
+            .is_ok_and(|count| count >= consts::INTENT_EXPIRY_SWEEP_BATCH_SIZE);
+
+        let mut expired_count = 0;
+        for payment_intent in stale_payment_intents {
+            let payment_id = payment_intent.payment_id;
+            match expire_stale_payment_intent(
+                state,
+                &process,
+                &merchant_account,
+                &key_store,
+                &business_profile,
+                &payment_id,
+            )
+            .await
+            {
+                Ok(()) => expired_count += 1,
+                Err(error) => {
+                    logger::error!(?error, %payment_id, "Failed to expire payment intent");
+                }
+            }
+        }
+
+        // A full batch is followed up right away, since more intents may have expired, unless
+        // none of the intents of the batch could be expired
+        let schedule_time = if is_batch_full && expired_count > 0 {
+            common_utils::date_time::now()
+        } else {
+            current_time.saturating_add(time::Duration::seconds(
+                consts::INTENT_EXPIRY_SWEEP_INTERVAL_IN_SECONDS,
+            ))
+        };
+        let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(schedule_time),
+            tracking_data: None,
+            business_status: None,
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(common_utils::date_time::now()),
+        };
+        db.process_tracker_update_process_status_by_ids(
+            vec![process.id.clone()],
+            updated_process_tracker_data,
+        )
+        .await?;
+        metrics::TASKS_RESET_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes("flow", "IntentExpiry")],
+        );
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Expires a stale payment intent under the payment lock, and notifies the merchant of the
+/// expiry. A payment which is locked by another request is skipped, and is picked up again by the
+/// next sweep if it is still stale.
+async fn expire_stale_payment_intent(
+    state: &AppState,
+    process: &storage::ProcessTracker,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &storage::business_profile::BusinessProfile,
+    payment_id: &str,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    // Hold the payment lock so that the payment can not be confirmed while it is expired
+    let lock_action = intent_expiry::get_payment_lock_action(payment_id);
+    let lock_holder = Some(process.id.clone());
+    lock_action
+        .clone()
+        .perform_locking_action_for_holder(
+            state,
+            merchant_account.merchant_id.clone(),
+            lock_holder.clone(),
+        )
+        .await?;
+
+    let expiry_result = expire_payment_intent(state, merchant_account, key_store, payment_id).await;
+
+    lock_action
+        .free_lock_action_for_holder(state, merchant_account.merchant_id.clone(), lock_holder)
+        .await?;
+
+    if let Some(payments_response) = expiry_result? {
+        let primary_object_created_at = payments_response.created;
+
+        Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile.clone(),
+            key_store,
+            storage_enums::EventType::PaymentExpired,
+            storage_enums::EventClass::Payments,
+            payment_id.to_owned(),
+            storage_enums::EventObjectType::PaymentDetails,
+            api::OutgoingWebhookContent::PaymentDetails(payments_response),
+            primary_object_created_at,
+        ))
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Expires the payment intent if it was not confirmed, returning the expired payment so that the
+/// merchant can be notified
+async fn expire_payment_intent(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+) -> Result<Option<api::PaymentsResponse>, sch_errors::ProcessTrackerError> {
+    let db = &*state.store;
+    // The intent is read under the payment lock, so its status can not change until it is updated
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // Payments which were confirmed before the expiry are left as is, authorized payments are
+    // handled by the authorization expiry workflow
+    if !intent_expiry::EXPIRABLE_INTENT_STATUSES.contains(&payment_intent.status) {
+        return Ok(None);
+    }
+
+    db.update_payment_intent(
+        payment_intent,
+        storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: storage_enums::IntentStatus::Expired,
+            incremental_authorization_allowed: None,
+            updated_by: merchant_account.storage_scheme.to_string(),
+        },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    retrieve_payment(state, merchant_account, key_store, payment_id)
+        .await
+        .map(Some)
+}
+
+async fn retrieve_payment(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+) -> Result<api::PaymentsResponse, sch_errors::ProcessTrackerError> {
+    let payments_response = Box::pin(payment_flows::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payment_flows::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(payment_id.to_owned()),
+            merchant_id: Some(merchant_account.merchant_id.clone()),
+            force_sync: false,
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        payment_flows::CallConnectorAction::Avoid,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    match payments_response {
+        services::ApplicationResponse::JsonWithHeaders((payments_response, _))
+        | services::ApplicationResponse::Json(payments_response) => Ok(payments_response),
+        _ => Err(sch_errors::ProcessTrackerError::UnexpectedFlow),
+    }
+}
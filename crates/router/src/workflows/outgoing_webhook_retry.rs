@@ -48,13 +48,6 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
             .find_business_profile_by_profile_id(&tracking_data.business_profile_id)
             .await?;
 
-        let event_id = webhooks_core::utils::generate_event_id();
-        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
-            &tracking_data.primary_object_id,
-            tracking_data.event_type,
-            delivery_attempt,
-        );
-
         let initial_event = match &tracking_data.initial_attempt_id {
             Some(initial_attempt_id) => {
                 db.find_event_by_merchant_id_event_id(
@@ -80,6 +73,16 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        // Deliveries to each webhook endpoint are retried independently, retry attempts are
+        // delivered to the same endpoint as the initial attempt
+        let event_id = webhooks_core::utils::generate_event_id();
+        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
+            &tracking_data.primary_object_id,
+            tracking_data.event_type,
+            delivery_attempt,
+            initial_event.webhook_endpoint_id.as_deref(),
+        );
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -97,6 +100,7 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
             request: initial_event.request,
             response: None,
             delivery_attempt: Some(delivery_attempt),
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -158,7 +162,9 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
                         let request_content = webhooks_core::get_outgoing_webhook_request(
                             &merchant_account,
                             outgoing_webhook,
-                            business_profile.payment_response_hash_key.as_deref(),
+                            webhooks_core::get_business_profile_webhook_secret(&business_profile)
+                                .as_deref(),
+                            None,
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -475,5 +481,45 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        #[cfg(feature = "payouts")]
+        diesel_models::enums::EventClass::Payouts => {
+            let payout_id = tracking_data.primary_object_id.clone();
+            let request = api_models::payouts::PayoutRetrieveRequest {
+                payout_id,
+                force_sync: None,
+                merchant_id: Some(tracking_data.merchant_id.clone()),
+            };
+
+            let payout_response = match crate::core::payouts::payouts_retrieve_core(
+                state,
+                merchant_account,
+                key_store,
+                request,
+            )
+            .await?
+            {
+                ApplicationResponse::Json(payout_response)
+                | ApplicationResponse::JsonWithHeaders((payout_response, _)) => Ok(payout_response),
+                ApplicationResponse::StatusOk
+                | ApplicationResponse::TextPlain(_)
+                | ApplicationResponse::JsonForRedirection(_)
+                | ApplicationResponse::Form(_)
+                | ApplicationResponse::PaymentLinkForm(_)
+                | ApplicationResponse::FileData(_) => {
+                    Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                        resource_name: tracking_data.primary_object_id.clone(),
+                    })
+                }
+            }
+            .map(Box::new)?;
+            let event_type = Option::<EventType>::foreign_from(payout_response.status);
+            logger::debug!(current_resource_status=%payout_response.status);
+
+            Ok((
+                OutgoingWebhookContent::PayoutDetails(payout_response),
+                event_type,
+            ))
+        }
     }
 }
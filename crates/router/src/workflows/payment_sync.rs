@@ -139,6 +139,8 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
                             unified_message: None,
                             connector_transaction_id: None,
                             payment_method_data: None,
+                            card_verification_results: None,
+                            authentication_details: None,
                         };
 
                     payment_data.payment_attempt = db
@@ -0,0 +1,141 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payment_methods::Oss,
+        payments::{self as payment_flows, scheduled_capture},
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{api, storage},
+};
+
+pub struct ScheduledCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ScheduledCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: scheduled_capture::ScheduledCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScheduledCaptureTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The payment was captured, voided or expired before the scheduled time
+        if !matches!(
+            payment_intent.status,
+            api_models::enums::IntentStatus::RequiresCapture
+                | api_models::enums::IntentStatus::PartiallyAuthorized
+        ) {
+            return Ok(state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?);
+        }
+
+        let capture_result = Box::pin(payment_flows::payments_core::<
+            api::Capture,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state.clone(),
+            state.get_req_state(),
+            merchant_account,
+            key_store,
+            payment_flows::PaymentCapture,
+            api::PaymentsCaptureRequest {
+                payment_id: tracking_data.payment_id.clone(),
+                merchant_id: Some(tracking_data.merchant_id.clone()),
+                ..Default::default()
+            },
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Trigger,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await;
+
+        let is_capture_failed = match capture_result {
+            Ok(services::ApplicationResponse::JsonWithHeaders((payments_response, _))) => {
+                matches!(
+                    payments_response.status,
+                    api_models::enums::IntentStatus::RequiresCapture
+                        | api_models::enums::IntentStatus::PartiallyAuthorized
+                )
+            }
+            Ok(_) => false,
+            Err(error) => {
+                logger::error!(?error, "Scheduled capture failed");
+                true
+            }
+        };
+
+        if !is_capture_failed {
+            return Ok(state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?);
+        }
+
+        match scheduled_capture::get_capture_retry_schedule_time(process.retry_count) {
+            Some(schedule_time) => {
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,110 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    consts,
+    core::locker_migration,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums as storage_enums},
+};
+
+pub struct VaultMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for VaultMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: locker_migration::VaultMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("VaultMigrationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        locker_migration::process_vault_migration_chunk(
+            state,
+            &merchant_account,
+            &key_store,
+            &mut tracking_data,
+        )
+        .await?;
+
+        // Every execution processes a single chunk of payment methods, and the task is scheduled
+        // again until the migration is finished, the tracking data serving as the cursor to
+        // resume from
+        let process_update = if tracking_data.is_finished() {
+            let business_status = if tracking_data.error_message.is_some() {
+                "FAILED"
+            } else {
+                "COMPLETED_BY_PT"
+            };
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data.encode_to_value()?),
+                business_status: Some(business_status.to_string()),
+                status: Some(storage_enums::ProcessTrackerStatus::Finish),
+                updated_at: Some(common_utils::date_time::now()),
+            }
+        } else {
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(tracking_data.get_next_schedule_time()),
+                tracking_data: Some(tracking_data.encode_to_value()?),
+                business_status: None,
+                status: Some(storage_enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(common_utils::date_time::now()),
+            }
+        };
+        db.as_scheduler()
+            .update_process(process, process_update)
+            .await?;
+
+        Ok(())
+    }
+
+    /// The chunk being processed is retried, since copying a payment method which was already
+    /// copied stores it again under the same reference
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        logger::error!(%process.id, ?error, "Failed while executing vault migration workflow");
+        if process.retry_count < consts::VAULT_MIGRATION_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now()
+                .saturating_add(time::Duration::seconds(consts::VAULT_MIGRATION_RETRY_DELAY));
+            return state
+                .get_db()
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
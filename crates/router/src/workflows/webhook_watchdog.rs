@@ -0,0 +1,115 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    consts,
+    core::{
+        errors::StorageErrorExt,
+        payments::{self as payment_flows, webhook_watchdog},
+    },
+    db::StorageInterface,
+    errors,
+    events::webhook_reliability_logs::WebhookArrivalStatus,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct WebhookWatchdogWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for WebhookWatchdogWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: webhook_watchdog::WebhookWatchdogTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookWatchdogTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &tracking_data.attempt_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The payment was resolved without its webhook, for instance through a sync requested by
+        // the merchant, so there is nothing left to sync, but the webhook still did not arrive
+        if !webhook_watchdog::is_awaiting_webhook(&payment_attempt) {
+            webhook_watchdog::raise_webhook_reliability_event(
+                state,
+                &tracking_data,
+                WebhookArrivalStatus::ResolvedWithoutWebhook,
+                None,
+            );
+            return Ok(state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    consts::WEBHOOK_WATCHDOG_RESOLVED_WITHOUT_WEBHOOK.to_string(),
+                )
+                .await?);
+        }
+
+        webhook_watchdog::raise_webhook_reliability_event(
+            state,
+            &tracking_data,
+            WebhookArrivalStatus::Missed,
+            None,
+        );
+
+        // Sync the payment with the connector right away, instead of waiting for the next
+        // scheduled sync of the payment, if any
+        let schedule_time = common_utils::date_time::now();
+        if let Err(error) =
+            payment_flows::reset_process_sync_task(db, &payment_attempt, schedule_time).await
+        {
+            logger::info!(
+                ?error,
+                "Could not reset payment sync task, adding a new payment sync task"
+            );
+            payment_flows::add_process_sync_task(db, &payment_attempt, schedule_time).await?;
+        }
+
+        state
+            .get_db()
+            .as_scheduler()
+            .finish_process_with_business_status(
+                process,
+                consts::WEBHOOK_WATCHDOG_MISSED.to_string(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
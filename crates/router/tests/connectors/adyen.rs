@@ -177,6 +177,8 @@ impl AdyenTest {
             customer_id: None,
             surcharge_details: None,
             request_incremental_authorization: false,
+            allow_partial_authorization: false,
+            l2_l3_data: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: None,
@@ -69,6 +69,8 @@ impl CashtocodeTest {
             customer_id: Some("John Doe".to_owned()),
             surcharge_details: None,
             request_incremental_authorization: false,
+            allow_partial_authorization: false,
+            l2_l3_data: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: None,
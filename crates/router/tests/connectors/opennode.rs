@@ -96,6 +96,8 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
         customer_id: None,
         surcharge_details: None,
         request_incremental_authorization: false,
+        allow_partial_authorization: false,
+        l2_l3_data: None,
         metadata: None,
         authentication_data: None,
         customer_acceptance: None,
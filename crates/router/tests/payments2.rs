@@ -137,7 +137,7 @@ async fn payments_create_core() {
         state.get_req_state(),
         merchant_account,
         key_store,
-        payments::PaymentCreate,
+        payments::PaymentCreate::default(),
         req,
         services::AuthFlow::Merchant,
         payments::CallConnectorAction::Trigger,
@@ -328,7 +328,7 @@ async fn payments_create_core_adyen_no_redirect() {
         state.get_req_state(),
         merchant_account,
         key_store,
-        payments::PaymentCreate,
+        payments::PaymentCreate::default(),
         req,
         services::AuthFlow::Merchant,
         payments::CallConnectorAction::Trigger,
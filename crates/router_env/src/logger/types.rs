@@ -190,6 +190,10 @@ pub enum Flow {
     RefundsUpdate,
     /// Refunds list flow.
     RefundsList,
+    /// Refunds approve flow.
+    RefundsApprove,
+    /// Refunds reject flow.
+    RefundsReject,
     // Retrieve forex flow.
     RetrieveForexFlow,
     /// Toggles recon service for a merchant.
@@ -264,6 +268,18 @@ pub enum Flow {
     DeleteDisputeEvidence,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Assemble Dispute Evidence flow
+    AssembleDisputeEvidence,
+    /// Dispute policy upsert flow
+    DisputePolicyUpsert,
+    /// Dispute policy retrieve flow
+    DisputePolicyRetrieve,
+    /// Dispute policy delete flow
+    DisputePolicyDelete,
+    /// Dispute alert receive flow
+    DisputeAlertReceive,
+    /// Dispute alert retrieve flow
+    DisputeAlertRetrieve,
     /// Invalidate cache flow
     CacheInvalidate,
     /// Payment Link Retrieve flow
@@ -288,6 +304,10 @@ pub enum Flow {
     Verification,
     /// Rust locker migration
     RustLockerMigration,
+    /// Vault provider retrieve flow
+    VaultProviderRetrieve,
+    /// Vault migration flow
+    VaultMigration,
     /// Gsm Rule Creation flow
     GsmRuleCreate,
     /// Gsm Rule Retrieve flow
@@ -402,14 +422,32 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// List incoming webhooks which could not be processed
+    IncomingWebhookDeadLetterList,
+    /// Retrieve an incoming webhook which could not be processed
+    IncomingWebhookDeadLetterRetrieve,
+    /// Replay an incoming webhook which could not be processed
+    IncomingWebhookDeadLetterReplay,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
     ToggleConnectorAgnosticMit,
+    /// Reveal the webhook signing secret of a business profile
+    WebhookSecretRetrieve,
+    /// Rotate the webhook signing secret of a business profile
+    WebhookSecretRotate,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
+    /// Verify a card without creating a payment
+    PaymentsCardVerification,
+    /// Get the timeline of events of a payment
+    PaymentsTimeline,
+    /// Create a bulk operation job from an uploaded file
+    BulkOperationsCreate,
+    /// Retrieve the status of a bulk operation job
+    BulkOperationsRetrieve,
 }
 
 ///
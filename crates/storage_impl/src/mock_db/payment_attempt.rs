@@ -80,6 +80,30 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn find_payment_attempt_by_merchant_id_acquirer_reference_number(
+        &self,
+        merchant_id: &str,
+        acquirer_reference_number: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<PaymentAttempt, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && payment_attempt.acquirer_reference_number.as_deref()
+                        == Some(acquirer_reference_number)
+            })
+            .cloned()
+            .ok_or(
+                StorageError::ValueNotFound(format!(
+                    "No payment attempt found for acquirer reference number {acquirer_reference_number}"
+                ))
+                .into(),
+            )
+    }
+
     async fn find_attempts_by_merchant_id_payment_id(
         &self,
         _merchant_id: &str,
@@ -90,6 +114,19 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn find_attempts_by_merchant_id_amount_currency_time_range(
+        &self,
+        _merchant_id: &str,
+        _amount: i64,
+        _currency: storage_enums::Currency,
+        _start_time: time::PrimitiveDateTime,
+        _end_time: time::PrimitiveDateTime,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     #[allow(clippy::panic)]
     async fn insert_payment_attempt(
         &self,
@@ -157,6 +194,12 @@ impl PaymentAttemptInterface for MockDb {
             mandate_data: payment_attempt.mandate_data,
             payment_method_billing_address_id: payment_attempt.payment_method_billing_address_id,
             fingerprint_id: payment_attempt.fingerprint_id,
+            authorization_expires_at: payment_attempt.authorization_expires_at,
+            card_verification_results: payment_attempt.card_verification_results,
+            authentication_details: payment_attempt.authentication_details,
+            is_split_tender: payment_attempt.is_split_tender,
+            acquirer_reference_number: payment_attempt.acquirer_reference_number,
+            authorized_at: payment_attempt.authorized_at,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -108,6 +108,8 @@ impl PaymentIntentInterface for MockDb {
             fingerprint_id: new.fingerprint_id,
             session_expiry: new.session_expiry,
             request_external_three_ds_authentication: new.request_external_three_ds_authentication,
+            allow_partial_authorization: new.allow_partial_authorization,
+            l2_l3_data: new.l2_l3_data,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
@@ -153,6 +155,32 @@ impl PaymentIntentInterface for MockDb {
             .unwrap())
     }
 
+    async fn filter_payment_intents_by_profile_id_status_created_before(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        statuses: &[storage_enums::IntentStatus],
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+        let mut stale_payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.profile_id.as_deref() == Some(profile_id)
+                    && statuses.contains(&payment_intent.status)
+                    && payment_intent.created_at <= created_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        stale_payment_intents.sort_by_key(|payment_intent| payment_intent.created_at);
+        stale_payment_intents.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(stale_payment_intents)
+    }
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
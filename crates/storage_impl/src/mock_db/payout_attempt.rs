@@ -45,6 +45,16 @@ impl PayoutAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        _merchant_id: &str,
+        _connector_payout_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<PayoutAttempt, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     async fn get_filters_for_payouts(
         &self,
         _payouts: &[Payouts],
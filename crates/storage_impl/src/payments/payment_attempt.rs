@@ -2,8 +2,9 @@ use api_models::enums::{AuthenticationType, Connector, PaymentMethod, PaymentMet
 use common_utils::{errors::CustomResult, fallback_reverse_lookup_not_found};
 use diesel_models::{
     enums::{
-        MandateAmountData as DieselMandateAmountData, MandateDataType as DieselMandateType,
-        MandateDetails as DieselMandateDetails, MerchantStorageScheme,
+        Currency, MandateAmountData as DieselMandateAmountData,
+        MandateDataType as DieselMandateType, MandateDetails as DieselMandateDetails,
+        MerchantStorageScheme,
     },
     kv,
     payment_attempt::{
@@ -26,6 +27,7 @@ use hyperswitch_domain_models::{
 };
 use redis_interface::HsetnxReply;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use crate::{
     diesel_error_to_data_error,
@@ -265,6 +267,58 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             })
     }
 
+    #[instrument(skip_all)]
+    async fn find_payment_attempt_by_merchant_id_acquirer_reference_number(
+        &self,
+        merchant_id: &str,
+        acquirer_reference_number: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<PaymentAttempt, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::find_by_merchant_id_acquirer_reference_number(
+            &conn,
+            merchant_id,
+            acquirer_reference_number,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(PaymentAttempt::from_storage_model)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_attempts_by_merchant_id_amount_currency_time_range(
+        &self,
+        merchant_id: &str,
+        amount: i64,
+        currency: Currency,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::find_by_merchant_id_amount_currency_created_at_range(
+            &conn,
+            merchant_id,
+            amount,
+            currency,
+            start_time,
+            end_time,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|a| {
+            a.into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect()
+        })
+    }
+
     #[instrument(skip_all)]
     async fn find_payment_attempt_by_attempt_id_merchant_id(
         &self,
@@ -410,6 +464,12 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         .payment_method_billing_address_id
                         .clone(),
                     fingerprint_id: payment_attempt.fingerprint_id.clone(),
+                    authorization_expires_at: payment_attempt.authorization_expires_at,
+                    card_verification_results: payment_attempt.card_verification_results.clone(),
+                    authentication_details: payment_attempt.authentication_details.clone(),
+                    is_split_tender: payment_attempt.is_split_tender,
+                    acquirer_reference_number: payment_attempt.acquirer_reference_number.clone(),
+                    authorized_at: payment_attempt.authorized_at,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1002,6 +1062,46 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn find_payment_attempt_by_merchant_id_acquirer_reference_number(
+        &self,
+        merchant_id: &str,
+        acquirer_reference_number: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<PaymentAttempt, errors::StorageError> {
+        // There is no KV lookup by acquirer reference number, hence the database is always queried
+        self.router_store
+            .find_payment_attempt_by_merchant_id_acquirer_reference_number(
+                merchant_id,
+                acquirer_reference_number,
+                storage_scheme,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_attempts_by_merchant_id_amount_currency_time_range(
+        &self,
+        merchant_id: &str,
+        amount: i64,
+        currency: Currency,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError> {
+        // There is no KV lookup by amount, hence the database is always queried
+        self.router_store
+            .find_attempts_by_merchant_id_amount_currency_time_range(
+                merchant_id,
+                amount,
+                currency,
+                start_time,
+                end_time,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payments(
         &self,
@@ -1164,6 +1264,12 @@ impl DataModelExt for PaymentAttempt {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            authorization_expires_at: self.authorization_expires_at,
+            card_verification_results: self.card_verification_results,
+            authentication_details: self.authentication_details,
+            is_split_tender: self.is_split_tender,
+            acquirer_reference_number: self.acquirer_reference_number,
+            authorized_at: self.authorized_at,
         }
     }
 
@@ -1228,6 +1334,12 @@ impl DataModelExt for PaymentAttempt {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            authorization_expires_at: storage_model.authorization_expires_at,
+            card_verification_results: storage_model.card_verification_results,
+            authentication_details: storage_model.authentication_details,
+            is_split_tender: storage_model.is_split_tender,
+            acquirer_reference_number: storage_model.acquirer_reference_number,
+            authorized_at: storage_model.authorized_at,
         }
     }
 }
@@ -1290,6 +1402,12 @@ impl DataModelExt for PaymentAttemptNew {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            authorization_expires_at: self.authorization_expires_at,
+            card_verification_results: self.card_verification_results,
+            authentication_details: self.authentication_details,
+            is_split_tender: self.is_split_tender,
+            acquirer_reference_number: self.acquirer_reference_number,
+            authorized_at: self.authorized_at,
         }
     }
 
@@ -1352,6 +1470,12 @@ impl DataModelExt for PaymentAttemptNew {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            authorization_expires_at: storage_model.authorization_expires_at,
+            card_verification_results: storage_model.card_verification_results,
+            authentication_details: storage_model.authentication_details,
+            is_split_tender: storage_model.is_split_tender,
+            acquirer_reference_number: storage_model.acquirer_reference_number,
+            authorized_at: storage_model.authorized_at,
         }
     }
 }
@@ -1374,6 +1498,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 business_sub_label,
                 amount_to_capture,
                 capture_method,
+                capture_on,
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
@@ -1391,6 +1516,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 business_sub_label,
                 amount_to_capture,
                 capture_method,
+                capture_on,
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
@@ -1468,6 +1594,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_connector,
                 authentication_id,
                 payment_method_billing_address_id,
+                is_split_tender,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount,
                 currency,
@@ -1496,6 +1623,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_connector,
                 authentication_id,
                 payment_method_billing_address_id,
+                is_split_tender,
             },
             Self::VoidUpdate {
                 status,
@@ -1520,12 +1648,18 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
+                is_split_tender,
+                acquirer_reference_number,
+                authorized_at,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
                 connector,
@@ -1540,12 +1674,18 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
+                is_split_tender,
+                acquirer_reference_number,
+                authorized_at,
             },
             Self::UnresolvedResponseUpdate {
                 status,
@@ -1583,6 +1723,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
             } => DieselPaymentAttemptUpdate::ErrorUpdate {
                 connector,
                 status,
@@ -1595,6 +1737,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
             },
             Self::CaptureUpdate {
                 multiple_capture_count,
@@ -1675,6 +1819,22 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_id,
                 updated_by,
             },
+            Self::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by,
+            },
+            Self::CaptureScheduleUpdate {
+                capture_method,
+                capture_on,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::CaptureScheduleUpdate {
+                capture_method,
+                capture_on,
+                updated_by,
+            },
         }
     }
 
@@ -1693,6 +1853,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 business_sub_label,
                 amount_to_capture,
                 capture_method,
+                capture_on,
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
@@ -1710,6 +1871,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 business_sub_label,
                 amount_to_capture,
                 capture_method,
+                capture_on,
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
@@ -1769,6 +1931,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_connector,
                 authentication_id,
                 payment_method_billing_address_id,
+                is_split_tender,
             } => Self::ConfirmUpdate {
                 amount,
                 currency,
@@ -1797,6 +1960,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_connector,
                 authentication_id,
                 payment_method_billing_address_id,
+                is_split_tender,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -1839,12 +2003,18 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
+                is_split_tender,
+                acquirer_reference_number,
+                authorized_at,
             } => Self::ResponseUpdate {
                 status,
                 connector,
@@ -1859,12 +2029,18 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
+                is_split_tender,
+                acquirer_reference_number,
+                authorized_at,
             },
             DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -1902,6 +2078,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
             } => Self::ErrorUpdate {
                 connector,
                 status,
@@ -1914,6 +2092,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                card_verification_results,
+                authentication_details,
             },
             DieselPaymentAttemptUpdate::CaptureUpdate {
                 amount_to_capture,
@@ -1994,6 +2174,22 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_id,
                 updated_by,
             },
+            DieselPaymentAttemptUpdate::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by,
+            } => Self::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by,
+            },
+            DieselPaymentAttemptUpdate::CaptureScheduleUpdate {
+                capture_method,
+                capture_on,
+                updated_by,
+            } => Self::CaptureScheduleUpdate {
+                capture_method,
+                capture_on,
+                updated_by,
+            },
         }
     }
 }
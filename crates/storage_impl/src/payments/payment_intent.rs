@@ -8,7 +8,7 @@ use common_utils::{date_time, ext_traits::Encode};
 #[cfg(feature = "olap")]
 use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
 use diesel_models::{
-    enums::MerchantStorageScheme,
+    enums::{self as storage_enums, MerchantStorageScheme},
     kv,
     payment_attempt::PaymentAttempt as DieselPaymentAttempt,
     payment_intent::{
@@ -115,6 +115,8 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     session_expiry: new.session_expiry,
                     request_external_three_ds_authentication: new
                         .request_external_three_ds_authentication,
+                    allow_partial_authorization: new.allow_partial_authorization,
+                    l2_l3_data: new.l2_l3_data.clone(),
                 };
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Insert {
@@ -251,6 +253,27 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
         .map(PaymentIntent::from_storage_model)
     }
 
+    async fn filter_payment_intents_by_profile_id_status_created_before(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        statuses: &[storage_enums::IntentStatus],
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        self.router_store
+            .filter_payment_intents_by_profile_id_status_created_before(
+                merchant_id,
+                profile_id,
+                statuses,
+                created_before,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
@@ -389,6 +412,38 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             })
     }
 
+    #[instrument(skip_all)]
+    async fn filter_payment_intents_by_profile_id_status_created_before(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        statuses: &[storage_enums::IntentStatus],
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentIntent::filter_by_merchant_id_profile_id_status_created_before(
+            &conn,
+            merchant_id,
+            profile_id,
+            statuses,
+            created_before,
+            limit,
+        )
+        .await
+        .map(|payment_intents| {
+            payment_intents
+                .into_iter()
+                .map(PaymentIntent::from_storage_model)
+                .collect()
+        })
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
     #[instrument(skip_all)]
     async fn get_active_payment_attempt(
         &self,
@@ -837,6 +892,8 @@ impl DataModelExt for PaymentIntentNew {
             fingerprint_id: self.fingerprint_id,
             session_expiry: self.session_expiry,
             request_external_three_ds_authentication: self.request_external_three_ds_authentication,
+            allow_partial_authorization: self.allow_partial_authorization,
+            l2_l3_data: self.l2_l3_data,
         }
     }
 
@@ -884,6 +941,8 @@ impl DataModelExt for PaymentIntentNew {
             session_expiry: storage_model.session_expiry,
             request_external_three_ds_authentication: storage_model
                 .request_external_three_ds_authentication,
+            allow_partial_authorization: storage_model.allow_partial_authorization,
+            l2_l3_data: storage_model.l2_l3_data,
         }
     }
 }
@@ -935,6 +994,8 @@ impl DataModelExt for PaymentIntent {
             fingerprint_id: self.fingerprint_id,
             session_expiry: self.session_expiry,
             request_external_three_ds_authentication: self.request_external_three_ds_authentication,
+            allow_partial_authorization: self.allow_partial_authorization,
+            l2_l3_data: self.l2_l3_data,
         }
     }
 
@@ -983,6 +1044,8 @@ impl DataModelExt for PaymentIntent {
             session_expiry: storage_model.session_expiry,
             request_external_three_ds_authentication: storage_model
                 .request_external_three_ds_authentication,
+            allow_partial_authorization: storage_model.allow_partial_authorization,
+            l2_l3_data: storage_model.l2_l3_data,
         }
     }
 }
@@ -1070,6 +1133,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 fingerprint_id,
                 session_expiry,
                 request_external_three_ds_authentication,
+                l2_l3_data,
             } => DieselPaymentIntentUpdate::Update {
                 amount,
                 currency,
@@ -1091,6 +1155,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 fingerprint_id,
                 session_expiry,
                 request_external_three_ds_authentication,
+                l2_l3_data,
             },
             Self::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -173,6 +173,24 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     },
                 };
 
+                // Reverse lookup for connector_payout_id, used for processing incoming webhooks
+                if !diesel_payout.connector_payout_id.is_empty()
+                    && diesel_payout.connector_payout_id != origin_diesel_payout.connector_payout_id
+                {
+                    let reverse_lookup = ReverseLookupNew {
+                        lookup_id: format!(
+                            "po_conn_payout_{}_{}",
+                            &diesel_payout.merchant_id, &diesel_payout.connector_payout_id,
+                        ),
+                        pk_id: key_str.clone(),
+                        sk_id: field.clone(),
+                        source: "payout_attempt".to_string(),
+                        updated_by: storage_scheme.to_string(),
+                    };
+                    self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                        .await?;
+                }
+
                 kv_wrapper::<(), _, _>(
                     self,
                     KvOperation::<DieselPayoutAttempt>::Hset((&field, redis_value), redis_entry),
@@ -246,6 +264,64 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<PayoutAttempt, errors::StorageError> {
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .find_payout_attempt_by_merchant_id_connector_payout_id(
+                        merchant_id,
+                        connector_payout_id,
+                        storage_scheme,
+                    )
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                let lookup_id = format!("po_conn_payout_{merchant_id}_{connector_payout_id}");
+                let lookup = fallback_reverse_lookup_not_found!(
+                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await,
+                    self.router_store
+                        .find_payout_attempt_by_merchant_id_connector_payout_id(
+                            merchant_id,
+                            connector_payout_id,
+                            storage_scheme,
+                        )
+                        .await
+                );
+                let key = PartitionKey::CombinationKey {
+                    combination: &lookup.pk_id,
+                };
+                Box::pin(utils::try_redis_get_else_try_database_get(
+                    async {
+                        kv_wrapper(
+                            self,
+                            KvOperation::<DieselPayoutAttempt>::HGet(&lookup.sk_id),
+                            key,
+                        )
+                        .await?
+                        .try_into_hget()
+                    },
+                    || async {
+                        self.router_store
+                            .find_payout_attempt_by_merchant_id_connector_payout_id(
+                                merchant_id,
+                                connector_payout_id,
+                                storage_scheme,
+                            )
+                            .await
+                    },
+                ))
+                .await
+            }
+        }
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
@@ -320,6 +396,27 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         })
     }
 
+    #[instrument(skip_all)]
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<PayoutAttempt, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayoutAttempt::find_by_merchant_id_connector_payout_id(
+            &conn,
+            merchant_id,
+            connector_payout_id,
+        )
+        .await
+        .map(PayoutAttempt::from_storage_model)
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
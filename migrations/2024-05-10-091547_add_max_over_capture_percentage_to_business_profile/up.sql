@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS max_over_capture_percentage SMALLINT DEFAULT NULL;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "RefundStatus" ADD VALUE IF NOT EXISTS 'pending_approval';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS refund_approval_policy;
+
+ALTER TABLE refund DROP COLUMN IF EXISTS approval_details;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE refund ADD COLUMN IF NOT EXISTS approval_details JSONB DEFAULT NULL;
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS refund_approval_policy JSONB DEFAULT NULL;
@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS dispute_alert (
+    id SERIAL PRIMARY KEY,
+    alert_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    provider VARCHAR(32) NOT NULL,
+    provider_alert_id VARCHAR(255) NOT NULL,
+    arn VARCHAR(64),
+    card_last4 VARCHAR(4),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    transaction_date TIMESTAMP NOT NULL,
+    reason VARCHAR(255),
+    payment_id VARCHAR(64),
+    attempt_id VARCHAR(64),
+    profile_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    outcome VARCHAR(32),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS dispute_alert_merchant_id_alert_id_index ON dispute_alert (merchant_id, alert_id);
+CREATE UNIQUE INDEX IF NOT EXISTS dispute_alert_merchant_id_provider_alert_id_index ON dispute_alert (merchant_id, provider, provider_alert_id);
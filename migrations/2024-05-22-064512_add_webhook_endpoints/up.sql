@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS webhook_endpoints JSONB;
+
+ALTER TABLE events ADD COLUMN IF NOT EXISTS webhook_endpoint_id VARCHAR(32);
+
+-- Idempotent event IDs of deliveries to webhook endpoints are suffixed with the endpoint ID
+ALTER TABLE events ALTER COLUMN idempotent_event_id TYPE VARCHAR(128);
@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS incoming_webhook_dead_letter (
+    dead_letter_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    body_hash VARCHAR(64) NOT NULL,
+    request BYTEA NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    error_code VARCHAR(64) NOT NULL,
+    error_message TEXT NOT NULL,
+    error_reason TEXT,
+    failure_count INTEGER NOT NULL DEFAULT 1,
+    replay_count INTEGER NOT NULL DEFAULT 0,
+    last_replayed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+-- Webhooks delivered again by the connector are recorded against the same entry
+CREATE UNIQUE INDEX IF NOT EXISTS incoming_webhook_dead_letter_merchant_id_connector_body_hash_index ON incoming_webhook_dead_letter (merchant_id, connector, body_hash);
+
+CREATE INDEX IF NOT EXISTS incoming_webhook_dead_letter_merchant_id_created_at_index ON incoming_webhook_dead_letter (merchant_id, created_at);
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payouts';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payout_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_success';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_failed';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_processing';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_cancelled';
+
+CREATE INDEX IF NOT EXISTS payout_attempt_merchant_id_connector_payout_id_index ON payout_attempt (merchant_id, connector_payout_id);
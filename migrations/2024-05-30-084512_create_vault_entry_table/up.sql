@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS vault_entry (
+    merchant_id VARCHAR(64) NOT NULL,
+    vault_reference VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    fingerprint VARCHAR(128),
+    enc_data BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, vault_reference)
+);
+
+-- Used to resolve cards stored again for the same customer to the existing entry. Not unique, since
+-- cards moved from another vault keep their references, which may include duplicates.
+CREATE INDEX IF NOT EXISTS vault_entry_merchant_id_customer_id_fingerprint_index ON vault_entry (merchant_id, customer_id, fingerprint);
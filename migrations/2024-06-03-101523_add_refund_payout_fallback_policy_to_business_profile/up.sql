@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS refund_payout_fallback_policy JSONB DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_attempt_merchant_id_amount_currency_created_at_index;
+
+DROP INDEX IF EXISTS payment_attempt_acquirer_reference_number_merchant_id_index;
+
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS acquirer_reference_number;
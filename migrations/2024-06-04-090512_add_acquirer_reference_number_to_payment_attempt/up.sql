@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS acquirer_reference_number VARCHAR(64) DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_attempt_acquirer_reference_number_merchant_id_index ON payment_attempt (acquirer_reference_number, merchant_id);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_amount_currency_created_at_index ON payment_attempt (merchant_id, amount, currency, created_at);